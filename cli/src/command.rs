@@ -0,0 +1,596 @@
+use std::path::PathBuf;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anyhow::{anyhow, bail, Result};
+use clap::Subcommand;
+use pool::state::{ElysiumPool, NUM_REWARDS};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    system_program, sysvar,
+};
+
+use crate::{
+    display::{fetch_account, WatchedAccount},
+    rpc::RpcClient,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print the state of a ElysiumPoolsConfig
+    ShowConfig { config: Pubkey },
+    /// Print the state of a FeeTier
+    ShowFeeTier { fee_tier: Pubkey },
+    /// Print the state of a ElysiumPool
+    ShowPool { pool: Pubkey },
+    /// Decode program accounts saved to files, as raw data or `solana account --output json`,
+    /// and print them as JSON
+    Inspect {
+        #[clap(required = true)]
+        files: Vec<PathBuf>,
+        /// Decimals of token A, to derive the decimal adjusted price of a pool
+        #[clap(long, requires = "decimals-b")]
+        decimals_a: Option<u8>,
+        /// Decimals of token B, to derive the decimal adjusted price of a pool
+        #[clap(long, requires = "decimals-a")]
+        decimals_b: Option<u8>,
+        /// Tick spacing of the pool, to derive the tick indexes of a tick array
+        #[clap(long)]
+        tick_spacing: Option<u16>,
+    },
+    /// Initialize a ElysiumPoolsConfig at the address of a new keypair
+    InitializeConfig {
+        /// Keypair file of the new config account
+        #[clap(long)]
+        config_keypair: PathBuf,
+        #[clap(long)]
+        fee_authority: Pubkey,
+        #[clap(long)]
+        collect_protocol_fees_authority: Pubkey,
+        #[clap(long)]
+        reward_emissions_super_authority: Pubkey,
+        #[clap(long)]
+        default_protocol_fee_rate: u16,
+    },
+    /// Initialize a FeeTier, keyed by its tick spacing unless a fee tier index is provided
+    InitializeFeeTier {
+        #[clap(long)]
+        config: Pubkey,
+        #[clap(long)]
+        tick_spacing: u16,
+        #[clap(long)]
+        default_fee_rate: u16,
+        #[clap(long)]
+        fee_tier_index: Option<u16>,
+    },
+    /// Set the default fee rate of a FeeTier
+    SetDefaultFeeRate {
+        #[clap(long)]
+        fee_tier: Pubkey,
+        #[clap(long)]
+        default_fee_rate: u16,
+    },
+    /// Set the default protocol fee rate of a ElysiumPoolsConfig
+    SetDefaultProtocolFeeRate {
+        #[clap(long)]
+        config: Pubkey,
+        #[clap(long)]
+        default_protocol_fee_rate: u16,
+    },
+    /// Set the fee rate of a ElysiumPool
+    SetFeeRate {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        fee_rate: u16,
+    },
+    /// Set the protocol fee rate of a ElysiumPool
+    SetProtocolFeeRate {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        protocol_fee_rate: u16,
+    },
+    /// Set the fee authority of a ElysiumPoolsConfig
+    SetFeeAuthority {
+        #[clap(long)]
+        config: Pubkey,
+        #[clap(long)]
+        new_authority: Pubkey,
+    },
+    /// Set the collect protocol fees authority of a ElysiumPoolsConfig
+    SetCollectProtocolFeesAuthority {
+        #[clap(long)]
+        config: Pubkey,
+        #[clap(long)]
+        new_authority: Pubkey,
+    },
+    /// Set the reward emissions super authority of a ElysiumPoolsConfig
+    SetRewardEmissionsSuperAuthority {
+        #[clap(long)]
+        config: Pubkey,
+        #[clap(long)]
+        new_authority: Pubkey,
+    },
+    /// Set the authority of a reward of a ElysiumPool
+    SetRewardAuthority {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        reward_index: u8,
+        #[clap(long)]
+        new_authority: Pubkey,
+        /// Sign as the reward emissions super authority instead of the reward authority
+        #[clap(long)]
+        by_super_authority: bool,
+    },
+    /// Initialize a reward of a ElysiumPool with a new reward vault
+    InitializeReward {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        reward_index: u8,
+        #[clap(long)]
+        reward_mint: Pubkey,
+        /// Keypair file of the new reward vault token account
+        #[clap(long)]
+        vault_keypair: PathBuf,
+    },
+    /// Set the emissions of a reward of a ElysiumPool
+    SetRewardEmissions {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        reward_index: u8,
+        /// Q64.64 amount of reward tokens emitted per second per unit of liquidity
+        #[clap(long)]
+        emissions_per_second_x64: u128,
+    },
+}
+
+/// A transaction to send on behalf of the authority.
+pub struct Operation {
+    pub instruction: Instruction,
+    /// Keypairs of new accounts that have to sign besides the authority
+    pub signers: Vec<Keypair>,
+    pub watched: Vec<WatchedAccount>,
+}
+
+impl Command {
+    /// Returns the account to print for read-only commands.
+    pub fn shown_account(&self) -> Option<WatchedAccount> {
+        match self {
+            Command::ShowConfig { config } => Some(WatchedAccount::Config(*config)),
+            Command::ShowFeeTier { fee_tier } => Some(WatchedAccount::FeeTier(*fee_tier)),
+            Command::ShowPool { pool } => Some(WatchedAccount::Pool(*pool)),
+            _ => None,
+        }
+    }
+
+    /// Builds the instruction of an admin command. The authority also funds new accounts.
+    pub fn build_operation(&self, rpc: &RpcClient, authority: Pubkey) -> Result<Operation> {
+        let operation = match self {
+            Command::ShowConfig { .. }
+            | Command::ShowFeeTier { .. }
+            | Command::ShowPool { .. }
+            | Command::Inspect { .. } => {
+                bail!("{:?} does not send a transaction", self)
+            }
+            Command::InitializeConfig {
+                config_keypair,
+                fee_authority,
+                collect_protocol_fees_authority,
+                reward_emissions_super_authority,
+                default_protocol_fee_rate,
+            } => {
+                let config_keypair = read_keypair(config_keypair)?;
+                let config = config_keypair.pubkey();
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::InitializeConfig {
+                            config,
+                            funder: authority,
+                            system_program: system_program::ID,
+                        },
+                        pool::instruction::InitializeConfig {
+                            fee_authority: *fee_authority,
+                            collect_protocol_fees_authority: *collect_protocol_fees_authority,
+                            reward_emissions_super_authority: *reward_emissions_super_authority,
+                            default_protocol_fee_rate: *default_protocol_fee_rate,
+                        },
+                    ),
+                    signers: vec![config_keypair],
+                    watched: vec![WatchedAccount::Config(config)],
+                }
+            }
+            Command::InitializeFeeTier {
+                config,
+                tick_spacing,
+                default_fee_rate,
+                fee_tier_index,
+            } => {
+                let fee_tier = fee_tier_address(config, fee_tier_index.unwrap_or(*tick_spacing));
+                let instruction = match fee_tier_index {
+                    Some(fee_tier_index) => instruction(
+                        pool::accounts::InitializeFeeTierWithIndex {
+                            config: *config,
+                            fee_tier,
+                            funder: authority,
+                            fee_authority: authority,
+                            system_program: system_program::ID,
+                        },
+                        pool::instruction::InitializeFeeTierWithIndex {
+                            fee_tier_index: *fee_tier_index,
+                            tick_spacing: *tick_spacing,
+                            default_fee_rate: *default_fee_rate,
+                        },
+                    ),
+                    None => instruction(
+                        pool::accounts::InitializeFeeTier {
+                            config: *config,
+                            fee_tier,
+                            funder: authority,
+                            fee_authority: authority,
+                            system_program: system_program::ID,
+                        },
+                        pool::instruction::InitializeFeeTier {
+                            tick_spacing: *tick_spacing,
+                            default_fee_rate: *default_fee_rate,
+                        },
+                    ),
+                };
+                Operation {
+                    instruction,
+                    signers: vec![],
+                    watched: vec![WatchedAccount::FeeTier(fee_tier)],
+                }
+            }
+            Command::SetDefaultFeeRate {
+                fee_tier,
+                default_fee_rate,
+            } => {
+                let pools_config =
+                    fetch_account::<pool::state::FeeTier>(rpc, fee_tier)?.pools_config;
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::SetDefaultFeeRate {
+                            pools_config,
+                            fee_tier: *fee_tier,
+                            fee_authority: authority,
+                        },
+                        pool::instruction::SetDefaultFeeRate {
+                            default_fee_rate: *default_fee_rate,
+                        },
+                    ),
+                    signers: vec![],
+                    watched: vec![WatchedAccount::FeeTier(*fee_tier)],
+                }
+            }
+            Command::SetDefaultProtocolFeeRate {
+                config,
+                default_protocol_fee_rate,
+            } => Operation {
+                instruction: instruction(
+                    pool::accounts::SetDefaultProtocolFeeRate {
+                        pools_config: *config,
+                        fee_authority: authority,
+                    },
+                    pool::instruction::SetDefaultProtocolFeeRate {
+                        default_protocol_fee_rate: *default_protocol_fee_rate,
+                    },
+                ),
+                signers: vec![],
+                watched: vec![WatchedAccount::Config(*config)],
+            },
+            Command::SetFeeRate {
+                pool: pool_address,
+                fee_rate,
+            } => {
+                let pools_config = fetch_account::<ElysiumPool>(rpc, pool_address)?.pools_config;
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::SetFeeRate {
+                            pools_config,
+                            pool: *pool_address,
+                            fee_authority: authority,
+                        },
+                        pool::instruction::SetFeeRate {
+                            fee_rate: *fee_rate,
+                        },
+                    ),
+                    signers: vec![],
+                    watched: vec![WatchedAccount::Pool(*pool_address)],
+                }
+            }
+            Command::SetProtocolFeeRate {
+                pool: pool_address,
+                protocol_fee_rate,
+            } => {
+                let pools_config = fetch_account::<ElysiumPool>(rpc, pool_address)?.pools_config;
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::SetProtocolFeeRate {
+                            pools_config,
+                            pool: *pool_address,
+                            fee_authority: authority,
+                        },
+                        pool::instruction::SetProtocolFeeRate {
+                            protocol_fee_rate: *protocol_fee_rate,
+                        },
+                    ),
+                    signers: vec![],
+                    watched: vec![WatchedAccount::Pool(*pool_address)],
+                }
+            }
+            Command::SetFeeAuthority {
+                config,
+                new_authority,
+            } => Operation {
+                instruction: instruction(
+                    pool::accounts::SetFeeAuthority {
+                        pools_config: *config,
+                        fee_authority: authority,
+                        new_fee_authority: *new_authority,
+                    },
+                    pool::instruction::SetFeeAuthority {},
+                ),
+                signers: vec![],
+                watched: vec![WatchedAccount::Config(*config)],
+            },
+            Command::SetCollectProtocolFeesAuthority {
+                config,
+                new_authority,
+            } => Operation {
+                instruction: instruction(
+                    pool::accounts::SetCollectProtocolFeesAuthority {
+                        pools_config: *config,
+                        collect_protocol_fees_authority: authority,
+                        new_collect_protocol_fees_authority: *new_authority,
+                    },
+                    pool::instruction::SetCollectProtocolFeesAuthority {},
+                ),
+                signers: vec![],
+                watched: vec![WatchedAccount::Config(*config)],
+            },
+            Command::SetRewardEmissionsSuperAuthority {
+                config,
+                new_authority,
+            } => Operation {
+                instruction: instruction(
+                    pool::accounts::SetRewardEmissionsSuperAuthority {
+                        pools_config: *config,
+                        reward_emissions_super_authority: authority,
+                        new_reward_emissions_super_authority: *new_authority,
+                    },
+                    pool::instruction::SetRewardEmissionsSuperAuthority {},
+                ),
+                signers: vec![],
+                watched: vec![WatchedAccount::Config(*config)],
+            },
+            Command::SetRewardAuthority {
+                pool: pool_address,
+                reward_index,
+                new_authority,
+                by_super_authority,
+            } => {
+                verify_reward_index(*reward_index)?;
+                let instruction = if *by_super_authority {
+                    let pools_config =
+                        fetch_account::<ElysiumPool>(rpc, pool_address)?.pools_config;
+                    instruction(
+                        pool::accounts::SetRewardAuthorityBySuperAuthority {
+                            pools_config,
+                            pool: *pool_address,
+                            reward_emissions_super_authority: authority,
+                            new_reward_authority: *new_authority,
+                        },
+                        pool::instruction::SetRewardAuthorityBySuperAuthority {
+                            reward_index: *reward_index,
+                        },
+                    )
+                } else {
+                    instruction(
+                        pool::accounts::SetRewardAuthority {
+                            pool: *pool_address,
+                            reward_authority: authority,
+                            new_reward_authority: *new_authority,
+                        },
+                        pool::instruction::SetRewardAuthority {
+                            reward_index: *reward_index,
+                        },
+                    )
+                };
+                Operation {
+                    instruction,
+                    signers: vec![],
+                    watched: vec![WatchedAccount::Pool(*pool_address)],
+                }
+            }
+            Command::InitializeReward {
+                pool: pool_address,
+                reward_index,
+                reward_mint,
+                vault_keypair,
+            } => {
+                verify_reward_index(*reward_index)?;
+                let vault_keypair = read_keypair(vault_keypair)?;
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::InitializeReward {
+                            reward_authority: authority,
+                            funder: authority,
+                            pool: *pool_address,
+                            reward_mint: *reward_mint,
+                            reward_vault: vault_keypair.pubkey(),
+                            token_program: spl_token::ID,
+                            system_program: system_program::ID,
+                            rent: sysvar::rent::ID,
+                        },
+                        pool::instruction::InitializeReward {
+                            reward_index: *reward_index,
+                        },
+                    ),
+                    signers: vec![vault_keypair],
+                    watched: vec![WatchedAccount::Pool(*pool_address)],
+                }
+            }
+            Command::SetRewardEmissions {
+                pool: pool_address,
+                reward_index,
+                emissions_per_second_x64,
+            } => {
+                verify_reward_index(*reward_index)?;
+                let reward_vault = fetch_account::<ElysiumPool>(rpc, pool_address)?.reward_infos
+                    [*reward_index as usize]
+                    .vault;
+                Operation {
+                    instruction: instruction(
+                        pool::accounts::SetRewardEmissions {
+                            pool: *pool_address,
+                            reward_authority: authority,
+                            reward_vault,
+                        },
+                        pool::instruction::SetRewardEmissions {
+                            reward_index: *reward_index,
+                            emissions_per_second_x64: *emissions_per_second_x64,
+                        },
+                    ),
+                    signers: vec![],
+                    watched: vec![WatchedAccount::Pool(*pool_address)],
+                }
+            }
+        };
+        Ok(operation)
+    }
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: pool::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Address of the FeeTier keyed by a fee tier index, or by the tick spacing for fee tiers
+/// initialized without an index.
+pub fn fee_tier_address(config: &Pubkey, fee_tier_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"fee_tier",
+            config.as_ref(),
+            fee_tier_index.to_le_bytes().as_ref(),
+        ],
+        &pool::ID,
+    )
+    .0
+}
+
+fn verify_reward_index(reward_index: u8) -> Result<()> {
+    if reward_index as usize >= NUM_REWARDS {
+        bail!(
+            "reward index {} is out of range, pools have {} rewards",
+            reward_index,
+            NUM_REWARDS
+        );
+    }
+    Ok(())
+}
+
+pub fn read_keypair(path: &PathBuf) -> Result<Keypair> {
+    read_keypair_file(path).map_err(|err| anyhow!("failed to read keypair {:?}: {}", path, err))
+}
+
+#[cfg(test)]
+mod command_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_set_fee_authority_operation() {
+        let rpc = RpcClient::new(String::new());
+        let authority = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let operation = Command::SetFeeAuthority {
+            config,
+            new_authority,
+        }
+        .build_operation(&rpc, authority)
+        .unwrap();
+
+        let instruction = operation.instruction;
+        assert_eq!(instruction.program_id, pool::ID);
+        assert_eq!(
+            instruction.data,
+            pool::instruction::SetFeeAuthority::discriminator().to_vec()
+        );
+        let keys: Vec<(Pubkey, bool, bool)> = instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey, meta.is_signer, meta.is_writable))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (config, false, true),
+                (authority, true, false),
+                (new_authority, false, false)
+            ]
+        );
+        assert!(operation.signers.is_empty());
+        assert_eq!(operation.watched, vec![WatchedAccount::Config(config)]);
+    }
+
+    #[test]
+    fn test_initialize_fee_tier_operation_uses_fee_tier_index_seed() {
+        let rpc = RpcClient::new(String::new());
+        let config = Pubkey::new_unique();
+
+        let by_tick_spacing = Command::InitializeFeeTier {
+            config,
+            tick_spacing: 64,
+            default_fee_rate: 3000,
+            fee_tier_index: None,
+        }
+        .build_operation(&rpc, Pubkey::new_unique())
+        .unwrap();
+        assert_eq!(
+            by_tick_spacing.watched,
+            vec![WatchedAccount::FeeTier(fee_tier_address(&config, 64))]
+        );
+
+        let by_index = Command::InitializeFeeTier {
+            config,
+            tick_spacing: 64,
+            default_fee_rate: 10000,
+            fee_tier_index: Some(1064),
+        }
+        .build_operation(&rpc, Pubkey::new_unique())
+        .unwrap();
+        assert_eq!(
+            by_index.watched,
+            vec![WatchedAccount::FeeTier(fee_tier_address(&config, 1064))]
+        );
+        assert_eq!(
+            by_index.instruction.accounts[1].pubkey,
+            fee_tier_address(&config, 1064)
+        );
+    }
+
+    #[test]
+    fn test_reward_index_out_of_range() {
+        let rpc = RpcClient::new(String::new());
+        let result = Command::SetRewardAuthority {
+            pool: Pubkey::new_unique(),
+            reward_index: NUM_REWARDS as u8,
+            new_authority: Pubkey::new_unique(),
+            by_super_authority: false,
+        }
+        .build_operation(&rpc, Pubkey::new_unique());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, Context, Result};
+use elysium_client::inspector::{inspect_account, parse_account_file, InspectOptions};
+use pool::state::{ElysiumPool, ElysiumPoolsConfig, FeeTier};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::rpc::RpcClient;
+
+/// An account whose state is printed before and after an operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WatchedAccount {
+    Config(Pubkey),
+    FeeTier(Pubkey),
+    Pool(Pubkey),
+}
+
+impl WatchedAccount {
+    pub fn address(&self) -> Pubkey {
+        match self {
+            WatchedAccount::Config(address)
+            | WatchedAccount::FeeTier(address)
+            | WatchedAccount::Pool(address) => *address,
+        }
+    }
+}
+
+/// Fetches and decodes an account of the program.
+pub fn fetch_account<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    let data = rpc
+        .get_account_data(address)?
+        .ok_or_else(|| anyhow!("account {} does not exist", address))?;
+    Ok(T::try_deserialize(&mut data.as_slice())?)
+}
+
+/// Prints the current state of an account, or a note if it does not exist yet.
+pub fn print_watched_account(rpc: &RpcClient, account: &WatchedAccount) -> Result<()> {
+    let address = account.address();
+    let data = match rpc.get_account_data(&address)? {
+        Some(data) => data,
+        None => {
+            println!("{} does not exist", address);
+            return Ok(());
+        }
+    };
+
+    match account {
+        WatchedAccount::Config(_) => print_config(
+            &address,
+            &ElysiumPoolsConfig::try_deserialize(&mut data.as_slice())?,
+        ),
+        WatchedAccount::FeeTier(_) => {
+            print_fee_tier(&address, &FeeTier::try_deserialize(&mut data.as_slice())?)
+        }
+        WatchedAccount::Pool(_) => print_pool(
+            &address,
+            &ElysiumPool::try_deserialize(&mut data.as_slice())?,
+        ),
+    }
+    Ok(())
+}
+
+pub fn print_config(address: &Pubkey, config: &ElysiumPoolsConfig) {
+    println!("ElysiumPoolsConfig {}", address);
+    println!("  fee_authority: {}", config.fee_authority);
+    println!(
+        "  collect_protocol_fees_authority: {}",
+        config.collect_protocol_fees_authority
+    );
+    println!(
+        "  reward_emissions_super_authority: {}",
+        config.reward_emissions_super_authority
+    );
+    println!(
+        "  default_protocol_fee_rate: {}",
+        config.default_protocol_fee_rate
+    );
+    println!("  max_creator_fee_rate: {}", config.max_creator_fee_rate);
+}
+
+pub fn print_fee_tier(address: &Pubkey, fee_tier: &FeeTier) {
+    println!("FeeTier {}", address);
+    println!("  pools_config: {}", fee_tier.pools_config);
+    println!("  tick_spacing: {}", fee_tier.tick_spacing);
+    println!("  default_fee_rate: {}", fee_tier.default_fee_rate);
+}
+
+pub fn print_pool(address: &Pubkey, pool: &ElysiumPool) {
+    println!("ElysiumPool {}", address);
+    println!("  pools_config: {}", pool.pools_config);
+    println!("  token_mint_a: {}", pool.token_mint_a);
+    println!("  token_mint_b: {}", pool.token_mint_b);
+    println!("  tick_spacing: {}", pool.tick_spacing);
+    println!("  fee_tier_index: {}", pool.fee_tier_index());
+    println!("  fee_rate: {}", pool.fee_rate);
+    println!("  protocol_fee_rate: {}", pool.protocol_fee_rate);
+    println!("  liquidity: {}", pool.liquidity);
+    println!("  sqrt_price: {}", pool.sqrt_price);
+    println!("  tick_current_index: {}", pool.tick_current_index);
+    println!("  protocol_fee_owed_a: {}", pool.protocol_fee_owed_a);
+    println!("  protocol_fee_owed_b: {}", pool.protocol_fee_owed_b);
+    for (index, reward_info) in pool.reward_infos.iter().enumerate() {
+        println!("  reward_infos[{}]:", index);
+        println!("    mint: {}", reward_info.mint);
+        println!("    vault: {}", reward_info.vault);
+        println!("    authority: {}", reward_info.authority);
+        println!(
+            "    emissions_per_second_x64: {}",
+            reward_info.emissions_per_second_x64
+        );
+        println!("    growth_global_x64: {}", reward_info.growth_global_x64);
+    }
+}
+
+/// Prints the accounts saved in files as JSON, as an array if there is more than one file.
+pub fn print_inspected_files(files: &[PathBuf], options: &InspectOptions) -> Result<()> {
+    let mut inspected = Vec::with_capacity(files.len());
+    for file in files {
+        let contents = std::fs::read(file).with_context(|| format!("failed to read {:?}", file))?;
+        let account_file =
+            parse_account_file(&contents).with_context(|| format!("failed to parse {:?}", file))?;
+        let mut account = inspect_account(&account_file.data, options)
+            .with_context(|| format!("failed to inspect {:?}", file))?;
+        account["file"] = json!(file);
+        account["pubkey"] = json!(account_file.pubkey.map(|pubkey| pubkey.to_string()));
+        inspected.push(account);
+    }
+
+    let output = match inspected.len() {
+        1 => inspected.remove(0),
+        _ => Value::Array(inspected),
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Admin CLI for the configs, fee tiers and pools of the Elysium program.
+//!
+//! Every admin command prints the state of the affected account, sends the transaction signed by
+//! the keypair and prints the state again. With `--dry-run` the unsigned transaction is printed
+//! instead, so that it can be proposed to a multisig that holds the authority. The `inspect`
+//! command decodes accounts saved to files without connecting to a cluster.
+
+mod command;
+mod display;
+mod rpc;
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use elysium_client::inspector::InspectOptions;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+use crate::{
+    command::{read_keypair, Command, Operation},
+    display::{print_inspected_files, print_watched_account},
+    rpc::RpcClient,
+};
+
+#[derive(Debug, Parser)]
+#[clap(name = "elysium-cli", version)]
+struct Cli {
+    /// Keypair file of the authority, which also pays for the transaction
+    #[clap(long, global = true)]
+    keypair: Option<PathBuf>,
+
+    /// JSON RPC URL of the cluster
+    #[clap(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Print the serialized unsigned transaction instead of sending it
+    #[clap(long, global = true)]
+    dry_run: bool,
+
+    /// Authority to build a dry-run transaction for, such as a multisig vault. Defaults to the
+    /// pubkey of the keypair.
+    #[clap(long, global = true, requires = "dry-run")]
+    authority: Option<Pubkey>,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("error: {:#}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let rpc = RpcClient::new(cli.url.clone());
+
+    if let Command::Inspect {
+        files,
+        decimals_a,
+        decimals_b,
+        tick_spacing,
+    } = &cli.command
+    {
+        let options = InspectOptions {
+            decimals_a: *decimals_a,
+            decimals_b: *decimals_b,
+            tick_spacing: *tick_spacing,
+        };
+        return print_inspected_files(files, &options);
+    }
+
+    if let Some(account) = cli.command.shown_account() {
+        return print_watched_account(&rpc, &account);
+    }
+
+    let (authority, keypair) = match cli.authority {
+        Some(authority) => (authority, None),
+        None => {
+            let keypair = read_keypair(&keypair_path(&cli)?)?;
+            (keypair.pubkey(), Some(keypair))
+        }
+    };
+
+    let operation = cli.command.build_operation(&rpc, authority)?;
+
+    println!("Before:");
+    for account in &operation.watched {
+        print_watched_account(&rpc, account)?;
+    }
+
+    let mut transaction = Transaction::new_with_payer(
+        std::slice::from_ref(&operation.instruction),
+        Some(&authority),
+    );
+    transaction.message.recent_blockhash = rpc.get_latest_blockhash()?;
+
+    if cli.dry_run {
+        print_dry_run(&transaction, &operation);
+        return Ok(());
+    }
+
+    let keypair = match keypair {
+        Some(keypair) => keypair,
+        None => bail!("a keypair is required to send a transaction"),
+    };
+    let mut signers: Vec<&Keypair> = vec![&keypair];
+    signers.extend(operation.signers.iter());
+    transaction.try_sign(&signers, transaction.message.recent_blockhash)?;
+
+    let signature = rpc.send_and_confirm_transaction(&transaction)?;
+    println!("Signature: {}", signature);
+
+    println!("After:");
+    for account in &operation.watched {
+        print_watched_account(&rpc, account)?;
+    }
+    Ok(())
+}
+
+fn print_dry_run(transaction: &Transaction, operation: &Operation) {
+    let required_signers = &transaction.message.account_keys
+        [..transaction.message.header.num_required_signatures as usize];
+
+    println!("Unsigned transaction (base64):");
+    println!(
+        "{}",
+        base64::encode(bincode::serialize(transaction).expect("transaction serializes"))
+    );
+    println!("Required signers:");
+    for signer in required_signers {
+        let is_new_account = operation
+            .signers
+            .iter()
+            .any(|keypair| keypair.pubkey() == *signer);
+        if is_new_account {
+            println!("  {} (new account keypair)", signer);
+        } else {
+            println!("  {}", signer);
+        }
+    }
+}
+
+fn keypair_path(cli: &Cli) -> Result<PathBuf> {
+    if let Some(keypair) = &cli.keypair {
+        return Ok(keypair.clone());
+    }
+    match std::env::var_os("HOME") {
+        Some(home) => Ok(PathBuf::from(home).join(".config/solana/id.json")),
+        None => bail!("--keypair is required when HOME is not set"),
+    }
+}
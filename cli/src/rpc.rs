@@ -0,0 +1,96 @@
+use std::{thread::sleep, time::Duration};
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CONFIRMATION_MAX_POLLS: usize = 120;
+
+/// Minimal JSON-RPC client covering the calls the CLI needs.
+pub struct RpcClient {
+    url: String,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = ureq::post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{} failed: {}", method, error);
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{} returned no result", method))
+    }
+
+    /// Returns the data of an account, or `None` if the account does not exist.
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+
+        match &result["value"] {
+            Value::Null => Ok(None),
+            value => {
+                let data = value["data"][0]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("account {} has no base64 data", address))?;
+                Ok(Some(base64::decode(data)?))
+            }
+        }
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("getLatestBlockhash returned no blockhash"))?;
+        Ok(blockhash.parse()?)
+    }
+
+    /// Sends a signed transaction and waits until it is confirmed.
+    pub fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature> {
+        let encoded = base64::encode(bincode::serialize(transaction)?);
+        let signature: Signature = self
+            .call(
+                "sendTransaction",
+                json!([encoded, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+            )?
+            .as_str()
+            .ok_or_else(|| anyhow!("sendTransaction returned no signature"))?
+            .parse()?;
+
+        for _ in 0..CONFIRMATION_MAX_POLLS {
+            let result = self.call("getSignatureStatuses", json!([[signature.to_string()]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {} failed: {}", signature, status["err"]);
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed") | Some("finalized")
+                ) {
+                    return Ok(signature);
+                }
+            }
+            sleep(CONFIRMATION_POLL_INTERVAL);
+        }
+
+        bail!("transaction {} was not confirmed in time", signature)
+    }
+}
@@ -0,0 +1,606 @@
+//! Rebuilds the active liquidity curve of a pool from its tick arrays, and derives the liquidity
+//! distribution per price bucket and the token amounts needed to move the price.
+
+use std::convert::TryInto;
+
+use pool::{
+    errors::ErrorCode,
+    math::{
+        add_liquidity_delta, checked_mul_div_round_up, get_amount_delta_a, get_amount_delta_b,
+        sqrt_price_from_tick_index, FEE_RATE_MUL_VALUE, MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64,
+    },
+    state::{ElysiumPool, TickArray, MAX_TICK_INDEX, MIN_TICK_INDEX, TICK_ARRAY_SIZE},
+};
+use thiserror::Error;
+
+use crate::price::price_from_sqrt_price;
+
+#[derive(Debug, Error)]
+pub enum DepthError {
+    #[error("no tick arrays were provided")]
+    NoTickArrays,
+    #[error("tick array starting at {0} is not aligned to the tick spacing of the pool")]
+    MisalignedTickArray(i32),
+    #[error("the current tick {0} is not covered by the provided tick arrays")]
+    CurrentTickNotCovered(i32),
+    #[error("bucket width must be positive")]
+    InvalidBucketWidth,
+    #[error("math error: {0:?}")]
+    Math(ErrorCode),
+}
+
+impl From<ErrorCode> for DepthError {
+    fn from(error: ErrorCode) -> Self {
+        DepthError::Math(error)
+    }
+}
+
+/// A tick range over which the active liquidity of the pool is constant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LiquiditySegment {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+}
+
+/// The liquidity and token amounts held within a range of prices.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LiquidityBucket {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub price_lower: f64,
+    pub price_upper: f64,
+    /// Active liquidity averaged over the ticks of the bucket.
+    pub liquidity: u128,
+    /// Amount of token A held above the current price, rounded down.
+    pub amount_a: u128,
+    /// Amount of token B held below the current price, rounded down.
+    pub amount_b: u128,
+}
+
+/// The token amounts a swap needs to move the price of the pool to a target price.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PriceImpact {
+    /// True if the swap sells token A and moves the price down.
+    pub a_to_b: bool,
+    /// The sqrt price the swap moves to. This is short of the target price if the tick arrays
+    /// do not cover the whole move.
+    pub sqrt_price: u128,
+    /// Amount of the input token swapped into the pool, excluding fees.
+    pub amount_in: u128,
+    /// Fees charged on top of `amount_in`.
+    pub fee_amount: u128,
+    /// Amount of the output token swapped out of the pool.
+    pub amount_out: u128,
+    /// False if the tick arrays end before the target price is reached.
+    pub complete: bool,
+}
+
+/// The active liquidity of a pool over the tick range covered by a set of its tick arrays.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidityCurve {
+    sqrt_price: u128,
+    tick_current_index: i32,
+    segments: Vec<LiquiditySegment>,
+}
+
+impl LiquidityCurve {
+    /// Rebuilds the curve by walking the `liquidity_net` of the initialized ticks outwards from
+    /// the current tick, starting with the active liquidity of the pool. The tick arrays must
+    /// belong to the pool and together cover a contiguous range around the current tick; the
+    /// curve ends where the first gap in the arrays begins.
+    pub fn new(pool: &ElysiumPool, tick_arrays: &[&TickArray]) -> Result<Self, DepthError> {
+        let tick_spacing = pool.tick_spacing as i32;
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing;
+
+        let mut start_tick_indexes: Vec<i32> = tick_arrays
+            .iter()
+            .map(|tick_array| tick_array.start_tick_index)
+            .collect();
+        if start_tick_indexes.is_empty() {
+            return Err(DepthError::NoTickArrays);
+        }
+        start_tick_indexes.sort_unstable();
+        start_tick_indexes.dedup();
+        if let Some(start_tick_index) = start_tick_indexes
+            .iter()
+            .find(|start_tick_index| **start_tick_index % ticks_in_array != 0)
+        {
+            return Err(DepthError::MisalignedTickArray(*start_tick_index));
+        }
+
+        let tick_current_index = pool.tick_current_index;
+        let current_start = start_tick_indexes
+            .iter()
+            .position(|start| (*start..*start + ticks_in_array).contains(&tick_current_index))
+            .ok_or(DepthError::CurrentTickNotCovered(tick_current_index))?;
+
+        // Extend the covered range from the array of the current tick while arrays are adjacent.
+        let mut first = current_start;
+        while first > 0
+            && start_tick_indexes[first - 1] + ticks_in_array == start_tick_indexes[first]
+        {
+            first -= 1;
+        }
+        let mut last = current_start;
+        while last + 1 < start_tick_indexes.len()
+            && start_tick_indexes[last] + ticks_in_array == start_tick_indexes[last + 1]
+        {
+            last += 1;
+        }
+        let range_lower = start_tick_indexes[first].max(MIN_TICK_INDEX);
+        let range_upper = (start_tick_indexes[last] + ticks_in_array).min(MAX_TICK_INDEX);
+
+        let mut initialized_ticks: Vec<(i32, i128)> = tick_arrays
+            .iter()
+            .flat_map(|tick_array| {
+                tick_array
+                    .ticks
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, tick)| tick.initialized)
+                    .map(move |(offset, tick)| {
+                        (
+                            tick_array.start_tick_index + offset as i32 * tick_spacing,
+                            tick.liquidity_net,
+                        )
+                    })
+            })
+            .filter(|(tick_index, _)| range_lower < *tick_index && *tick_index < range_upper)
+            .collect();
+        initialized_ticks.sort_unstable_by_key(|(tick_index, _)| *tick_index);
+        initialized_ticks.dedup_by_key(|(tick_index, _)| *tick_index);
+
+        let split =
+            initialized_ticks.partition_point(|(tick_index, _)| *tick_index <= tick_current_index);
+        let (below, above) = initialized_ticks.split_at(split);
+        let tick_index_at = |ticks: &[(i32, i128)], index: usize, default: i32| {
+            ticks
+                .get(index)
+                .map_or(default, |(tick_index, _)| *tick_index)
+        };
+
+        // Crossing a tick downwards subtracts its liquidity_net.
+        let mut segments = Vec::with_capacity(initialized_ticks.len() + 1);
+        let mut liquidity = pool.liquidity;
+        for index in (0..below.len()).rev() {
+            let (tick_index, liquidity_net) = below[index];
+            liquidity = add_liquidity_delta(liquidity, -liquidity_net)?;
+            segments.push(LiquiditySegment {
+                tick_lower_index: match index {
+                    0 => range_lower,
+                    _ => below[index - 1].0,
+                },
+                tick_upper_index: tick_index,
+                liquidity,
+            });
+        }
+        segments.reverse();
+
+        segments.push(LiquiditySegment {
+            tick_lower_index: below
+                .last()
+                .map_or(range_lower, |(tick_index, _)| *tick_index),
+            tick_upper_index: tick_index_at(above, 0, range_upper),
+            liquidity: pool.liquidity,
+        });
+
+        // Crossing a tick upwards adds its liquidity_net.
+        let mut liquidity = pool.liquidity;
+        for (index, (tick_index, liquidity_net)) in above.iter().enumerate() {
+            liquidity = add_liquidity_delta(liquidity, *liquidity_net)?;
+            segments.push(LiquiditySegment {
+                tick_lower_index: *tick_index,
+                tick_upper_index: tick_index_at(above, index + 1, range_upper),
+                liquidity,
+            });
+        }
+
+        Ok(Self {
+            sqrt_price: pool.sqrt_price,
+            tick_current_index,
+            segments,
+        })
+    }
+
+    /// The segments of constant liquidity in increasing tick order. They are contiguous and
+    /// together span the covered tick range.
+    pub fn segments(&self) -> &[LiquiditySegment] {
+        &self.segments
+    }
+
+    /// The tick range covered by the curve, with an exclusive upper bound.
+    pub fn tick_range(&self) -> (i32, i32) {
+        (
+            self.segments[0].tick_lower_index,
+            self.segments[self.segments.len() - 1].tick_upper_index,
+        )
+    }
+
+    /// Returns the active liquidity at a tick, or `None` if the curve does not cover it.
+    pub fn liquidity_at(&self, tick_index: i32) -> Option<u128> {
+        self.segments
+            .iter()
+            .find(|segment| {
+                (segment.tick_lower_index..segment.tick_upper_index).contains(&tick_index)
+            })
+            .map(|segment| segment.liquidity)
+    }
+
+    /// Splits the covered tick range into buckets of `bucket_width` ticks aligned to multiples of
+    /// the width, and returns the liquidity and token amounts held in each of them. The first and
+    /// last buckets are clipped to the covered range.
+    pub fn buckets(&self, bucket_width: i32) -> Result<Vec<LiquidityBucket>, DepthError> {
+        if bucket_width <= 0 {
+            return Err(DepthError::InvalidBucketWidth);
+        }
+
+        let (range_lower, range_upper) = self.tick_range();
+        let mut buckets = Vec::new();
+        let mut bucket_lower = range_lower.div_euclid(bucket_width) * bucket_width;
+        while bucket_lower < range_upper {
+            let tick_lower_index = bucket_lower.max(range_lower);
+            let tick_upper_index = (bucket_lower + bucket_width).min(range_upper);
+            buckets.push(self.bucket(tick_lower_index, tick_upper_index)?);
+            bucket_lower += bucket_width;
+        }
+        Ok(buckets)
+    }
+
+    fn bucket(
+        &self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<LiquidityBucket, DepthError> {
+        let mut weighted_liquidity: u128 = 0;
+        let mut amount_a: u128 = 0;
+        let mut amount_b: u128 = 0;
+        for segment in self.segments.iter() {
+            let lower = segment.tick_lower_index.max(tick_lower_index);
+            let upper = segment.tick_upper_index.min(tick_upper_index);
+            if lower >= upper {
+                continue;
+            }
+
+            weighted_liquidity = segment
+                .liquidity
+                .checked_mul((upper - lower) as u128)
+                .and_then(|liquidity| liquidity.checked_add(weighted_liquidity))
+                .ok_or(ErrorCode::MultiplicationOverflow)?;
+
+            let (amount_a_held, amount_b_held) = amounts_held(
+                sqrt_price_from_tick_index(lower),
+                sqrt_price_from_tick_index(upper),
+                segment.liquidity,
+                self.sqrt_price,
+            )?;
+            amount_a += amount_a_held as u128;
+            amount_b += amount_b_held as u128;
+        }
+
+        Ok(LiquidityBucket {
+            tick_lower_index,
+            tick_upper_index,
+            price_lower: price_from_sqrt_price(sqrt_price_from_tick_index(tick_lower_index)),
+            price_upper: price_from_sqrt_price(sqrt_price_from_tick_index(tick_upper_index)),
+            liquidity: weighted_liquidity / (tick_upper_index - tick_lower_index) as u128,
+            amount_a,
+            amount_b,
+        })
+    }
+
+    /// Returns the amounts a swap needs to move the price by `price_change_bps` basis points of
+    /// the current price, e.g. 100 for +1% or -100 for -1%. The target sqrt price is derived in
+    /// floating point; use [`LiquidityCurve::price_impact_to`] for an exact target.
+    pub fn price_impact(
+        &self,
+        price_change_bps: i32,
+        fee_rate: u32,
+    ) -> Result<PriceImpact, DepthError> {
+        let price_ratio = (1.0 + price_change_bps as f64 / 10_000.0).max(0.0);
+        let target_sqrt_price = self.sqrt_price as f64 * price_ratio.sqrt();
+        self.price_impact_to(target_sqrt_price as u128, fee_rate)
+    }
+
+    /// Returns the amounts a swap needs to move the price to `target_sqrt_price`, walking the
+    /// segments as the swap would cross ticks. The fee of each step is computed the way the swap
+    /// does when a step reaches its target price, so `fee_rate` should be the effective fee rate
+    /// of the pool at the time of the swap.
+    pub fn price_impact_to(
+        &self,
+        target_sqrt_price: u128,
+        fee_rate: u32,
+    ) -> Result<PriceImpact, DepthError> {
+        let target_sqrt_price = target_sqrt_price.clamp(MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64);
+        let a_to_b = target_sqrt_price < self.sqrt_price;
+        let current_segment = self
+            .segments
+            .iter()
+            .position(|segment| segment.tick_upper_index > self.tick_current_index)
+            .unwrap_or(self.segments.len() - 1);
+        let segments: Box<dyn Iterator<Item = &LiquiditySegment>> = if a_to_b {
+            Box::new(self.segments[..=current_segment].iter().rev())
+        } else {
+            Box::new(self.segments[current_segment..].iter())
+        };
+
+        let mut impact = PriceImpact {
+            a_to_b,
+            sqrt_price: self.sqrt_price,
+            amount_in: 0,
+            fee_amount: 0,
+            amount_out: 0,
+            complete: target_sqrt_price == self.sqrt_price,
+        };
+        for segment in segments {
+            if impact.complete {
+                break;
+            }
+
+            let step_sqrt_price = if a_to_b {
+                sqrt_price_from_tick_index(segment.tick_lower_index).max(target_sqrt_price)
+            } else {
+                sqrt_price_from_tick_index(segment.tick_upper_index).min(target_sqrt_price)
+            };
+            let (amount_in, amount_out) = if a_to_b {
+                (
+                    get_amount_delta_a(
+                        step_sqrt_price,
+                        impact.sqrt_price,
+                        segment.liquidity,
+                        true,
+                    )?,
+                    get_amount_delta_b(
+                        step_sqrt_price,
+                        impact.sqrt_price,
+                        segment.liquidity,
+                        false,
+                    )?,
+                )
+            } else {
+                (
+                    get_amount_delta_b(
+                        impact.sqrt_price,
+                        step_sqrt_price,
+                        segment.liquidity,
+                        true,
+                    )?,
+                    get_amount_delta_a(
+                        impact.sqrt_price,
+                        step_sqrt_price,
+                        segment.liquidity,
+                        false,
+                    )?,
+                )
+            };
+            let fee_amount: u64 = checked_mul_div_round_up(
+                amount_in as u128,
+                fee_rate as u128,
+                FEE_RATE_MUL_VALUE - fee_rate as u128,
+            )?
+            .try_into()
+            .map_err(ErrorCode::from)?;
+
+            impact.amount_in += amount_in as u128;
+            impact.fee_amount += fee_amount as u128;
+            impact.amount_out += amount_out as u128;
+            impact.sqrt_price = step_sqrt_price;
+            impact.complete = step_sqrt_price == target_sqrt_price;
+        }
+        Ok(impact)
+    }
+}
+
+/// Returns the amounts of token A above and token B below the current price that `liquidity`
+/// holds between two sqrt prices, rounded down.
+fn amounts_held(
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    liquidity: u128,
+    sqrt_price_current: u128,
+) -> Result<(u64, u64), ErrorCode> {
+    let amount_a = if sqrt_price_current < sqrt_price_upper {
+        get_amount_delta_a(
+            sqrt_price_lower.max(sqrt_price_current),
+            sqrt_price_upper,
+            liquidity,
+            false,
+        )?
+    } else {
+        0
+    };
+    let amount_b = if sqrt_price_current > sqrt_price_lower {
+        get_amount_delta_b(
+            sqrt_price_lower,
+            sqrt_price_upper.min(sqrt_price_current),
+            liquidity,
+            false,
+        )?
+    } else {
+        0
+    };
+    Ok((amount_a, amount_b))
+}
+
+#[cfg(test)]
+mod depth_tests {
+    use super::*;
+    use pool::state::Tick;
+
+    const LIQUIDITY: u128 = 1_000_000_000;
+
+    // Two positions around the current tick 0: [-80, 80) and [40, 400).
+    fn pool_and_tick_arrays() -> (ElysiumPool, Vec<TickArray>) {
+        let pool = ElysiumPool {
+            tick_spacing: 8,
+            tick_current_index: 0,
+            sqrt_price: sqrt_price_from_tick_index(0),
+            liquidity: LIQUIDITY,
+            ..Default::default()
+        };
+        let mut tick_arrays = vec![
+            TickArray {
+                start_tick_index: -704,
+                ..Default::default()
+            },
+            TickArray {
+                start_tick_index: 0,
+                ..Default::default()
+            },
+        ];
+        let liquidity_net = LIQUIDITY as i128;
+        for (array_index, offset, liquidity_net) in [
+            (0, 78, liquidity_net),
+            (1, 5, liquidity_net / 2),
+            (1, 10, -liquidity_net),
+            (1, 50, -liquidity_net / 2),
+        ] {
+            tick_arrays[array_index].ticks[offset] = Tick {
+                initialized: true,
+                liquidity_net,
+                liquidity_gross: liquidity_net.unsigned_abs(),
+                ..Default::default()
+            };
+        }
+        (pool, tick_arrays)
+    }
+
+    fn curve() -> LiquidityCurve {
+        let (pool, tick_arrays) = pool_and_tick_arrays();
+        let tick_arrays: Vec<&TickArray> = tick_arrays.iter().collect();
+        LiquidityCurve::new(&pool, &tick_arrays).unwrap()
+    }
+
+    fn segment(tick_lower_index: i32, tick_upper_index: i32, liquidity: u128) -> LiquiditySegment {
+        LiquiditySegment {
+            tick_lower_index,
+            tick_upper_index,
+            liquidity,
+        }
+    }
+
+    #[test]
+    fn test_curve_segments() {
+        let curve = curve();
+        assert_eq!(
+            curve.segments(),
+            &[
+                segment(-704, -80, 0),
+                segment(-80, 40, LIQUIDITY),
+                segment(40, 80, LIQUIDITY * 3 / 2),
+                segment(80, 400, LIQUIDITY / 2),
+                segment(400, 704, 0),
+            ]
+        );
+        assert_eq!(curve.tick_range(), (-704, 704));
+        assert_eq!(curve.liquidity_at(79), Some(LIQUIDITY * 3 / 2));
+        assert_eq!(curve.liquidity_at(704), None);
+    }
+
+    #[test]
+    fn test_curve_requires_current_tick_coverage() {
+        let (pool, tick_arrays) = pool_and_tick_arrays();
+        assert!(matches!(
+            LiquidityCurve::new(&pool, &[&tick_arrays[0]]),
+            Err(DepthError::CurrentTickNotCovered(0))
+        ));
+        assert!(matches!(
+            LiquidityCurve::new(&pool, &[]),
+            Err(DepthError::NoTickArrays)
+        ));
+    }
+
+    #[test]
+    fn test_buckets() {
+        let curve = curve();
+        let buckets = curve.buckets(704).unwrap();
+        assert_eq!(buckets.len(), 2);
+
+        assert_eq!(buckets[0].liquidity, LIQUIDITY * 80 / 704);
+        assert_eq!(buckets[0].amount_a, 0);
+        assert_eq!(
+            buckets[0].amount_b,
+            get_amount_delta_b(
+                sqrt_price_from_tick_index(-80),
+                sqrt_price_from_tick_index(0),
+                LIQUIDITY,
+                false
+            )
+            .unwrap() as u128
+        );
+
+        assert_eq!(
+            buckets[1].liquidity,
+            (LIQUIDITY * 40 + LIQUIDITY * 3 / 2 * 40 + LIQUIDITY / 2 * 320) / 704
+        );
+        assert!(buckets[1].amount_a > 0);
+        assert_eq!(buckets[1].amount_b, 0);
+        assert!(buckets[1].price_lower < buckets[1].price_upper);
+
+        assert!(matches!(
+            curve.buckets(0),
+            Err(DepthError::InvalidBucketWidth)
+        ));
+    }
+
+    #[test]
+    fn test_price_impact_crosses_ticks() {
+        let curve = curve();
+        let fee_rate = 3000;
+        let impact = curve
+            .price_impact_to(sqrt_price_from_tick_index(100), fee_rate)
+            .unwrap();
+        assert!(!impact.a_to_b);
+        assert!(impact.complete);
+        assert_eq!(impact.sqrt_price, sqrt_price_from_tick_index(100));
+
+        let steps = [
+            (0, 40, LIQUIDITY),
+            (40, 80, LIQUIDITY * 3 / 2),
+            (80, 100, LIQUIDITY / 2),
+        ];
+        let (mut amount_in, mut fee_amount, mut amount_out) = (0, 0, 0);
+        for (tick_lower_index, tick_upper_index, liquidity) in steps {
+            let sqrt_price_lower = sqrt_price_from_tick_index(tick_lower_index);
+            let sqrt_price_upper = sqrt_price_from_tick_index(tick_upper_index);
+            let step_in =
+                get_amount_delta_b(sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+            amount_in += step_in as u128;
+            fee_amount += checked_mul_div_round_up(
+                step_in as u128,
+                fee_rate as u128,
+                FEE_RATE_MUL_VALUE - fee_rate as u128,
+            )
+            .unwrap();
+            amount_out += get_amount_delta_a(sqrt_price_lower, sqrt_price_upper, liquidity, false)
+                .unwrap() as u128;
+        }
+        assert_eq!(impact.amount_in, amount_in);
+        assert_eq!(impact.fee_amount, fee_amount);
+        assert_eq!(impact.amount_out, amount_out);
+    }
+
+    #[test]
+    fn test_price_impact_stops_at_end_of_coverage() {
+        let curve = curve();
+        let impact = curve.price_impact(-5000, 0).unwrap();
+        assert!(impact.a_to_b);
+        assert!(!impact.complete);
+        assert_eq!(impact.sqrt_price, sqrt_price_from_tick_index(-704));
+        assert_eq!(impact.fee_amount, 0);
+        assert_eq!(
+            impact.amount_in,
+            get_amount_delta_a(
+                sqrt_price_from_tick_index(-80),
+                sqrt_price_from_tick_index(0),
+                LIQUIDITY,
+                true
+            )
+            .unwrap() as u128
+        );
+
+        let no_move = curve.price_impact(0, 3000).unwrap();
+        assert!(no_move.complete);
+        assert_eq!(no_move.amount_in, 0);
+    }
+}
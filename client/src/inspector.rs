@@ -0,0 +1,520 @@
+//! Decodes raw accounts of the program into JSON, along with fields derived from the account
+//! state such as the pool price or the initialized ticks of a TickArray.
+
+use std::mem::size_of;
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
+use pool::instructions::migrate_pool_layout_v0;
+use pool::state::{
+    ElysiumPool, ElysiumPoolRewardInfo, ElysiumPoolsConfig, FeeTier, Position, PositionBundle,
+    PositionLock, PositionRewardInfo, TickArray, POSITION_BUNDLE_SIZE, POSITION_LOCK_PERMANENT,
+    TICK_ARRAY_SIZE_USIZE,
+};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::price::{adjust_price_for_decimals, price_from_sqrt_price};
+
+#[derive(Debug, Error)]
+pub enum InspectError {
+    #[error("account data is shorter than the 8 byte discriminator")]
+    MissingDiscriminator,
+    #[error("unknown account discriminator {0:?}")]
+    UnknownDiscriminator([u8; 8]),
+    #[error("failed to decode {0}: {1}")]
+    InvalidAccountData(&'static str, String),
+    #[error("invalid account file: {0}")]
+    InvalidAccountFile(String),
+}
+
+/// A decoded account of the program.
+pub enum ElysiumAccount {
+    PoolsConfig(ElysiumPoolsConfig),
+    FeeTier(FeeTier),
+    Pool(Box<ElysiumPool>),
+    /// A pool that still has the original layout, mapped to the current layout the way
+    /// `migrate_pool` maps it, except that its reward accounting is left at zero.
+    LegacyPool(Box<ElysiumPool>),
+    TickArray(Box<TickArray>),
+    Position(Position),
+    PositionBundle(PositionBundle),
+    PositionLock(PositionLock),
+}
+
+impl ElysiumAccount {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ElysiumAccount::PoolsConfig(_) => "ElysiumPoolsConfig",
+            ElysiumAccount::FeeTier(_) => "FeeTier",
+            ElysiumAccount::Pool(_) | ElysiumAccount::LegacyPool(_) => "ElysiumPool",
+            ElysiumAccount::TickArray(_) => "TickArray",
+            ElysiumAccount::Position(_) => "Position",
+            ElysiumAccount::PositionBundle(_) => "PositionBundle",
+            ElysiumAccount::PositionLock(_) => "PositionLock",
+        }
+    }
+}
+
+/// Context that is not stored in the account itself but is needed to derive some fields.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InspectOptions {
+    /// Decimals of token A, used with `decimals_b` to derive the decimal adjusted price.
+    pub decimals_a: Option<u8>,
+    /// Decimals of token B, used with `decimals_a` to derive the decimal adjusted price.
+    pub decimals_b: Option<u8>,
+    /// Tick spacing of the pool of a TickArray, used to derive the tick indexes of its ticks.
+    pub tick_spacing: Option<u16>,
+}
+
+/// Account data read from a file, with the address if the file records it.
+pub struct AccountFile {
+    pub pubkey: Option<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+/// Reads an account from the contents of a file. Supports the JSON output of
+/// `solana account --output json` and raw account data.
+pub fn parse_account_file(contents: &[u8]) -> Result<AccountFile, InspectError> {
+    let json = match serde_json::from_slice::<Value>(contents) {
+        Ok(json) => json,
+        Err(_) => {
+            return Ok(AccountFile {
+                pubkey: None,
+                data: contents.to_vec(),
+            })
+        }
+    };
+
+    let account = json.get("account").unwrap_or(&json);
+    let data = match &account["data"] {
+        Value::Array(data) if data.get(1) == Some(&json!("base64")) => data[0].as_str(),
+        _ => None,
+    }
+    .ok_or_else(|| InspectError::InvalidAccountFile("expected base64 account data".to_string()))?;
+    let data =
+        base64::decode(data).map_err(|err| InspectError::InvalidAccountFile(err.to_string()))?;
+
+    let pubkey =
+        match json.get("pubkey").and_then(Value::as_str) {
+            Some(pubkey) => Some(pubkey.parse().map_err(|_| {
+                InspectError::InvalidAccountFile(format!("invalid pubkey {}", pubkey))
+            })?),
+            None => None,
+        };
+
+    Ok(AccountFile { pubkey, data })
+}
+
+/// Decodes an account of the program, detecting its type from the anchor discriminator.
+pub fn decode_account(data: &[u8]) -> Result<ElysiumAccount, InspectError> {
+    if data.len() < 8 {
+        return Err(InspectError::MissingDiscriminator);
+    }
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&data[..8]);
+
+    if discriminator == ElysiumPoolsConfig::discriminator() {
+        deserialize(data, "ElysiumPoolsConfig").map(ElysiumAccount::PoolsConfig)
+    } else if discriminator == FeeTier::discriminator() {
+        deserialize(data, "FeeTier").map(ElysiumAccount::FeeTier)
+    } else if discriminator == ElysiumPool::discriminator() {
+        if data.len() == ElysiumPool::LEN_V0 {
+            let mut data = data.to_vec();
+            data.resize(ElysiumPool::LEN, 0);
+            migrate_pool_layout_v0(&mut data);
+            deserialize(&data, "ElysiumPool").map(|pool| ElysiumAccount::LegacyPool(Box::new(pool)))
+        } else {
+            deserialize(data, "ElysiumPool").map(|pool| ElysiumAccount::Pool(Box::new(pool)))
+        }
+    } else if discriminator == TickArray::discriminator() {
+        let tick_array = data
+            .get(8..8 + size_of::<TickArray>())
+            .and_then(|bytes| bytemuck::try_from_bytes::<TickArray>(bytes).ok())
+            .ok_or_else(|| {
+                InspectError::InvalidAccountData("TickArray", "unexpected size".to_string())
+            })?;
+        Ok(ElysiumAccount::TickArray(Box::new(*tick_array)))
+    } else if discriminator == Position::discriminator() {
+        deserialize(data, "Position").map(ElysiumAccount::Position)
+    } else if discriminator == PositionBundle::discriminator() {
+        deserialize(data, "PositionBundle").map(ElysiumAccount::PositionBundle)
+    } else if discriminator == PositionLock::discriminator() {
+        deserialize(data, "PositionLock").map(ElysiumAccount::PositionLock)
+    } else {
+        Err(InspectError::UnknownDiscriminator(discriminator))
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(
+    data: &[u8],
+    type_name: &'static str,
+) -> Result<T, InspectError> {
+    T::try_deserialize(&mut &data[..])
+        .map_err(|err| InspectError::InvalidAccountData(type_name, err.to_string()))
+}
+
+/// Decodes an account and renders it as JSON. 128 bit integers are rendered as strings so that
+/// JSON consumers do not lose precision.
+pub fn inspect_account(data: &[u8], options: &InspectOptions) -> Result<Value, InspectError> {
+    Ok(account_to_json(&decode_account(data)?, options))
+}
+
+pub fn account_to_json(account: &ElysiumAccount, options: &InspectOptions) -> Value {
+    let fields = match account {
+        ElysiumAccount::PoolsConfig(config) => json!({
+            "fee_authority": config.fee_authority.to_string(),
+            "collect_protocol_fees_authority": config.collect_protocol_fees_authority.to_string(),
+            "reward_emissions_super_authority": config.reward_emissions_super_authority.to_string(),
+            "default_protocol_fee_rate": config.default_protocol_fee_rate,
+            "max_creator_fee_rate": config.max_creator_fee_rate,
+        }),
+        ElysiumAccount::FeeTier(fee_tier) => json!({
+            "pools_config": fee_tier.pools_config.to_string(),
+            "tick_spacing": fee_tier.tick_spacing,
+            "default_fee_rate": fee_tier.default_fee_rate,
+        }),
+        ElysiumAccount::Pool(pool) => pool_to_json(pool, "current", options),
+        ElysiumAccount::LegacyPool(pool) => pool_to_json(pool, "v0", options),
+        ElysiumAccount::TickArray(tick_array) => tick_array_to_json(tick_array, options),
+        ElysiumAccount::Position(position) => position_to_json(position),
+        ElysiumAccount::PositionBundle(position_bundle) => json!({
+            "position_bundle_mint": position_bundle.position_bundle_mint.to_string(),
+            "open_bundle_indexes": open_bundle_indexes(position_bundle),
+        }),
+        ElysiumAccount::PositionLock(position_lock) => json!({
+            "position": position_lock.position.to_string(),
+            "beneficiary": position_lock.beneficiary.to_string(),
+            "unlock_timestamp": position_lock.unlock_timestamp,
+            "permanently_locked": position_lock.unlock_timestamp == POSITION_LOCK_PERMANENT,
+        }),
+    };
+
+    json!({
+        "type": account.type_name(),
+        "data": fields,
+    })
+}
+
+fn pool_to_json(pool: &ElysiumPool, layout: &str, options: &InspectOptions) -> Value {
+    let price = price_from_sqrt_price(pool.sqrt_price);
+    let decimal_adjusted_price = match (options.decimals_a, options.decimals_b) {
+        (Some(decimals_a), Some(decimals_b)) => {
+            Some(adjust_price_for_decimals(price, decimals_a, decimals_b))
+        }
+        _ => None,
+    };
+
+    json!({
+        "layout": layout,
+        "pools_config": pool.pools_config.to_string(),
+        "pool_bump": pool.pool_bump[0],
+        "tick_spacing": pool.tick_spacing,
+        "fee_tier_index": pool.fee_tier_index(),
+        "fee_rate": pool.fee_rate,
+        "protocol_fee_rate": pool.protocol_fee_rate,
+        "liquidity": pool.liquidity.to_string(),
+        "sqrt_price": pool.sqrt_price.to_string(),
+        "tick_current_index": pool.tick_current_index,
+        "price": price,
+        "decimal_adjusted_price": decimal_adjusted_price,
+        "protocol_fee_owed_a": pool.protocol_fee_owed_a,
+        "protocol_fee_owed_b": pool.protocol_fee_owed_b,
+        "token_mint_a": pool.token_mint_a.to_string(),
+        "token_vault_a": pool.token_vault_a.to_string(),
+        "fee_growth_global_a": pool.fee_growth_global_a.to_string(),
+        "token_mint_b": pool.token_mint_b.to_string(),
+        "token_vault_b": pool.token_vault_b.to_string(),
+        "fee_growth_global_b": pool.fee_growth_global_b.to_string(),
+        "reward_last_updated_timestamp": pool.reward_last_updated_timestamp,
+        "reward_infos": pool.reward_infos.iter().map(pool_reward_info_to_json).collect::<Vec<_>>(),
+        "reward_extension": pool.reward_extension.to_string(),
+        "activation_timestamp": pool.activation_timestamp,
+        "activation_slot": pool.activation_slot,
+        "launch_fee_schedule": {
+            "initial_fee_rate": pool.launch_fee_schedule.initial_fee_rate,
+            "decay_period": pool.launch_fee_schedule.decay_period,
+            "decay_in_slots": pool.launch_fee_schedule.decay_in_slots,
+        },
+        "creator": pool.creator.to_string(),
+        "creator_fee_rate": pool.creator_fee_rate,
+        "creator_fee_owed_a": pool.creator_fee_owed_a,
+        "creator_fee_owed_b": pool.creator_fee_owed_b,
+        "tick_array_bitmap": pool.tick_array_bitmap.to_string(),
+        "hook_program": pool.hook_program.to_string(),
+        "access_authority": pool.access_authority.to_string(),
+    })
+}
+
+fn pool_reward_info_to_json(reward_info: &ElysiumPoolRewardInfo) -> Value {
+    json!({
+        "initialized": reward_info.initialized(),
+        "mint": reward_info.mint.to_string(),
+        "vault": reward_info.vault.to_string(),
+        "authority": reward_info.authority.to_string(),
+        "emissions_per_second_x64": reward_info.emissions_per_second_x64.to_string(),
+        "growth_global_x64": reward_info.growth_global_x64.to_string(),
+        "emissions_start_timestamp": reward_info.emissions_start_timestamp,
+        "emissions_end_timestamp": reward_info.emissions_end_timestamp,
+        "emissions_budget": reward_info.emissions_budget,
+        "total_funded": reward_info.total_funded,
+        "total_emitted": reward_info.total_emitted,
+        "total_collected": reward_info.total_collected,
+        "uncredited_x64": reward_info.uncredited_x64.to_string(),
+        "total_credited": reward_info.total_credited,
+        "unfunded_emissions_x64": reward_info.unfunded_emissions_x64.to_string(),
+    })
+}
+
+fn tick_array_to_json(tick_array: &TickArray, options: &InspectOptions) -> Value {
+    let start_tick_index = tick_array.start_tick_index;
+    let ticks = tick_array.ticks;
+
+    let initialized_ticks: Vec<Value> = (0..TICK_ARRAY_SIZE_USIZE)
+        .filter(|offset| ticks[*offset].initialized)
+        .map(|offset| {
+            let tick = ticks[offset];
+            let (liquidity_net, liquidity_gross) = (tick.liquidity_net, tick.liquidity_gross);
+            let (fee_growth_outside_a, fee_growth_outside_b) =
+                (tick.fee_growth_outside_a, tick.fee_growth_outside_b);
+            let reward_growths_outside = tick.reward_growths_outside;
+            let tick_index = options
+                .tick_spacing
+                .map(|tick_spacing| start_tick_index + offset as i32 * i32::from(tick_spacing));
+
+            json!({
+                "offset": offset,
+                "tick_index": tick_index,
+                "liquidity_net": liquidity_net.to_string(),
+                "liquidity_gross": liquidity_gross.to_string(),
+                "fee_growth_outside_a": fee_growth_outside_a.to_string(),
+                "fee_growth_outside_b": fee_growth_outside_b.to_string(),
+                "reward_growths_outside": reward_growths_outside
+                    .iter()
+                    .map(|growth| growth.to_string())
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    json!({
+        "pool": tick_array.pool.to_string(),
+        "start_tick_index": start_tick_index,
+        "initialized_tick_count": initialized_ticks.len(),
+        "initialized_ticks": initialized_ticks,
+    })
+}
+
+fn position_to_json(position: &Position) -> Value {
+    json!({
+        "pool": position.pool.to_string(),
+        "position_mint": position.position_mint.to_string(),
+        "liquidity": position.liquidity.to_string(),
+        "tick_lower_index": position.tick_lower_index,
+        "tick_upper_index": position.tick_upper_index,
+        "fee_growth_checkpoint_a": position.fee_growth_checkpoint_a.to_string(),
+        "fee_owed_a": position.fee_owed_a,
+        "fee_growth_checkpoint_b": position.fee_growth_checkpoint_b.to_string(),
+        "fee_owed_b": position.fee_owed_b,
+        "reward_infos": position
+            .reward_infos
+            .iter()
+            .map(position_reward_info_to_json)
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn position_reward_info_to_json(reward_info: &PositionRewardInfo) -> Value {
+    json!({
+        "growth_inside_checkpoint": reward_info.growth_inside_checkpoint.to_string(),
+        "amount_owed": reward_info.amount_owed,
+    })
+}
+
+/// Bundle indexes of the open positions in a PositionBundle.
+pub fn open_bundle_indexes(position_bundle: &PositionBundle) -> Vec<u16> {
+    (0..POSITION_BUNDLE_SIZE)
+        .filter(|bundle_index| {
+            let bitmap = position_bundle.position_bitmap[(bundle_index / 8) as usize];
+            bitmap & (1 << (bundle_index % 8)) != 0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod inspector_tests {
+    use super::*;
+    use anchor_lang::{AccountSerialize, AnchorSerialize};
+    use pool::state::Tick;
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_inspect_pool_price() {
+        let pool = ElysiumPool {
+            sqrt_price: 2 << 64,
+            tick_spacing: 64,
+            ..Default::default()
+        };
+
+        let json = inspect_account(&serialize(&pool), &InspectOptions::default()).unwrap();
+        assert_eq!(json["type"], "ElysiumPool");
+        assert_eq!(json["data"]["price"], 4.0);
+        assert_eq!(json["data"]["decimal_adjusted_price"], Value::Null);
+        assert_eq!(json["data"]["sqrt_price"], (2u128 << 64).to_string());
+
+        let options = InspectOptions {
+            decimals_a: Some(9),
+            decimals_b: Some(6),
+            ..Default::default()
+        };
+        let json = inspect_account(&serialize(&pool), &options).unwrap();
+        assert_eq!(json["data"]["decimal_adjusted_price"], 4000.0);
+    }
+
+    #[test]
+    fn test_inspect_legacy_pool() {
+        let mut pool = ElysiumPool {
+            sqrt_price: 2 << 64,
+            liquidity: 1_000,
+            ..Default::default()
+        };
+        for reward_info in pool.reward_infos.iter_mut() {
+            reward_info.mint = Pubkey::new_unique();
+            reward_info.vault = Pubkey::new_unique();
+            reward_info.growth_global_x64 = 5 << 64;
+        }
+
+        // The original layout only lacks the fields added to the reward infos and after them.
+        let current = serialize(&pool);
+        let reward_infos_offset = 8 + 261;
+        let mut data = current[..reward_infos_offset].to_vec();
+        for reward_info in pool.reward_infos.iter() {
+            data.extend_from_slice(&reward_info.try_to_vec().unwrap()[..128]);
+        }
+        assert_eq!(data.len(), ElysiumPool::LEN_V0);
+
+        let json = inspect_account(&data, &InspectOptions::default()).unwrap();
+        assert_eq!(json["type"], "ElysiumPool");
+        assert_eq!(json["data"]["layout"], "v0");
+        assert_eq!(json["data"]["price"], 4.0);
+        assert_eq!(json["data"]["liquidity"], "1000");
+        for (index, reward_info) in pool.reward_infos.iter().enumerate() {
+            let reward_json = &json["data"]["reward_infos"][index];
+            assert_eq!(reward_json["mint"], reward_info.mint.to_string());
+            assert_eq!(reward_json["vault"], reward_info.vault.to_string());
+            assert_eq!(reward_json["growth_global_x64"], (5u128 << 64).to_string());
+            assert_eq!(reward_json["total_funded"], 0);
+        }
+        assert_eq!(json["data"]["creator"], Pubkey::default().to_string());
+
+        let json = inspect_account(&current, &InspectOptions::default()).unwrap();
+        assert_eq!(json["data"]["layout"], "current");
+    }
+
+    #[test]
+    fn test_inspect_tick_array_initialized_ticks() {
+        let mut tick_array = TickArray {
+            start_tick_index: -88 * 8,
+            ..Default::default()
+        };
+        tick_array.ticks[3] = Tick {
+            initialized: true,
+            liquidity_net: -5,
+            liquidity_gross: 5,
+            ..Default::default()
+        };
+
+        let mut data = TickArray::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&tick_array));
+
+        let options = InspectOptions {
+            tick_spacing: Some(8),
+            ..Default::default()
+        };
+        let json = inspect_account(&data, &options).unwrap();
+        assert_eq!(json["type"], "TickArray");
+        assert_eq!(json["data"]["initialized_tick_count"], 1);
+        let tick = &json["data"]["initialized_ticks"][0];
+        assert_eq!(tick["offset"], 3);
+        assert_eq!(tick["tick_index"], -88 * 8 + 3 * 8);
+        assert_eq!(tick["liquidity_net"], "-5");
+
+        let json = inspect_account(&data, &InspectOptions::default()).unwrap();
+        assert_eq!(
+            json["data"]["initialized_ticks"][0]["tick_index"],
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_inspect_position_bundle_open_indexes() {
+        let mut position_bundle = PositionBundle::default();
+        position_bundle.open_bundled_position(0).unwrap();
+        position_bundle.open_bundled_position(9).unwrap();
+        position_bundle.open_bundled_position(255).unwrap();
+
+        let json =
+            inspect_account(&serialize(&position_bundle), &InspectOptions::default()).unwrap();
+        assert_eq!(json["type"], "PositionBundle");
+        assert_eq!(json["data"]["open_bundle_indexes"], json!([0, 9, 255]));
+    }
+
+    #[test]
+    fn test_inspect_permanent_position_lock() {
+        let position_lock = PositionLock {
+            unlock_timestamp: POSITION_LOCK_PERMANENT,
+            ..Default::default()
+        };
+
+        let json = inspect_account(&serialize(&position_lock), &InspectOptions::default()).unwrap();
+        assert_eq!(json["type"], "PositionLock");
+        assert_eq!(json["data"]["permanently_locked"], true);
+    }
+
+    #[test]
+    fn test_unknown_discriminator() {
+        assert!(matches!(
+            decode_account(&[1, 2, 3, 4, 5, 6, 7, 8, 0]),
+            Err(InspectError::UnknownDiscriminator(_))
+        ));
+        assert!(matches!(
+            decode_account(&[1, 2, 3]),
+            Err(InspectError::MissingDiscriminator)
+        ));
+    }
+
+    #[test]
+    fn test_parse_solana_account_json_file() {
+        let fee_tier = FeeTier {
+            pools_config: Pubkey::new_unique(),
+            tick_spacing: 64,
+            default_fee_rate: 3000,
+        };
+        let data = serialize(&fee_tier);
+        let pubkey = Pubkey::new_unique();
+        let contents = json!({
+            "pubkey": pubkey.to_string(),
+            "account": {
+                "lamports": 1_000_000,
+                "data": [base64::encode(&data), "base64"],
+                "owner": pool::ID.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+            },
+        })
+        .to_string();
+
+        let account_file = parse_account_file(contents.as_bytes()).unwrap();
+        assert_eq!(account_file.pubkey, Some(pubkey));
+        assert_eq!(account_file.data, data);
+
+        let raw_file = parse_account_file(&data).unwrap();
+        assert_eq!(raw_file.pubkey, None);
+        let json = inspect_account(&raw_file.data, &InspectOptions::default()).unwrap();
+        assert_eq!(json["data"]["tick_spacing"], 64);
+    }
+}
@@ -0,0 +1,8 @@
+//! Off-chain helpers for the Elysium program that reuse the on-chain state and math.
+
+pub mod depth;
+pub mod inspector;
+pub mod position_quote;
+pub mod price;
+pub mod router;
+pub mod tick_array_bitmap;
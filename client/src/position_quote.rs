@@ -0,0 +1,309 @@
+//! Quotes the token amounts a position is worth and the fees and rewards it has earned, using the
+//! same managers as the on-chain `update_fees_and_rewards` and `decrease_liquidity` instructions.
+
+use std::convert::TryFrom;
+
+use anchor_lang::error::Error as ProgramError;
+use pool::{
+    errors::ErrorCode,
+    manager::{
+        liquidity_manager::calculate_liquidity_token_deltas,
+        pool_manager::next_pool_reward_infos,
+        position_manager::next_position_modify_liquidity_update,
+        tick_manager::{next_fee_growths_inside, next_reward_growths_inside},
+    },
+    state::{ElysiumPool, Position, PositionUpdate, Tick, TickArray, NUM_REWARDS},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum QuoteError {
+    #[error("tick array {0} does not belong to the pool of the position")]
+    TickArrayPoolMismatch(&'static str),
+    #[error("program error: {0}")]
+    Program(Box<ProgramError>),
+}
+
+impl From<ProgramError> for QuoteError {
+    fn from(error: ProgramError) -> Self {
+        QuoteError::Program(Box::new(error))
+    }
+}
+
+impl From<ErrorCode> for QuoteError {
+    fn from(error: ErrorCode) -> Self {
+        QuoteError::Program(Box::new(error.into()))
+    }
+}
+
+/// What a position is worth and what it has earned at a given timestamp.
+#[derive(Debug, PartialEq)]
+pub struct PositionQuote {
+    /// Amount of token A withdrawn by removing all liquidity of the position.
+    pub amount_a: u64,
+    /// Amount of token B withdrawn by removing all liquidity of the position.
+    pub amount_b: u64,
+    /// Fees of token A collectable after the update, including fees owed before it.
+    pub fee_owed_a: u64,
+    /// Fees of token B collectable after the update, including fees owed before it.
+    pub fee_owed_b: u64,
+    /// Rewards collectable after the update, including rewards owed before it.
+    pub reward_owed: [u64; NUM_REWARDS],
+    /// The position state `update_fees_and_rewards` would write at the timestamp.
+    pub position_update: PositionUpdate,
+}
+
+impl PositionQuote {
+    /// Fees of token A earned since the position was last updated.
+    pub fn pending_fee_a(&self, position: &Position) -> u64 {
+        self.fee_owed_a.wrapping_sub(position.fee_owed_a)
+    }
+
+    /// Fees of token B earned since the position was last updated.
+    pub fn pending_fee_b(&self, position: &Position) -> u64 {
+        self.fee_owed_b.wrapping_sub(position.fee_owed_b)
+    }
+
+    /// Rewards earned since the position was last updated.
+    pub fn pending_rewards(&self, position: &Position) -> [u64; NUM_REWARDS] {
+        let mut pending_rewards = [0; NUM_REWARDS];
+        for (i, pending_reward) in pending_rewards.iter_mut().enumerate() {
+            *pending_reward =
+                self.reward_owed[i].wrapping_sub(position.reward_infos[i].amount_owed);
+        }
+        pending_rewards
+    }
+}
+
+/// Quotes a position from the tick arrays that contain its lower and upper ticks. The timestamp
+/// must not be earlier than the last reward update of the pool.
+pub fn quote_position(
+    pool: &ElysiumPool,
+    position: &Position,
+    tick_array_lower: &TickArray,
+    tick_array_upper: &TickArray,
+    timestamp: u64,
+) -> Result<PositionQuote, QuoteError> {
+    if { tick_array_lower.pool } != position.pool {
+        return Err(QuoteError::TickArrayPoolMismatch("lower"));
+    }
+    if { tick_array_upper.pool } != position.pool {
+        return Err(QuoteError::TickArrayPoolMismatch("upper"));
+    }
+
+    let tick_lower = tick_array_lower.get_tick(position.tick_lower_index, pool.tick_spacing)?;
+    let tick_upper = tick_array_upper.get_tick(position.tick_upper_index, pool.tick_spacing)?;
+    quote_position_with_ticks(pool, position, tick_lower, tick_upper, timestamp)
+}
+
+/// Quotes a position from its lower and upper ticks. The timestamp must not be earlier than the
+/// last reward update of the pool.
+pub fn quote_position_with_ticks(
+    pool: &ElysiumPool,
+    position: &Position,
+    tick_lower: &Tick,
+    tick_upper: &Tick,
+    timestamp: u64,
+) -> Result<PositionQuote, QuoteError> {
+    let reward_infos = next_pool_reward_infos(pool, timestamp)?;
+
+    let (fee_growth_inside_a, fee_growth_inside_b) = next_fee_growths_inside(
+        pool.tick_current_index,
+        tick_lower,
+        position.tick_lower_index,
+        tick_upper,
+        position.tick_upper_index,
+        pool.fee_growth_global_a,
+        pool.fee_growth_global_b,
+    );
+    let reward_growths_inside = next_reward_growths_inside(
+        pool.tick_current_index,
+        tick_lower,
+        position.tick_lower_index,
+        tick_upper,
+        position.tick_upper_index,
+        &reward_infos,
+    );
+
+    // The on-chain update rejects positions without liquidity, but these cannot earn anything
+    // either, so the update leaves them as they are apart from the checkpoints.
+    let position_update = next_position_modify_liquidity_update(
+        position,
+        0,
+        fee_growth_inside_a,
+        fee_growth_inside_b,
+        &reward_growths_inside,
+    )?;
+
+    let (amount_a, amount_b) = if position.liquidity == 0 {
+        (0, 0)
+    } else {
+        let liquidity_delta =
+            i128::try_from(position.liquidity).map_err(|_| ErrorCode::LiquidityTooHigh)?;
+        calculate_liquidity_token_deltas(
+            pool.tick_current_index,
+            pool.sqrt_price,
+            position,
+            -liquidity_delta,
+        )?
+    };
+
+    let mut reward_owed = [0; NUM_REWARDS];
+    for (i, amount_owed) in reward_owed.iter_mut().enumerate() {
+        *amount_owed = position_update.reward_infos[i].amount_owed;
+    }
+
+    Ok(PositionQuote {
+        amount_a,
+        amount_b,
+        fee_owed_a: position_update.fee_owed_a,
+        fee_owed_b: position_update.fee_owed_b,
+        reward_owed,
+        position_update,
+    })
+}
+
+#[cfg(test)]
+mod position_quote_tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use pool::{
+        math::{sqrt_price_from_tick_index, Q64_RESOLUTION},
+        state::ElysiumPoolRewardInfo,
+    };
+
+    const LIQUIDITY: u128 = 1_000_000_000;
+
+    fn pool_position_and_tick_arrays() -> (ElysiumPool, Position, TickArray, TickArray) {
+        let pool_key = Pubkey::new_unique();
+        let mut reward_infos = [ElysiumPoolRewardInfo::default(); NUM_REWARDS];
+        reward_infos[0] = ElysiumPoolRewardInfo {
+            mint: Pubkey::new_unique(),
+            emissions_per_second_x64: 1 << Q64_RESOLUTION,
+            total_funded: 1_000,
+            ..Default::default()
+        };
+        let pool = ElysiumPool {
+            tick_spacing: 8,
+            tick_current_index: 0,
+            sqrt_price: sqrt_price_from_tick_index(0),
+            liquidity: LIQUIDITY,
+            fee_growth_global_a: 30 << Q64_RESOLUTION,
+            fee_growth_global_b: 50 << Q64_RESOLUTION,
+            reward_last_updated_timestamp: 100,
+            reward_infos,
+            ..Default::default()
+        };
+        let position = Position {
+            pool: pool_key,
+            liquidity: LIQUIDITY,
+            tick_lower_index: -64,
+            tick_upper_index: 64,
+            fee_growth_checkpoint_a: 1 << Q64_RESOLUTION,
+            fee_owed_a: 7,
+            ..Default::default()
+        };
+
+        let mut tick_array_lower = TickArray {
+            start_tick_index: -704,
+            pool: pool_key,
+            ..Default::default()
+        };
+        tick_array_lower.ticks[80] = Tick {
+            initialized: true,
+            liquidity_net: LIQUIDITY as i128,
+            liquidity_gross: LIQUIDITY,
+            fee_growth_outside_a: 10 << Q64_RESOLUTION,
+            fee_growth_outside_b: 20 << Q64_RESOLUTION,
+            ..Default::default()
+        };
+        let mut tick_array_upper = TickArray {
+            start_tick_index: 0,
+            pool: pool_key,
+            ..Default::default()
+        };
+        tick_array_upper.ticks[8] = Tick {
+            initialized: true,
+            liquidity_net: -(LIQUIDITY as i128),
+            liquidity_gross: LIQUIDITY,
+            fee_growth_outside_a: 5 << Q64_RESOLUTION,
+            ..Default::default()
+        };
+
+        (pool, position, tick_array_lower, tick_array_upper)
+    }
+
+    #[test]
+    fn test_quote_position_in_range() {
+        let (pool, position, tick_array_lower, tick_array_upper) = pool_position_and_tick_arrays();
+        let quote =
+            quote_position(&pool, &position, &tick_array_lower, &tick_array_upper, 110).unwrap();
+
+        // Growth inside is global minus below minus above: 30 - 10 - 5 for A, 50 - 20 for B.
+        assert_eq!(quote.pending_fee_a(&position), (LIQUIDITY * 14) as u64);
+        assert_eq!(quote.fee_owed_a, 7 + (LIQUIDITY * 14) as u64);
+        assert_eq!(quote.fee_owed_b, (LIQUIDITY * 30) as u64);
+
+        // 10 seconds at one token per second accrue to the only position, rounded down twice.
+        let growth = (10u128 << Q64_RESOLUTION) / LIQUIDITY;
+        let reward = ((LIQUIDITY * growth) >> Q64_RESOLUTION) as u64;
+        assert_eq!(quote.reward_owed, [reward, 0, 0]);
+        assert_eq!(quote.pending_rewards(&position), [reward, 0, 0]);
+        assert!(reward <= 10);
+
+        let (amount_a, amount_b) =
+            calculate_liquidity_token_deltas(0, pool.sqrt_price, &position, -(LIQUIDITY as i128))
+                .unwrap();
+        assert_eq!((quote.amount_a, quote.amount_b), (amount_a, amount_b));
+        assert!(quote.amount_a > 0 && quote.amount_b > 0);
+    }
+
+    #[test]
+    fn test_quote_matches_position_update() {
+        let (mut pool, mut position, tick_array_lower, tick_array_upper) =
+            pool_position_and_tick_arrays();
+        let quote =
+            quote_position(&pool, &position, &tick_array_lower, &tick_array_upper, 110).unwrap();
+        position.update(&quote.position_update);
+
+        // Nothing more is pending right after the update at the same timestamp.
+        pool.update_rewards(next_pool_reward_infos(&pool, 110).unwrap(), 110);
+        let requote =
+            quote_position(&pool, &position, &tick_array_lower, &tick_array_upper, 110).unwrap();
+        assert_eq!(requote.pending_fee_a(&position), 0);
+        assert_eq!(requote.pending_fee_b(&position), 0);
+        assert_eq!(requote.pending_rewards(&position), [0; NUM_REWARDS]);
+    }
+
+    #[test]
+    fn test_quote_position_out_of_range_and_errors() {
+        let (mut pool, position, tick_array_lower, tick_array_upper) =
+            pool_position_and_tick_arrays();
+        pool.tick_current_index = 100;
+        pool.sqrt_price = sqrt_price_from_tick_index(100);
+        let quote =
+            quote_position(&pool, &position, &tick_array_lower, &tick_array_upper, 100).unwrap();
+        assert_eq!(quote.amount_a, 0);
+        assert!(quote.amount_b > 0);
+
+        assert!(matches!(
+            quote_position(&pool, &position, &tick_array_lower, &tick_array_upper, 99),
+            Err(QuoteError::Program(_))
+        ));
+
+        let foreign_tick_array = TickArray {
+            pool: Pubkey::new_unique(),
+            ..tick_array_upper
+        };
+        assert!(matches!(
+            quote_position(
+                &pool,
+                &position,
+                &tick_array_lower,
+                &foreign_tick_array,
+                100
+            ),
+            Err(QuoteError::TickArrayPoolMismatch("upper"))
+        ));
+    }
+}
@@ -0,0 +1,585 @@
+//! Conversions between decimal adjusted prices, Q64.64 sqrt-prices and tick indexes.
+//!
+//! A decimal adjusted price is the price of one whole token A in whole tokens B, as shown to
+//! users, while the pool prices base units. Conversions from a price are exact for the binary
+//! value of the `f64`, so every rounding mode gives the same result on every platform, and the
+//! tick conversions agree with `sqrt_price_from_tick_index` and `tick_index_from_sqrt_price`.
+
+use pool::{
+    math::{
+        sqrt_price_from_tick_index, tick_index_from_sqrt_price, MAX_SQRT_PRICE_X64,
+        MIN_SQRT_PRICE_X64, U256,
+    },
+    state::{MAX_TICK_INDEX, MIN_TICK_INDEX},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum PriceError {
+    #[error("price must be finite and positive, got {0}")]
+    InvalidPrice(f64),
+    #[error("price is outside of the range supported by the pool")]
+    PriceOutOfRange,
+    #[error("sqrt price {0} is outside of the range supported by the pool")]
+    SqrtPriceOutOfRange(u128),
+    #[error("tick index {0} is outside of the range supported by the pool")]
+    TickIndexOutOfRange(i32),
+    #[error("tick spacing must be positive")]
+    InvalidTickSpacing,
+}
+
+/// How a conversion rounds a value that falls between two representable results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the lower result.
+    Down,
+    /// Round to the higher result.
+    Up,
+    /// Round to the closer result, and to the higher one when the value is halfway. Tick
+    /// indexes are compared in tick space, which is logarithmic in the price.
+    Nearest,
+}
+
+/// Price of token A in token B, in base units, from a Q64.64 sqrt-price.
+pub fn price_from_sqrt_price(sqrt_price: u128) -> f64 {
+    let sqrt_price = sqrt_price as f64 / 2f64.powi(64);
+    sqrt_price * sqrt_price
+}
+
+/// Converts a price in base units to a decimal adjusted price.
+pub fn adjust_price_for_decimals(price: f64, decimals_a: u8, decimals_b: u8) -> f64 {
+    price * 10f64.powi(i32::from(decimals_a) - i32::from(decimals_b))
+}
+
+/// Decimal adjusted price from a Q64.64 sqrt-price.
+pub fn decimal_price_from_sqrt_price(sqrt_price: u128, decimals_a: u8, decimals_b: u8) -> f64 {
+    adjust_price_for_decimals(price_from_sqrt_price(sqrt_price), decimals_a, decimals_b)
+}
+
+/// Decimal adjusted price at a tick index.
+pub fn decimal_price_from_tick_index(
+    tick_index: i32,
+    decimals_a: u8,
+    decimals_b: u8,
+) -> Result<f64, PriceError> {
+    check_tick_index(tick_index)?;
+    Ok(decimal_price_from_sqrt_price(
+        sqrt_price_from_tick_index(tick_index),
+        decimals_a,
+        decimals_b,
+    ))
+}
+
+/// Q64.64 sqrt-price of a decimal adjusted price, rounded as requested.
+pub fn sqrt_price_from_decimal_price(
+    price: f64,
+    decimals_a: u8,
+    decimals_b: u8,
+    rounding: Rounding,
+) -> Result<u128, PriceError> {
+    let square = SqrtPriceSquare::from_decimal_price(price, decimals_a, decimals_b)?;
+    let floor = square.floor.integer_sqrt();
+    let sqrt_price = match rounding {
+        Rounding::Down => floor,
+        Rounding::Up if square.is_square_of(floor) => floor,
+        Rounding::Up => floor + 1,
+        // The square lies between floor^2 and (floor + 1)^2. It is at least (floor + 1/2)^2 =
+        // floor^2 + floor + 1/4 if its integer part exceeds floor^2 + floor, or equals it and
+        // the fractional part is at least 1/4.
+        Rounding::Nearest => {
+            let midpoint = floor * floor + floor;
+            let quarter = (square.denominator + 3) / 4;
+            if square.floor > midpoint || (square.floor == midpoint && square.remainder >= quarter)
+            {
+                floor + 1
+            } else {
+                floor
+            }
+        }
+    };
+
+    if sqrt_price < U256::from(MIN_SQRT_PRICE_X64) || sqrt_price > U256::from(MAX_SQRT_PRICE_X64) {
+        return Err(PriceError::PriceOutOfRange);
+    }
+    Ok(sqrt_price.as_u128())
+}
+
+/// Tick index of a decimal adjusted price. `Down` gives the highest tick at or below the price
+/// and `Up` the lowest tick at or above it.
+pub fn tick_index_from_decimal_price(
+    price: f64,
+    decimals_a: u8,
+    decimals_b: u8,
+    rounding: Rounding,
+) -> Result<i32, PriceError> {
+    let square = SqrtPriceSquare::from_decimal_price(price, decimals_a, decimals_b)?;
+    let sqrt_price = square.floor.integer_sqrt();
+    if sqrt_price < U256::from(MIN_SQRT_PRICE_X64) || sqrt_price > U256::from(MAX_SQRT_PRICE_X64) {
+        return Err(PriceError::PriceOutOfRange);
+    }
+    let tick_index = round_tick_index(&square, sqrt_price.as_u128(), rounding);
+    if tick_index > MAX_TICK_INDEX {
+        return Err(PriceError::PriceOutOfRange);
+    }
+    Ok(tick_index)
+}
+
+/// Tick index of a Q64.64 sqrt-price. `Down` matches `tick_index_from_sqrt_price`.
+pub fn tick_index_from_sqrt_price_rounded(
+    sqrt_price: u128,
+    rounding: Rounding,
+) -> Result<i32, PriceError> {
+    if !(MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64).contains(&sqrt_price) {
+        return Err(PriceError::SqrtPriceOutOfRange(sqrt_price));
+    }
+    let square = SqrtPriceSquare {
+        floor: U256::from(sqrt_price) * U256::from(sqrt_price),
+        remainder: U256::zero(),
+        denominator: U256::one(),
+    };
+    Ok(round_tick_index(&square, sqrt_price, rounding))
+}
+
+/// Rounds a tick index to a multiple of the tick spacing that positions can use, clamped to the
+/// usable range of the pool.
+pub fn usable_tick_index(
+    tick_index: i32,
+    tick_spacing: u16,
+    rounding: Rounding,
+) -> Result<i32, PriceError> {
+    if tick_spacing == 0 {
+        return Err(PriceError::InvalidTickSpacing);
+    }
+    check_tick_index(tick_index)?;
+
+    let tick_spacing = i32::from(tick_spacing);
+    let lower = tick_index.div_euclid(tick_spacing) * tick_spacing;
+    let usable_tick_index = match rounding {
+        Rounding::Down => lower,
+        Rounding::Up if lower == tick_index => lower,
+        Rounding::Up => lower + tick_spacing,
+        Rounding::Nearest if 2 * (tick_index - lower) >= tick_spacing => lower + tick_spacing,
+        Rounding::Nearest => lower,
+    };
+
+    let min_usable_tick_index =
+        MIN_TICK_INDEX + (tick_spacing - MIN_TICK_INDEX.rem_euclid(tick_spacing)) % tick_spacing;
+    let max_usable_tick_index = MAX_TICK_INDEX - MAX_TICK_INDEX.rem_euclid(tick_spacing);
+    Ok(usable_tick_index.clamp(min_usable_tick_index, max_usable_tick_index))
+}
+
+/// Q64.64 sqrt-price of the inverted price, token B in token A, rounded as requested. The range
+/// of sqrt-prices is not exactly symmetric, so the extreme sqrt-prices have no inverse.
+pub fn invert_sqrt_price(sqrt_price: u128, rounding: Rounding) -> Result<u128, PriceError> {
+    if !(MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64).contains(&sqrt_price) {
+        return Err(PriceError::SqrtPriceOutOfRange(sqrt_price));
+    }
+    // 1 / (s / 2^64) in Q64.64 is 2^128 / s.
+    let numerator = U256::one() << 128;
+    let divisor = U256::from(sqrt_price);
+    let (quotient, remainder) = numerator.div_mod(divisor);
+    let inverted = match rounding {
+        Rounding::Down => quotient,
+        Rounding::Up if remainder.is_zero() => quotient,
+        Rounding::Up => quotient + 1,
+        Rounding::Nearest if remainder >= divisor - remainder => quotient + 1,
+        Rounding::Nearest => quotient,
+    };
+    if inverted < U256::from(MIN_SQRT_PRICE_X64) || inverted > U256::from(MAX_SQRT_PRICE_X64) {
+        return Err(PriceError::PriceOutOfRange);
+    }
+    Ok(inverted.as_u128())
+}
+
+/// Tick index of the inverted price, token B in token A. Ticks are symmetric around zero, so
+/// the sqrt-price at the inverted tick is the inverted sqrt-price at the tick, up to rounding.
+pub fn invert_tick_index(tick_index: i32) -> Result<i32, PriceError> {
+    check_tick_index(tick_index)?;
+    Ok(-tick_index)
+}
+
+/// Decimal adjusted price of token B in token A. Both prices are in whole tokens, so no decimals
+/// are needed.
+pub fn invert_decimal_price(price: f64) -> Result<f64, PriceError> {
+    check_price(price)?;
+    Ok(1.0 / price)
+}
+
+fn check_tick_index(tick_index: i32) -> Result<(), PriceError> {
+    if !(MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick_index) {
+        return Err(PriceError::TickIndexOutOfRange(tick_index));
+    }
+    Ok(())
+}
+
+fn check_price(price: f64) -> Result<(), PriceError> {
+    if !price.is_finite() || price <= 0.0 {
+        return Err(PriceError::InvalidPrice(price));
+    }
+    Ok(())
+}
+
+// Rounds the tick index of a squared sqrt-price. The sqrt-price of the tick below is at most
+// the floor of the square root, so tick_index_from_sqrt_price of it gives the lower tick.
+fn round_tick_index(square: &SqrtPriceSquare, sqrt_price_floor: u128, rounding: Rounding) -> i32 {
+    let lower = tick_index_from_sqrt_price(&sqrt_price_floor);
+    let lower_sqrt_price = U256::from(sqrt_price_from_tick_index(lower));
+    if square.is_square_of(lower_sqrt_price) || lower >= MAX_TICK_INDEX {
+        return lower;
+    }
+
+    match rounding {
+        Rounding::Down => lower,
+        Rounding::Up => lower + 1,
+        // In tick space the midpoint between two ticks is the geometric mean of their prices,
+        // which is the product of their sqrt-prices.
+        Rounding::Nearest => {
+            let upper_sqrt_price = U256::from(sqrt_price_from_tick_index(lower + 1));
+            if square.floor >= lower_sqrt_price * upper_sqrt_price {
+                lower + 1
+            } else {
+                lower
+            }
+        }
+    }
+}
+
+// The exact square of a Q64.64 sqrt-price, that is the price in base units scaled by 2^128,
+// split into its integer part and a fraction remainder / denominator.
+struct SqrtPriceSquare {
+    floor: U256,
+    remainder: U256,
+    denominator: U256,
+}
+
+impl SqrtPriceSquare {
+    fn from_decimal_price(price: f64, decimals_a: u8, decimals_b: u8) -> Result<Self, PriceError> {
+        check_price(price)?;
+
+        // Reject prices far out of range up front, so that the exact values below fit.
+        let sqrt_price_estimate =
+            adjust_price_for_decimals(price, decimals_b, decimals_a).sqrt() * 2f64.powi(64);
+        if !(MIN_SQRT_PRICE_X64 as f64 / 2.0..=MAX_SQRT_PRICE_X64 as f64 * 2.0)
+            .contains(&sqrt_price_estimate)
+        {
+            return Err(PriceError::PriceOutOfRange);
+        }
+
+        // price = mantissa * 2^exponent exactly, and the base unit price is
+        // price * 10^(decimals_b - decimals_a).
+        let bits = price.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = match biased_exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | (1 << 52), biased_exponent - 1075),
+        };
+
+        let mut numerator = U256::from(mantissa);
+        let mut denominator = U256::one();
+        let shift = exponent + 128;
+        if shift >= 0 {
+            numerator = checked_shl(numerator, shift as u32)?;
+        } else {
+            denominator = checked_shl(denominator, shift.unsigned_abs())?;
+        }
+        let decimals_delta = i32::from(decimals_b) - i32::from(decimals_a);
+        let scale = U256::from(10)
+            .checked_pow(U256::from(decimals_delta.unsigned_abs()))
+            .ok_or(PriceError::PriceOutOfRange)?;
+        if decimals_delta >= 0 {
+            numerator = numerator
+                .checked_mul(scale)
+                .ok_or(PriceError::PriceOutOfRange)?;
+        } else {
+            denominator = denominator
+                .checked_mul(scale)
+                .ok_or(PriceError::PriceOutOfRange)?;
+        }
+
+        let (floor, remainder) = numerator.div_mod(denominator);
+        Ok(Self {
+            floor,
+            remainder,
+            denominator,
+        })
+    }
+
+    fn is_square_of(&self, value: U256) -> bool {
+        self.remainder.is_zero() && value * value == self.floor
+    }
+}
+
+fn checked_shl(value: U256, shift: u32) -> Result<U256, PriceError> {
+    if shift >= 256 || value.leading_zeros() < shift {
+        return Err(PriceError::PriceOutOfRange);
+    }
+    Ok(value << shift as usize)
+}
+
+#[cfg(test)]
+mod price_tests {
+    use super::*;
+
+    const ROUNDINGS: [Rounding; 3] = [Rounding::Down, Rounding::Up, Rounding::Nearest];
+
+    fn sample_tick_indexes() -> Vec<i32> {
+        let mut tick_indexes: Vec<i32> = (MIN_TICK_INDEX..=MAX_TICK_INDEX).step_by(997).collect();
+        tick_indexes.extend_from_slice(&[
+            MIN_TICK_INDEX,
+            MIN_TICK_INDEX + 1,
+            -98765,
+            -1,
+            0,
+            1,
+            12345,
+            MAX_TICK_INDEX - 1,
+            MAX_TICK_INDEX,
+        ]);
+        tick_indexes
+    }
+
+    #[test]
+    fn test_sqrt_price_from_exact_price() {
+        for rounding in ROUNDINGS {
+            assert_eq!(
+                sqrt_price_from_decimal_price(4.0, 0, 0, rounding),
+                Ok(2 << 64)
+            );
+            // 4000 whole tokens B per whole token A is 4 base units when A has 3 more decimals.
+            assert_eq!(
+                sqrt_price_from_decimal_price(4000.0, 9, 6, rounding),
+                Ok(2 << 64)
+            );
+            assert_eq!(
+                sqrt_price_from_decimal_price(0.0625, 6, 6, rounding),
+                Ok(1 << 62)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sqrt_price_rounding() {
+        // sqrt(2) * 2^64 = 26087635650665564424.69...
+        let floor = 26087635650665564424;
+        assert_eq!(
+            sqrt_price_from_decimal_price(2.0, 0, 0, Rounding::Down),
+            Ok(floor)
+        );
+        assert_eq!(
+            sqrt_price_from_decimal_price(2.0, 0, 0, Rounding::Up),
+            Ok(floor + 1)
+        );
+        assert_eq!(
+            sqrt_price_from_decimal_price(2.0, 0, 0, Rounding::Nearest),
+            Ok(floor + 1)
+        );
+    }
+
+    // Prices at the extreme ticks can round out of range as an f64, and their inverses are out of
+    // range too.
+    fn interior_sample_tick_indexes() -> Vec<i32> {
+        sample_tick_indexes()
+            .into_iter()
+            .filter(|tick_index| tick_index.abs() < MAX_TICK_INDEX)
+            .collect()
+    }
+
+    #[test]
+    fn test_sqrt_price_round_trip() {
+        for tick_index in interior_sample_tick_indexes() {
+            let sqrt_price = sqrt_price_from_tick_index(tick_index);
+            let price = decimal_price_from_sqrt_price(sqrt_price, 6, 9);
+            let round_trip = sqrt_price_from_decimal_price(price, 6, 9, Rounding::Nearest).unwrap();
+            assert!(
+                round_trip.abs_diff(sqrt_price) <= sqrt_price >> 50,
+                "tick {}",
+                tick_index
+            );
+        }
+    }
+
+    #[test]
+    fn test_tick_index_from_sqrt_price_round_trip() {
+        for tick_index in sample_tick_indexes() {
+            let sqrt_price = sqrt_price_from_tick_index(tick_index);
+            for rounding in ROUNDINGS {
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(sqrt_price, rounding),
+                    Ok(tick_index)
+                );
+            }
+            assert_eq!(
+                tick_index_from_sqrt_price_rounded(sqrt_price, Rounding::Down),
+                Ok(tick_index_from_sqrt_price(&sqrt_price))
+            );
+
+            if tick_index < MAX_TICK_INDEX {
+                let above = sqrt_price + 1;
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(above, Rounding::Down),
+                    Ok(tick_index)
+                );
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(above, Rounding::Up),
+                    Ok(tick_index + 1)
+                );
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(above, Rounding::Nearest),
+                    Ok(tick_index)
+                );
+            }
+            if tick_index > MIN_TICK_INDEX {
+                let below = sqrt_price - 1;
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(below, Rounding::Down),
+                    Ok(tick_index - 1)
+                );
+                assert_eq!(
+                    tick_index_from_sqrt_price_rounded(below, Rounding::Up),
+                    Ok(tick_index)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tick_index_nearest_uses_geometric_midpoint() {
+        let lower = U256::from(sqrt_price_from_tick_index(100));
+        let upper = U256::from(sqrt_price_from_tick_index(101));
+        let midpoint = (lower * upper).integer_sqrt().as_u128();
+        assert_eq!(
+            tick_index_from_sqrt_price_rounded(midpoint, Rounding::Nearest),
+            Ok(100)
+        );
+        assert_eq!(
+            tick_index_from_sqrt_price_rounded(midpoint + 1, Rounding::Nearest),
+            Ok(101)
+        );
+    }
+
+    #[test]
+    fn test_tick_index_from_decimal_price_round_trip() {
+        for tick_index in interior_sample_tick_indexes() {
+            for (decimals_a, decimals_b) in [(0, 0), (6, 9), (9, 6)] {
+                let price =
+                    decimal_price_from_tick_index(tick_index, decimals_a, decimals_b).unwrap();
+                assert_eq!(
+                    tick_index_from_decimal_price(price, decimals_a, decimals_b, Rounding::Nearest),
+                    Ok(tick_index),
+                    "tick {} decimals {} {}",
+                    tick_index,
+                    decimals_a,
+                    decimals_b
+                );
+            }
+        }
+
+        // The price at tick 1 is 1.0001, so 1.00005 lies between ticks 0 and 1.
+        assert_eq!(
+            tick_index_from_decimal_price(1.00005, 0, 0, Rounding::Down),
+            Ok(0)
+        );
+        assert_eq!(
+            tick_index_from_decimal_price(1.00005, 0, 0, Rounding::Up),
+            Ok(1)
+        );
+        assert_eq!(
+            tick_index_from_decimal_price(1.0, 0, 0, Rounding::Up),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_usable_tick_index() {
+        assert_eq!(usable_tick_index(-1, 64, Rounding::Down), Ok(-64));
+        assert_eq!(usable_tick_index(-1, 64, Rounding::Up), Ok(0));
+        assert_eq!(usable_tick_index(-1, 64, Rounding::Nearest), Ok(0));
+        assert_eq!(usable_tick_index(-33, 64, Rounding::Nearest), Ok(-64));
+        assert_eq!(usable_tick_index(-32, 64, Rounding::Nearest), Ok(0));
+        assert_eq!(usable_tick_index(128, 64, Rounding::Up), Ok(128));
+        assert_eq!(usable_tick_index(129, 1, Rounding::Down), Ok(129));
+
+        assert_eq!(
+            usable_tick_index(MIN_TICK_INDEX, 64, Rounding::Down),
+            Ok(-443584)
+        );
+        assert_eq!(
+            usable_tick_index(MAX_TICK_INDEX, 64, Rounding::Up),
+            Ok(443584)
+        );
+        assert_eq!(
+            usable_tick_index(MAX_TICK_INDEX, 1, Rounding::Up),
+            Ok(MAX_TICK_INDEX)
+        );
+
+        assert_eq!(
+            usable_tick_index(0, 0, Rounding::Down),
+            Err(PriceError::InvalidTickSpacing)
+        );
+        assert_eq!(
+            usable_tick_index(MAX_TICK_INDEX + 1, 8, Rounding::Down),
+            Err(PriceError::TickIndexOutOfRange(MAX_TICK_INDEX + 1))
+        );
+    }
+
+    #[test]
+    fn test_invert() {
+        for rounding in ROUNDINGS {
+            assert_eq!(invert_sqrt_price(2 << 64, rounding), Ok(1 << 63));
+        }
+        assert_eq!(
+            invert_sqrt_price(3 << 64, Rounding::Up).unwrap(),
+            invert_sqrt_price(3 << 64, Rounding::Down).unwrap() + 1
+        );
+
+        for tick_index in interior_sample_tick_indexes() {
+            let inverted =
+                invert_sqrt_price(sqrt_price_from_tick_index(tick_index), Rounding::Nearest)
+                    .unwrap();
+            assert_eq!(
+                tick_index_from_sqrt_price_rounded(inverted, Rounding::Nearest),
+                invert_tick_index(tick_index)
+            );
+        }
+
+        assert_eq!(
+            invert_sqrt_price(MIN_SQRT_PRICE_X64, Rounding::Down),
+            Err(PriceError::PriceOutOfRange)
+        );
+
+        assert_eq!(invert_decimal_price(4.0), Ok(0.25));
+        assert_eq!(
+            invert_decimal_price(0.0),
+            Err(PriceError::InvalidPrice(0.0))
+        );
+    }
+
+    #[test]
+    fn test_invalid_prices() {
+        assert_eq!(
+            sqrt_price_from_decimal_price(-1.0, 0, 0, Rounding::Down),
+            Err(PriceError::InvalidPrice(-1.0))
+        );
+        assert!(matches!(
+            sqrt_price_from_decimal_price(f64::NAN, 0, 0, Rounding::Down),
+            Err(PriceError::InvalidPrice(_))
+        ));
+        assert_eq!(
+            sqrt_price_from_decimal_price(1e40, 0, 0, Rounding::Down),
+            Err(PriceError::PriceOutOfRange)
+        );
+        assert_eq!(
+            tick_index_from_decimal_price(1e-40, 0, 0, Rounding::Down),
+            Err(PriceError::PriceOutOfRange)
+        );
+        assert_eq!(
+            sqrt_price_from_decimal_price(1.0, 0, 255, Rounding::Down),
+            Err(PriceError::PriceOutOfRange)
+        );
+        assert_eq!(
+            tick_index_from_sqrt_price_rounded(MIN_SQRT_PRICE_X64 - 1, Rounding::Up),
+            Err(PriceError::SqrtPriceOutOfRange(MIN_SQRT_PRICE_X64 - 1))
+        );
+    }
+}
@@ -0,0 +1,1158 @@
+//! Routes swaps across pools. The router builds a graph of token mints connected by pools, finds
+//! routes of one to three hops, quotes them by running the program's swap against the tick arrays
+//! of each pool, and builds `swap` and `two_hop_swap` instructions for the best route or for a
+//! split of the trade across parallel routes.
+//!
+//! Quotes run `swap_manager::swap`, which logs the fee growth of every simulated swap through
+//! `msg!`. Off-chain this is printed to stdout.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use anchor_lang::{
+    error::Error as ProgramError,
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token::get_associated_token_address, token};
+use pool::{
+    manager::swap_manager::swap,
+    math::{MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64},
+    state::{ElysiumPool, TickArray, TICK_ARRAY_SIZE},
+    util::SwapTickSequence,
+};
+use thiserror::Error;
+
+/// The most hops a route can have.
+pub const MAX_ROUTE_HOPS: usize = 3;
+
+/// The number of equal parts a trade is divided into when it is split across routes.
+pub const SPLIT_PARTS: u64 = 10;
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+#[derive(Debug, Error)]
+pub enum RouteError {
+    #[error("no route from {0} to {1} can fill the trade")]
+    NoRoute(Pubkey, Pubkey),
+    #[error("routes must have between 1 and {} hops", MAX_ROUTE_HOPS)]
+    InvalidHopCount,
+    #[error("pool {0} has not been added to the router")]
+    UnknownPool(Pubkey),
+    #[error("no tick array of pool {0} covers its current tick")]
+    MissingTickArray(Pubkey),
+    #[error("pool {0} does not have the liquidity to swap the whole input")]
+    InsufficientLiquidity(Pubkey),
+    #[error("slippage must be at most 10000 bps")]
+    InvalidSlippage,
+    #[error("program error: {0}")]
+    Program(Box<ProgramError>),
+}
+
+impl From<ProgramError> for RouteError {
+    fn from(error: ProgramError) -> Self {
+        RouteError::Program(Box::new(error))
+    }
+}
+
+/// A swap through one pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hop {
+    pub pool: Pubkey,
+    pub a_to_b: bool,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+}
+
+/// A sequence of swaps where the output of each hop is the input of the next one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub hops: Vec<Hop>,
+}
+
+impl Route {
+    fn shares_pool_with(&self, other: &Route) -> bool {
+        self.hops
+            .iter()
+            .any(|hop| other.hops.iter().any(|other| other.pool == hop.pool))
+    }
+}
+
+/// The result of swapping an exact input amount along a route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteQuote {
+    pub route: Route,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// The output amount of each hop, the last one being `amount_out`.
+    pub hop_amounts_out: Vec<u64>,
+}
+
+/// A trade split across routes that share no pool, so that each part is quoted independently.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub parts: Vec<RouteQuote>,
+}
+
+/// The token accounts used by the instructions of a route.
+#[derive(Clone, Debug)]
+pub struct SwapAccounts {
+    pub token_authority: Pubkey,
+    token_accounts: HashMap<Pubkey, Pubkey>,
+}
+
+impl SwapAccounts {
+    /// Uses the associated token accounts of the token authority unless overridden.
+    pub fn new(token_authority: Pubkey) -> Self {
+        Self {
+            token_authority,
+            token_accounts: HashMap::new(),
+        }
+    }
+
+    /// Uses `token_account` for the given mint instead of the associated token account.
+    pub fn with_token_account(mut self, mint: Pubkey, token_account: Pubkey) -> Self {
+        self.token_accounts.insert(mint, token_account);
+        self
+    }
+
+    pub fn token_account(&self, mint: &Pubkey) -> Pubkey {
+        match self.token_accounts.get(mint) {
+            Some(token_account) => *token_account,
+            None => get_associated_token_address(&self.token_authority, mint),
+        }
+    }
+}
+
+struct RoutablePool {
+    pool: ElysiumPool,
+    tick_arrays: HashMap<i32, TickArray>,
+}
+
+/// Finds and quotes routes across a set of pools at a given timestamp and slot.
+pub struct Router {
+    timestamp: u64,
+    slot: u64,
+    pools: HashMap<Pubkey, RoutablePool>,
+    pools_by_mint: HashMap<Pubkey, Vec<Pubkey>>,
+    allowlisted_pools: HashSet<Pubkey>,
+}
+
+impl Router {
+    /// Creates a router that quotes swaps as if they executed at the timestamp and slot, which
+    /// determine the activation of pools, their launch fee rate and reward growth.
+    pub fn new(timestamp: u64, slot: u64) -> Self {
+        Self {
+            timestamp,
+            slot,
+            pools: HashMap::new(),
+            pools_by_mint: HashMap::new(),
+            allowlisted_pools: HashSet::new(),
+        }
+    }
+
+    /// Adds a pool with the tick arrays it can swap through, or replaces a pool added before.
+    /// Pools with a hook program are never routed through, since their hook accounts are unknown.
+    pub fn add_pool(&mut self, address: Pubkey, pool: ElysiumPool, tick_arrays: Vec<TickArray>) {
+        let tick_arrays = tick_arrays
+            .into_iter()
+            .filter(|tick_array| tick_array.pool == address)
+            .map(|tick_array| (tick_array.start_tick_index, tick_array))
+            .collect();
+
+        for mint in [pool.token_mint_a, pool.token_mint_b] {
+            let pools = self.pools_by_mint.entry(mint).or_default();
+            if !pools.contains(&address) {
+                pools.push(address);
+            }
+        }
+        self.pools
+            .insert(address, RoutablePool { pool, tick_arrays });
+    }
+
+    /// Routes through a permissioned pool, which requires the token authority of the swaps to
+    /// hold an AllowlistEntry for it.
+    pub fn allow_permissioned_pool(&mut self, address: Pubkey) {
+        self.allowlisted_pools.insert(address);
+    }
+
+    fn is_routable(&self, address: &Pubkey, pool: &ElysiumPool) -> bool {
+        !pool.has_hook_program()
+            && pool.is_activated(self.timestamp, self.slot)
+            && (!pool.is_permissioned() || self.allowlisted_pools.contains(address))
+    }
+
+    fn routable_pool(&self, address: &Pubkey) -> Result<&RoutablePool, RouteError> {
+        self.pools
+            .get(address)
+            .ok_or(RouteError::UnknownPool(*address))
+    }
+
+    /// Finds every route from the input to the output mint with at most `max_hops` hops that
+    /// visits no mint twice.
+    pub fn find_routes(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        max_hops: usize,
+    ) -> Vec<Route> {
+        let mut routes = Vec::new();
+        if input_mint != output_mint {
+            let mut hops = Vec::with_capacity(MAX_ROUTE_HOPS);
+            self.extend_routes(
+                input_mint,
+                output_mint,
+                max_hops.min(MAX_ROUTE_HOPS),
+                &mut hops,
+                &mut routes,
+            );
+        }
+        routes
+    }
+
+    fn extend_routes(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        max_hops: usize,
+        hops: &mut Vec<Hop>,
+        routes: &mut Vec<Route>,
+    ) {
+        let addresses = match self.pools_by_mint.get(&input_mint) {
+            Some(addresses) => addresses,
+            None => return,
+        };
+
+        for address in addresses {
+            let pool = &self.pools[address].pool;
+            if !self.is_routable(address, pool) {
+                continue;
+            }
+
+            let a_to_b = pool.token_mint_a == input_mint;
+            let next_mint = if a_to_b {
+                pool.token_mint_b
+            } else {
+                pool.token_mint_a
+            };
+            if next_mint == input_mint || hops.iter().any(|hop| hop.input_mint == next_mint) {
+                continue;
+            }
+
+            hops.push(Hop {
+                pool: *address,
+                a_to_b,
+                input_mint,
+                output_mint: next_mint,
+            });
+            if next_mint == output_mint {
+                routes.push(Route { hops: hops.clone() });
+            } else if hops.len() < max_hops {
+                self.extend_routes(next_mint, output_mint, max_hops, hops, routes);
+            }
+            hops.pop();
+        }
+    }
+
+    /// Quotes swapping an exact input amount along a route.
+    pub fn quote_route(&self, route: &Route, amount_in: u64) -> Result<RouteQuote, RouteError> {
+        if route.hops.is_empty() || route.hops.len() > MAX_ROUTE_HOPS {
+            return Err(RouteError::InvalidHopCount);
+        }
+
+        let mut amount = amount_in;
+        let mut hop_amounts_out = Vec::with_capacity(route.hops.len());
+        for hop in route.hops.iter() {
+            amount = self.quote_hop(hop, amount)?;
+            hop_amounts_out.push(amount);
+        }
+
+        Ok(RouteQuote {
+            route: route.clone(),
+            amount_in,
+            amount_out: amount,
+            hop_amounts_out,
+        })
+    }
+
+    // Runs the swap of the program on copies of the tick arrays the swap instruction would get.
+    fn quote_hop(&self, hop: &Hop, amount: u64) -> Result<u64, RouteError> {
+        let routable_pool = self.routable_pool(&hop.pool)?;
+        let tick_arrays: Vec<RefCell<TickArray>> = routable_pool
+            .swap_tick_arrays(hop.a_to_b)
+            .into_iter()
+            .map(|tick_array| RefCell::new(*tick_array))
+            .collect();
+        if tick_arrays.is_empty() {
+            return Err(RouteError::MissingTickArray(hop.pool));
+        }
+
+        let mut swap_tick_sequence = SwapTickSequence::new(
+            tick_arrays[0].borrow_mut(),
+            tick_arrays.get(1).map(RefCell::borrow_mut),
+            tick_arrays.get(2).map(RefCell::borrow_mut),
+        );
+        let swap_update = swap(
+            &routable_pool.pool,
+            &mut swap_tick_sequence,
+            amount,
+            sqrt_price_limit(hop.a_to_b),
+            true,
+            hop.a_to_b,
+            self.timestamp,
+            self.slot,
+        )?;
+
+        let (amount_in, amount_out) = if hop.a_to_b {
+            (swap_update.amount_a, swap_update.amount_b)
+        } else {
+            (swap_update.amount_b, swap_update.amount_a)
+        };
+        if amount_in != amount {
+            return Err(RouteError::InsufficientLiquidity(hop.pool));
+        }
+        Ok(amount_out)
+    }
+
+    /// Quotes every route that can swap the whole input amount, best output first. Routes with
+    /// equal output are ordered by fewer hops.
+    pub fn best_routes(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        amount_in: u64,
+        max_hops: usize,
+    ) -> Result<Vec<RouteQuote>, RouteError> {
+        let mut quotes: Vec<RouteQuote> = self
+            .find_routes(input_mint, output_mint, max_hops)
+            .iter()
+            .filter_map(|route| self.quote_route(route, amount_in).ok())
+            .collect();
+        if quotes.is_empty() {
+            return Err(RouteError::NoRoute(input_mint, output_mint));
+        }
+
+        quotes.sort_by(|a, b| {
+            b.amount_out
+                .cmp(&a.amount_out)
+                .then(a.route.hops.len().cmp(&b.route.hops.len()))
+        });
+        Ok(quotes)
+    }
+
+    /// Finds the best way to swap the input amount, either along the best single route or split
+    /// across up to `max_routes` routes that share no pool. The input is divided into
+    /// `SPLIT_PARTS` parts, each given to the route where it adds the most output. The split is
+    /// only returned if it beats the best single route.
+    pub fn best_split(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        amount_in: u64,
+        max_hops: usize,
+        max_routes: usize,
+    ) -> Result<SplitQuote, RouteError> {
+        let best_route = self
+            .best_routes(input_mint, output_mint, amount_in, max_hops)
+            .ok()
+            .and_then(|quotes| quotes.into_iter().next());
+
+        let part_amount = amount_in / SPLIT_PARTS;
+        let candidates = match part_amount {
+            0 => Vec::new(),
+            _ => self.split_candidates(input_mint, output_mint, part_amount, max_hops, max_routes),
+        };
+
+        let mut allocations: Vec<Option<RouteQuote>> = vec![None; candidates.len()];
+        let mut split_complete = candidates.len() > 1;
+        for part in 0..SPLIT_PARTS {
+            if !split_complete {
+                break;
+            }
+            // The last part also takes the remainder of the division.
+            let amount = match part {
+                p if p == SPLIT_PARTS - 1 => amount_in - part_amount * (SPLIT_PARTS - 1),
+                _ => part_amount,
+            };
+
+            let mut best: Option<(usize, RouteQuote, u64)> = None;
+            for (index, route) in candidates.iter().enumerate() {
+                let (allocated_in, allocated_out) = match &allocations[index] {
+                    Some(quote) => (quote.amount_in, quote.amount_out),
+                    None => (0, 0),
+                };
+                let quote = match self.quote_route(route, allocated_in + amount) {
+                    Ok(quote) => quote,
+                    Err(_) => continue,
+                };
+                let gain = quote.amount_out.saturating_sub(allocated_out);
+                let improves = match &best {
+                    Some((_, _, best_gain)) => gain > *best_gain,
+                    None => true,
+                };
+                if improves {
+                    best = Some((index, quote, gain));
+                }
+            }
+
+            match best {
+                Some((index, quote, _)) => allocations[index] = Some(quote),
+                None => split_complete = false,
+            }
+        }
+
+        let parts: Vec<RouteQuote> = allocations.into_iter().flatten().collect();
+        let split_amount_out: u64 = parts.iter().map(|quote| quote.amount_out).sum();
+        let split_wins = split_complete
+            && parts.len() > 1
+            && match &best_route {
+                Some(quote) => split_amount_out > quote.amount_out,
+                None => true,
+            };
+
+        if split_wins {
+            Ok(SplitQuote {
+                amount_in,
+                amount_out: split_amount_out,
+                parts,
+            })
+        } else {
+            let quote = best_route.ok_or(RouteError::NoRoute(input_mint, output_mint))?;
+            Ok(SplitQuote {
+                amount_in,
+                amount_out: quote.amount_out,
+                parts: vec![quote],
+            })
+        }
+    }
+
+    // Picks the routes with the best output for a single part that share no pool with a better
+    // route, so that their quotes do not affect each other.
+    fn split_candidates(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        part_amount: u64,
+        max_hops: usize,
+        max_routes: usize,
+    ) -> Vec<Route> {
+        let quotes = match self.best_routes(input_mint, output_mint, part_amount, max_hops) {
+            Ok(quotes) => quotes,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut candidates: Vec<Route> = Vec::with_capacity(max_routes);
+        for quote in quotes {
+            if candidates.len() == max_routes {
+                break;
+            }
+            if !candidates
+                .iter()
+                .any(|candidate| candidate.shares_pool_with(&quote.route))
+            {
+                candidates.push(quote.route);
+            }
+        }
+        candidates
+    }
+
+    /// Builds the instructions that execute a quoted route: `swap` for one hop, `two_hop_swap`
+    /// for two, and `two_hop_swap` followed by `swap` for three. The minimum output of each
+    /// instruction is its quoted output less `slippage_bps`, and the last swap of a three hop
+    /// route takes the minimum output of the first two hops as its input.
+    pub fn route_instructions(
+        &self,
+        quote: &RouteQuote,
+        accounts: &SwapAccounts,
+        slippage_bps: u16,
+    ) -> Result<Vec<Instruction>, RouteError> {
+        if u64::from(slippage_bps) > BPS_DENOMINATOR {
+            return Err(RouteError::InvalidSlippage);
+        }
+
+        let hops = &quote.route.hops;
+        match hops.len() {
+            1 => Ok(vec![self.swap_instruction(
+                &hops[0],
+                quote.amount_in,
+                apply_slippage(quote.amount_out, slippage_bps),
+                accounts,
+            )?]),
+            2 => Ok(vec![self.two_hop_swap_instruction(
+                &hops[0],
+                &hops[1],
+                quote.amount_in,
+                apply_slippage(quote.amount_out, slippage_bps),
+                accounts,
+            )?]),
+            3 => {
+                let intermediate_amount = apply_slippage(quote.hop_amounts_out[1], slippage_bps);
+                let amount_out = self.quote_hop(&hops[2], intermediate_amount)?;
+                Ok(vec![
+                    self.two_hop_swap_instruction(
+                        &hops[0],
+                        &hops[1],
+                        quote.amount_in,
+                        intermediate_amount,
+                        accounts,
+                    )?,
+                    self.swap_instruction(
+                        &hops[2],
+                        intermediate_amount,
+                        apply_slippage(amount_out, slippage_bps),
+                        accounts,
+                    )?,
+                ])
+            }
+            _ => Err(RouteError::InvalidHopCount),
+        }
+    }
+
+    /// Builds the instructions of every part of a split quote.
+    pub fn split_instructions(
+        &self,
+        quote: &SplitQuote,
+        accounts: &SwapAccounts,
+        slippage_bps: u16,
+    ) -> Result<Vec<Instruction>, RouteError> {
+        let mut instructions = Vec::new();
+        for part in quote.parts.iter() {
+            instructions.extend(self.route_instructions(part, accounts, slippage_bps)?);
+        }
+        Ok(instructions)
+    }
+
+    fn swap_instruction(
+        &self,
+        hop: &Hop,
+        amount: u64,
+        other_amount_threshold: u64,
+        accounts: &SwapAccounts,
+    ) -> Result<Instruction, RouteError> {
+        let routable_pool = self.routable_pool(&hop.pool)?;
+        let pool = &routable_pool.pool;
+        let tick_arrays = routable_pool.swap_tick_array_addresses(&hop.pool, hop.a_to_b)?;
+
+        let mut account_metas = pool::accounts::Swap {
+            token_program: token::ID,
+            token_authority: accounts.token_authority,
+            pool: hop.pool,
+            token_owner_account_a: accounts.token_account(&pool.token_mint_a),
+            token_vault_a: pool.token_vault_a,
+            token_owner_account_b: accounts.token_account(&pool.token_mint_b),
+            token_vault_b: pool.token_vault_b,
+            tick_array_0: tick_arrays[0],
+            tick_array_1: tick_arrays[1],
+            tick_array_2: tick_arrays[2],
+            oracle: oracle_address(&hop.pool),
+        }
+        .to_account_metas(None);
+        account_metas.extend(remaining_account_metas(
+            &hop.pool,
+            pool,
+            &tick_arrays,
+            &accounts.token_authority,
+        ));
+
+        Ok(Instruction {
+            program_id: pool::ID,
+            accounts: account_metas,
+            data: pool::instruction::Swap {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit: sqrt_price_limit(hop.a_to_b),
+                amount_specified_is_input: true,
+                a_to_b: hop.a_to_b,
+            }
+            .data(),
+        })
+    }
+
+    fn two_hop_swap_instruction(
+        &self,
+        hop_one: &Hop,
+        hop_two: &Hop,
+        amount: u64,
+        other_amount_threshold: u64,
+        accounts: &SwapAccounts,
+    ) -> Result<Instruction, RouteError> {
+        let routable_pool_one = self.routable_pool(&hop_one.pool)?;
+        let routable_pool_two = self.routable_pool(&hop_two.pool)?;
+        let (pool_one, pool_two) = (&routable_pool_one.pool, &routable_pool_two.pool);
+        let tick_arrays_one =
+            routable_pool_one.swap_tick_array_addresses(&hop_one.pool, hop_one.a_to_b)?;
+        let tick_arrays_two =
+            routable_pool_two.swap_tick_array_addresses(&hop_two.pool, hop_two.a_to_b)?;
+
+        let mut account_metas = pool::accounts::TwoHopSwap {
+            token_program: token::ID,
+            token_authority: accounts.token_authority,
+            pool_one: hop_one.pool,
+            pool_two: hop_two.pool,
+            token_owner_account_one_a: accounts.token_account(&pool_one.token_mint_a),
+            token_vault_one_a: pool_one.token_vault_a,
+            token_owner_account_one_b: accounts.token_account(&pool_one.token_mint_b),
+            token_vault_one_b: pool_one.token_vault_b,
+            token_owner_account_two_a: accounts.token_account(&pool_two.token_mint_a),
+            token_vault_two_a: pool_two.token_vault_a,
+            token_owner_account_two_b: accounts.token_account(&pool_two.token_mint_b),
+            token_vault_two_b: pool_two.token_vault_b,
+            tick_array_one_0: tick_arrays_one[0],
+            tick_array_one_1: tick_arrays_one[1],
+            tick_array_one_2: tick_arrays_one[2],
+            tick_array_two_0: tick_arrays_two[0],
+            tick_array_two_1: tick_arrays_two[1],
+            tick_array_two_2: tick_arrays_two[2],
+            oracle_one: oracle_address(&hop_one.pool),
+            oracle_two: oracle_address(&hop_two.pool),
+        }
+        .to_account_metas(None);
+        account_metas.extend(remaining_account_metas(
+            &hop_one.pool,
+            pool_one,
+            &tick_arrays_one,
+            &accounts.token_authority,
+        ));
+        account_metas.extend(remaining_account_metas(
+            &hop_two.pool,
+            pool_two,
+            &tick_arrays_two,
+            &accounts.token_authority,
+        ));
+
+        Ok(Instruction {
+            program_id: pool::ID,
+            accounts: account_metas,
+            data: pool::instruction::TwoHopSwap {
+                amount,
+                other_amount_threshold,
+                amount_specified_is_input: true,
+                a_to_b_one: hop_one.a_to_b,
+                a_to_b_two: hop_two.a_to_b,
+                sqrt_price_limit_one: sqrt_price_limit(hop_one.a_to_b),
+                sqrt_price_limit_two: sqrt_price_limit(hop_two.a_to_b),
+            }
+            .data(),
+        })
+    }
+}
+
+impl RoutablePool {
+    // Start tick indexes of the up to three tick arrays a swap in the given direction uses. The
+    // first array holds the current tick, shifted by one tick spacing for b to a swaps as the
+    // swap searches from the next tick.
+    fn swap_start_tick_indexes(&self, a_to_b: bool) -> [i32; 3] {
+        let tick_spacing = i32::from(self.pool.tick_spacing);
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing;
+        let shift = if a_to_b { 0 } else { tick_spacing };
+        let start =
+            (self.pool.tick_current_index + shift).div_euclid(ticks_in_array) * ticks_in_array;
+        let step = if a_to_b {
+            -ticks_in_array
+        } else {
+            ticks_in_array
+        };
+        [start, start + step, start + 2 * step]
+    }
+
+    // The known tick arrays a swap uses, stopping at the first one that is missing.
+    fn swap_tick_arrays(&self, a_to_b: bool) -> Vec<&TickArray> {
+        self.swap_start_tick_indexes(a_to_b)
+            .iter()
+            .map_while(|start_tick_index| self.tick_arrays.get(start_tick_index))
+            .collect()
+    }
+
+    // Addresses of the tick arrays a swap uses. Missing arrays are replaced by the last known
+    // one, which the swap instruction then ignores as it is already loaded.
+    fn swap_tick_array_addresses(
+        &self,
+        address: &Pubkey,
+        a_to_b: bool,
+    ) -> Result<[Pubkey; 3], RouteError> {
+        let tick_arrays = self.swap_tick_arrays(a_to_b);
+        if tick_arrays.is_empty() {
+            return Err(RouteError::MissingTickArray(*address));
+        }
+
+        let mut addresses = [Pubkey::default(); 3];
+        for (i, slot) in addresses.iter_mut().enumerate() {
+            let tick_array = tick_arrays[i.min(tick_arrays.len() - 1)];
+            *slot = tick_array_address(address, tick_array.start_tick_index);
+        }
+        Ok(addresses)
+    }
+}
+
+// Accounts a swap through the pool needs besides the named ones.
+fn remaining_account_metas(
+    address: &Pubkey,
+    pool: &ElysiumPool,
+    tick_arrays: &[Pubkey; 3],
+    token_authority: &Pubkey,
+) -> Vec<AccountMeta> {
+    let mut account_metas = Vec::new();
+    if pool.has_reward_extension() {
+        account_metas.push(AccountMeta::new(pool.reward_extension, false));
+        let mut tick_arrays = tick_arrays.to_vec();
+        tick_arrays.dedup();
+        for tick_array in tick_arrays {
+            let (extension, _) = Pubkey::find_program_address(
+                &[b"tick_array_reward_extension", tick_array.as_ref()],
+                &pool::ID,
+            );
+            account_metas.push(AccountMeta::new(extension, false));
+        }
+    }
+    if pool.is_permissioned() {
+        let (allowlist_entry, _) = Pubkey::find_program_address(
+            &[b"allow", address.as_ref(), token_authority.as_ref()],
+            &pool::ID,
+        );
+        account_metas.push(AccountMeta::new_readonly(allowlist_entry, false));
+    }
+    account_metas
+}
+
+fn tick_array_address(pool: &Pubkey, start_tick_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"tick_array",
+            pool.as_ref(),
+            start_tick_index.to_string().as_bytes(),
+        ],
+        &pool::ID,
+    )
+    .0
+}
+
+fn oracle_address(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"oracle", pool.as_ref()], &pool::ID).0
+}
+
+fn sqrt_price_limit(a_to_b: bool) -> u128 {
+    if a_to_b {
+        MIN_SQRT_PRICE_X64
+    } else {
+        MAX_SQRT_PRICE_X64
+    }
+}
+
+fn apply_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    (u128::from(amount) * u128::from(BPS_DENOMINATOR - u64::from(slippage_bps))
+        / u128::from(BPS_DENOMINATOR)) as u64
+}
+
+#[cfg(test)]
+mod router_tests {
+    use super::*;
+    use pool::{
+        math::{compute_swap, sqrt_price_from_tick_index},
+        state::TickUpdate,
+    };
+
+    // One position over [-1400, 1400) covers the three tick arrays a swap uses either way.
+    fn add_test_pool(
+        router: &mut Router,
+        token_mint_a: Pubkey,
+        token_mint_b: Pubkey,
+        liquidity: u128,
+    ) -> Pubkey {
+        add_test_pool_with_positions(
+            router,
+            token_mint_a,
+            token_mint_b,
+            &[(-1400, 1400, liquidity)],
+        )
+    }
+
+    // Builds the tick arrays of a pool at tick 0 holding positions of (tick lower, tick upper,
+    // liquidity) the way the program updates the ticks of a position.
+    fn add_test_pool_with_positions(
+        router: &mut Router,
+        token_mint_a: Pubkey,
+        token_mint_b: Pubkey,
+        positions: &[(i32, i32, u128)],
+    ) -> Pubkey {
+        let address = Pubkey::new_unique();
+        let tick_spacing = 8;
+        let liquidity = positions
+            .iter()
+            .filter(|(tick_lower, tick_upper, _)| *tick_lower <= 0 && 0 < *tick_upper)
+            .map(|(_, _, liquidity)| liquidity)
+            .sum();
+        let pool = ElysiumPool {
+            tick_spacing,
+            fee_rate: 3000,
+            tick_current_index: 0,
+            sqrt_price: sqrt_price_from_tick_index(0),
+            liquidity,
+            token_mint_a,
+            token_vault_a: Pubkey::new_unique(),
+            token_mint_b,
+            token_vault_b: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let mut tick_arrays: Vec<TickArray> = [-1408, -704, 0, 704]
+            .iter()
+            .map(|start_tick_index| TickArray {
+                start_tick_index: *start_tick_index,
+                pool: address,
+                ..Default::default()
+            })
+            .collect();
+        for (tick_lower, tick_upper, liquidity) in positions {
+            for (tick_index, liquidity_delta) in [
+                (*tick_lower, *liquidity as i128),
+                (*tick_upper, -(*liquidity as i128)),
+            ] {
+                let tick_array = tick_arrays
+                    .iter_mut()
+                    .find(|tick_array| tick_array.check_in_array_bounds(tick_index, tick_spacing))
+                    .unwrap();
+                let tick = tick_array.get_tick(tick_index, tick_spacing).unwrap();
+                let update = TickUpdate {
+                    initialized: true,
+                    liquidity_net: tick.liquidity_net + liquidity_delta,
+                    liquidity_gross: tick.liquidity_gross + liquidity,
+                    ..TickUpdate::from(tick)
+                };
+                tick_array
+                    .update_tick(tick_index, tick_spacing, &update)
+                    .unwrap();
+            }
+        }
+        router.add_pool(address, pool, tick_arrays);
+        address
+    }
+
+    fn update_pool(router: &mut Router, address: Pubkey, update: impl FnOnce(&mut ElysiumPool)) {
+        update(&mut router.pools.get_mut(&address).unwrap().pool);
+    }
+
+    fn route_pools(route: &Route) -> Vec<Pubkey> {
+        route.hops.iter().map(|hop| hop.pool).collect()
+    }
+
+    #[test]
+    fn test_find_routes() {
+        let mut router = Router::new(0, 0);
+        let (mint_a, mint_b, mint_c, mint_d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let pool_ab = add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        let pool_cb = add_test_pool(&mut router, mint_c, mint_b, 1 << 40);
+        let pool_ac = add_test_pool(&mut router, mint_a, mint_c, 1 << 40);
+        add_test_pool(&mut router, mint_c, mint_d, 1 << 40);
+
+        let routes = router.find_routes(mint_a, mint_c, 3);
+        assert_eq!(routes.len(), 2);
+        assert!(routes
+            .iter()
+            .any(|route| route_pools(route) == vec![pool_ac]));
+        let two_hop = routes.iter().find(|route| route.hops.len() == 2).unwrap();
+        assert_eq!(route_pools(two_hop), vec![pool_ab, pool_cb]);
+        assert!(two_hop.hops[0].a_to_b);
+        assert!(!two_hop.hops[1].a_to_b);
+        assert_eq!(two_hop.hops[1].output_mint, mint_c);
+
+        assert_eq!(router.find_routes(mint_a, mint_c, 1).len(), 1);
+        assert_eq!(router.find_routes(mint_b, mint_d, 3).len(), 2);
+        assert_eq!(router.find_routes(mint_b, mint_d, 2).len(), 1);
+        assert!(router.find_routes(mint_a, mint_a, 3).is_empty());
+
+        // Updating a pool does not duplicate its edges.
+        let pool = router.pools[&pool_ac].pool.clone();
+        router.add_pool(pool_ac, pool, Vec::new());
+        assert_eq!(router.find_routes(mint_a, mint_c, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_unroutable_pools() {
+        let mut router = Router::new(100, 10);
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let hooked = add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        let inactive = add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        let permissioned = add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        update_pool(&mut router, hooked, |pool| {
+            pool.update_hook_program(Pubkey::new_unique())
+        });
+        update_pool(&mut router, inactive, |pool| {
+            pool.update_activation(100, 11)
+        });
+        update_pool(&mut router, permissioned, |pool| {
+            pool.update_access_authority(Pubkey::new_unique())
+        });
+        assert!(router.find_routes(mint_a, mint_b, 3).is_empty());
+        assert!(matches!(
+            router.best_routes(mint_a, mint_b, 1_000, 3),
+            Err(RouteError::NoRoute(..))
+        ));
+
+        router.allow_permissioned_pool(permissioned);
+        let routes = router.find_routes(mint_a, mint_b, 3);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(route_pools(&routes[0]), vec![permissioned]);
+    }
+
+    #[test]
+    fn test_quote_route_chains_hops() {
+        let mut router = Router::new(0, 0);
+        let (mint_a, mint_b, mint_c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        add_test_pool(&mut router, mint_c, mint_b, 1 << 40);
+
+        let routes = router.find_routes(mint_a, mint_c, 2);
+        let quote = router.quote_route(&routes[0], 1_000_000).unwrap();
+        let hops = &routes[0].hops;
+        let amount_b = router.quote_hop(&hops[0], 1_000_000).unwrap();
+        let amount_c = router.quote_hop(&hops[1], amount_b).unwrap();
+        assert_eq!(quote.hop_amounts_out, vec![amount_b, amount_c]);
+        assert_eq!(quote.amount_out, amount_c);
+        // Both pools take a 0.3% fee at a price of 1.
+        assert!(amount_c < 1_000_000 * 997 * 997 / 1_000_000);
+        assert!(amount_c > 1_000_000 * 99 / 100);
+
+        // The pool cannot absorb this much input within its tick arrays.
+        assert!(matches!(
+            router.quote_route(&routes[0], u64::MAX / 2),
+            Err(RouteError::Program(_)) | Err(RouteError::InsufficientLiquidity(_))
+        ));
+        assert!(matches!(
+            router.quote_route(&Route { hops: Vec::new() }, 1),
+            Err(RouteError::InvalidHopCount)
+        ));
+
+        let unknown = Route {
+            hops: vec![Hop {
+                pool: Pubkey::new_unique(),
+                ..hops[0]
+            }],
+        };
+        assert!(matches!(
+            router.quote_route(&unknown, 1),
+            Err(RouteError::UnknownPool(_))
+        ));
+    }
+
+    #[test]
+    fn test_quote_route_crosses_ticks() {
+        let mut router = Router::new(0, 0);
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let wide_liquidity = 1 << 34;
+        let narrow_liquidity = 1 << 36;
+        add_test_pool_with_positions(
+            &mut router,
+            mint_a,
+            mint_b,
+            &[(-1400, 1400, wide_liquidity), (-200, 200, narrow_liquidity)],
+        );
+
+        // Swapping a to b moves the price down through tick -200, where the narrow position
+        // leaves the range and the rest of the input is swapped against the wide position only.
+        let sqrt_price_lower = sqrt_price_from_tick_index(-200);
+        let step_to_tick = compute_swap(
+            u64::MAX,
+            3000,
+            wide_liquidity + narrow_liquidity,
+            sqrt_price_from_tick_index(0),
+            sqrt_price_lower,
+            true,
+            true,
+        )
+        .unwrap();
+        let amount_in = step_to_tick.amount_in + step_to_tick.fee_amount + 10_000_000;
+        let step_after_tick = compute_swap(
+            10_000_000,
+            3000,
+            wide_liquidity,
+            sqrt_price_lower,
+            sqrt_price_from_tick_index(-1400),
+            true,
+            true,
+        )
+        .unwrap();
+        assert!(step_after_tick.next_price > sqrt_price_from_tick_index(-1400));
+
+        let routes = router.find_routes(mint_a, mint_b, 1);
+        let quote = router.quote_route(&routes[0], amount_in).unwrap();
+        assert_eq!(
+            quote.amount_out,
+            step_to_tick.amount_out + step_after_tick.amount_out
+        );
+        assert_eq!(quote.amount_out, 864_437_231);
+    }
+
+    #[test]
+    fn test_best_routes_and_split() {
+        let mut router = Router::new(0, 0);
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let deep = add_test_pool(&mut router, mint_a, mint_b, 1 << 34);
+        let shallow = add_test_pool(&mut router, mint_a, mint_b, 1 << 33);
+
+        let quotes = router.best_routes(mint_a, mint_b, 1_000_000, 1).unwrap();
+        assert_eq!(route_pools(&quotes[0].route), vec![deep]);
+        assert_eq!(route_pools(&quotes[1].route), vec![shallow]);
+        assert!(quotes[0].amount_out > quotes[1].amount_out);
+
+        // A small trade is barely affected by price impact and stays on one route.
+        let split = router.best_split(mint_a, mint_b, 1_000, 1, 2).unwrap();
+        assert_eq!(split.parts.len(), 1);
+
+        let amount_in = 100_000_000;
+        let single = router.best_routes(mint_a, mint_b, amount_in, 1).unwrap();
+        let split = router.best_split(mint_a, mint_b, amount_in, 1, 2).unwrap();
+        assert_eq!(split.parts.len(), 2);
+        assert!(split.amount_out > single[0].amount_out);
+        assert_eq!(
+            split.parts.iter().map(|part| part.amount_in).sum::<u64>(),
+            amount_in
+        );
+        assert_eq!(
+            split.parts.iter().map(|part| part.amount_out).sum::<u64>(),
+            split.amount_out
+        );
+        for part in split.parts.iter() {
+            assert_eq!(
+                router.quote_route(&part.route, part.amount_in).unwrap(),
+                *part
+            );
+        }
+        let deep_part = split
+            .parts
+            .iter()
+            .find(|part| route_pools(&part.route) == vec![deep])
+            .unwrap();
+        assert!(deep_part.amount_in > amount_in / 2);
+        assert!(router
+            .best_split(mint_a, mint_b, amount_in, 1, 1)
+            .unwrap()
+            .parts
+            .iter()
+            .all(|part| route_pools(&part.route) == vec![deep]));
+    }
+
+    #[test]
+    fn test_route_instructions() {
+        let mut router = Router::new(0, 0);
+        let (mint_a, mint_b, mint_c, mint_d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let pool_ab = add_test_pool(&mut router, mint_a, mint_b, 1 << 40);
+        let pool_bc = add_test_pool(&mut router, mint_b, mint_c, 1 << 40);
+        let pool_dc = add_test_pool(&mut router, mint_d, mint_c, 1 << 40);
+        let reward_extension = Pubkey::new_unique();
+        update_pool(&mut router, pool_bc, |pool| {
+            pool.update_reward_extension(reward_extension)
+        });
+        update_pool(&mut router, pool_dc, |pool| {
+            pool.update_access_authority(Pubkey::new_unique())
+        });
+        router.allow_permissioned_pool(pool_dc);
+
+        let token_authority = Pubkey::new_unique();
+        let token_account_a = Pubkey::new_unique();
+        let accounts =
+            SwapAccounts::new(token_authority).with_token_account(mint_a, token_account_a);
+        assert_eq!(accounts.token_account(&mint_a), token_account_a);
+        assert_eq!(
+            accounts.token_account(&mint_b),
+            get_associated_token_address(&token_authority, &mint_b)
+        );
+
+        let quote = router
+            .best_routes(mint_a, mint_b, 1_000_000, 3)
+            .unwrap()
+            .remove(0);
+        let instructions = router.route_instructions(&quote, &accounts, 50).unwrap();
+        assert_eq!(instructions.len(), 1);
+        let instruction = &instructions[0];
+        assert_eq!(instruction.program_id, pool::ID);
+        assert_eq!(instruction.accounts.len(), 11);
+        assert_eq!(instruction.accounts[2].pubkey, pool_ab);
+        assert_eq!(instruction.accounts[3].pubkey, token_account_a);
+        assert_eq!(
+            instruction.accounts[7].pubkey,
+            tick_array_address(&pool_ab, 0)
+        );
+        assert_eq!(
+            instruction.accounts[8].pubkey,
+            tick_array_address(&pool_ab, -704)
+        );
+        assert_eq!(instruction.accounts[10].pubkey, oracle_address(&pool_ab));
+        assert_eq!(
+            instruction.data,
+            pool::instruction::Swap {
+                amount: 1_000_000,
+                other_amount_threshold: quote.amount_out * 9_950 / 10_000,
+                sqrt_price_limit: MIN_SQRT_PRICE_X64,
+                amount_specified_is_input: true,
+                a_to_b: true,
+            }
+            .data()
+        );
+
+        // The second pool needs its reward extension and the extensions of three tick arrays.
+        let quote = router
+            .best_routes(mint_a, mint_c, 1_000_000, 3)
+            .unwrap()
+            .remove(0);
+        let instructions = router.route_instructions(&quote, &accounts, 50).unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].accounts.len(), 20 + 4);
+        assert_eq!(instructions[0].accounts[20].pubkey, reward_extension);
+        assert!(instructions[0].accounts[20..]
+            .iter()
+            .all(|meta| meta.is_writable));
+
+        // The third pool is swapped separately and needs the allowlist entry of the authority.
+        let quote = router
+            .best_routes(mint_a, mint_d, 1_000_000, 3)
+            .unwrap()
+            .remove(0);
+        assert_eq!(quote.route.hops.len(), 3);
+        let instructions = router.route_instructions(&quote, &accounts, 50).unwrap();
+        assert_eq!(instructions.len(), 2);
+        let intermediate_amount = quote.hop_amounts_out[1] * 9_950 / 10_000;
+        let amount_out = router
+            .quote_hop(&quote.route.hops[2], intermediate_amount)
+            .unwrap();
+        assert_eq!(
+            instructions[1].data,
+            pool::instruction::Swap {
+                amount: intermediate_amount,
+                other_amount_threshold: amount_out * 9_950 / 10_000,
+                sqrt_price_limit: MAX_SQRT_PRICE_X64,
+                amount_specified_is_input: true,
+                a_to_b: false,
+            }
+            .data()
+        );
+        let (allowlist_entry, _) = Pubkey::find_program_address(
+            &[b"allow", pool_dc.as_ref(), token_authority.as_ref()],
+            &pool::ID,
+        );
+        assert_eq!(instructions[1].accounts.len(), 12);
+        assert_eq!(instructions[1].accounts[11].pubkey, allowlist_entry);
+
+        assert!(matches!(
+            router.route_instructions(&quote, &accounts, 10_001),
+            Err(RouteError::InvalidSlippage)
+        ));
+    }
+}
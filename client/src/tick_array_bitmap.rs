@@ -0,0 +1,123 @@
+//! Locates the tick arrays of a pool with its TickArrayBitmap instead of probing addresses.
+//!
+//! Once a pool has a TickArrayBitmap, instructions that initialize or close tick arrays or modify
+//! liquidity fail without it, so clients must add the bitmap at `tick_array_bitmap_address` to
+//! their remaining accounts. Tick arrays initialized before the bitmap are only recorded in it
+//! once passed to `sync_tick_array_bitmap`.
+
+use anchor_lang::prelude::Pubkey;
+use pool::state::{ElysiumPool, Tick, TickArrayBitmap, TICK_ARRAY_SIZE};
+
+/// Address of the TickArrayBitmap of a pool.
+pub fn tick_array_bitmap_address(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"tick_array_bitmap", pool.as_ref()], &pool::ID).0
+}
+
+/// Start tick indexes of up to `count` non-empty tick arrays in the direction of a swap,
+/// beginning with the tick array the swap starts searching in. Only these tick arrays hold
+/// initialized ticks, so they are the ones a quote has to fetch; the tick arrays between them
+/// are empty.
+pub fn next_non_empty_start_tick_indexes(
+    pool: &ElysiumPool,
+    tick_array_bitmap: &TickArrayBitmap,
+    a_to_b: bool,
+    count: usize,
+) -> Vec<i32> {
+    let tick_spacing = i32::from(pool.tick_spacing);
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing;
+    // b to a swaps search from the next tick, which can be in the next tick array
+    let shift = if a_to_b { 0 } else { tick_spacing };
+    let mut start_tick_index =
+        (pool.tick_current_index + shift).div_euclid(ticks_in_array) * ticks_in_array;
+
+    let mut start_tick_indexes = Vec::with_capacity(count);
+    while start_tick_indexes.len() < count
+        && Tick::check_is_valid_start_tick(start_tick_index, pool.tick_spacing)
+    {
+        // The search can not fail, the start tick index is checked above
+        let next_start_tick_index = match tick_array_bitmap.next_non_empty_start_tick_index(
+            start_tick_index,
+            pool.tick_spacing,
+            a_to_b,
+        ) {
+            Ok(Some(next_start_tick_index)) => next_start_tick_index,
+            _ => break,
+        };
+        start_tick_indexes.push(next_start_tick_index);
+        start_tick_index = if a_to_b {
+            next_start_tick_index - ticks_in_array
+        } else {
+            next_start_tick_index + ticks_in_array
+        };
+    }
+
+    start_tick_indexes
+}
+
+#[cfg(test)]
+mod tick_array_bitmap_tests {
+    use super::*;
+
+    const TICK_SPACING: u16 = 64;
+    const TICKS_IN_ARRAY: i32 = TICK_ARRAY_SIZE * TICK_SPACING as i32;
+
+    fn pool(tick_current_index: i32) -> ElysiumPool {
+        ElysiumPool {
+            tick_spacing: TICK_SPACING,
+            tick_current_index,
+            ..Default::default()
+        }
+    }
+
+    fn bitmap(non_empty_start_tick_indexes: &[i32]) -> TickArrayBitmap {
+        let mut bitmap = TickArrayBitmap::default();
+        for start_tick_index in non_empty_start_tick_indexes {
+            bitmap
+                .update(*start_tick_index, TICK_SPACING, true, true)
+                .unwrap();
+        }
+        bitmap
+    }
+
+    #[test]
+    fn test_skips_empty_tick_arrays() {
+        let bitmap = bitmap(&[-5 * TICKS_IN_ARRAY, -TICKS_IN_ARRAY, 0, 3 * TICKS_IN_ARRAY]);
+
+        assert_eq!(
+            next_non_empty_start_tick_indexes(&pool(10), &bitmap, true, 3),
+            vec![0, -TICKS_IN_ARRAY, -5 * TICKS_IN_ARRAY]
+        );
+        assert_eq!(
+            next_non_empty_start_tick_indexes(&pool(10), &bitmap, false, 3),
+            vec![0, 3 * TICKS_IN_ARRAY]
+        );
+        assert_eq!(
+            next_non_empty_start_tick_indexes(&pool(10), &bitmap, true, 1),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn test_b_to_a_starts_from_the_next_tick() {
+        let bitmap = bitmap(&[0, TICKS_IN_ARRAY]);
+
+        // The next tick of the last tick of an array is in the next array
+        assert_eq!(
+            next_non_empty_start_tick_indexes(
+                &pool(TICKS_IN_ARRAY - TICK_SPACING as i32),
+                &bitmap,
+                false,
+                3
+            ),
+            vec![TICKS_IN_ARRAY]
+        );
+    }
+
+    #[test]
+    fn test_empty_bitmap() {
+        assert!(
+            next_non_empty_start_tick_indexes(&pool(0), &TickArrayBitmap::default(), true, 3)
+                .is_empty()
+        );
+    }
+}
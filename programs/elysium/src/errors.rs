@@ -115,6 +115,88 @@ pub enum ErrorCode {
     BundledPositionAlreadyClosed, //0x179d
     #[msg("Unable to delete PositionBundle with open positions")]
     PositionBundleNotDeletable, //0x179e
+
+    #[msg("Invalid or missing reward accounts")]
+    InvalidRewardAccounts, //0x179f
+    #[msg("Position range does not accept the specified token")]
+    TokenNotInPositionRange, //0x17a0
+
+    #[msg("Transaction deadline exceeded")]
+    DeadlineExceeded, //0x17a1
+    #[msg("Pool sqrt price is outside of the provided bounds")]
+    PoolSqrtPriceOutOfBounds, //0x17a2
+
+    #[msg("Reward emissions schedule is invalid")]
+    InvalidRewardSchedule, //0x17a3
+
+    #[msg("Reward extension accounts are missing or invalid")]
+    InvalidRewardExtension, //0x17a4
+
+    #[msg("Reward emissions have not ended")]
+    RewardEmissionsNotEnded, //0x17a5
+    #[msg("Reward settlement does not match the reward")]
+    InvalidRewardSettlement, //0x17a6
+    #[msg("Reward settlement period has not elapsed")]
+    RewardSettlementPeriodNotElapsed, //0x17a7
+
+    #[msg("Amount exceeds the unallocated reward amount")]
+    UnallocatedRewardInsufficient, //0x17a8
+
+    #[msg("Position liquidity is locked")]
+    PositionLocked, //0x17a9
+    #[msg("Position unlock timestamp is invalid")]
+    InvalidPositionLock, //0x17aa
+
+    #[msg("Pool is not activated for trading yet")]
+    PoolNotActivated, //0x17ab
+
+    #[msg("Launch fee rate exceeds MAX_LAUNCH_FEE_RATE")]
+    LaunchFeeRateMaxExceeded, //0x17ac
+    #[msg("Launch fee schedule is invalid")]
+    InvalidLaunchFeeSchedule, //0x17ad
+
+    #[msg("Exceeded max creator fee rate")]
+    CreatorFeeRateMaxExceeded, //0x17ae
+
+    #[msg("Tick array has initialized ticks")]
+    TickArrayNotEmpty, //0x17af
+
+    #[msg("Tick array accounts do not match the start tick indexes")]
+    InvalidTickArrayAccounts, //0x17b0
+    #[msg("Tick array bitmap is missing or does not belong to the pool")]
+    InvalidTickArrayBitmap, //0x17b1
+
+    #[msg("Pool hook program is missing from the remaining accounts")]
+    InvalidPoolHookAccounts, //0x17b2
+    #[msg("Pools with a hook program are not supported by this instruction")]
+    PoolHookNotSupported, //0x17b3
+
+    #[msg("Wallet is not on the allowlist of the pool")]
+    WalletNotAllowlisted, //0x17b4
+
+    #[msg("Positions have not settled all rewards of the retired reward")]
+    RewardNotSettled, //0x17b5
+
+    #[msg("Pool activation is delayed past the max activation delay of the config")]
+    ActivationDelayMaxExceeded, //0x17b6
+
+    #[msg("Launch fee decay period exceeds the max decay period of the config")]
+    LaunchFeeDecayPeriodMaxExceeded, //0x17b7
+
+    #[msg("Permissioned pools require the signature of the permissioned pool authority")]
+    InvalidPermissionedPoolAuthority, //0x17b8
+
+    #[msg("Hook program is not on the hook program allowlist of the config")]
+    HookProgramNotAllowlisted, //0x17b9
+
+    #[msg("Timelock of the hook program has not elapsed")]
+    HookProgramTimelocked, //0x17ba
+
+    #[msg("Hook program allowlist of the config is full")]
+    HookProgramAllowlistFull, //0x17bb
+
+    #[msg("Layout of the pool account is not supported")]
+    UnsupportedPoolLayout, //0x17bc
 }
 
 impl From<TryFromIntError> for ErrorCode {
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+pub struct AddHookProgram<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddHookProgram>, hook_program: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts
+        .config_extension
+        .add_hook_program(hook_program, timestamp)
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AllowlistEntry, ElysiumPool};
+
+#[derive(Accounts)]
+pub struct CloseAllowlistEntry<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pool.access_authority)]
+    pub access_authority: Signer<'info>,
+
+    #[account(mut, has_one = pool, close = receiver)]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseAllowlistEntry>) -> Result<()> {
+    Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
 use crate::state::*;
-use crate::util::{burn_and_close_user_position_token, verify_position_authority};
+use crate::util::{burn_and_close_user_position_token, verify_unlocked_position_authority};
 
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
@@ -33,9 +33,10 @@ pub struct ClosePosition<'info> {
 }
 
 pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
-    verify_position_authority(
+    verify_unlocked_position_authority(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
     )?;
 
     if !Position::is_position_empty(&ctx.accounts.position) {
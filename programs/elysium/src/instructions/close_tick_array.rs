@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::load_tick_array_bitmap;
+
+#[derive(Accounts)]
+pub struct CloseTickArray<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut, has_one = pool, close = funder)]
+    pub tick_array: AccountLoader<'info, TickArray>,
+
+    #[account(mut,
+        seeds = [b"tick_array_funder", tick_array.key().as_ref()],
+        bump,
+        has_one = tick_array,
+        has_one = funder,
+        close = funder,
+    )]
+    pub tick_array_funder: Account<'info, TickArrayFunder>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    // remaining accounts
+    // - required if the pool has a tick array bitmap:
+    //   - tick_array_bitmap (mut)
+}
+
+pub fn handler(ctx: Context<CloseTickArray>) -> Result<()> {
+    let tick_array = ctx.accounts.tick_array.load()?;
+    if !tick_array.is_empty() {
+        return Err(ErrorCode::TickArrayNotEmpty.into());
+    }
+
+    if let Some(tick_array_bitmap) =
+        load_tick_array_bitmap(&ctx.accounts.pool, ctx.remaining_accounts)?
+    {
+        tick_array_bitmap.load_mut()?.update(
+            tick_array.start_tick_index,
+            ctx.accounts.pool.tick_spacing,
+            false,
+            false,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::{state::*, util::transfer_from_vault_to_owner};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CollectCreatorFees<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    pub creator: Signer<'info>,
+
+    #[account(mut, address = pool.token_vault_a)]
+    pub token_vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.token_vault_b)]
+    pub token_vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = token_destination_a.mint == pool.token_mint_a)]
+    pub token_destination_a: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = token_destination_b.mint == pool.token_mint_b)]
+    pub token_destination_b: Account<'info, TokenAccount>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CollectCreatorFees>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_destination_a,
+        &ctx.accounts.token_program,
+        pool.creator_fee_owed_a,
+    )?;
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_destination_b,
+        &ctx.accounts.token_program,
+        pool.creator_fee_owed_b,
+    )?;
+
+    ctx.accounts.pool.reset_creator_fees_owed();
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    manager::reward_extension_manager::calculate_reward_extension_modify_liquidity,
+    state::*,
+    util::{to_timestamp_u64, transfer_from_vault_to_owner, verify_position_or_lock_authority},
+};
+
+use super::calculate_collect_reward;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct CollectExtensionReward<'info> {
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_extension: Box<Account<'info, PoolRewardExtension>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = position)]
+    pub position_extension: Box<Account<'info, PositionRewardExtension>>,
+
+    #[account(has_one = pool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(has_one = pool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(constraint = tick_array_lower_extension.load()?.tick_array == tick_array_lower.key())]
+    pub tick_array_lower_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    #[account(constraint = tick_array_upper_extension.load()?.tick_array == tick_array_upper.key())]
+    pub tick_array_upper_extension: AccountLoader<'info, TickArrayRewardExtension>,
+
+    #[account(mut,
+        constraint = reward_owner_account.mint == reward_extension.reward_infos[reward_index as usize].mint
+    )]
+    pub reward_owner_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = reward_extension.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    // remaining accounts
+    // - position_lock, if the position token is held by the lock of the position
+}
+
+/// Refreshes the extension rewards of a position and collects all harvestable tokens for the
+/// specified extension reward.
+///
+/// If the reward vault does not have enough tokens, the maximum number of available tokens will be
+/// debited to the user. The unharvested amount remains tracked, and it can be harvested in the
+/// future.
+///
+/// # Parameters
+/// - `reward_index` - The extension reward to harvest. Acceptable values are 0 and 1.
+///
+/// # Returns
+/// - `Ok`: Reward tokens at the specified extension reward index have been successfully harvested
+/// - `Err`: `InvalidRewardIndex` if the reward index is not 0 or 1
+pub fn handler(ctx: Context<CollectExtensionReward>, reward_index: u8) -> Result<()> {
+    verify_position_or_lock_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    let index = reward_index as usize;
+    let pool = &ctx.accounts.pool;
+    let position = &ctx.accounts.position;
+    let reward_extension = &mut ctx.accounts.reward_extension;
+    let position_extension = &mut ctx.accounts.position_extension;
+
+    // Positions without liquidity cannot accrue anything, so there is nothing to refresh.
+    if position.liquidity > 0 {
+        let clock = Clock::get()?;
+        let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+        let tick_lower = *ctx
+            .accounts
+            .tick_array_lower
+            .load()?
+            .get_tick(position.tick_lower_index, pool.tick_spacing)?;
+        let tick_upper = *ctx
+            .accounts
+            .tick_array_upper
+            .load()?
+            .get_tick(position.tick_upper_index, pool.tick_spacing)?;
+
+        let update = calculate_reward_extension_modify_liquidity(
+            pool,
+            reward_extension,
+            position,
+            position_extension,
+            &tick_lower,
+            ctx.accounts
+                .tick_array_lower_extension
+                .load()?
+                .get_reward_growths_outside(position.tick_lower_index, pool.tick_spacing)?,
+            &tick_upper,
+            ctx.accounts
+                .tick_array_upper_extension
+                .load()?
+                .get_reward_growths_outside(position.tick_upper_index, pool.tick_spacing)?,
+            0,
+            timestamp,
+        )?;
+
+        reward_extension.update_rewards(update.reward_infos, timestamp);
+        position_extension.update_reward_infos(update.position_reward_infos);
+    }
+
+    let (transfer_amount, updated_amount_owed) = calculate_collect_reward(
+        position_extension.reward_infos[index],
+        ctx.accounts.reward_vault.amount,
+    );
+
+    position_extension.update_reward_owed(index, updated_amount_owed);
+    reward_extension.record_reward_collected(
+        index,
+        ctx.accounts.reward_vault.amount,
+        transfer_amount,
+    )?;
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_owner_account,
+        &ctx.accounts.token_program,
+        transfer_amount,
+    )
+}
@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::{
     state::*,
-    util::{transfer_from_vault_to_owner, verify_position_authority},
+    util::{transfer_from_vault_to_owner, verify_position_or_lock_authority},
 };
 
 #[derive(Accounts)]
@@ -32,12 +32,16 @@ pub struct CollectFees<'info> {
 
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
+    // remaining accounts
+    // - position_lock, if the position token is held by the lock of the position
 }
 
 pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
-    verify_position_authority(
+    verify_position_or_lock_authority(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
+        ctx.remaining_accounts,
     )?;
 
     let position = &mut ctx.accounts.position;
@@ -3,12 +3,13 @@ use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::{
     state::*,
-    util::{transfer_from_vault_to_owner, verify_position_authority},
+    util::{transfer_from_vault_to_owner, verify_position_or_lock_authority},
 };
 
 #[derive(Accounts)]
 #[instruction(reward_index: u8)]
 pub struct CollectReward<'info> {
+    #[account(mut)]
     pub pool: Box<Account<'info, ElysiumPool>>,
 
     pub position_authority: Signer<'info>,
@@ -31,6 +32,8 @@ pub struct CollectReward<'info> {
 
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
+    // remaining accounts
+    // - position_lock, if the position token is held by the lock of the position
 }
 
 /// Collects all harvestable tokens for a specified reward.
@@ -47,9 +50,11 @@ pub struct CollectReward<'info> {
 /// - `Err`: `RewardNotInitialized` if the specified reward has not been initialized
 ///          `InvalidRewardIndex` if the reward index is not 0, 1, or 2
 pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
-    verify_position_authority(
+    verify_position_or_lock_authority(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
+        ctx.remaining_accounts,
     )?;
 
     let index = reward_index as usize;
@@ -62,6 +67,12 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
 
     position.update_reward_owed(index, updated_amount_owed);
 
+    ctx.accounts.pool.record_reward_collected(
+        index,
+        ctx.accounts.reward_vault.amount,
+        transfer_amount,
+    )?;
+
     Ok(transfer_from_vault_to_owner(
         &ctx.accounts.pool,
         &ctx.accounts.reward_vault,
@@ -71,7 +82,10 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
     )?)
 }
 
-fn calculate_collect_reward(position_reward: PositionRewardInfo, vault_amount: u64) -> (u64, u64) {
+pub fn calculate_collect_reward(
+    position_reward: PositionRewardInfo,
+    vault_amount: u64,
+) -> (u64, u64) {
     let amount_owed = position_reward.amount_owed;
     let (transfer_amount, updated_amount_owed) = if amount_owed > vault_amount {
         (vault_amount, amount_owed - vault_amount)
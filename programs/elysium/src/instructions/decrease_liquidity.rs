@@ -5,25 +5,36 @@ use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
-use crate::util::{to_timestamp_u64, transfer_from_vault_to_owner, verify_position_authority};
+use crate::util::{
+    load_modify_liquidity_reward_extension, load_tick_array_bitmap,
+    modify_liquidity_reward_extension, record_tick_array_in_bitmap, to_timestamp_u64,
+    transfer_from_vault_to_owner, verify_deadline, verify_sqrt_price_bounds,
+    verify_unlocked_position_authority, Deadline,
+};
 
 use super::ModifyLiquidity;
 
 /*
   Removes liquidity from an existing ElysiumPool Position.
 */
-pub fn handler(
-    ctx: Context<ModifyLiquidity>,
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     liquidity_amount: u128,
     token_min_a: u64,
     token_min_b: u64,
+    deadline: Option<Deadline>,
+    min_sqrt_price: Option<u128>,
+    max_sqrt_price: Option<u128>,
 ) -> Result<()> {
-    verify_position_authority(
+    verify_unlocked_position_authority(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
     )?;
 
     let clock = Clock::get()?;
+    verify_deadline(deadline, &clock)?;
+    verify_sqrt_price_bounds(ctx.accounts.pool.sqrt_price, min_sqrt_price, max_sqrt_price)?;
 
     if liquidity_amount == 0 {
         return Err(ErrorCode::LiquidityZero.into());
@@ -40,6 +51,24 @@ pub fn handler(
         timestamp,
     )?;
 
+    if let Some(mut reward_extension) = load_modify_liquidity_reward_extension(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        ctx.accounts.tick_array_lower.key(),
+        ctx.accounts.tick_array_upper.key(),
+        ctx.remaining_accounts,
+    )? {
+        modify_liquidity_reward_extension(
+            &mut reward_extension,
+            &ctx.accounts.pool,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            liquidity_delta,
+            timestamp,
+        )?;
+    }
+
     sync_modify_liquidity_values(
         &mut ctx.accounts.pool,
         &mut ctx.accounts.position,
@@ -49,6 +78,21 @@ pub fn handler(
         timestamp,
     )?;
 
+    if let Some(tick_array_bitmap) =
+        load_tick_array_bitmap(&ctx.accounts.pool, ctx.remaining_accounts)?
+    {
+        for tick_array in [
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+        ] {
+            record_tick_array_in_bitmap(
+                &tick_array_bitmap,
+                tick_array,
+                ctx.accounts.pool.tick_spacing,
+            )?;
+        }
+    }
+
     let (delta_a, delta_b) = calculate_liquidity_token_deltas(
         ctx.accounts.pool.tick_current_index,
         ctx.accounts.pool.sqrt_price,
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExtendPositionLock<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(mut, has_one = beneficiary)]
+    pub position_lock: Account<'info, PositionLock>,
+}
+
+pub fn handler(ctx: Context<ExtendPositionLock>, unlock_timestamp: u64) -> Result<()> {
+    ctx.accounts.position_lock.extend(unlock_timestamp)
+}
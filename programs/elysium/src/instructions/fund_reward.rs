@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::manager::pool_manager::next_pool_reward_infos_with_vault;
+use crate::state::ElysiumPool;
+use crate::util::{to_timestamp_u64, transfer_from_owner_to_vault};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct FundReward<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    pub funder: Signer<'info>,
+
+    #[account(mut,
+        constraint = funder_token_account.mint == pool.reward_infos[reward_index as usize].mint
+    )]
+    pub funder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FundReward>, reward_index: u8, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_pool_reward_infos_with_vault(
+        pool,
+        reward_index as usize,
+        ctx.accounts.reward_vault.amount,
+        timestamp,
+    )?;
+
+    pool.fund_reward(
+        reward_index as usize,
+        next_reward_infos,
+        timestamp,
+        ctx.accounts.reward_vault.amount,
+        amount,
+    )?;
+
+    transfer_from_owner_to_vault(
+        &ctx.accounts.funder,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
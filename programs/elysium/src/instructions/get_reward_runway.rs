@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::manager::pool_manager::{calculate_reward_runway, RewardRunway};
+use crate::state::ElysiumPool;
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct GetRewardRunway<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<GetRewardRunway>, reward_index: u8) -> Result<RewardRunway> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    calculate_reward_runway(
+        &ctx.accounts.pool,
+        reward_index as usize,
+        ctx.accounts.reward_vault.amount,
+        timestamp,
+    )
+}
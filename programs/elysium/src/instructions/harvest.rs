@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    manager::liquidity_manager::calculate_fee_and_reward_growths,
+    state::*,
+    util::{to_timestamp_u64, transfer_from_vault_to_owner, verify_position_or_lock_authority},
+};
+
+use super::calculate_collect_reward;
+
+#[derive(Accounts)]
+pub struct Harvest<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(has_one = pool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(has_one = pool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == pool.token_mint_a)]
+    pub token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.token_vault_a)]
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_b.mint == pool.token_mint_b)]
+    pub token_owner_account_b: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.token_vault_b)]
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    // remaining accounts
+    // - for every initialized reward, in reward index order:
+    //   - reward_owner_account (mut)
+    //   - reward_vault (mut)
+    // - position_lock, if the position token is held by the lock of the position
+}
+
+/// Refreshes the position's fee and reward growths, then collects all fees and every initialized
+/// reward in a single instruction.
+///
+/// Reward collection follows the same rules as `collect_reward`: if a reward vault does not hold
+/// enough tokens, the available amount is transferred and the remainder stays owed.
+///
+/// # Returns
+/// - `Ok`: Fees and all initialized rewards have been successfully harvested
+/// - `Err`: `InvalidRewardAccounts` if the remaining accounts do not provide an owner account and
+///          the vault for every initialized reward
+pub fn handler<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Harvest<'info>>) -> Result<()> {
+    verify_position_or_lock_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        ctx.accounts.position.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    let pool = &mut ctx.accounts.pool;
+    let position = &mut ctx.accounts.position;
+
+    // Positions without liquidity cannot accrue anything, so there is nothing to refresh.
+    if position.liquidity > 0 {
+        let clock = Clock::get()?;
+        let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+        let (position_update, reward_infos) = calculate_fee_and_reward_growths(
+            pool,
+            position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            timestamp,
+        )?;
+
+        pool.update_rewards(reward_infos, timestamp);
+        position.update(&position_update);
+    }
+
+    // Store the fees owed to use as transfer amounts.
+    let fee_owed_a = position.fee_owed_a;
+    let fee_owed_b = position.fee_owed_b;
+
+    position.reset_fees_owed();
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program,
+        fee_owed_a,
+    )?;
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program,
+        fee_owed_b,
+    )?;
+
+    let mut reward_accounts = ctx.remaining_accounts.iter();
+    for index in 0..NUM_REWARDS {
+        let reward_info = pool.reward_infos[index];
+        if !reward_info.initialized() {
+            continue;
+        }
+
+        let (reward_owner_account_info, reward_vault_info) =
+            match (reward_accounts.next(), reward_accounts.next()) {
+                (Some(owner), Some(vault)) => (owner, vault),
+                _ => return Err(ErrorCode::InvalidRewardAccounts.into()),
+            };
+
+        let reward_owner_account = Account::<TokenAccount>::try_from(reward_owner_account_info)?;
+        let reward_vault = Account::<TokenAccount>::try_from(reward_vault_info)?;
+
+        if reward_vault.key() != reward_info.vault || reward_owner_account.mint != reward_info.mint
+        {
+            return Err(ErrorCode::InvalidRewardAccounts.into());
+        }
+
+        let (transfer_amount, updated_amount_owed) =
+            calculate_collect_reward(position.reward_infos[index], reward_vault.amount);
+
+        position.update_reward_owed(index, updated_amount_owed);
+        pool.record_reward_collected(index, reward_vault.amount, transfer_amount)?;
+
+        transfer_from_vault_to_owner(
+            pool,
+            &reward_vault,
+            &reward_owner_account,
+            &ctx.accounts.token_program,
+            transfer_amount,
+        )?;
+    }
+
+    Ok(())
+}
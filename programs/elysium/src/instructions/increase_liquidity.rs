@@ -7,7 +7,12 @@ use crate::manager::liquidity_manager::{
 };
 use crate::math::convert_to_liquidity_delta;
 use crate::state::*;
-use crate::util::{to_timestamp_u64, transfer_from_owner_to_vault, verify_position_authority};
+use crate::util::{
+    invoke_pool_hook, load_modify_liquidity_reward_extension, load_tick_array_bitmap,
+    modify_liquidity_reward_extension, record_tick_array_in_bitmap, to_timestamp_u64,
+    transfer_from_owner_to_vault, verify_allowlisted, verify_deadline, verify_position_authority,
+    verify_sqrt_price_bounds, Deadline, PoolHookEvent,
+};
 
 #[derive(Accounts)]
 pub struct ModifyLiquidity<'info> {
@@ -41,13 +46,31 @@ pub struct ModifyLiquidity<'info> {
     pub tick_array_lower: AccountLoader<'info, TickArray>,
     #[account(mut, has_one = pool)]
     pub tick_array_upper: AccountLoader<'info, TickArray>,
+    // remaining accounts
+    // - required if the pool has a reward extension, anywhere before the hook program:
+    //   - reward_extension (mut)
+    //   - the account at the position extension address (mut), even if the extension has not
+    //     been initialized
+    //   - required if the position extension has been initialized:
+    //     - tick_array_lower_extension (mut)
+    //     - tick_array_upper_extension (mut)
+    // - required if the pool has a tick array bitmap, anywhere in the remaining accounts:
+    //   - tick_array_bitmap (mut)
+    // - required if the pool is permissioned, anywhere before the hook program:
+    //   - allowlist_entry of the position_authority
+    // - required if the pool has a hook program, after all other remaining accounts:
+    //   - hook_program
+    //   - accounts forwarded to the hook program
 }
 
-pub fn handler(
-    ctx: Context<ModifyLiquidity>,
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     liquidity_amount: u128,
     token_max_a: u64,
     token_max_b: u64,
+    deadline: Option<Deadline>,
+    min_sqrt_price: Option<u128>,
+    max_sqrt_price: Option<u128>,
 ) -> Result<()> {
     verify_position_authority(
         &ctx.accounts.position_token_account,
@@ -55,6 +78,13 @@ pub fn handler(
     )?;
 
     let clock = Clock::get()?;
+    verify_deadline(deadline, &clock)?;
+    verify_allowlisted(
+        &ctx.accounts.pool,
+        ctx.accounts.position_authority.key(),
+        ctx.remaining_accounts,
+    )?;
+    verify_sqrt_price_bounds(ctx.accounts.pool.sqrt_price, min_sqrt_price, max_sqrt_price)?;
 
     if liquidity_amount == 0 {
         return Err(ErrorCode::LiquidityZero.into());
@@ -62,6 +92,16 @@ pub fn handler(
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    invoke_pool_hook(
+        &ctx.accounts.pool,
+        &ctx.accounts.position_authority.to_account_info(),
+        ctx.remaining_accounts,
+        PoolHookEvent::BeforeModifyLiquidity {
+            position: ctx.accounts.position.key(),
+            liquidity_delta,
+        },
+    )?;
+
     let update = calculate_modify_liquidity(
         &ctx.accounts.pool,
         &ctx.accounts.position,
@@ -71,6 +111,24 @@ pub fn handler(
         timestamp,
     )?;
 
+    if let Some(mut reward_extension) = load_modify_liquidity_reward_extension(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        ctx.accounts.tick_array_lower.key(),
+        ctx.accounts.tick_array_upper.key(),
+        ctx.remaining_accounts,
+    )? {
+        modify_liquidity_reward_extension(
+            &mut reward_extension,
+            &ctx.accounts.pool,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            liquidity_delta,
+            timestamp,
+        )?;
+    }
+
     sync_modify_liquidity_values(
         &mut ctx.accounts.pool,
         &mut ctx.accounts.position,
@@ -80,6 +138,21 @@ pub fn handler(
         timestamp,
     )?;
 
+    if let Some(tick_array_bitmap) =
+        load_tick_array_bitmap(&ctx.accounts.pool, ctx.remaining_accounts)?
+    {
+        for tick_array in [
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+        ] {
+            record_tick_array_in_bitmap(
+                &tick_array_bitmap,
+                tick_array,
+                ctx.accounts.pool.tick_spacing,
+            )?;
+        }
+    }
+
     let (delta_a, delta_b) = calculate_liquidity_token_deltas(
         ctx.accounts.pool.tick_current_index,
         ctx.accounts.pool.sqrt_price,
@@ -109,5 +182,16 @@ pub fn handler(
         delta_b,
     )?;
 
-    Ok(())
+    ctx.accounts.position.exit(&crate::ID)?;
+    invoke_pool_hook(
+        &ctx.accounts.pool,
+        &ctx.accounts.position_authority.to_account_info(),
+        ctx.remaining_accounts,
+        PoolHookEvent::AfterModifyLiquidity {
+            position: ctx.accounts.position.key(),
+            liquidity_delta,
+            amount_a: delta_a,
+            amount_b: delta_b,
+        },
+    )
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::manager::liquidity_manager::calculate_liquidity_from_token_amount;
+use crate::util::Deadline;
+
+use super::ModifyLiquidity;
+
+/*
+  Adds liquidity to an existing ElysiumPool Position, sized by an exact amount of token A or B.
+*/
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+    token_amount: u64,
+    other_token_max: u64,
+    amount_specified_is_a: bool,
+    deadline: Option<Deadline>,
+    min_sqrt_price: Option<u128>,
+    max_sqrt_price: Option<u128>,
+) -> Result<()> {
+    let liquidity_amount = calculate_liquidity_from_token_amount(
+        ctx.accounts.pool.tick_current_index,
+        ctx.accounts.pool.sqrt_price,
+        &ctx.accounts.position,
+        token_amount,
+        amount_specified_is_a,
+    )?;
+
+    let (token_max_a, token_max_b) = if amount_specified_is_a {
+        (token_amount, other_token_max)
+    } else {
+        (other_token_max, token_amount)
+    };
+
+    super::increase_liquidity::handler(
+        ctx,
+        liquidity_amount,
+        token_max_a,
+        token_max_b,
+        deadline,
+        min_sqrt_price,
+        max_sqrt_price,
+    )
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AllowlistEntry, ElysiumPool};
+
+#[derive(Accounts)]
+pub struct InitializeAllowlistEntry<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pool.access_authority)]
+    pub access_authority: Signer<'info>,
+
+    /// CHECK: safe, the allowlisted wallet can be arbitrary
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(init,
+      seeds = [b"allow", pool.key().as_ref(), wallet.key().as_ref()],
+      bump,
+      payer = funder,
+      space = AllowlistEntry::LEN)]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeAllowlistEntry>) -> Result<()> {
+    ctx.accounts
+        .allowlist_entry
+        .initialize(ctx.accounts.pool.key(), ctx.accounts.wallet.key());
+    Ok(())
+}
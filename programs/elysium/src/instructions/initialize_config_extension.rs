@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfigExtension<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"config_extension", pools_config.key().as_ref()],
+      bump,
+      space = ElysiumPoolsConfigExtension::LEN)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfigExtension>) -> Result<()> {
+    let pools_config = ctx.accounts.pools_config.key();
+    ctx.accounts.config_extension.initialize(pools_config);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::state::{ElysiumPool, PoolRewardExtension};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct InitializeExtensionReward<'info> {
+    #[account(address = reward_extension.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_extension: Box<Account<'info, PoolRewardExtension>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = funder,
+        token::mint = reward_mint,
+        token::authority = pool
+    )]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeExtensionReward>, reward_index: u8) -> Result<()> {
+    let reward_extension = &mut ctx.accounts.reward_extension;
+
+    reward_extension.initialize_reward(
+        reward_index as usize,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_vault.key(),
+    )
+}
@@ -0,0 +1,41 @@
+use crate::{errors::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fee_tier_index: u16)]
+pub struct InitializeFeeTierWithIndex<'info> {
+    pub config: Box<Account<'info, ElysiumPoolsConfig>>,
+
+    // Shares the seeds of fee tiers keyed by tick spacing, so that pools of either kind of
+    // fee tier are keyed by the fee tier index.
+    #[account(init,
+      payer = funder,
+      seeds = [b"fee_tier", config.key().as_ref(),
+               fee_tier_index.to_le_bytes().as_ref()],
+      bump,
+      space = FeeTier::LEN)]
+    pub fee_tier: Account<'info, FeeTier>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(address = config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeFeeTierWithIndex>,
+    _fee_tier_index: u16,
+    tick_spacing: u16,
+    default_fee_rate: u16,
+) -> Result<()> {
+    if tick_spacing == 0 {
+        return Err(ErrorCode::InvalidTickSpacing.into());
+    }
+
+    ctx.accounts
+        .fee_tier
+        .initialize(&ctx.accounts.config, tick_spacing, default_fee_rate)
+}
@@ -1,9 +1,10 @@
 use crate::state::*;
+use crate::util::{load_config_extension, to_timestamp_u64, verify_permissioned_pool_authority};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
-#[instruction(bumps: ElysiumPoolBumps, tick_spacing: u16)]
+#[instruction(bumps: ElysiumPoolBumps, fee_tier_index: u16)]
 pub struct InitializePool<'info> {
     pub pools_config: Box<Account<'info, ElysiumPoolsConfig>>,
 
@@ -19,7 +20,7 @@ pub struct InitializePool<'info> {
         pools_config.key().as_ref(),
         token_mint_a.key().as_ref(),
         token_mint_b.key().as_ref(),
-        tick_spacing.to_le_bytes().as_ref()
+        fee_tier_index.to_le_bytes().as_ref()
       ],
       bump,
       payer = funder,
@@ -38,7 +39,10 @@ pub struct InitializePool<'info> {
       token::authority = pool)]
     pub token_vault_b: Box<Account<'info, TokenAccount>>,
 
-    #[account(has_one = pools_config)]
+    #[account(
+      has_one = pools_config,
+      seeds = [b"fee_tier", pools_config.key().as_ref(), fee_tier_index.to_le_bytes().as_ref()],
+      bump)]
     pub fee_tier: Account<'info, FeeTier>,
 
     #[account(address = token::ID)]
@@ -50,8 +54,13 @@ pub struct InitializePool<'info> {
 pub fn handler(
     ctx: Context<InitializePool>,
     _bumps: ElysiumPoolBumps,
-    tick_spacing: u16,
+    fee_tier_index: u16,
     initial_sqrt_price: u128,
+    activation_timestamp: Option<u64>,
+    activation_slot: Option<u64>,
+    launch_fee_schedule: Option<LaunchFeeSchedule>,
+    creator_fee_rate: Option<u16>,
+    access_authority: Option<Pubkey>,
 ) -> Result<()> {
     let token_mint_a = ctx.accounts.token_mint_a.key();
     let token_mint_b = ctx.accounts.token_mint_b.key();
@@ -59,20 +68,61 @@ pub fn handler(
     let pool = &mut ctx.accounts.pool;
     let pools_config = &ctx.accounts.pools_config;
 
+    let tick_spacing = ctx.accounts.fee_tier.tick_spacing;
     let default_fee_rate = ctx.accounts.fee_tier.default_fee_rate;
 
     // ignore the bump passed and use one Anchor derived
     let bump = *ctx.bumps.get("pool").unwrap();
 
-    Ok(pool.initialize(
+    pool.initialize(
         pools_config,
         bump,
         tick_spacing,
+        fee_tier_index,
         initial_sqrt_price,
         default_fee_rate,
         token_mint_a,
         ctx.accounts.token_vault_a.key(),
         token_mint_b,
         ctx.accounts.token_vault_b.key(),
-    )?)
+    )?;
+
+    // Pools without an explicit activation are activated on creation, which is also where the
+    // launch fee schedule starts decaying from.
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let activation_timestamp = activation_timestamp.unwrap_or(timestamp);
+    let activation_slot = activation_slot.unwrap_or(clock.slot);
+
+    // The extension of the config bounds how long a pool can be left inactive
+    let config_extension = load_config_extension(pools_config.key(), ctx.remaining_accounts)?;
+    config_extension.verify_activation(
+        activation_timestamp,
+        activation_slot,
+        timestamp,
+        clock.slot,
+    )?;
+    pool.update_activation(activation_timestamp, activation_slot);
+
+    if let Some(launch_fee_schedule) = launch_fee_schedule {
+        let max_decay_period = config_extension.max_decay_period(&launch_fee_schedule);
+        pool.update_launch_fee_schedule(launch_fee_schedule, max_decay_period)?;
+    }
+
+    // The funder is recorded as the creator, who can collect the creator fees of the pool
+    pool.update_creator_fee(
+        ctx.accounts.funder.key(),
+        creator_fee_rate.unwrap_or_default(),
+        pools_config.max_creator_fee_rate,
+    )?;
+
+    // Pools created with an access authority only admit allowlisted traders and LPs. Only the
+    // permissioned pool authority of the config can create them, or anyone could claim the
+    // address of a pool by creating it as a permissioned pool first.
+    if let Some(access_authority) = access_authority {
+        verify_permissioned_pool_authority(&config_extension, ctx.remaining_accounts)?;
+        pool.update_access_authority(access_authority);
+    }
+
+    Ok(())
 }
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::manager::reward_extension_manager::calculate_reward_extension_growths_inside;
+use crate::state::*;
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+pub struct InitializePositionRewardExtension<'info> {
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(mut, address = pool.reward_extension)]
+    pub reward_extension: Box<Account<'info, PoolRewardExtension>>,
+
+    #[account(has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(has_one = pool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(has_one = pool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(constraint = tick_array_lower_extension.load()?.tick_array == tick_array_lower.key())]
+    pub tick_array_lower_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    #[account(constraint = tick_array_upper_extension.load()?.tick_array == tick_array_upper.key())]
+    pub tick_array_upper_extension: AccountLoader<'info, TickArrayRewardExtension>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"position_reward_extension", position.key().as_ref()],
+      bump,
+      space = PositionRewardExtension::LEN)]
+    pub position_extension: Account<'info, PositionRewardExtension>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePositionRewardExtension>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let position = &ctx.accounts.position;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let tick_lower = *ctx
+        .accounts
+        .tick_array_lower
+        .load()?
+        .get_tick(position.tick_lower_index, pool.tick_spacing)?;
+    let tick_upper = *ctx
+        .accounts
+        .tick_array_upper
+        .load()?
+        .get_tick(position.tick_upper_index, pool.tick_spacing)?;
+
+    let (reward_infos, reward_growths_inside) = calculate_reward_extension_growths_inside(
+        pool,
+        &ctx.accounts.reward_extension,
+        position,
+        &tick_lower,
+        ctx.accounts
+            .tick_array_lower_extension
+            .load()?
+            .get_reward_growths_outside(position.tick_lower_index, pool.tick_spacing)?,
+        &tick_upper,
+        ctx.accounts
+            .tick_array_upper_extension
+            .load()?
+            .get_reward_growths_outside(position.tick_upper_index, pool.tick_spacing)?,
+        timestamp,
+    )?;
+
+    ctx.accounts
+        .reward_extension
+        .update_rewards(reward_infos, timestamp);
+    ctx.accounts
+        .position_extension
+        .initialize(position.key(), reward_growths_inside);
+
+    Ok(())
+}
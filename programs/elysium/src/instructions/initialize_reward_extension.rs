@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+pub struct InitializeRewardExtension<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(address = pools_config.reward_emissions_super_authority)]
+    pub reward_emissions_super_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"reward_extension", pool.key().as_ref()],
+      bump,
+      space = PoolRewardExtension::LEN)]
+    pub reward_extension: Box<Account<'info, PoolRewardExtension>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeRewardExtension>) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts.reward_extension.initialize(
+        &ctx.accounts.pool,
+        ctx.accounts.pools_config.reward_emissions_super_authority,
+        timestamp,
+    )?;
+
+    let reward_extension = ctx.accounts.reward_extension.key();
+    ctx.accounts.pool.update_reward_extension(reward_extension);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::state::*;
+use crate::util::load_tick_array_bitmap;
 
 #[derive(Accounts)]
 #[instruction(start_tick_index: i32)]
@@ -15,13 +16,31 @@ pub struct InitializeTickArray<'info> {
       payer = funder,
       seeds = [b"tick_array", pool.key().as_ref(), start_tick_index.to_string().as_bytes()],
       bump,
-      space = TickArray::LEN)]
+      space = TickArray::LEN_WITH_BITMAP)]
     pub tick_array: AccountLoader<'info, TickArray>,
 
     pub system_program: Program<'info, System>,
+    // remaining accounts
+    // - required if the pool has a tick array bitmap:
+    //   - tick_array_bitmap (mut)
 }
 
 pub fn handler(ctx: Context<InitializeTickArray>, start_tick_index: i32) -> Result<()> {
-    let mut tick_array = ctx.accounts.tick_array.load_init()?;
-    Ok(tick_array.initialize(&ctx.accounts.pool, start_tick_index)?)
+    ctx.accounts
+        .tick_array
+        .load_init()?
+        .initialize(&ctx.accounts.pool, start_tick_index)?;
+
+    if let Some(tick_array_bitmap) =
+        load_tick_array_bitmap(&ctx.accounts.pool, ctx.remaining_accounts)?
+    {
+        tick_array_bitmap.load_mut()?.update(
+            start_tick_index,
+            ctx.accounts.pool.tick_spacing,
+            true,
+            false,
+        )?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeTickArrayBitmap<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"tick_array_bitmap", pool.key().as_ref()],
+      bump,
+      space = TickArrayBitmap::LEN)]
+    pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmap>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTickArrayBitmap>) -> Result<()> {
+    let pool_key = ctx.accounts.pool.key();
+    ctx.accounts
+        .tick_array_bitmap
+        .load_init()?
+        .initialize(pool_key);
+
+    let tick_array_bitmap = ctx.accounts.tick_array_bitmap.key();
+    ctx.accounts
+        .pool
+        .update_tick_array_bitmap(tick_array_bitmap);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeTickArrayRewardExtension<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(has_one = pool)]
+    pub tick_array: AccountLoader<'info, TickArray>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"tick_array_reward_extension", tick_array.key().as_ref()],
+      bump,
+      space = TickArrayRewardExtension::LEN)]
+    pub tick_array_extension: AccountLoader<'info, TickArrayRewardExtension>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTickArrayRewardExtension>) -> Result<()> {
+    let start_tick_index = ctx.accounts.tick_array.load()?.start_tick_index;
+
+    let mut tick_array_extension = ctx.accounts.tick_array_extension.load_init()?;
+    tick_array_extension.initialize(ctx.accounts.tick_array.key(), start_tick_index);
+
+    Ok(())
+}
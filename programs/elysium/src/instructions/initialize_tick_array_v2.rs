@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::util::load_tick_array_bitmap;
+
+#[derive(Accounts)]
+#[instruction(start_tick_index: i32)]
+pub struct InitializeTickArrayV2<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"tick_array", pool.key().as_ref(), start_tick_index.to_string().as_bytes()],
+      bump,
+      space = TickArray::LEN_WITH_BITMAP)]
+    pub tick_array: AccountLoader<'info, TickArray>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"tick_array_funder", tick_array.key().as_ref()],
+      bump,
+      space = TickArrayFunder::LEN)]
+    pub tick_array_funder: Account<'info, TickArrayFunder>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // - required if the pool has a tick array bitmap:
+    //   - tick_array_bitmap (mut)
+}
+
+pub fn handler(ctx: Context<InitializeTickArrayV2>, start_tick_index: i32) -> Result<()> {
+    ctx.accounts
+        .tick_array_funder
+        .initialize(ctx.accounts.tick_array.key(), ctx.accounts.funder.key());
+
+    ctx.accounts
+        .tick_array
+        .load_init()?
+        .initialize(&ctx.accounts.pool, start_tick_index)?;
+
+    if let Some(tick_array_bitmap) =
+        load_tick_array_bitmap(&ctx.accounts.pool, ctx.remaining_accounts)?
+    {
+        tick_array_bitmap.load_mut()?.update(
+            start_tick_index,
+            ctx.accounts.pool.tick_spacing,
+            true,
+            false,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,336 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::{create_pda_account, load_tick_array_bitmap};
+
+#[derive(Accounts)]
+pub struct InitializeTickArrays<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+   Initializes a tick array and its funder record for every start tick index, skipping tick
+   arrays that already exist. The remaining accounts hold the tick array followed by its
+   funder record for every start tick index, in order, followed by the pool's tick array
+   bitmap if it has one.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeTickArrays<'info>>,
+    start_tick_indexes: Vec<i32>,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let pool_key = pool.key();
+
+    let tick_array_accounts_len = start_tick_indexes.len() * 2;
+    if ctx.remaining_accounts.len() < tick_array_accounts_len {
+        return Err(ErrorCode::InvalidTickArrayAccounts.into());
+    }
+    let (tick_array_accounts, tick_array_bitmap_accounts) =
+        ctx.remaining_accounts.split_at(tick_array_accounts_len);
+    let tick_array_bitmap = load_tick_array_bitmap(pool, tick_array_bitmap_accounts)?;
+
+    for (start_tick_index, accounts) in start_tick_indexes.iter().zip(tick_array_accounts.chunks(2))
+    {
+        if !Tick::check_is_valid_start_tick(*start_tick_index, pool.tick_spacing) {
+            return Err(ErrorCode::InvalidStartTick.into());
+        }
+
+        let tick_array_info = &accounts[0];
+        let tick_array_funder_info = &accounts[1];
+
+        let start_tick_index_seed = start_tick_index.to_string();
+        let (tick_array_key, tick_array_bump) = Pubkey::find_program_address(
+            &[
+                b"tick_array",
+                pool_key.as_ref(),
+                start_tick_index_seed.as_bytes(),
+            ],
+            &crate::ID,
+        );
+        let (tick_array_funder_key, tick_array_funder_bump) = Pubkey::find_program_address(
+            &[b"tick_array_funder", tick_array_key.as_ref()],
+            &crate::ID,
+        );
+        if tick_array_info.key() != tick_array_key
+            || tick_array_funder_info.key() != tick_array_funder_key
+        {
+            return Err(ErrorCode::InvalidTickArrayAccounts.into());
+        }
+
+        if tick_array_info.owner == &crate::ID {
+            continue;
+        }
+
+        create_pda_account(
+            &ctx.accounts.funder,
+            tick_array_info,
+            TickArray::LEN_WITH_BITMAP,
+            &ctx.accounts.system_program,
+            &[
+                b"tick_array",
+                pool_key.as_ref(),
+                start_tick_index_seed.as_bytes(),
+                &[tick_array_bump],
+            ],
+        )?;
+        let tick_array =
+            AccountLoader::<TickArray>::try_from_unchecked(&crate::ID, tick_array_info)?;
+        tick_array
+            .load_init()?
+            .initialize(pool, *start_tick_index)?;
+        tick_array.exit(&crate::ID)?;
+
+        create_pda_account(
+            &ctx.accounts.funder,
+            tick_array_funder_info,
+            TickArrayFunder::LEN,
+            &ctx.accounts.system_program,
+            &[
+                b"tick_array_funder",
+                tick_array_key.as_ref(),
+                &[tick_array_funder_bump],
+            ],
+        )?;
+        let mut tick_array_funder =
+            Account::<TickArrayFunder>::try_from_unchecked(tick_array_funder_info)?;
+        tick_array_funder.initialize(tick_array_key, ctx.accounts.funder.key());
+        tick_array_funder.exit(&crate::ID)?;
+
+        if let Some(tick_array_bitmap) = &tick_array_bitmap {
+            tick_array_bitmap.load_mut()?.update(
+                *start_tick_index,
+                pool.tick_spacing,
+                true,
+                false,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::BTreeMap;
+
+    use anchor_lang::system_program;
+    use solana_program::{
+        program_utils::limited_deserialize, system_instruction::SystemInstruction,
+    };
+
+    use super::*;
+    use crate::util::test_utils::*;
+
+    const FUNDER_LAMPORTS: u64 = 1_000_000_000_000;
+
+    struct Fixture {
+        pool: AccountInfo<'static>,
+        funder: AccountInfo<'static>,
+        system_program: AccountInfo<'static>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            reset_test_runtime(Clock::default());
+            let pool = ElysiumPool {
+                tick_spacing: 8,
+                ..Default::default()
+            };
+            Fixture {
+                pool: TestAccount::with_account(Pubkey::new_unique(), &pool).into_account_info(),
+                funder: TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS)
+                    .into_account_info(),
+                system_program: TestAccount::program(system_program::ID).into_account_info(),
+            }
+        }
+
+        fn tick_array_keys(&self, start_tick_index: i32) -> (Pubkey, Pubkey) {
+            let (tick_array, _) = Pubkey::find_program_address(
+                &[
+                    b"tick_array",
+                    self.pool.key.as_ref(),
+                    start_tick_index.to_string().as_bytes(),
+                ],
+                &crate::ID,
+            );
+            let (tick_array_funder, _) = Pubkey::find_program_address(
+                &[b"tick_array_funder", tick_array.as_ref()],
+                &crate::ID,
+            );
+            (tick_array, tick_array_funder)
+        }
+
+        fn empty_accounts(&self, start_tick_index: i32) -> Vec<AccountInfo<'static>> {
+            let (tick_array, tick_array_funder) = self.tick_array_keys(start_tick_index);
+            vec![
+                TestAccount::new(tick_array, system_program::ID, 0, Vec::new()).into_account_info(),
+                TestAccount::new(tick_array_funder, system_program::ID, 0, Vec::new())
+                    .into_account_info(),
+            ]
+        }
+
+        fn initialize(
+            &self,
+            start_tick_indexes: Vec<i32>,
+            remaining_accounts: &[AccountInfo<'static>],
+        ) -> Result<()> {
+            let mut accounts = InitializeTickArrays {
+                pool: Account::try_from(&self.pool).unwrap(),
+                funder: Signer::try_from(&self.funder).unwrap(),
+                system_program: Program::try_from(&self.system_program).unwrap(),
+            };
+            handler(
+                Context::new(
+                    &crate::ID,
+                    &mut accounts,
+                    remaining_accounts,
+                    BTreeMap::new(),
+                ),
+                start_tick_indexes,
+            )
+        }
+    }
+
+    fn invoked_system_instructions() -> Vec<SystemInstruction> {
+        invoked_instructions()
+            .iter()
+            .map(|instruction| {
+                assert_eq!(instruction.program_id, system_program::ID);
+                limited_deserialize(&instruction.data, 1024).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_initialize_tick_arrays_creates_arrays_and_funder_records() {
+        let fixture = Fixture::new();
+        let mut remaining_accounts = fixture.empty_accounts(-704);
+        remaining_accounts.extend(fixture.empty_accounts(0));
+
+        fixture
+            .initialize(vec![-704, 0], &remaining_accounts)
+            .unwrap();
+
+        for (accounts, start_tick_index) in remaining_accounts.chunks(2).zip([-704, 0]) {
+            assert_eq!(accounts[0].owner, &crate::ID);
+            let tick_array = AccountLoader::<TickArray>::try_from(&accounts[0]).unwrap();
+            let tick_array = tick_array.load().unwrap();
+            assert_eq!({ tick_array.start_tick_index }, start_tick_index);
+            assert_eq!({ tick_array.pool }, fixture.pool.key());
+
+            let tick_array_funder = Account::<TickArrayFunder>::try_from(&accounts[1]).unwrap();
+            assert_eq!(tick_array_funder.tick_array, accounts[0].key());
+            assert_eq!(tick_array_funder.funder, fixture.funder.key());
+        }
+
+        let rent = Rent::default();
+        let spent = 2
+            * (rent.minimum_balance(TickArray::LEN_WITH_BITMAP)
+                + rent.minimum_balance(TickArrayFunder::LEN));
+        assert_eq!(fixture.funder.lamports(), FUNDER_LAMPORTS - spent);
+    }
+
+    #[test]
+    fn test_initialize_tick_arrays_skips_existing_arrays() {
+        let fixture = Fixture::new();
+        let mut remaining_accounts = fixture.empty_accounts(0);
+        fixture.initialize(vec![0], &remaining_accounts).unwrap();
+        let tick_array_data = remaining_accounts[0].data.borrow().to_vec();
+
+        reset_test_runtime(Clock::default());
+        remaining_accounts.extend(fixture.empty_accounts(704));
+        fixture
+            .initialize(vec![0, 704], &remaining_accounts)
+            .unwrap();
+
+        // Only the new array and its funder record are created.
+        assert_eq!(*remaining_accounts[0].data.borrow(), &tick_array_data[..]);
+        assert_eq!(invoked_instructions().len(), 2);
+        assert_eq!(remaining_accounts[2].owner, &crate::ID);
+    }
+
+    #[test]
+    fn test_initialize_tick_arrays_tops_up_prefunded_accounts() {
+        let fixture = Fixture::new();
+        let (tick_array, tick_array_funder) = fixture.tick_array_keys(0);
+        let remaining_accounts = vec![
+            TestAccount::new(tick_array, system_program::ID, 1_000, Vec::new()).into_account_info(),
+            TestAccount::new(tick_array_funder, system_program::ID, 0, Vec::new())
+                .into_account_info(),
+        ];
+
+        fixture.initialize(vec![0], &remaining_accounts).unwrap();
+
+        let rent = Rent::default();
+        assert_eq!(
+            remaining_accounts[0].lamports(),
+            rent.minimum_balance(TickArray::LEN_WITH_BITMAP)
+        );
+        assert_eq!(remaining_accounts[0].data_len(), TickArray::LEN_WITH_BITMAP);
+        assert_eq!(remaining_accounts[0].owner, &crate::ID);
+
+        let system_instructions = invoked_system_instructions();
+        assert!(matches!(
+            system_instructions[..3],
+            [
+                SystemInstruction::Transfer { lamports },
+                SystemInstruction::Allocate { .. },
+                SystemInstruction::Assign { .. },
+            ] if lamports == rent.minimum_balance(TickArray::LEN_WITH_BITMAP) - 1_000
+        ));
+        assert!(matches!(
+            system_instructions[3],
+            SystemInstruction::CreateAccount { .. }
+        ));
+    }
+
+    #[test]
+    fn test_initialize_tick_arrays_rejects_invalid_accounts() {
+        let fixture = Fixture::new();
+
+        // Accounts for a different start tick index
+        let remaining_accounts = fixture.empty_accounts(704);
+        assert_eq!(
+            fixture
+                .initialize(vec![0], &remaining_accounts)
+                .unwrap_err(),
+            ErrorCode::InvalidTickArrayAccounts.into()
+        );
+
+        // Funder record of a different tick array
+        let mut remaining_accounts = fixture.empty_accounts(0);
+        remaining_accounts[1] = fixture.empty_accounts(704).remove(1);
+        assert_eq!(
+            fixture
+                .initialize(vec![0], &remaining_accounts)
+                .unwrap_err(),
+            ErrorCode::InvalidTickArrayAccounts.into()
+        );
+
+        // Missing accounts
+        let remaining_accounts = fixture.empty_accounts(0);
+        assert_eq!(
+            fixture
+                .initialize(vec![0, 704], &remaining_accounts)
+                .unwrap_err(),
+            ErrorCode::InvalidTickArrayAccounts.into()
+        );
+
+        // Start tick index not aligned to the tick array size
+        let remaining_accounts = fixture.empty_accounts(8);
+        assert_eq!(
+            fixture
+                .initialize(vec![8], &remaining_accounts)
+                .unwrap_err(),
+            ErrorCode::InvalidStartTick.into()
+        );
+
+        assert!(invoked_instructions().is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    state::*,
+    util::{to_timestamp_u64, transfer_position_token_to_lock},
+};
+
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"position".as_ref(), position_mint.key().as_ref()],
+        bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(address = position.position_mint)]
+    pub position_mint: Account<'info, Mint>,
+
+    #[account(mut,
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1,
+        constraint = position_token_account.owner == position_authority.key()
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(init,
+      payer = funder,
+      space = PositionLock::LEN,
+      seeds = [b"position_lock".as_ref(), position.key().as_ref()],
+      bump,
+    )]
+    pub position_lock: Account<'info, PositionLock>,
+
+    #[account(init,
+      payer = funder,
+      associated_token::mint = position_mint,
+      associated_token::authority = position_lock,
+    )]
+    pub lock_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<LockPosition>, unlock_timestamp: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let position_lock = &mut ctx.accounts.position_lock;
+    position_lock.initialize(
+        ctx.accounts.position.key(),
+        ctx.accounts.position_authority.key(),
+        *ctx.bumps.get("position_lock").unwrap(),
+        unlock_timestamp,
+        timestamp,
+    )?;
+
+    transfer_position_token_to_lock(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.lock_token_account,
+        &ctx.accounts.token_program,
+    )
+}
@@ -0,0 +1,389 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::realloc_account;
+
+// Offset of the reward infos in the pool account data, which is the same in every layout.
+const REWARD_INFOS_OFFSET: usize = 8 + 261;
+const REWARD_INFO_LEN_V0: usize = 128;
+const REWARD_INFO_LEN: usize = 216;
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    /// CHECK: Deserialized by the handler once it is grown to the current layout.
+    #[account(mut, owner = crate::ID)]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+   Grows a pool initialized with the original layout to the current layout. The fields added
+   since are zeroed, so the pool is active, has no launch fee, creator fee, hook or access
+   authority, and the accounting of its initialized rewards starts from their vault balances,
+   which are passed as remaining accounts. Migrating a pool with the current layout does nothing.
+*/
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, MigratePool<'info>>,
+) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+    if !pool_info
+        .try_borrow_data()?
+        .starts_with(&ElysiumPool::discriminator())
+    {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    match pool_info.data_len() {
+        ElysiumPool::LEN => return Ok(()),
+        ElysiumPool::LEN_V0 => {}
+        _ => return Err(ErrorCode::UnsupportedPoolLayout.into()),
+    }
+
+    realloc_account(
+        &ctx.accounts.funder,
+        &pool_info,
+        ElysiumPool::LEN,
+        &ctx.accounts.system_program,
+    )?;
+    migrate_pool_layout_v0(&mut pool_info.try_borrow_mut_data()?);
+
+    let mut pool = Account::<ElysiumPool>::try_from(&pool_info)?;
+    for reward_info in pool.reward_infos.iter_mut() {
+        if !reward_info.initialized() {
+            continue;
+        }
+        let vault_info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key() == reward_info.vault)
+            .ok_or(ErrorCode::InvalidRewardAccounts)?;
+        let vault = Account::<TokenAccount>::try_from(vault_info)?;
+        reward_info.migrate_accounting(vault.amount);
+    }
+    pool.exit(&crate::ID)
+}
+
+/// Moves the reward infos of a pool grown from the original layout to their current offsets and
+/// zeroes the fields added to them. The fields added after the reward infos must already be zeroed.
+pub fn migrate_pool_layout_v0(data: &mut [u8]) {
+    for index in (0..NUM_REWARDS).rev() {
+        let from = REWARD_INFOS_OFFSET + index * REWARD_INFO_LEN_V0;
+        let to = REWARD_INFOS_OFFSET + index * REWARD_INFO_LEN;
+        data.copy_within(from..from + REWARD_INFO_LEN_V0, to);
+        data[to + REWARD_INFO_LEN_V0..to + REWARD_INFO_LEN].fill(0);
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::BTreeMap;
+
+    use anchor_lang::system_program;
+    use anchor_spl::token;
+    use solana_program::{program_pack::Pack, sysvar};
+
+    use super::*;
+    use crate::instructions::{reinitialize_reward, ReinitializeReward};
+    use crate::manager::pool_manager::next_pool_reward_infos;
+    use crate::util::test_utils::*;
+
+    const FUNDER_LAMPORTS: u64 = 1_000_000_000;
+
+    fn legacy_reward_info() -> ElysiumPoolRewardInfo {
+        ElysiumPoolRewardInfo {
+            mint: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            emissions_per_second_x64: 7 << 64,
+            growth_global_x64: 11 << 64,
+            ..Default::default()
+        }
+    }
+
+    fn legacy_pool() -> ElysiumPool {
+        let mut pool = ElysiumPool {
+            pools_config: Pubkey::new_unique(),
+            liquidity: 1_000,
+            sqrt_price: 1 << 64,
+            fee_rate: 3_000,
+            ..Default::default()
+        };
+        pool.reward_infos[0] = legacy_reward_info();
+        pool.reward_infos[1] = legacy_reward_info();
+        pool
+    }
+
+    // Lays the pool out in the original layout, which differs only in the reward infos.
+    fn legacy_pool_account(pool: &ElysiumPool) -> AccountInfo<'static> {
+        let mut current = Vec::new();
+        pool.try_serialize(&mut current).unwrap();
+        let mut data = current[..REWARD_INFOS_OFFSET].to_vec();
+        for reward_info in pool.reward_infos.iter() {
+            data.extend_from_slice(&reward_info.try_to_vec().unwrap()[..REWARD_INFO_LEN_V0]);
+        }
+        assert_eq!(data.len(), ElysiumPool::LEN_V0);
+        let lamports = Rent::default().minimum_balance(data.len());
+        TestAccount::new(Pubkey::new_unique(), crate::ID, lamports, data).into_account_info()
+    }
+
+    fn vault_account(key: Pubkey, amount: u64) -> AccountInfo<'static> {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        TestAccount::new(key, spl_token::ID, 1, data)
+            .readonly()
+            .into_account_info()
+    }
+
+    fn migrate(
+        pool: &AccountInfo<'static>,
+        funder: &AccountInfo<'static>,
+        vaults: &[AccountInfo<'static>],
+    ) -> Result<()> {
+        let system_program = TestAccount::program(system_program::ID).into_account_info();
+        let mut accounts = MigratePool {
+            pool: UncheckedAccount::try_from(pool.clone()),
+            funder: Signer::try_from(funder).unwrap(),
+            system_program: Program::try_from(&system_program).unwrap(),
+        };
+        handler(Context::new(
+            &crate::ID,
+            &mut accounts,
+            vaults,
+            BTreeMap::new(),
+        ))
+    }
+
+    #[test]
+    fn test_migrate_pool_grows_account_with_zeroed_defaults() {
+        reset_test_runtime(Clock::default());
+        let legacy = legacy_pool();
+        let pool = legacy_pool_account(&legacy);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+        let vaults = [
+            vault_account(legacy.reward_infos[1].vault, 200),
+            vault_account(legacy.reward_infos[0].vault, 100),
+        ];
+
+        migrate(&pool, &funder, &vaults).unwrap();
+
+        let rent = Rent::default();
+        let additional_rent =
+            rent.minimum_balance(ElysiumPool::LEN) - rent.minimum_balance(ElysiumPool::LEN_V0);
+        assert_eq!(pool.data_len(), ElysiumPool::LEN);
+        assert_eq!(pool.lamports(), rent.minimum_balance(ElysiumPool::LEN));
+        assert_eq!(funder.lamports(), FUNDER_LAMPORTS - additional_rent);
+
+        let mut expected = legacy;
+        expected.reward_infos[0].migrate_accounting(100);
+        expected.reward_infos[1].migrate_accounting(200);
+        let migrated = Account::<ElysiumPool>::try_from(&pool).unwrap();
+        assert_eq!(migrated.reward_infos, expected.reward_infos);
+        assert_eq!(migrated.pools_config, expected.pools_config);
+        assert_eq!(migrated.liquidity, expected.liquidity);
+        assert_eq!(migrated.sqrt_price, expected.sqrt_price);
+        assert_eq!(migrated.fee_rate, expected.fee_rate);
+        let mut data = Vec::new();
+        migrated.try_serialize(&mut data).unwrap();
+        assert!(data[REWARD_INFOS_OFFSET + REWARD_INFO_LEN * NUM_REWARDS..]
+            .iter()
+            .all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_migrate_pool_keeps_rewards_emitting() {
+        reset_test_runtime(Clock::default());
+        let mut legacy = legacy_pool();
+        legacy.reward_last_updated_timestamp = 100;
+        let pool = legacy_pool_account(&legacy);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+        let vaults = [
+            vault_account(legacy.reward_infos[0].vault, 1_000_000),
+            vault_account(legacy.reward_infos[1].vault, 1_000_000),
+        ];
+
+        migrate(&pool, &funder, &vaults).unwrap();
+
+        // 7 tokens per second over 10 seconds, shared by 1_000 units of liquidity
+        let migrated = Account::<ElysiumPool>::try_from(&pool).unwrap();
+        let reward_infos = next_pool_reward_infos(&migrated, 110).unwrap();
+        assert_eq!(
+            reward_infos[0].growth_global_x64,
+            (11 << 64) + (70 << 64) / 1_000
+        );
+        assert_eq!(reward_infos[0].total_emitted, 70);
+        assert_eq!(reward_infos[0].unemitted_amount(), 1_000_000 - 70);
+    }
+
+    fn reinitialize(
+        pool: &AccountInfo<'static>,
+        reward_settlement: &AccountInfo<'static>,
+        timestamp: u64,
+    ) -> Result<()> {
+        reset_test_runtime(Clock {
+            unix_timestamp: timestamp as i64,
+            ..Clock::default()
+        });
+        let pool = Account::<ElysiumPool>::try_from(pool).unwrap();
+        let reward_authority =
+            TestAccount::signer(pool.reward_infos[0].authority, 0).into_account_info();
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+
+        let mut mint_data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut mint_data);
+        let reward_mint =
+            TestAccount::new(Pubkey::new_unique(), token::ID, 1, mint_data).into_account_info();
+        let reward_vault = vault_account(Pubkey::new_unique(), 0);
+        let token_program = TestAccount::program(token::ID).into_account_info();
+        let system_program = TestAccount::program(system_program::ID).into_account_info();
+        // Rent is serialized as its lamports per byte year, exemption threshold and burn percent.
+        let rent = Rent::default();
+        let mut rent_data = rent.lamports_per_byte_year.to_le_bytes().to_vec();
+        rent_data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+        rent_data.push(rent.burn_percent);
+        let rent = TestAccount::new(sysvar::rent::ID, sysvar::ID, 1, rent_data).into_account_info();
+
+        let mut accounts = ReinitializeReward {
+            reward_authority: Signer::try_from(&reward_authority).unwrap(),
+            funder: Signer::try_from(&funder).unwrap(),
+            pool: Box::new(pool),
+            reward_settlement: Box::new(Account::try_from(reward_settlement).unwrap()),
+            reward_mint: Box::new(Account::try_from(&reward_mint).unwrap()),
+            reward_vault: Box::new(Account::try_from(&reward_vault).unwrap()),
+            token_program: Program::try_from(&token_program).unwrap(),
+            system_program: Program::try_from(&system_program).unwrap(),
+            rent: Sysvar::from_account_info(&rent).unwrap(),
+        };
+        reinitialize_reward::handler(
+            Context::new(&crate::ID, &mut accounts, &[], BTreeMap::new()),
+            0,
+        )?;
+        accounts.exit(&crate::ID)
+    }
+
+    #[test]
+    fn test_migrated_pool_reward_reinitializes_after_settlement_period() {
+        reset_test_runtime(Clock::default());
+        let legacy = legacy_pool();
+        let pool = legacy_pool_account(&legacy);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+        // An empty vault has nothing left to emit, so the reward can be retired right away.
+        let vaults = [
+            vault_account(legacy.reward_infos[0].vault, 0),
+            vault_account(legacy.reward_infos[1].vault, 0),
+        ];
+        migrate(&pool, &funder, &vaults).unwrap();
+
+        let mut migrated = Account::<ElysiumPool>::try_from(&pool).unwrap();
+        let reward_infos = next_pool_reward_infos(&migrated, 100).unwrap();
+        migrated.retire_reward(0, reward_infos, 100).unwrap();
+        migrated.exit(&crate::ID).unwrap();
+        let retired = migrated.reward_infos[0];
+        assert!(!retired.settled());
+
+        let mut settlement = RewardSettlement::default();
+        settlement.initialize(
+            pool.key(),
+            0,
+            retired.mint,
+            retired.vault,
+            retired.growth_global_x64,
+            100,
+        );
+        let reward_settlement =
+            TestAccount::with_account(Pubkey::new_unique(), &settlement).into_account_info();
+
+        // What positions owe for a migrated reward is unknown, so it never settles early
+        assert_eq!(
+            reinitialize(
+                &pool,
+                &reward_settlement,
+                100 + REWARD_SETTLEMENT_PERIOD_SECONDS - 1
+            )
+            .unwrap_err(),
+            ErrorCode::RewardNotSettled.into()
+        );
+
+        reinitialize(
+            &pool,
+            &reward_settlement,
+            100 + REWARD_SETTLEMENT_PERIOD_SECONDS,
+        )
+        .unwrap();
+        let reinitialized = Account::<ElysiumPool>::try_from(&pool)
+            .unwrap()
+            .reward_infos[0];
+        assert_ne!(reinitialized.vault, retired.vault);
+        assert_eq!(reinitialized.authority, retired.authority);
+        assert_eq!(reinitialized.growth_global_x64, retired.growth_global_x64);
+        assert!(reinitialized.settled());
+    }
+
+    #[test]
+    fn test_migrate_pool_ignores_migrated_pool() {
+        reset_test_runtime(Clock::default());
+        let pool =
+            TestAccount::with_account(Pubkey::new_unique(), &legacy_pool()).into_account_info();
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+
+        migrate(&pool, &funder, &[]).unwrap();
+
+        assert_eq!(pool.data_len(), ElysiumPool::LEN);
+        assert_eq!(funder.lamports(), FUNDER_LAMPORTS);
+        assert!(invoked_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_migrate_pool_requires_reward_vaults() {
+        reset_test_runtime(Clock::default());
+        let legacy = legacy_pool();
+        let pool = legacy_pool_account(&legacy);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+        let vaults = [vault_account(legacy.reward_infos[0].vault, 100)];
+
+        assert_eq!(
+            migrate(&pool, &funder, &vaults).unwrap_err(),
+            ErrorCode::InvalidRewardAccounts.into()
+        );
+    }
+
+    #[test]
+    fn test_migrate_pool_rejects_unknown_layouts() {
+        reset_test_runtime(Clock::default());
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+
+        let mut data = ElysiumPool::discriminator().to_vec();
+        data.resize(ElysiumPool::LEN_V0 + 8, 0);
+        let pool = TestAccount::new(Pubkey::new_unique(), crate::ID, 1, data).into_account_info();
+        assert_eq!(
+            migrate(&pool, &funder, &[]).unwrap_err(),
+            ErrorCode::UnsupportedPoolLayout.into()
+        );
+
+        let other = TestAccount::new(
+            Pubkey::new_unique(),
+            crate::ID,
+            1,
+            vec![1; ElysiumPool::LEN_V0],
+        )
+        .into_account_info();
+        assert!(migrate(&other, &funder, &[]).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::util::{realloc_account, sync_initialized_tick_bitmap};
+
+#[derive(Accounts)]
+pub struct MigrateTickArray<'info> {
+    #[account(mut)]
+    pub tick_array: AccountLoader<'info, TickArray>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+   Grows a tick array initialized before the initialized-tick bitmap to also store the bitmap,
+   and rebuilds the bitmap from its ticks. Migrating a tick array that already stores the bitmap
+   only rebuilds it.
+*/
+pub fn handler(ctx: Context<MigrateTickArray>) -> Result<()> {
+    let tick_array_info = ctx.accounts.tick_array.to_account_info();
+    if tick_array_info.data_len() < TickArray::LEN_WITH_BITMAP {
+        realloc_account(
+            &ctx.accounts.funder,
+            &tick_array_info,
+            TickArray::LEN_WITH_BITMAP,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    sync_initialized_tick_bitmap(&ctx.accounts.tick_array)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::BTreeMap;
+
+    use anchor_lang::system_program;
+
+    use super::*;
+    use crate::util::{load_initialized_tick_bitmap, test_utils::*};
+
+    const FUNDER_LAMPORTS: u64 = 1_000_000_000;
+
+    fn tick_array_account(initialized_offsets: &[usize], data_len: usize) -> AccountInfo<'static> {
+        let lamports = Rent::default().minimum_balance(data_len);
+        let tick_array_info =
+            TestAccount::new(Pubkey::new_unique(), crate::ID, lamports, vec![0; data_len])
+                .into_account_info();
+        let tick_array =
+            AccountLoader::<TickArray>::try_from_unchecked(&crate::ID, &tick_array_info).unwrap();
+        {
+            let mut tick_array = tick_array.load_init().unwrap();
+            for offset in initialized_offsets {
+                tick_array.ticks[*offset].initialized = true;
+            }
+        }
+        tick_array.exit(&crate::ID).unwrap();
+        tick_array_info
+    }
+
+    fn migrate(tick_array: &AccountInfo<'static>, funder: &AccountInfo<'static>) -> Result<()> {
+        let system_program = TestAccount::program(system_program::ID).into_account_info();
+        let mut accounts = MigrateTickArray {
+            tick_array: AccountLoader::try_from(tick_array).unwrap(),
+            funder: Signer::try_from(funder).unwrap(),
+            system_program: Program::try_from(&system_program).unwrap(),
+        };
+        handler(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            BTreeMap::new(),
+        ))
+    }
+
+    #[test]
+    fn test_migrate_tick_array_grows_account_and_rebuilds_bitmap() {
+        reset_test_runtime(Clock::default());
+        let tick_array = tick_array_account(&[0, 5, 87], TickArray::LEN);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+        let loader = AccountLoader::<TickArray>::try_from(&tick_array).unwrap();
+        assert_eq!(load_initialized_tick_bitmap(&loader).unwrap(), None);
+
+        migrate(&tick_array, &funder).unwrap();
+
+        let rent = Rent::default();
+        let additional_rent =
+            rent.minimum_balance(TickArray::LEN_WITH_BITMAP) - rent.minimum_balance(TickArray::LEN);
+        assert_eq!(tick_array.data_len(), TickArray::LEN_WITH_BITMAP);
+        assert_eq!(
+            tick_array.lamports(),
+            rent.minimum_balance(TickArray::LEN_WITH_BITMAP)
+        );
+        assert_eq!(funder.lamports(), FUNDER_LAMPORTS - additional_rent);
+        assert_eq!(
+            load_initialized_tick_bitmap(&loader).unwrap(),
+            Some(1 | 1 << 5 | 1 << 87)
+        );
+    }
+
+    #[test]
+    fn test_migrate_tick_array_rebuilds_bitmap_of_migrated_array() {
+        reset_test_runtime(Clock::default());
+        let tick_array = tick_array_account(&[3], TickArray::LEN_WITH_BITMAP);
+        let funder = TestAccount::signer(Pubkey::new_unique(), FUNDER_LAMPORTS).into_account_info();
+
+        migrate(&tick_array, &funder).unwrap();
+
+        let loader = AccountLoader::<TickArray>::try_from(&tick_array).unwrap();
+        assert_eq!(tick_array.data_len(), TickArray::LEN_WITH_BITMAP);
+        assert_eq!(funder.lamports(), FUNDER_LAMPORTS);
+        assert_eq!(load_initialized_tick_bitmap(&loader).unwrap(), Some(1 << 3));
+        assert!(invoked_instructions().is_empty());
+    }
+}
@@ -1,63 +1,140 @@
+pub mod add_hook_program;
+pub mod close_allowlist_entry;
 pub mod close_bundled_position;
 pub mod close_position;
+pub mod close_tick_array;
+pub mod collect_creator_fees;
+pub mod collect_extension_reward;
 pub mod collect_fees;
 pub mod collect_protocol_fees;
 pub mod collect_reward;
 pub mod decrease_liquidity;
 pub mod delete_position_bundle;
+pub mod extend_position_lock;
+pub mod fund_reward;
+pub mod get_reward_runway;
+pub mod harvest;
 pub mod increase_liquidity;
+pub mod increase_liquidity_by_token_amount;
+pub mod initialize_allowlist_entry;
 pub mod initialize_config;
+pub mod initialize_config_extension;
+pub mod initialize_extension_reward;
 pub mod initialize_fee_tier;
+pub mod initialize_fee_tier_with_index;
 pub mod initialize_pool;
 pub mod initialize_position_bundle;
 pub mod initialize_position_bundle_with_metadata;
+pub mod initialize_position_reward_extension;
 pub mod initialize_reward;
+pub mod initialize_reward_extension;
 pub mod initialize_tick_array;
+pub mod initialize_tick_array_bitmap;
+pub mod initialize_tick_array_reward_extension;
+pub mod initialize_tick_array_v2;
+pub mod initialize_tick_arrays;
+pub mod lock_position;
+pub mod migrate_pool;
+pub mod migrate_tick_array;
 pub mod open_bundled_position;
 pub mod open_position;
 pub mod open_position_with_metadata;
+pub mod reinitialize_reward;
+pub mod remove_hook_program;
+pub mod retire_reward;
+pub mod set_access_authority;
 pub mod set_collect_protocol_fees_authority;
 pub mod set_default_fee_rate;
 pub mod set_default_protocol_fee_rate;
+pub mod set_extension_reward_authority;
+pub mod set_extension_reward_emissions;
 pub mod set_fee_authority;
 pub mod set_fee_rate;
+pub mod set_max_activation_delay;
+pub mod set_max_creator_fee_rate;
+pub mod set_max_launch_fee_decay_period;
+pub mod set_permissioned_pool_authority;
+pub mod set_pool_hook;
 pub mod set_protocol_fee_rate;
 pub mod set_reward_authority;
 pub mod set_reward_authority_by_super_authority;
 pub mod set_reward_emissions;
+pub mod set_reward_emissions_schedule;
 pub mod set_reward_emissions_super_authority;
+pub mod settle_reward;
 pub mod swap;
+pub mod sync_tick_array_bitmap;
 pub mod two_hop_swap;
+pub mod unlock_position;
 pub mod update_fees_and_rewards;
+pub mod withdraw_unallocated_reward;
 
+pub use add_hook_program::*;
+pub use close_allowlist_entry::*;
 pub use close_bundled_position::*;
 pub use close_position::*;
+pub use close_tick_array::*;
+pub use collect_creator_fees::*;
+pub use collect_extension_reward::*;
 pub use collect_fees::*;
 pub use collect_protocol_fees::*;
 pub use collect_reward::*;
 pub use decrease_liquidity::*;
 pub use delete_position_bundle::*;
+pub use extend_position_lock::*;
+pub use fund_reward::*;
+pub use get_reward_runway::*;
+pub use harvest::*;
 pub use increase_liquidity::*;
+pub use initialize_allowlist_entry::*;
 pub use initialize_config::*;
+pub use initialize_config_extension::*;
+pub use initialize_extension_reward::*;
 pub use initialize_fee_tier::*;
+pub use initialize_fee_tier_with_index::*;
 pub use initialize_pool::*;
 pub use initialize_position_bundle::*;
 pub use initialize_position_bundle_with_metadata::*;
+pub use initialize_position_reward_extension::*;
 pub use initialize_reward::*;
+pub use initialize_reward_extension::*;
 pub use initialize_tick_array::*;
+pub use initialize_tick_array_bitmap::*;
+pub use initialize_tick_array_reward_extension::*;
+pub use initialize_tick_array_v2::*;
+pub use initialize_tick_arrays::*;
+pub use lock_position::*;
+pub use migrate_pool::*;
+pub use migrate_tick_array::*;
 pub use open_bundled_position::*;
 pub use open_position::*;
 pub use open_position_with_metadata::*;
+pub use reinitialize_reward::*;
+pub use remove_hook_program::*;
+pub use retire_reward::*;
+pub use set_access_authority::*;
 pub use set_collect_protocol_fees_authority::*;
 pub use set_default_fee_rate::*;
 pub use set_default_protocol_fee_rate::*;
+pub use set_extension_reward_authority::*;
+pub use set_extension_reward_emissions::*;
 pub use set_fee_authority::*;
 pub use set_fee_rate::*;
+pub use set_max_activation_delay::*;
+pub use set_max_creator_fee_rate::*;
+pub use set_max_launch_fee_decay_period::*;
+pub use set_permissioned_pool_authority::*;
+pub use set_pool_hook::*;
 pub use set_protocol_fee_rate::*;
 pub use set_reward_authority::*;
 pub use set_reward_authority_by_super_authority::*;
 pub use set_reward_emissions::*;
+pub use set_reward_emissions_schedule::*;
 pub use set_reward_emissions_super_authority::*;
+pub use settle_reward::*;
 pub use swap::*;
+pub use sync_tick_array_bitmap::*;
 pub use two_hop_swap::*;
+pub use unlock_position::*;
 pub use update_fees_and_rewards::*;
+pub use withdraw_unallocated_reward::*;
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::{state::*, util::verify_position_bundle_authority};
+use crate::{
+    state::*,
+    util::{verify_allowlisted, verify_position_bundle_authority},
+};
 
 #[derive(Accounts)]
 #[instruction(bundle_index: u16)]
@@ -36,6 +39,9 @@ pub struct OpenBundledPosition<'info> {
 
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    // remaining accounts
+    // - required if the pool is permissioned:
+    //   - allowlist_entry of the position_bundle_authority
 }
 
 pub fn handler(
@@ -53,6 +59,11 @@ pub fn handler(
         &ctx.accounts.position_bundle_token_account,
         &ctx.accounts.position_bundle_authority,
     )?;
+    verify_allowlisted(
+        pool,
+        ctx.accounts.position_bundle_authority.key(),
+        ctx.remaining_accounts,
+    )?;
 
     position_bundle.open_bundled_position(bundle_index)?;
 
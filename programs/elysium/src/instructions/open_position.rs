@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::{state::*, util::mint_position_token_and_remove_authority};
+use crate::{
+    state::*,
+    util::{mint_position_token_and_remove_authority, verify_allowlisted},
+};
 
 #[derive(Accounts)]
 #[instruction(bumps: OpenPositionBumps)]
@@ -42,6 +45,9 @@ pub struct OpenPosition<'info> {
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    // remaining accounts
+    // - required if the pool is permissioned:
+    //   - allowlist_entry of the owner
 }
 
 /*
@@ -54,6 +60,7 @@ pub fn handler(
     tick_upper_index: i32,
 ) -> Result<()> {
     let pool = &ctx.accounts.pool;
+    verify_allowlisted(pool, ctx.accounts.owner.key(), ctx.remaining_accounts)?;
     let position_mint = &ctx.accounts.position_mint;
     let position = &mut ctx.accounts.position;
 
@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::{state::*, util::mint_position_token_with_metadata_and_remove_authority};
+use crate::{
+    state::*,
+    util::{mint_position_token_with_metadata_and_remove_authority, verify_allowlisted},
+};
 
 use crate::constants::nft::pool_nft_update_auth::ID as WP_NFT_UPDATE_AUTH;
 
@@ -57,6 +60,9 @@ pub struct OpenPositionWithMetadata<'info> {
     /// CHECK: checked via account constraints
     #[account(address = WP_NFT_UPDATE_AUTH)]
     pub metadata_update_auth: UncheckedAccount<'info>,
+    // remaining accounts
+    // - required if the pool is permissioned:
+    //   - allowlist_entry of the owner
 }
 
 /*
@@ -69,6 +75,7 @@ pub fn handler(
     tick_upper_index: i32,
 ) -> Result<()> {
     let pool = &ctx.accounts.pool;
+    verify_allowlisted(pool, ctx.accounts.owner.key(), ctx.remaining_accounts)?;
     let position_mint = &ctx.accounts.position_mint;
     let position = &mut ctx.accounts.position;
 
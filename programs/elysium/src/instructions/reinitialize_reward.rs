@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::{ElysiumPool, RewardSettlement};
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct ReinitializeReward<'info> {
+    #[account(address = pool.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(has_one = pool)]
+    pub reward_settlement: Box<Account<'info, RewardSettlement>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = funder,
+        token::mint = reward_mint,
+        token::authority = pool
+    )]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<ReinitializeReward>, reward_index: u8) -> Result<()> {
+    let reward_settlement = &ctx.accounts.reward_settlement;
+    let index = reward_index as usize;
+
+    // Only the settlement of the reward currently in the slot proves that it has been retired.
+    if reward_settlement.reward_index != reward_index
+        || reward_settlement.vault != ctx.accounts.pool.reward_infos[index].vault
+    {
+        return Err(ErrorCode::InvalidRewardSettlement.into());
+    }
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // A reward that every position has settled can be reinitialized right away. Otherwise the
+    // settlement period bounds how long positions that never settle can hold up the slot.
+    ctx.accounts.pool.reinitialize_reward(
+        index,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_vault.key(),
+        timestamp,
+        reward_settlement.settlement_period_elapsed(timestamp),
+    )
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+
+#[derive(Accounts)]
+pub struct RemoveHookProgram<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveHookProgram>, hook_program: Pubkey) -> Result<()> {
+    ctx.accounts
+        .config_extension
+        .remove_hook_program(hook_program)
+}
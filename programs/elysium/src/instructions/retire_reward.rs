@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::manager::pool_manager::next_pool_reward_infos_with_vault;
+use crate::state::{ElysiumPool, RewardSettlement};
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct RetireReward<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"reward_settlement", pool.key().as_ref(), reward_vault.key().as_ref()],
+      bump,
+      space = RewardSettlement::LEN)]
+    pub reward_settlement: Box<Account<'info, RewardSettlement>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RetireReward>, reward_index: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let index = reward_index as usize;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_pool_reward_infos_with_vault(
+        pool,
+        index,
+        ctx.accounts.reward_vault.amount,
+        timestamp,
+    )?;
+
+    pool.retire_reward(index, next_reward_infos, timestamp)?;
+
+    let reward_info = pool.reward_infos[index];
+    ctx.accounts.reward_settlement.initialize(
+        pool.key(),
+        reward_index,
+        reward_info.mint,
+        reward_info.vault,
+        reward_info.growth_global_x64,
+        timestamp,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ElysiumPool;
+
+#[derive(Accounts)]
+pub struct SetAccessAuthority<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pool.access_authority)]
+    pub access_authority: Signer<'info>,
+
+    /// CHECK: safe, the account that will be new authority can be arbitrary
+    pub new_access_authority: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetAccessAuthority>) -> Result<()> {
+    ctx.accounts
+        .pool
+        .update_access_authority(ctx.accounts.new_access_authority.key());
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPool, PoolRewardExtension};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct SetExtensionRewardAuthority<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_extension: Account<'info, PoolRewardExtension>,
+
+    #[account(address = reward_extension.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    /// CHECK: safe, the account that will be new authority can be arbitrary
+    pub new_reward_authority: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetExtensionRewardAuthority>, reward_index: u8) -> Result<()> {
+    ctx.accounts.reward_extension.update_reward_authority(
+        reward_index as usize,
+        ctx.accounts.new_reward_authority.key(),
+    )
+}
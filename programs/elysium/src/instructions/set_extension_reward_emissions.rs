@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::manager::pool_manager::next_extension_reward_infos_with_vault;
+use crate::math::checked_mul_shift_right;
+use crate::state::{ElysiumPool, PoolRewardExtension};
+use crate::util::to_timestamp_u64;
+
+use super::set_reward_emissions::DAY_IN_SECONDS;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct SetExtensionRewardEmissions<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_extension: Account<'info, PoolRewardExtension>,
+
+    #[account(address = reward_extension.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(address = reward_extension.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler(
+    ctx: Context<SetExtensionRewardEmissions>,
+    reward_index: u8,
+    emissions_per_second_x64: u128,
+) -> Result<()> {
+    let reward_vault = &ctx.accounts.reward_vault;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_extension_reward_infos_with_vault(
+        &ctx.accounts.pool,
+        &ctx.accounts.reward_extension,
+        reward_index as usize,
+        reward_vault.amount,
+        timestamp,
+    )?;
+
+    // Only funds that have not been emitted yet can back the new emissions.
+    let reward_info = &next_reward_infos[reward_index as usize];
+
+    let emissions_per_day = checked_mul_shift_right(DAY_IN_SECONDS, emissions_per_second_x64)?;
+    if reward_info.unemitted_amount() < emissions_per_day {
+        return Err(ErrorCode::RewardVaultAmountInsufficient.into());
+    }
+
+    ctx.accounts.reward_extension.update_emissions(
+        reward_index as usize,
+        next_reward_infos,
+        timestamp,
+        emissions_per_second_x64,
+    )
+}
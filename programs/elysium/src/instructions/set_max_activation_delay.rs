@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+
+#[derive(Accounts)]
+pub struct SetMaxActivationDelay<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMaxActivationDelay>,
+    max_activation_delay: u64,
+    max_activation_slot_delay: u64,
+) -> Result<()> {
+    ctx.accounts
+        .config_extension
+        .update_max_activation_delay(max_activation_delay, max_activation_slot_delay);
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ElysiumPoolsConfig;
+
+#[derive(Accounts)]
+pub struct SetMaxCreatorFeeRate<'info> {
+    #[account(mut)]
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMaxCreatorFeeRate>, max_creator_fee_rate: u16) -> Result<()> {
+    ctx.accounts
+        .pools_config
+        .update_max_creator_fee_rate(max_creator_fee_rate)
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+
+#[derive(Accounts)]
+pub struct SetMaxLaunchFeeDecayPeriod<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMaxLaunchFeeDecayPeriod>,
+    max_launch_fee_decay_period: u64,
+    max_launch_fee_decay_slots: u64,
+) -> Result<()> {
+    ctx.accounts
+        .config_extension
+        .update_max_launch_fee_decay_period(
+            max_launch_fee_decay_period,
+            max_launch_fee_decay_slots,
+        );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+
+#[derive(Accounts)]
+pub struct SetPermissionedPoolAuthority<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(mut, has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    /// CHECK: safe, the account that will be new authority can be arbitrary
+    pub new_permissioned_pool_authority: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetPermissionedPoolAuthority>) -> Result<()> {
+    ctx.accounts
+        .config_extension
+        .update_permissioned_pool_authority(ctx.accounts.new_permissioned_pool_authority.key());
+    Ok(())
+}
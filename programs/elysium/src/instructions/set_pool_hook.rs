@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ElysiumPool, ElysiumPoolsConfig, ElysiumPoolsConfigExtension};
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+pub struct SetPoolHook<'info> {
+    pub pools_config: Account<'info, ElysiumPoolsConfig>,
+
+    #[account(has_one = pools_config)]
+    pub config_extension: Account<'info, ElysiumPoolsConfigExtension>,
+
+    #[account(mut, has_one = pools_config)]
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPoolHook>, hook_program: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // Pools can only start invoking hook programs that LPs were warned about by the timelock
+    ctx.accounts
+        .config_extension
+        .verify_hook_program(hook_program, timestamp)?;
+    ctx.accounts.pool.update_hook_program(hook_program);
+    Ok(())
+}
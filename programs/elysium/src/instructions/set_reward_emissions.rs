@@ -2,12 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
 use crate::errors::ErrorCode;
-use crate::manager::pool_manager::next_pool_reward_infos;
+use crate::manager::pool_manager::next_pool_reward_infos_with_vault;
 use crate::math::checked_mul_shift_right;
 use crate::state::ElysiumPool;
 use crate::util::to_timestamp_u64;
 
-const DAY_IN_SECONDS: u128 = 60 * 60 * 24;
+pub const DAY_IN_SECONDS: u128 = 60 * 60 * 24;
 
 #[derive(Accounts)]
 #[instruction(reward_index: u8)]
@@ -30,15 +30,23 @@ pub fn handler(
     let pool = &ctx.accounts.pool;
     let reward_vault = &ctx.accounts.reward_vault;
 
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_pool_reward_infos_with_vault(
+        pool,
+        reward_index as usize,
+        reward_vault.amount,
+        timestamp,
+    )?;
+
+    // Only funds that have not been emitted yet can back the new emissions.
+    let reward_info = &next_reward_infos[reward_index as usize];
+
     let emissions_per_day = checked_mul_shift_right(DAY_IN_SECONDS, emissions_per_second_x64)?;
-    if reward_vault.amount < emissions_per_day {
+    if reward_info.unemitted_amount() < emissions_per_day {
         return Err(ErrorCode::RewardVaultAmountInsufficient.into());
     }
 
-    let clock = Clock::get()?;
-    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
-    let next_reward_infos = next_pool_reward_infos(pool, timestamp)?;
-
     Ok(ctx.accounts.pool.update_emissions(
         reward_index as usize,
         next_reward_infos,
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::manager::pool_manager::next_pool_reward_infos_with_vault;
+use crate::state::ElysiumPool;
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct SetRewardEmissionsSchedule<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler(
+    ctx: Context<SetRewardEmissionsSchedule>,
+    reward_index: u8,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    budget: u64,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let reward_vault = &ctx.accounts.reward_vault;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_pool_reward_infos_with_vault(
+        pool,
+        reward_index as usize,
+        reward_vault.amount,
+        timestamp,
+    )?;
+
+    // Only funds that have not been emitted yet can back the campaign.
+    let reward_info = &next_reward_infos[reward_index as usize];
+
+    if reward_info.unemitted_amount() < budget {
+        return Err(ErrorCode::RewardVaultAmountInsufficient.into());
+    }
+
+    ctx.accounts.pool.update_emissions_schedule(
+        reward_index as usize,
+        next_reward_infos,
+        timestamp,
+        start_timestamp,
+        end_timestamp,
+        budget,
+    )
+}
@@ -0,0 +1,319 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    manager::liquidity_manager::calculate_fee_and_reward_growths,
+    state::*,
+    util::{position_or_lock_owner, to_timestamp_u64, transfer_from_vault_to_owner},
+};
+
+use super::calculate_collect_reward;
+
+#[derive(Accounts)]
+pub struct SettleReward<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_settlement: Box<Account<'info, RewardSettlement>>,
+
+    #[account(mut, has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(has_one = pool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(has_one = pool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(mut, constraint = reward_owner_account.mint == reward_settlement.mint)]
+    pub reward_owner_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = reward_settlement.vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refreshes the position's rewards and transfers everything it is owed for a retired reward to
+/// the owner of the position token, or to the beneficiary of the lock if the position is locked.
+///
+/// Settlement is permissionless so that the reward authority can settle every position before
+/// the reward slot is reinitialized. If the reward vault does not have enough tokens, the
+/// maximum number of available tokens will be transferred and the remainder stays owed.
+///
+/// # Returns
+/// - `Ok`: The owed reward tokens have been transferred to the position owner
+/// - `Err`: `InvalidRewardSettlement` if the reward slot has been reinitialized since retirement,
+///          or emissions were turned back on
+/// - `Err`: `MissingOrInvalidDelegate` if the position is locked and its lock is not passed in the
+///          remaining accounts
+/// - `Err`: `InvalidRewardAccounts` if the reward owner account is not owned by the position owner
+pub fn handler(ctx: Context<SettleReward>) -> Result<()> {
+    // Anyone can settle, so the rewards of a locked position must not be paid out to an account
+    // of its lock, which nothing could transfer them out of.
+    let owner = position_or_lock_owner(
+        &ctx.accounts.position_token_account,
+        ctx.accounts.position.key(),
+        ctx.remaining_accounts,
+    )?;
+    if ctx.accounts.reward_owner_account.owner != owner {
+        return Err(ErrorCode::InvalidRewardAccounts.into());
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    let position = &mut ctx.accounts.position;
+    let reward_settlement = &mut ctx.accounts.reward_settlement;
+    let index = reward_settlement.reward_index as usize;
+
+    // The slot can only be reinitialized once every position has settled or the settlement period
+    // has elapsed, so positions have that long to settle rewards of the retired mint. The growth must not have moved since retirement, so
+    // that positions are only credited rewards that the retired vault backs.
+    let reward_info = &pool.reward_infos[index];
+    if reward_info.vault != reward_settlement.vault
+        || reward_info.growth_global_x64 != reward_settlement.growth_global_x64
+    {
+        return Err(ErrorCode::InvalidRewardSettlement.into());
+    }
+
+    // Positions without liquidity cannot accrue anything, so there is nothing to refresh.
+    if position.liquidity > 0 {
+        let clock = Clock::get()?;
+        let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+        let (position_update, reward_infos) = calculate_fee_and_reward_growths(
+            pool,
+            position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            timestamp,
+        )?;
+
+        pool.update_rewards(reward_infos, timestamp);
+        position.update(&position_update);
+    }
+
+    let (transfer_amount, updated_amount_owed) = calculate_collect_reward(
+        position.reward_infos[index],
+        ctx.accounts.reward_vault.amount,
+    );
+
+    position.update_reward_owed(index, updated_amount_owed);
+    pool.record_reward_collected(index, ctx.accounts.reward_vault.amount, transfer_amount)?;
+    reward_settlement.record_settled(transfer_amount);
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_owner_account,
+        &ctx.accounts.token_program,
+        transfer_amount,
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::BTreeMap;
+
+    use solana_program::program_pack::Pack;
+
+    use super::*;
+    use crate::util::test_utils::*;
+
+    const GROWTH_GLOBAL_X64: u128 = 5 << 64;
+
+    struct Fixture {
+        pool: AccountInfo<'static>,
+        reward_settlement: AccountInfo<'static>,
+        position: AccountInfo<'static>,
+        position_token_account: AccountInfo<'static>,
+        tick_array: AccountInfo<'static>,
+        reward_mint: Pubkey,
+        reward_vault: AccountInfo<'static>,
+        token_program: AccountInfo<'static>,
+    }
+
+    fn token_account(
+        key: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> AccountInfo<'static> {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        TestAccount::new(key, token::ID, 1, data).into_account_info()
+    }
+
+    // Builds a position owed rewards of a retired reward, locked if it has no owner.
+    fn fixture(position_owner: Option<Pubkey>) -> Fixture {
+        let pool_key = Pubkey::new_unique();
+        let reward_mint = Pubkey::new_unique();
+        let reward_vault = Pubkey::new_unique();
+        let mut pool = ElysiumPool::default();
+        pool.reward_infos[0].vault = reward_vault;
+        pool.reward_infos[0].growth_global_x64 = GROWTH_GLOBAL_X64;
+
+        let position_key = Pubkey::new_unique();
+        let position_mint = Pubkey::new_unique();
+        let mut position = Position {
+            pool: pool_key,
+            position_mint,
+            ..Default::default()
+        };
+        position.reward_infos[0].amount_owed = 100;
+
+        let tick_array =
+            TestAccount::new(Pubkey::new_unique(), crate::ID, 1, vec![0; TickArray::LEN])
+                .into_account_info();
+        let loader =
+            AccountLoader::<TickArray>::try_from_unchecked(&crate::ID, &tick_array).unwrap();
+        loader.load_init().unwrap().pool = pool_key;
+        loader.exit(&crate::ID).unwrap();
+
+        let position_owner =
+            position_owner.unwrap_or_else(|| PositionLock::find_address(&position_key));
+
+        Fixture {
+            pool: TestAccount::with_account(pool_key, &pool).into_account_info(),
+            reward_settlement: TestAccount::with_account(
+                Pubkey::new_unique(),
+                &RewardSettlement {
+                    pool: pool_key,
+                    reward_index: 0,
+                    mint: reward_mint,
+                    vault: reward_vault,
+                    growth_global_x64: GROWTH_GLOBAL_X64,
+                    retired_timestamp: 0,
+                    total_settled: 0,
+                },
+            )
+            .into_account_info(),
+            position: TestAccount::with_account(position_key, &position).into_account_info(),
+            position_token_account: token_account(
+                Pubkey::new_unique(),
+                position_mint,
+                position_owner,
+                1,
+            ),
+            tick_array,
+            reward_mint,
+            reward_vault: token_account(reward_vault, reward_mint, pool_key, 1_000),
+            token_program: TestAccount::program(token::ID).into_account_info(),
+        }
+    }
+
+    fn settle(
+        fixture: &Fixture,
+        reward_owner_account: &AccountInfo<'static>,
+        remaining_accounts: &[AccountInfo<'static>],
+    ) -> Result<()> {
+        let mut accounts = SettleReward {
+            pool: Box::new(Account::try_from(&fixture.pool).unwrap()),
+            reward_settlement: Box::new(Account::try_from(&fixture.reward_settlement).unwrap()),
+            position: Box::new(Account::try_from(&fixture.position).unwrap()),
+            position_token_account: Box::new(
+                Account::try_from(&fixture.position_token_account).unwrap(),
+            ),
+            tick_array_lower: AccountLoader::try_from(&fixture.tick_array).unwrap(),
+            tick_array_upper: AccountLoader::try_from(&fixture.tick_array).unwrap(),
+            reward_owner_account: Box::new(Account::try_from(reward_owner_account).unwrap()),
+            reward_vault: Box::new(Account::try_from(&fixture.reward_vault).unwrap()),
+            token_program: Program::try_from(&fixture.token_program).unwrap(),
+        };
+        handler(Context::new(
+            &crate::ID,
+            &mut accounts,
+            remaining_accounts,
+            BTreeMap::new(),
+        ))?;
+        accounts.exit(&crate::ID)
+    }
+
+    fn lock_account(position: Pubkey, beneficiary: Pubkey) -> AccountInfo<'static> {
+        TestAccount::with_account(
+            PositionLock::find_address(&position),
+            &PositionLock {
+                position,
+                beneficiary,
+                unlock_timestamp: 200,
+                bump: [255],
+            },
+        )
+        .into_account_info()
+    }
+
+    #[test]
+    fn test_settle_reward_pays_position_owner() {
+        reset_test_runtime(Clock::default());
+        let owner = Pubkey::new_unique();
+        let fixture = fixture(Some(owner));
+        let owner_account = token_account(Pubkey::new_unique(), fixture.reward_mint, owner, 0);
+
+        settle(&fixture, &owner_account, &[]).unwrap();
+
+        let transfers = invoked_instructions();
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].accounts[1].pubkey, owner_account.key());
+
+        let other_account = token_account(
+            Pubkey::new_unique(),
+            fixture.reward_mint,
+            Pubkey::new_unique(),
+            0,
+        );
+        assert_eq!(
+            settle(&fixture, &other_account, &[]).unwrap_err(),
+            ErrorCode::InvalidRewardAccounts.into()
+        );
+    }
+
+    #[test]
+    fn test_settle_reward_of_locked_position_pays_beneficiary() {
+        reset_test_runtime(Clock::default());
+        let fixture = fixture(None);
+        let beneficiary = Pubkey::new_unique();
+        let lock = lock_account(fixture.position.key(), beneficiary);
+        let beneficiary_account =
+            token_account(Pubkey::new_unique(), fixture.reward_mint, beneficiary, 0);
+
+        settle(&fixture, &beneficiary_account, &[lock]).unwrap();
+
+        let transfers = invoked_instructions();
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].accounts[1].pubkey, beneficiary_account.key());
+        let position = Account::<Position>::try_from(&fixture.position).unwrap();
+        assert_eq!(position.reward_infos[0].amount_owed, 0);
+    }
+
+    #[test]
+    fn test_settle_reward_of_locked_position_rejects_lock_account() {
+        reset_test_runtime(Clock::default());
+        let fixture = fixture(None);
+        let lock = lock_account(fixture.position.key(), Pubkey::new_unique());
+        let lock_owned_account =
+            token_account(Pubkey::new_unique(), fixture.reward_mint, lock.key(), 0);
+
+        assert_eq!(
+            settle(&fixture, &lock_owned_account, &[lock]).unwrap_err(),
+            ErrorCode::InvalidRewardAccounts.into()
+        );
+        assert_eq!(
+            settle(&fixture, &lock_owned_account, &[]).unwrap_err(),
+            ErrorCode::MissingOrInvalidDelegate.into()
+        );
+        assert!(invoked_instructions().is_empty());
+    }
+}
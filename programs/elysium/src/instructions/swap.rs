@@ -5,7 +5,11 @@ use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
     state::{ElysiumPool, TickArray},
-    util::{to_timestamp_u64, update_and_swap_pool, SwapTickSequence},
+    util::{
+        invoke_pool_hook, load_initialized_tick_bitmap, load_swap_reward_extension,
+        swap_reward_extension, to_timestamp_u64, update_and_swap_pool, verify_allowlisted,
+        verify_deadline, verify_pool_activated, Deadline, PoolHookEvent, SwapTickSequence,
+    },
 };
 
 #[derive(Accounts)]
@@ -40,24 +44,59 @@ pub struct Swap<'info> {
     #[account(seeds = [b"oracle", pool.key().as_ref()],bump)]
     /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
     pub oracle: UncheckedAccount<'info>,
+    // remaining accounts
+    // - required if the pool has a reward extension and the swap crosses a tick:
+    //   - reward_extension (mut)
+    //   - the account at the tick array extension address (mut) of every tick array, even if
+    //     the extension has not been initialized
+    // - required if the pool is permissioned, anywhere before the hook program:
+    //   - allowlist_entry of the token_authority
+    // - required if the pool has a hook program, after all other remaining accounts:
+    //   - hook_program
+    //   - accounts forwarded to the hook program
 }
 
-pub fn handler(
-    ctx: Context<Swap>,
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
     amount: u64,
     other_amount_threshold: u64,
     sqrt_price_limit: u128,
     amount_specified_is_input: bool,
     a_to_b: bool, // Zero for one
+    deadline: Option<Deadline>,
 ) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
+    verify_deadline(deadline, &clock)?;
+    verify_pool_activated(pool, &clock)?;
+    verify_allowlisted(
+        pool,
+        ctx.accounts.token_authority.key(),
+        ctx.remaining_accounts,
+    )?;
+    invoke_pool_hook(
+        pool,
+        &ctx.accounts.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        PoolHookEvent::BeforeSwap {
+            amount,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+        },
+    )?;
     // Update the global reward growth which increases as a function of time.
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
-    let mut swap_tick_sequence = SwapTickSequence::new(
+    let initialized_tick_bitmaps = [
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_0)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_1)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_2)?,
+    ];
+    let mut swap_tick_sequence = SwapTickSequence::new_with_initialized_tick_bitmaps(
         ctx.accounts.tick_array_0.load_mut().unwrap(),
         ctx.accounts.tick_array_1.load_mut().ok(),
         ctx.accounts.tick_array_2.load_mut().ok(),
+        initialized_tick_bitmaps,
     );
 
     let swap_update = swap(
@@ -68,6 +107,7 @@ pub fn handler(
         amount_specified_is_input,
         a_to_b,
         timestamp,
+        clock.slot,
     )?;
 
     if amount_specified_is_input {
@@ -84,6 +124,28 @@ pub fn handler(
         }
     }
 
+    if let Some(mut reward_extension) = load_swap_reward_extension(
+        pool,
+        &[
+            ctx.accounts.tick_array_0.key(),
+            ctx.accounts.tick_array_1.key(),
+            ctx.accounts.tick_array_2.key(),
+        ],
+        &swap_update.crossed_tick_indexes,
+        ctx.remaining_accounts,
+    )? {
+        swap_reward_extension(
+            &mut reward_extension,
+            pool,
+            &swap_update.crossed_tick_indexes,
+            timestamp,
+        )?;
+    }
+
+    // Tick arrays are not forwarded to the hook, release them before it is invoked.
+    drop(swap_tick_sequence);
+    let (amount_a, amount_b) = (swap_update.amount_a, swap_update.amount_b);
+
     update_and_swap_pool(
         pool,
         &ctx.accounts.token_authority,
@@ -95,5 +157,16 @@ pub fn handler(
         swap_update,
         a_to_b,
         timestamp,
+    )?;
+
+    invoke_pool_hook(
+        pool,
+        &ctx.accounts.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        PoolHookEvent::AfterSwap {
+            amount_a,
+            amount_b,
+            a_to_b,
+        },
     )
 }
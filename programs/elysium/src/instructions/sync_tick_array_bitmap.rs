@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::record_tick_array_in_bitmap;
+
+#[derive(Accounts)]
+pub struct SyncTickArrayBitmap<'info> {
+    pub pool: Account<'info, ElysiumPool>,
+
+    #[account(mut, address = pool.tick_array_bitmap)]
+    pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmap>,
+    // remaining accounts
+    // - tick arrays of the pool to record in the bitmap
+}
+
+/*
+   Records tick arrays of the pool in its bitmap. Used to record the tick arrays that were
+   initialized before the bitmap.
+*/
+pub fn handler(ctx: Context<SyncTickArrayBitmap>) -> Result<()> {
+    for account_info in ctx.remaining_accounts {
+        let tick_array = AccountLoader::<TickArray>::try_from(account_info)?;
+        if tick_array.load()?.pool != ctx.accounts.pool.key() {
+            return Err(ErrorCode::InvalidTickArrayAccounts.into());
+        }
+        record_tick_array_in_bitmap(
+            &ctx.accounts.tick_array_bitmap,
+            &tick_array,
+            ctx.accounts.pool.tick_spacing,
+        )?;
+    }
+
+    Ok(())
+}
@@ -5,7 +5,11 @@ use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
     state::{ElysiumPool, TickArray},
-    util::{to_timestamp_u64, update_and_swap_pool, SwapTickSequence},
+    util::{
+        load_initialized_tick_bitmap, load_swap_reward_extension, swap_reward_extension,
+        to_timestamp_u64, update_and_swap_pool, verify_allowlisted, verify_deadline,
+        verify_pool_activated, Deadline, SwapTickSequence,
+    },
 };
 
 #[derive(Accounts)]
@@ -66,6 +70,13 @@ pub struct TwoHopSwap<'info> {
     #[account(seeds = [b"oracle", pool_two.key().as_ref()],bump)]
     /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
     pub oracle_two: UncheckedAccount<'info>,
+    // remaining accounts
+    // - for each pool that has a reward extension and whose swap crosses a tick:
+    //   - reward_extension (mut)
+    //   - the account at the tick array extension address (mut) of every tick array, even if
+    //     the extension has not been initialized
+    // - for each pool that is permissioned:
+    //   - allowlist_entry of the token_authority
 }
 
 pub fn handler(
@@ -77,8 +88,10 @@ pub fn handler(
     a_to_b_two: bool,
     sqrt_price_limit_one: u128,
     sqrt_price_limit_two: u128,
+    deadline: Option<Deadline>,
 ) -> Result<()> {
     let clock = Clock::get()?;
+    verify_deadline(deadline, &clock)?;
     // Update the global reward growth which increases as a function of time.
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
@@ -90,6 +103,18 @@ pub fn handler(
         return Err(ErrorCode::DuplicateTwoHopPool.into());
     }
 
+    // Hook accounts of two pools cannot be told apart in the remaining accounts
+    if pool_one.has_hook_program() || pool_two.has_hook_program() {
+        return Err(ErrorCode::PoolHookNotSupported.into());
+    }
+
+    verify_pool_activated(pool_one, &clock)?;
+    verify_pool_activated(pool_two, &clock)?;
+
+    let token_authority = ctx.accounts.token_authority.key();
+    verify_allowlisted(pool_one, token_authority, ctx.remaining_accounts)?;
+    verify_allowlisted(pool_two, token_authority, ctx.remaining_accounts)?;
+
     let swap_one_output_mint = if a_to_b_one {
         pool_one.token_mint_b
     } else {
@@ -105,16 +130,28 @@ pub fn handler(
         return Err(ErrorCode::InvalidIntermediaryMint.into());
     }
 
-    let mut swap_tick_sequence_one = SwapTickSequence::new(
+    let initialized_tick_bitmaps_one = [
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_one_0)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_one_1)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_one_2)?,
+    ];
+    let mut swap_tick_sequence_one = SwapTickSequence::new_with_initialized_tick_bitmaps(
         ctx.accounts.tick_array_one_0.load_mut().unwrap(),
         ctx.accounts.tick_array_one_1.load_mut().ok(),
         ctx.accounts.tick_array_one_2.load_mut().ok(),
+        initialized_tick_bitmaps_one,
     );
 
-    let mut swap_tick_sequence_two = SwapTickSequence::new(
+    let initialized_tick_bitmaps_two = [
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_two_0)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_two_1)?,
+        load_initialized_tick_bitmap(&ctx.accounts.tick_array_two_2)?,
+    ];
+    let mut swap_tick_sequence_two = SwapTickSequence::new_with_initialized_tick_bitmaps(
         ctx.accounts.tick_array_two_0.load_mut().unwrap(),
         ctx.accounts.tick_array_two_1.load_mut().ok(),
         ctx.accounts.tick_array_two_2.load_mut().ok(),
+        initialized_tick_bitmaps_two,
     );
 
     // TODO: WLOG, we could extend this to N-swaps, but the account inputs to the instruction would
@@ -131,6 +168,7 @@ pub fn handler(
             amount_specified_is_input, // true
             a_to_b_one,
             timestamp,
+            clock.slot,
         )?;
 
         // Swap two input is the output of swap one
@@ -148,6 +186,7 @@ pub fn handler(
             amount_specified_is_input, // true
             a_to_b_two,
             timestamp,
+            clock.slot,
         )?;
         (swap_calc_one, swap_calc_two)
     } else {
@@ -162,6 +201,7 @@ pub fn handler(
             amount_specified_is_input, // false
             a_to_b_two,
             timestamp,
+            clock.slot,
         )?;
 
         // The output of swap 1 is input of swap_calc_two
@@ -179,6 +219,7 @@ pub fn handler(
             amount_specified_is_input, // false
             a_to_b_one,
             timestamp,
+            clock.slot,
         )?;
         (swap_calc_one, swap_calc_two)
     };
@@ -209,6 +250,42 @@ pub fn handler(
         }
     }
 
+    if let Some(mut reward_extension) = load_swap_reward_extension(
+        pool_one,
+        &[
+            ctx.accounts.tick_array_one_0.key(),
+            ctx.accounts.tick_array_one_1.key(),
+            ctx.accounts.tick_array_one_2.key(),
+        ],
+        &swap_update_one.crossed_tick_indexes,
+        ctx.remaining_accounts,
+    )? {
+        swap_reward_extension(
+            &mut reward_extension,
+            pool_one,
+            &swap_update_one.crossed_tick_indexes,
+            timestamp,
+        )?;
+    }
+
+    if let Some(mut reward_extension) = load_swap_reward_extension(
+        pool_two,
+        &[
+            ctx.accounts.tick_array_two_0.key(),
+            ctx.accounts.tick_array_two_1.key(),
+            ctx.accounts.tick_array_two_2.key(),
+        ],
+        &swap_update_two.crossed_tick_indexes,
+        ctx.remaining_accounts,
+    )? {
+        swap_reward_extension(
+            &mut reward_extension,
+            pool_two,
+            &swap_update_two.crossed_tick_indexes,
+            timestamp,
+        )?;
+    }
+
     update_and_swap_pool(
         pool_one,
         &ctx.accounts.token_authority,
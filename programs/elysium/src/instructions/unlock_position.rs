@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::{
+    state::*,
+    util::{to_timestamp_u64, transfer_position_token_from_lock_and_close},
+};
+
+#[derive(Accounts)]
+pub struct UnlockPosition<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(mut,
+        close = beneficiary,
+        has_one = position,
+        has_one = beneficiary,
+        seeds = [b"position_lock".as_ref(), position.key().as_ref()],
+        bump = position_lock.bump[0],
+    )]
+    pub position_lock: Account<'info, PositionLock>,
+
+    #[account(mut,
+        associated_token::mint = position.position_mint,
+        associated_token::authority = position_lock,
+    )]
+    pub lock_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = position_token_account.mint == position.position_mint)]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<UnlockPosition>) -> Result<()> {
+    let clock = Clock::get()?;
+    if ctx
+        .accounts
+        .position_lock
+        .is_locked(to_timestamp_u64(clock.unix_timestamp)?)
+    {
+        return Err(ErrorCode::PositionLocked.into());
+    }
+
+    transfer_position_token_from_lock_and_close(
+        &ctx.accounts.position_lock,
+        &ctx.accounts.lock_token_account,
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.beneficiary,
+        &ctx.accounts.token_program,
+    )
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::manager::pool_manager::next_pool_reward_infos_with_vault;
+use crate::state::ElysiumPool;
+use crate::util::{to_timestamp_u64, transfer_from_vault_to_owner};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct WithdrawUnallocatedReward<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, ElysiumPool>>,
+
+    #[account(address = pool.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(mut, address = pool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut,
+        constraint = token_destination.mint == pool.reward_infos[reward_index as usize].mint
+    )]
+    pub token_destination: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawUnallocatedReward>,
+    reward_index: u8,
+    amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_pool_reward_infos_with_vault(
+        pool,
+        reward_index as usize,
+        ctx.accounts.reward_vault.amount,
+        timestamp,
+    )?;
+
+    pool.withdraw_unallocated_reward(
+        reward_index as usize,
+        next_reward_infos,
+        timestamp,
+        ctx.accounts.reward_vault.amount,
+        amount,
+    )?;
+
+    transfer_from_vault_to_owner(
+        pool,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_destination,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
@@ -19,7 +19,11 @@ pub mod tests;
 #[doc(hidden)]
 pub mod util;
 
-use crate::state::{ElysiumPoolBumps, OpenPositionBumps, OpenPositionWithMetadataBumps};
+use crate::manager::pool_manager::RewardRunway;
+use crate::state::{
+    ElysiumPoolBumps, LaunchFeeSchedule, OpenPositionBumps, OpenPositionWithMetadataBumps,
+};
+use crate::util::Deadline;
 use instructions::*;
 
 #[program]
@@ -50,11 +54,14 @@ pub mod elysium {
     }
 
     /// Initializes a ElysiumPool account.
-    /// Fee rate is set to the default values on the config and supplied fee_tier.
+    /// Fee rate and tick spacing are set to the default values on the config and supplied fee_tier.
+    /// The pool is activated on creation, without launch fee schedule, creator fee or access
+    /// authority. Use `initialize_pool_v2` to set them.
     ///
     /// ### Parameters
     /// - `bumps` - The bump value when deriving the PDA of the ElysiumPool address.
-    /// - `tick_spacing` - The desired tick spacing for this pool.
+    /// - `fee_tier_index` - The index of the fee tier for this pool. Fee tiers initialized with
+    ///                      `initialize_fee_tier` are indexed by their tick spacing.
     /// - `initial_sqrt_price` - The desired initial sqrt-price for this pool
     ///
     /// #### Special Errors
@@ -64,14 +71,77 @@ pub mod elysium {
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
         bumps: ElysiumPoolBumps,
-        tick_spacing: u16,
+        fee_tier_index: u16,
         initial_sqrt_price: u128,
     ) -> Result<()> {
         return instructions::initialize_pool::handler(
             ctx,
             bumps,
-            tick_spacing,
+            fee_tier_index,
+            initial_sqrt_price,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    /// Initializes a ElysiumPool account with launch settings.
+    /// Takes the accounts and parameters of `initialize_pool`, followed by the parameters below.
+    ///
+    /// ### Parameters
+    /// - `activation_timestamp` - Optional unix timestamp before which swaps are rejected. At most
+    ///                            the config extension's max_activation_delay after creation.
+    /// - `activation_slot` - Optional slot before which swaps are rejected. At most the config
+    ///                       extension's max_activation_slot_delay after creation.
+    /// - `launch_fee_schedule` - Optional fee schedule that decays from an initial fee rate to the
+    ///                           pool's fee rate after activation. The excess fees go to LPs.
+    /// - `creator_fee_rate` - Optional portion of the fee, in basis points, owed to the funder as
+    ///                        the pool creator.
+    /// - `access_authority` - Optional authority managing the allowlist of the pool. If provided,
+    ///                        only allowlisted wallets can trade and provide liquidity, and the
+    ///                        config extension's permissioned_pool_authority has to sign.
+    ///
+    /// #### Special Errors
+    /// `LaunchFeeRateMaxExceeded` - The initial launch fee rate exceeds MAX_LAUNCH_FEE_RATE.
+    /// `InvalidLaunchFeeSchedule` - The initial launch fee rate is not above the pool's fee rate
+    ///                              or the decay period is zero.
+    /// `LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds the config extension's max
+    ///                                     launch fee decay period in the unit of the schedule.
+    /// `CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.
+    /// `ActivationDelayMaxExceeded` - The activation is delayed past the max activation delay.
+    /// `InvalidPermissionedPoolAuthority` - An access authority is provided without the signature
+    ///                                      of the config extension's permissioned_pool_authority.
+    /// The special errors of `initialize_pool`.
+    ///
+    /// ### Remaining Accounts
+    /// - Optionally the ElysiumPoolsConfigExtension of the config. Without it the activation can
+    ///   not be delayed past creation, no launch fee schedule can be set and no permissioned pool
+    ///   can be created.
+    /// - If an access authority is provided: the permissioned_pool_authority as a signer.
+    ///
+    pub fn initialize_pool_v2(
+        ctx: Context<InitializePool>,
+        bumps: ElysiumPoolBumps,
+        fee_tier_index: u16,
+        initial_sqrt_price: u128,
+        activation_timestamp: Option<u64>,
+        activation_slot: Option<u64>,
+        launch_fee_schedule: Option<LaunchFeeSchedule>,
+        creator_fee_rate: Option<u16>,
+        access_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        return instructions::initialize_pool::handler(
+            ctx,
+            bumps,
+            fee_tier_index,
             initial_sqrt_price,
+            activation_timestamp,
+            activation_slot,
+            launch_fee_schedule,
+            creator_fee_rate,
+            access_authority,
         );
     }
 
@@ -81,9 +151,13 @@ pub mod elysium {
     /// - `start_tick_index` - The starting tick index for this tick-array.
     ///                        Has to be a multiple of TickArray size & the tick spacing of this pool.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    ///
     /// #### Special Errors
     /// - `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of
     ///                        TICK_ARRAY_SIZE * tick spacing.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
     pub fn initialize_tick_array(
         ctx: Context<InitializeTickArray>,
         start_tick_index: i32,
@@ -91,6 +165,134 @@ pub mod elysium {
         return instructions::initialize_tick_array::handler(ctx, start_tick_index);
     }
 
+    /// Initializes a tick_array account to represent a tick-range in a ElysiumPool, and records
+    /// the funder in a tick_array_funder account so that the funder can close the tick array
+    /// once it is empty. Takes the accounts of `initialize_tick_array` with the tick_array_funder
+    /// account after the tick_array.
+    ///
+    /// ### Parameters
+    /// - `start_tick_index` - The starting tick index for this tick-array.
+    ///                        Has to be a multiple of TickArray size & the tick spacing of this pool.
+    ///
+    /// ### Remaining Accounts
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    ///
+    /// #### Special Errors
+    /// - `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of
+    ///                        TICK_ARRAY_SIZE * tick spacing.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    pub fn initialize_tick_array_v2(
+        ctx: Context<InitializeTickArrayV2>,
+        start_tick_index: i32,
+    ) -> Result<()> {
+        return instructions::initialize_tick_array_v2::handler(ctx, start_tick_index);
+    }
+
+    /// Initializes a tick_array account for every provided start tick index, skipping tick
+    /// arrays that already exist. Allows a range of tick arrays to be prepared in one instruction
+    /// without checking which of them exist.
+    ///
+    /// ### Parameters
+    /// - `start_tick_indexes` - The starting tick index of every tick-array.
+    ///                          Each has to be a multiple of TickArray size & the tick spacing of this pool.
+    ///
+    /// ### Remaining Accounts
+    /// - For every start tick index, in order: the tick array followed by its tick_array_funder
+    ///   record.
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap, after the tick array accounts.
+    ///
+    /// #### Special Errors
+    /// - `InvalidStartTick` - if a provided start tick is out of bounds or is not a multiple of
+    ///                        TICK_ARRAY_SIZE * tick spacing.
+    /// - `InvalidTickArrayAccounts` - The remaining accounts are not the tick arrays and funder
+    ///                                records of the provided start tick indexes.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    pub fn initialize_tick_arrays<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, InitializeTickArrays<'info>>,
+        start_tick_indexes: Vec<i32>,
+    ) -> Result<()> {
+        return instructions::initialize_tick_arrays::handler(ctx, start_tick_indexes);
+    }
+
+    /// Closes an empty tick_array account, refunding its rent and the rent of its funder record
+    /// to the funder that initialized it. Only tick arrays initialized by `initialize_tick_array_v2`
+    /// or `initialize_tick_arrays` have a funder record and can be closed. A closed tick array can
+    /// be initialized again by anyone.
+    ///
+    /// ### Authority
+    /// - "funder" - The funder recorded when the tick_array was initialized.
+    ///
+    /// ### Remaining Accounts
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    ///
+    /// #### Special Errors
+    /// - `TickArrayNotEmpty` - The tick array has initialized ticks, so positions with liquidity
+    ///                         may reference it.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    pub fn close_tick_array(ctx: Context<CloseTickArray>) -> Result<()> {
+        return instructions::close_tick_array::handler(ctx);
+    }
+
+    /// Migrates a ElysiumPool account initialized with the original layout to the current layout.
+    /// The account is grown, with the funder paying the additional rent, and the fields added
+    /// since are zeroed: the pool is active, without launch fee, creator fee, hook or access
+    /// authority. Initialized rewards keep emitting until the balances of their vaults at migration
+    /// have been emitted, and can only be reinitialized once the settlement period after their
+    /// retirement has elapsed. Migrating a pool that already has the current layout does nothing.
+    ///
+    /// ### Authority
+    /// - "funder" - Any account that pays for the additional rent.
+    ///
+    /// ### Remaining Accounts
+    /// - The reward vaults of the initialized rewards of the pool.
+    ///
+    /// #### Special Errors
+    /// - `UnsupportedPoolLayout` - The pool account has neither the original nor the current layout.
+    /// - `InvalidRewardAccounts` - The vault of an initialized reward is missing.
+    pub fn migrate_pool<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, MigratePool<'info>>,
+    ) -> Result<()> {
+        return instructions::migrate_pool::handler(ctx);
+    }
+
+    /// Migrates a tick_array account initialized before tick arrays stored a bitmap of their
+    /// initialized ticks. The account is grown to hold the bitmap, with the funder paying the
+    /// additional rent, and the bitmap is rebuilt from the ticks. Swaps search the ticks of
+    /// migrated tick arrays with the bitmap, and the ticks of other tick arrays one by one.
+    ///
+    /// ### Authority
+    /// - "funder" - Any account that pays for the additional rent.
+    pub fn migrate_tick_array(ctx: Context<MigrateTickArray>) -> Result<()> {
+        return instructions::migrate_tick_array::handler(ctx);
+    }
+
+    /// Initialize the TickArrayBitmap of a ElysiumPool, which records the initialized and
+    /// non-empty TickArrays of the pool. Once initialized, clients must pass the bitmap to every
+    /// instruction that initializes or closes tick arrays or modifies liquidity, which fail with
+    /// `InvalidTickArrayBitmap` without it.
+    ///
+    /// The bitmap is an index for clients and is not read by swaps. Tick arrays initialized
+    /// before the bitmap are only recorded once passed to `sync_tick_array_bitmap`, so swaps keep
+    /// taking consecutive tick arrays rather than skipping arrays the bitmap reports as empty.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the ElysiumPoolConfig
+    pub fn initialize_tick_array_bitmap(ctx: Context<InitializeTickArrayBitmap>) -> Result<()> {
+        return instructions::initialize_tick_array_bitmap::handler(ctx);
+    }
+
+    /// Record the current state of tick arrays in the TickArrayBitmap of their ElysiumPool, such
+    /// as tick arrays initialized before the bitmap.
+    ///
+    /// ### Remaining Accounts
+    /// - The tick arrays to record.
+    ///
+    /// #### Special Errors
+    /// - `InvalidTickArrayAccounts` - A tick array does not belong to the pool.
+    pub fn sync_tick_array_bitmap(ctx: Context<SyncTickArrayBitmap>) -> Result<()> {
+        return instructions::sync_tick_array_bitmap::handler(ctx);
+    }
+
     /// Initializes a fee_tier account usable by ElysiumPools in a ElysiumPoolConfig space.
     ///
     /// ### Authority
@@ -111,6 +313,36 @@ pub mod elysium {
         return instructions::initialize_fee_tier::handler(ctx, tick_spacing, default_fee_rate);
     }
 
+    /// Initializes a fee_tier account keyed by a fee tier index rather than its tick spacing,
+    /// allowing multiple fee tiers with the same tick spacing in a ElysiumPoolConfig space.
+    /// The index must not be in use by another fee tier, including fee tiers keyed by tick spacing.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `fee_tier_index` - The index that pools using this fee tier are keyed by.
+    /// - `tick_spacing` - The tick-spacing of pools initialized with this fee-tier.
+    /// - `default_fee_rate` - The default fee rate that a pool will use if the pool uses this
+    ///                        fee tier during initialization.
+    ///
+    /// #### Special Errors
+    /// - `InvalidTickSpacing` - If the provided tick_spacing is 0.
+    /// - `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.
+    pub fn initialize_fee_tier_with_index(
+        ctx: Context<InitializeFeeTierWithIndex>,
+        fee_tier_index: u16,
+        tick_spacing: u16,
+        default_fee_rate: u16,
+    ) -> Result<()> {
+        return instructions::initialize_fee_tier_with_index::handler(
+            ctx,
+            fee_tier_index,
+            tick_spacing,
+            default_fee_rate,
+        );
+    }
+
     /// Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.
     ///
     /// ### Authority
@@ -139,8 +371,8 @@ pub mod elysium {
     /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
     ///
     /// #### Special Errors
-    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
-    ///                                     more than a day of desired emissions.
+    /// - `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot
+    ///                                     cover a day of desired emissions.
     /// - `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.
     /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
     ///                          index in this pool, or exceeds NUM_REWARDS, or
@@ -157,6 +389,247 @@ pub mod elysium {
         );
     }
 
+    /// Schedule a reward emissions campaign for a reward in a ElysiumPool. The budget is emitted
+    /// at a constant rate between the start and end timestamps, and no rewards accrue outside of
+    /// that window. Calling `set_reward_emissions` afterwards clears the schedule.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this ElysiumPool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.
+    /// - `start_timestamp` - The timestamp from which rewards start accruing.
+    /// - `end_timestamp` - The timestamp after which rewards stop accruing.
+    /// - `budget` - The total amount of rewards emitted over the campaign.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet are
+    ///                                     less than the budget of the campaign.
+    /// - `InvalidRewardSchedule` - The start timestamp is in the past or the end timestamp is not
+    ///                             after the start timestamp.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn set_reward_emissions_schedule(
+        ctx: Context<SetRewardEmissionsSchedule>,
+        reward_index: u8,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        budget: u64,
+    ) -> Result<()> {
+        return instructions::set_reward_emissions_schedule::handler(
+            ctx,
+            reward_index,
+            start_timestamp,
+            end_timestamp,
+            budget,
+        );
+    }
+
+    /// Get the funded rewards that have not been emitted yet for a reward in a ElysiumPool, and
+    /// how many seconds of emissions at the current rate they cover. Once the funded amount has
+    /// been emitted, emissions are deferred until the reward vault is topped up. The result is
+    /// written to the return data.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to query.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn get_reward_runway(
+        ctx: Context<GetRewardRunway>,
+        reward_index: u8,
+    ) -> Result<RewardRunway> {
+        return instructions::get_reward_runway::handler(ctx, reward_index);
+    }
+
+    /// Deposit tokens into the reward vault of a reward in a ElysiumPool. Anyone can fund a
+    /// reward. Rewards are updated before the deposit, so it only backs future emissions.
+    ///
+    /// ### Authority
+    /// - "funder" - The authority to withdraw tokens from the funder token account.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to fund.
+    /// - `amount` - The amount of reward tokens to deposit.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn fund_reward(ctx: Context<FundReward>, reward_index: u8, amount: u64) -> Result<()> {
+        return instructions::fund_reward::handler(ctx, reward_index, amount);
+    }
+
+    /// Withdraw reward tokens that have not been emitted to positions and are not committed to
+    /// the remainder of a scheduled campaign.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this ElysiumPool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.
+    /// - `amount` - The amount of reward tokens to withdraw.
+    ///
+    /// #### Special Errors
+    /// - `UnallocatedRewardInsufficient` - The amount exceeds the unallocated reward amount.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn withdraw_unallocated_reward(
+        ctx: Context<WithdrawUnallocatedReward>,
+        reward_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        return instructions::withdraw_unallocated_reward::handler(ctx, reward_index, amount);
+    }
+
+    /// Retire a reward in a ElysiumPool whose emissions have ended. Emissions are turned off and
+    /// a RewardSettlement records the retired reward, so positions can settle what they are owed
+    /// before the reward slot is reinitialized.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this ElysiumPool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to retire.
+    ///
+    /// #### Special Errors
+    /// - `RewardEmissionsNotEnded` - The reward is not initialized or still emits rewards.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn retire_reward(ctx: Context<RetireReward>, reward_index: u8) -> Result<()> {
+        return instructions::retire_reward::handler(ctx, reward_index);
+    }
+
+    /// Update the accrued rewards of a position and transfer everything it is owed for a retired
+    /// reward to the owner of the position token, or to the beneficiary of its PositionLock if the
+    /// position is locked. Anyone can settle a position.
+    ///
+    /// ### Remaining Accounts
+    /// - If the position is locked: the PositionLock of the position.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardSettlement` - The reward slot has been reinitialized since the settlement,
+    ///                               or emissions were turned back on.
+    /// - `MissingOrInvalidDelegate` - The position is locked and its PositionLock is not passed.
+    /// - `InvalidRewardAccounts` - The reward owner account is not owned by the position owner, or
+    ///                             the lock beneficiary if the position is locked.
+    /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
+    pub fn settle_reward(ctx: Context<SettleReward>) -> Result<()> {
+        return instructions::settle_reward::handler(ctx);
+    }
+
+    /// Reinitialize a retired reward in a ElysiumPool with a new mint and vault, once every
+    /// position has been settled or the settlement period of the retired reward has elapsed.
+    /// Positions that have not settled the retired reward by then are credited what they are
+    /// still owed for it in the new mint.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this ElysiumPool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to reinitialize.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardSettlement` - The settlement does not belong to the reward in the slot.
+    /// - `RewardEmissionsNotEnded` - Emissions were turned back on after the reward was retired.
+    /// - `RewardNotSettled` - Positions have not credited or collected everything they are owed
+    ///                        and the settlement period has not elapsed yet.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn reinitialize_reward(ctx: Context<ReinitializeReward>, reward_index: u8) -> Result<()> {
+        return instructions::reinitialize_reward::handler(ctx, reward_index);
+    }
+
+    /// Initialize the PoolRewardExtension of a ElysiumPool, adding NUM_EXTENSION_REWARDS reward
+    /// slots on top of the rewards stored on the pool. Once initialized, every liquidity change
+    /// and every swap that crosses a tick must pass the reward extension as a remaining account.
+    /// Tick array and position extensions are optional and can be created at any time.
+    ///
+    /// ### Authority
+    /// - "reward_emissions_super_authority" - Set authority in the ElysiumPoolConfig. Becomes the
+    ///                                        reward authority of every extension reward slot.
+    pub fn initialize_reward_extension(ctx: Context<InitializeRewardExtension>) -> Result<()> {
+        return instructions::initialize_reward_extension::handler(ctx);
+    }
+
+    /// Initialize the TickArrayRewardExtension of a TickArray. Required before a position with
+    /// a tick in the tick array can earn extension rewards. Ticks crossed before the extension
+    /// exists are not tracked, which no position extension depends on.
+    pub fn initialize_tick_array_reward_extension(
+        ctx: Context<InitializeTickArrayRewardExtension>,
+    ) -> Result<()> {
+        return instructions::initialize_tick_array_reward_extension::handler(ctx);
+    }
+
+    /// Initialize the PositionRewardExtension of a Position, which tracks the extension rewards
+    /// owed to the position. The position earns extension rewards from this point on, so the
+    /// extensions of its lower and upper tick arrays must already exist.
+    pub fn initialize_position_reward_extension(
+        ctx: Context<InitializePositionRewardExtension>,
+    ) -> Result<()> {
+        return instructions::initialize_position_reward_extension::handler(ctx);
+    }
+
+    /// Initialize an extension reward of a ElysiumPool.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority for the specified extension reward-index.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index that we'd like to initialize.
+    ///                    (0 <= index < NUM_EXTENSION_REWARDS)
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index in this extension, or exceeds NUM_EXTENSION_REWARDS.
+    pub fn initialize_extension_reward(
+        ctx: Context<InitializeExtensionReward>,
+        reward_index: u8,
+    ) -> Result<()> {
+        return instructions::initialize_extension_reward::handler(ctx, reward_index);
+    }
+
+    /// Set the authority of an extension reward of a ElysiumPool.
+    /// Only the current authority of the extension reward has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority for the specified extension reward-index.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.
+    pub fn set_extension_reward_authority(
+        ctx: Context<SetExtensionRewardAuthority>,
+        reward_index: u8,
+    ) -> Result<()> {
+        return instructions::set_extension_reward_authority::handler(ctx, reward_index);
+    }
+
+    /// Set the reward emissions for an extension reward of a ElysiumPool.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority for the specified extension reward-index.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).
+    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot
+    ///                                     cover a day of desired emissions.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.
+    pub fn set_extension_reward_emissions(
+        ctx: Context<SetExtensionRewardEmissions>,
+        reward_index: u8,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        return instructions::set_extension_reward_emissions::handler(
+            ctx,
+            reward_index,
+            emissions_per_second_x64,
+        );
+    }
+
     /// Open a position in a ElysiumPool. A unique token will be minted to represent the position
     /// in the users wallet. The position will start off with 0 liquidity.
     ///
@@ -164,9 +637,13 @@ pub mod elysium {
     /// - `tick_lower_index` - The tick specifying the lower end of the position range.
     /// - `tick_upper_index` - The tick specifying the upper end of the position range.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool is permissioned: the allowlist entry of the position owner.
+    ///
     /// #### Special Errors
     /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
     ///                        the tick-spacing in this pool.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted.
     pub fn open_position(
         ctx: Context<OpenPosition>,
         bumps: OpenPositionBumps,
@@ -189,9 +666,13 @@ pub mod elysium {
     /// - `tick_lower_index` - The tick specifying the lower end of the position range.
     /// - `tick_upper_index` - The tick specifying the upper end of the position range.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool is permissioned: the allowlist entry of the position owner.
+    ///
     /// #### Special Errors
     /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
     ///                        the tick-spacing in this pool.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted.
     pub fn open_position_with_metadata(
         ctx: Context<OpenPositionWithMetadata>,
         bumps: OpenPositionWithMetadataBumps,
@@ -206,6 +687,56 @@ pub mod elysium {
         );
     }
 
+    /// Lock the liquidity of a position until a timestamp, or forever. The position token is moved
+    /// into a token account owned by the PositionLock at `[b"position_lock", position]`, so the
+    /// liquidity cannot be withdrawn and the position cannot be closed. The position authority
+    /// becomes the beneficiary of the lock, and can still collect fees and rewards by passing the
+    /// PositionLock as a remaining account. To lock a position from the block it is opened in,
+    /// call this instruction in the same transaction as `open_position_with_metadata`.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `unlock_timestamp` - The timestamp until which the position's liquidity is locked.
+    ///                        POSITION_LOCK_PERMANENT locks the liquidity forever.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionLock` - The unlock timestamp is not in the future.
+    pub fn lock_position(ctx: Context<LockPosition>, unlock_timestamp: u64) -> Result<()> {
+        return instructions::lock_position::handler(ctx, unlock_timestamp);
+    }
+
+    /// Extend the lock on the liquidity of a position. A lock can never be shortened.
+    ///
+    /// ### Authority
+    /// - `beneficiary` - The beneficiary of the lock.
+    ///
+    /// ### Parameters
+    /// - `unlock_timestamp` - The timestamp until which the position's liquidity is locked.
+    ///                        POSITION_LOCK_PERMANENT locks the liquidity forever.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionLock` - The unlock timestamp is earlier than the current one.
+    pub fn extend_position_lock(
+        ctx: Context<ExtendPositionLock>,
+        unlock_timestamp: u64,
+    ) -> Result<()> {
+        return instructions::extend_position_lock::handler(ctx, unlock_timestamp);
+    }
+
+    /// Return the position token of an expired lock to the beneficiary and close the lock. The
+    /// rent of the lock and of its token account is refunded to the beneficiary.
+    ///
+    /// ### Authority
+    /// - `beneficiary` - The beneficiary of the lock.
+    ///
+    /// #### Special Errors
+    /// - `PositionLocked` - The lock has not expired.
+    pub fn unlock_position(ctx: Context<UnlockPosition>) -> Result<()> {
+        return instructions::unlock_position::handler(ctx);
+    }
+
     /// Add liquidity to a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.
     ///
     /// ### Authority
@@ -216,21 +747,125 @@ pub mod elysium {
     /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
     /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool has a PoolRewardExtension: the reward extension and the account at the address
+    ///   of the position's extension, whether initialized or not. If the position's extension is
+    ///   initialized, also the extensions of the lower and upper tick arrays.
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    /// - If the pool has a hook program: the hook program followed by the accounts it requires,
+    ///   after all other remaining accounts.
+    /// - If the pool is permissioned: the allowlist entry of the position_authority.
+    ///
     /// #### Special Errors
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
-    pub fn increase_liquidity(
-        ctx: Context<ModifyLiquidity>,
+    /// - `InvalidRewardExtension` - The reward extension accounts are missing or do not match.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    /// - `InvalidPoolHookAccounts` - The pool's hook program is missing.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted.
+    pub fn increase_liquidity<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> Result<()> {
+        return instructions::increase_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_max_a,
+            token_max_b,
+            None,
+            None,
+            None,
+        );
+    }
+
+    /// Add liquidity to a position in the ElysiumPool, rejecting it once the deadline has passed or
+    /// while the pool's sqrt-price is outside of the provided bounds.
+    /// Takes the accounts, parameters and remaining accounts of `increase_liquidity`, followed by the
+    /// parameters below.
+    ///
+    /// ### Parameters
+    /// - `deadline` - Optional unix timestamp or slot after which the instruction is rejected.
+    /// - `min_sqrt_price` - Optional minimum pool sqrt-price at execution.
+    /// - `max_sqrt_price` - Optional maximum pool sqrt-price at execution.
+    ///
+    /// #### Special Errors
+    /// - `DeadlineExceeded` - The provided deadline has passed.
+    /// - `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.
+    /// - The special errors of `increase_liquidity`.
+    pub fn increase_liquidity_v2<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
         liquidity_amount: u128,
         token_max_a: u64,
         token_max_b: u64,
+        deadline: Option<Deadline>,
+        min_sqrt_price: Option<u128>,
+        max_sqrt_price: Option<u128>,
     ) -> Result<()> {
         return instructions::increase_liquidity::handler(
             ctx,
             liquidity_amount,
             token_max_a,
             token_max_b,
+            deadline,
+            min_sqrt_price,
+            max_sqrt_price,
+        );
+    }
+
+    /// Add liquidity to a position in the ElysiumPool, sized by an exact amount of one token.
+    /// The liquidity is derived on-chain from the pool's current sqrt-price.
+    /// This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `token_amount` - The exact amount of tokenA (or tokenB) the user wants to deposit.
+    /// - `other_token_max` - The maximum amount of the other token the user is willing to deposit.
+    /// - `amount_specified_is_a` - True if `token_amount` is tokenA. False if it is tokenB.
+    /// - `deadline` - Optional unix timestamp or slot after which the instruction is rejected.
+    /// - `min_sqrt_price` - Optional minimum pool sqrt-price at execution.
+    /// - `max_sqrt_price` - Optional maximum pool sqrt-price at execution.
+    ///
+    /// ### Remaining Accounts
+    /// - If the pool has a PoolRewardExtension: the reward extension and the account at the address
+    ///   of the position's extension, whether initialized or not. If the position's extension is
+    ///   initialized, also the extensions of the lower and upper tick arrays.
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    /// - If the pool has a hook program: the hook program followed by the accounts it requires,
+    ///   after all other remaining accounts.
+    /// - If the pool is permissioned: the allowlist entry of the position_authority.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided token amount is zero or too small to mint any liquidity.
+    /// - `TokenNotInPositionRange` - The position's range does not take the specified token at the current price.
+    /// - `TokenMaxExceeded` - The required amount of the other token exceeds the user defined amount.
+    /// - `DeadlineExceeded` - The provided deadline has passed.
+    /// - `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.
+    /// - `InvalidRewardExtension` - The reward extension accounts are missing or do not match.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    /// - `InvalidPoolHookAccounts` - The pool's hook program is missing.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted.
+    pub fn increase_liquidity_by_token_amount<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+        token_amount: u64,
+        other_token_max: u64,
+        amount_specified_is_a: bool,
+        deadline: Option<Deadline>,
+        min_sqrt_price: Option<u128>,
+        max_sqrt_price: Option<u128>,
+    ) -> Result<()> {
+        return instructions::increase_liquidity_by_token_amount::handler(
+            ctx,
+            token_amount,
+            other_token_max,
+            amount_specified_is_a,
+            deadline,
+            min_sqrt_price,
+            max_sqrt_price,
         );
     }
 
@@ -238,27 +873,77 @@ pub mod elysium {
     ///
     /// ### Authority
     /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          Does not have to be allowlisted, so that liquidity can always be
+    ///                          withdrawn from a permissioned pool. The pool's hook program is
+    ///                          not invoked either, before or after the withdrawal, as a failing
+    ///                          hook would fail the withdrawal.
     ///
     /// ### Parameters
     /// - `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.
     /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.
     /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool has a PoolRewardExtension: the reward extension and the account at the address
+    ///   of the position's extension, whether initialized or not. If the position's extension is
+    ///   initialized, also the extensions of the lower and upper tick arrays.
+    /// - If the pool has a TickArrayBitmap: the tick array bitmap.
+    ///
     /// #### Special Errors
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
-    pub fn decrease_liquidity(
-        ctx: Context<ModifyLiquidity>,
+    /// - `PositionLocked` - The position token is held by the lock of the position.
+    /// - `InvalidRewardExtension` - The reward extension accounts are missing or do not match.
+    /// - `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.
+    pub fn decrease_liquidity<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> Result<()> {
+        return instructions::decrease_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_min_a,
+            token_min_b,
+            None,
+            None,
+            None,
+        );
+    }
+
+    /// Withdraw liquidity from a position in the ElysiumPool, rejecting it once the deadline has
+    /// passed or while the pool's sqrt-price is outside of the provided bounds.
+    /// Takes the accounts, parameters and remaining accounts of `decrease_liquidity`, followed by the
+    /// parameters below.
+    ///
+    /// ### Parameters
+    /// - `deadline` - Optional unix timestamp or slot after which the instruction is rejected.
+    /// - `min_sqrt_price` - Optional minimum pool sqrt-price at execution.
+    /// - `max_sqrt_price` - Optional maximum pool sqrt-price at execution.
+    ///
+    /// #### Special Errors
+    /// - `DeadlineExceeded` - The provided deadline has passed.
+    /// - `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.
+    /// - The special errors of `decrease_liquidity`.
+    pub fn decrease_liquidity_v2<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
         liquidity_amount: u128,
         token_min_a: u64,
         token_min_b: u64,
+        deadline: Option<Deadline>,
+        min_sqrt_price: Option<u128>,
+        max_sqrt_price: Option<u128>,
     ) -> Result<()> {
         return instructions::decrease_liquidity::handler(
             ctx,
             liquidity_amount,
             token_min_a,
             token_min_b,
+            deadline,
+            min_sqrt_price,
+            max_sqrt_price,
         );
     }
 
@@ -274,7 +959,11 @@ pub mod elysium {
     /// Collect fees accrued for this position.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    /// - `position_authority` - authority that owns the token corresponding to this desired position,
+    ///                          or the beneficiary of the position's lock.
+    ///
+    /// ### Remaining Accounts
+    /// - If the position is locked: the PositionLock of the position.
     pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
         return instructions::collect_fees::handler(ctx);
     }
@@ -282,11 +971,57 @@ pub mod elysium {
     /// Collect rewards accrued for this position.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    /// - `position_authority` - authority that owns the token corresponding to this desired position,
+    ///                          or the beneficiary of the position's lock.
+    ///
+    /// ### Remaining Accounts
+    /// - If the position is locked: the PositionLock of the position.
     pub fn collect_reward(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
         return instructions::collect_reward::handler(ctx, reward_index);
     }
 
+    /// Update the accrued extension rewards for a position and collect an extension reward.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position,
+    ///                          or the beneficiary of the position's lock.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).
+    ///
+    /// ### Remaining Accounts
+    /// - If the position is locked: the PositionLock of the position.
+    ///
+    /// #### Special Errors
+    /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
+    pub fn collect_extension_reward(
+        ctx: Context<CollectExtensionReward>,
+        reward_index: u8,
+    ) -> Result<()> {
+        return instructions::collect_extension_reward::handler(ctx, reward_index);
+    }
+
+    /// Update the accrued fees and rewards for a position, then collect all fees and every
+    /// initialized reward in one call.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position,
+    ///                          or the beneficiary of the position's lock.
+    ///
+    /// ### Remaining Accounts
+    /// - For every initialized reward, in reward index order: the reward owner account followed by
+    ///   the reward vault.
+    /// - If the position is locked: the PositionLock of the position, after the reward accounts.
+    ///
+    /// #### Special Errors
+    /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
+    /// - `InvalidRewardAccounts` - A reward owner account or vault is missing or does not match the reward.
+    pub fn harvest<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Harvest<'info>>,
+    ) -> Result<()> {
+        return instructions::harvest::handler(ctx);
+    }
+
     /// Collect the protocol fees accrued in this ElysiumPool
     ///
     /// ### Authority
@@ -295,6 +1030,14 @@ pub mod elysium {
         return instructions::collect_protocol_fees::handler(ctx);
     }
 
+    /// Collect the creator fees accrued in this ElysiumPool
+    ///
+    /// ### Authority
+    /// - `creator` - the creator recorded when the ElysiumPool was initialized
+    pub fn collect_creator_fees(ctx: Context<CollectCreatorFees>) -> Result<()> {
+        return instructions::collect_creator_fees::handler(ctx);
+    }
+
     /// Perform a swap in this ElysiumPool
     ///
     /// ### Authority
@@ -307,7 +1050,16 @@ pub mod elysium {
     /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
     /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension
+    ///   and the account at the address of the extension of every tick array of the swap, whether
+    ///   initialized or not.
+    /// - If the pool has a hook program: the hook program followed by the accounts it requires,
+    ///   after all other remaining accounts.
+    /// - If the pool is permissioned: the allowlist entry of the token_authority.
+    ///
     /// #### Special Errors
+    /// - `PoolNotActivated` - The pool has not reached its activation timestamp or slot.
     /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
     /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
     /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
@@ -316,8 +1068,11 @@ pub mod elysium {
     /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
     /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
-    pub fn swap(
-        ctx: Context<Swap>,
+    /// - `InvalidRewardExtension` - The reward extension accounts are missing or do not match.
+    /// - `InvalidPoolHookAccounts` - The pool's hook program is missing.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the token_authority is not allowlisted.
+    pub fn swap<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         sqrt_price_limit: u128,
@@ -331,6 +1086,37 @@ pub mod elysium {
             sqrt_price_limit,
             amount_specified_is_input,
             a_to_b,
+            None,
+        );
+    }
+
+    /// Perform a swap in this ElysiumPool, rejecting it once the deadline has passed.
+    /// Takes the accounts, parameters and remaining accounts of `swap`, followed by the
+    /// parameters below.
+    ///
+    /// ### Parameters
+    /// - `deadline` - Optional unix timestamp or slot after which the instruction is rejected.
+    ///
+    /// #### Special Errors
+    /// - `DeadlineExceeded` - The provided deadline has passed.
+    /// - The special errors of `swap`.
+    pub fn swap_v2<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        deadline: Option<Deadline>,
+    ) -> Result<()> {
+        return instructions::swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            deadline,
         );
     }
 
@@ -341,6 +1127,7 @@ pub mod elysium {
     ///
     /// #### Special Errors
     /// - `ClosePositionNotEmpty` - The provided position account is not empty.
+    /// - `PositionLocked` - The position token is held by the lock of the position.
     pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
         return instructions::close_position::handler(ctx);
     }
@@ -386,6 +1173,127 @@ pub mod elysium {
         );
     }
 
+    /// Sets the max creator fee rate for a ElysiumPoolConfig
+    /// Creator fee rate is represented as a basis point.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `max_creator_fee_rate` - Upper bound on the creator fee rate of pools initialized using this config.
+    ///
+    /// #### Special Errors
+    /// - `CreatorFeeRateMaxExceeded` - If the provided max_creator_fee_rate exceeds MAX_CREATOR_FEE_RATE.
+    pub fn set_max_creator_fee_rate(
+        ctx: Context<SetMaxCreatorFeeRate>,
+        max_creator_fee_rate: u16,
+    ) -> Result<()> {
+        return instructions::set_max_creator_fee_rate::handler(ctx, max_creator_fee_rate);
+    }
+
+    /// Initializes the ElysiumPoolsConfigExtension of a ElysiumPoolsConfig, which holds the
+    /// config settings that do not fit in the config account. Every setting starts at its
+    /// strictest value.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    pub fn initialize_config_extension(ctx: Context<InitializeConfigExtension>) -> Result<()> {
+        return instructions::initialize_config_extension::handler(ctx);
+    }
+
+    /// Sets how far past its creation the activation of a pool in the config can be set.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `max_activation_delay` - Upper bound, in seconds, on the delay of activation_timestamp.
+    /// - `max_activation_slot_delay` - Upper bound, in slots, on the delay of activation_slot.
+    pub fn set_max_activation_delay(
+        ctx: Context<SetMaxActivationDelay>,
+        max_activation_delay: u64,
+        max_activation_slot_delay: u64,
+    ) -> Result<()> {
+        return instructions::set_max_activation_delay::handler(
+            ctx,
+            max_activation_delay,
+            max_activation_slot_delay,
+        );
+    }
+
+    /// Sets the longest decay period of the launch fee schedule of pools in the config.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `max_launch_fee_decay_period` - Upper bound on the decay period of schedules decaying
+    ///                                   in seconds.
+    /// - `max_launch_fee_decay_slots` - Upper bound on the decay period of schedules decaying
+    ///                                  in slots.
+    pub fn set_max_launch_fee_decay_period(
+        ctx: Context<SetMaxLaunchFeeDecayPeriod>,
+        max_launch_fee_decay_period: u64,
+        max_launch_fee_decay_slots: u64,
+    ) -> Result<()> {
+        return instructions::set_max_launch_fee_decay_period::handler(
+            ctx,
+            max_launch_fee_decay_period,
+            max_launch_fee_decay_slots,
+        );
+    }
+
+    /// Sets the authority that signs the creation of permissioned pools in the config. Setting
+    /// the default pubkey disables the creation of permissioned pools.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    pub fn set_permissioned_pool_authority(
+        ctx: Context<SetPermissionedPoolAuthority>,
+    ) -> Result<()> {
+        return instructions::set_permissioned_pool_authority::handler(ctx);
+    }
+
+    /// Adds a program to the hook program allowlist of the config. Pools can be set to use the
+    /// program once HOOK_PROGRAM_TIMELOCK_SECONDS have passed.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `hook_program` - The hook program to allowlist.
+    ///
+    /// #### Special Errors
+    /// - `HookProgramAllowlistFull` - The allowlist holds NUM_HOOK_PROGRAMS programs already.
+    pub fn add_hook_program(ctx: Context<AddHookProgram>, hook_program: Pubkey) -> Result<()> {
+        return instructions::add_hook_program::handler(ctx, hook_program);
+    }
+
+    /// Removes a program from the hook program allowlist of the config. Pools already using it
+    /// keep invoking it until their hook program is changed.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `hook_program` - The hook program to remove.
+    ///
+    /// #### Special Errors
+    /// - `HookProgramNotAllowlisted` - The hook program is not allowlisted.
+    pub fn remove_hook_program(
+        ctx: Context<RemoveHookProgram>,
+        hook_program: Pubkey,
+    ) -> Result<()> {
+        return instructions::remove_hook_program::handler(ctx, hook_program);
+    }
+
     /// Sets the fee rate for a ElysiumPool.
     /// Fee rate is represented as hundredths of a basis point.
     /// Only the current fee authority has permission to invoke this instruction.
@@ -402,6 +1310,56 @@ pub mod elysium {
         return instructions::set_fee_rate::handler(ctx, fee_rate);
     }
 
+    /// Sets the hook program of a ElysiumPool. The hook program is invoked before and after
+    /// swaps and increases of liquidity, and can veto the action by returning an error.
+    /// Withdrawals never invoke the hook program, not even after the withdrawal: an error
+    /// returned by an invoked program fails the whole transaction, so a notification of
+    /// `decrease_liquidity` could not be made non-failing and would let the hook lock the
+    /// liquidity of LPs. Hook programs that track liquidity have to read the positions instead.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig
+    ///
+    /// ### Parameters
+    /// - `hook_program` - The program the pool invokes. Has to be on the hook program allowlist
+    ///                    of the config extension. The default pubkey removes the hook.
+    ///
+    /// #### Special Errors
+    /// - `HookProgramNotAllowlisted` - The hook program is not allowlisted.
+    /// - `HookProgramTimelocked` - The timelock of the allowlisted hook program has not elapsed.
+    pub fn set_pool_hook(ctx: Context<SetPoolHook>, hook_program: Pubkey) -> Result<()> {
+        return instructions::set_pool_hook::handler(ctx, hook_program);
+    }
+
+    /// Adds a wallet to the allowlist of a permissioned ElysiumPool.
+    /// Allowlisted wallets can trade, open positions and add liquidity in the pool. Withdrawing
+    /// liquidity, collecting and closing positions never require an allowlist entry.
+    ///
+    /// ### Authority
+    /// - "access_authority" - Set authority that manages the allowlist of the ElysiumPool.
+    pub fn initialize_allowlist_entry(ctx: Context<InitializeAllowlistEntry>) -> Result<()> {
+        return instructions::initialize_allowlist_entry::handler(ctx);
+    }
+
+    /// Removes a wallet from the allowlist of a permissioned ElysiumPool and refunds the rent of
+    /// the allowlist entry to the receiver.
+    ///
+    /// ### Authority
+    /// - "access_authority" - Set authority that manages the allowlist of the ElysiumPool.
+    pub fn close_allowlist_entry(ctx: Context<CloseAllowlistEntry>) -> Result<()> {
+        return instructions::close_allowlist_entry::handler(ctx);
+    }
+
+    /// Sets the access authority of a permissioned ElysiumPool. Setting the default pubkey opens
+    /// the pool to every wallet permanently.
+    ///
+    /// ### Authority
+    /// - "access_authority" - Set authority that manages the allowlist of the ElysiumPool.
+    pub fn set_access_authority(ctx: Context<SetAccessAuthority>) -> Result<()> {
+        return instructions::set_access_authority::handler(ctx);
+    }
+
     /// Sets the protocol fee rate for a ElysiumPool.
     /// Protocol fee rate is represented as a basis point.
     /// Only the current fee authority has permission to invoke this instruction.
@@ -500,7 +1458,14 @@ pub mod elysium {
     /// - `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.
     /// - `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension
+    ///   and the account at the address of the extension of every tick array of the swap, whether
+    ///   initialized or not.
+    /// - For each permissioned pool: the allowlist entry of the token_authority.
+    ///
     /// #### Special Errors
+    /// - `PoolNotActivated` - The pool has not reached its activation timestamp or slot.
     /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
     /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
     /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
@@ -511,6 +1476,9 @@ pub mod elysium {
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
     /// - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
     /// - `DuplicateTwoHopPool` - Error if pool one & two are the same pool.
+    /// - `InvalidRewardExtension` - The reward extension accounts are missing or do not match.
+    /// - `PoolHookNotSupported` - Either pool has a hook program.
+    /// - `WalletNotAllowlisted` - A pool is permissioned and the token_authority is not allowlisted.
     pub fn two_hop_swap(
         ctx: Context<TwoHopSwap>,
         amount: u64,
@@ -530,6 +1498,41 @@ pub mod elysium {
             a_to_b_two,
             sqrt_price_limit_one,
             sqrt_price_limit_two,
+            None,
+        );
+    }
+
+    /// Perform a two-hop swap, rejecting it once the deadline has passed.
+    /// Takes the accounts, parameters and remaining accounts of `two_hop_swap`, followed by the
+    /// parameters below.
+    ///
+    /// ### Parameters
+    /// - `deadline` - Optional unix timestamp or slot after which the instruction is rejected.
+    ///
+    /// #### Special Errors
+    /// - `DeadlineExceeded` - The provided deadline has passed.
+    /// - The special errors of `two_hop_swap`.
+    pub fn two_hop_swap_v2(
+        ctx: Context<TwoHopSwap>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b_one: bool,
+        a_to_b_two: bool,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+        deadline: Option<Deadline>,
+    ) -> Result<()> {
+        return instructions::two_hop_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            a_to_b_one,
+            a_to_b_two,
+            sqrt_price_limit_one,
+            sqrt_price_limit_two,
+            deadline,
         );
     }
 
@@ -571,10 +1574,14 @@ pub mod elysium {
     /// - `tick_lower_index` - The tick specifying the lower end of the position range.
     /// - `tick_upper_index` - The tick specifying the upper end of the position range.
     ///
+    /// ### Remaining Accounts
+    /// - If the pool is permissioned: the allowlist entry of the position_bundle_authority.
+    ///
     /// #### Special Errors
     /// - `InvalidBundleIndex` - If the provided bundle index is out of bounds.
     /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
     ///                        the tick-spacing in this pool.
+    /// - `WalletNotAllowlisted` - The pool is permissioned and the position_bundle_authority is not allowlisted.
     pub fn open_bundled_position(
         ctx: Context<OpenBundledPosition>,
         bundle_index: u16,
@@ -1,5 +1,5 @@
 use super::{
-    pool_manager::{next_pool_liquidity, next_pool_reward_infos},
+    pool_manager::{next_credited_reward_infos, next_pool_liquidity, next_pool_reward_infos},
     position_manager::next_position_modify_liquidity_update,
     tick_manager::{
         next_fee_growths_inside, next_reward_growths_inside, next_tick_modify_liquidity_update,
@@ -7,8 +7,12 @@ use super::{
 };
 use crate::{
     errors::ErrorCode,
-    math::{get_amount_delta_a, get_amount_delta_b, sqrt_price_from_tick_index},
+    math::{
+        get_amount_delta_a, get_amount_delta_b, get_liquidity_from_amount_a,
+        get_liquidity_from_amount_b, sqrt_price_from_tick_index,
+    },
     state::*,
+    util::sync_initialized_tick_bitmap,
 };
 use anchor_lang::prelude::{AccountLoader, *};
 
@@ -152,6 +156,13 @@ fn _calculate_modify_liquidity(
         &reward_growths_inside,
     )?;
 
+    let next_reward_infos = next_credited_reward_infos(
+        &next_reward_infos,
+        position.liquidity,
+        &position.reward_infos,
+        &position_update.reward_infos,
+    );
+
     Ok(ModifyLiquidityUpdate {
         pool_liquidity: next_global_liquidity,
         reward_infos: next_reward_infos,
@@ -195,6 +206,50 @@ pub fn calculate_liquidity_token_deltas(
     Ok((delta_a, delta_b))
 }
 
+// Calculates the liquidity that an exact deposit of token A (or token B) provides to the position
+// at the current price. The other token amount required is derived from this liquidity afterwards.
+pub fn calculate_liquidity_from_token_amount(
+    current_tick_index: i32,
+    sqrt_price: u128,
+    position: &Position,
+    token_amount: u64,
+    amount_specified_is_a: bool,
+) -> Result<u128> {
+    if token_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+
+    let lower_price = sqrt_price_from_tick_index(position.tick_lower_index);
+    let upper_price = sqrt_price_from_tick_index(position.tick_upper_index);
+
+    let liquidity = if current_tick_index < position.tick_lower_index {
+        // current tick below position, only token A is deposited
+        if !amount_specified_is_a {
+            return Err(ErrorCode::TokenNotInPositionRange.into());
+        }
+        get_liquidity_from_amount_a(lower_price, upper_price, token_amount)?
+    } else if current_tick_index < position.tick_upper_index {
+        // current tick inside position
+        if amount_specified_is_a {
+            get_liquidity_from_amount_a(sqrt_price, upper_price, token_amount)?
+        } else {
+            get_liquidity_from_amount_b(lower_price, sqrt_price, token_amount)?
+        }
+    } else {
+        // current tick above position, only token B is deposited
+        if amount_specified_is_a {
+            return Err(ErrorCode::TokenNotInPositionRange.into());
+        }
+        get_liquidity_from_amount_b(lower_price, upper_price, token_amount)?
+    };
+
+    if liquidity == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+
+    Ok(liquidity)
+}
+
 pub fn sync_modify_liquidity_values<'info>(
     pool: &mut ElysiumPool,
     position: &mut Position,
@@ -216,6 +271,8 @@ pub fn sync_modify_liquidity_values<'info>(
         pool.tick_spacing,
         &modify_liquidity_update.tick_upper_update,
     )?;
+    sync_initialized_tick_bitmap(tick_array_lower)?;
+    sync_initialized_tick_bitmap(tick_array_upper)?;
 
     pool.update_rewards_and_liquidity(
         modify_liquidity_update.reward_infos,
@@ -3580,3 +3637,72 @@ mod calculate_modify_liquidity_unit_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod calculate_liquidity_from_token_amount_unit_tests {
+    use super::{calculate_liquidity_from_token_amount, calculate_liquidity_token_deltas};
+    use crate::math::sqrt_price_from_tick_index;
+    use crate::state::position_builder::PositionBuilder;
+
+    const TOKEN_AMOUNT: u64 = 1_000_000;
+
+    fn token_deltas(current_tick_index: i32, amount_specified_is_a: bool) -> (u64, u64) {
+        let position = PositionBuilder::new(-1280, 1280).build();
+        let sqrt_price = sqrt_price_from_tick_index(current_tick_index);
+        let liquidity = calculate_liquidity_from_token_amount(
+            current_tick_index,
+            sqrt_price,
+            &position,
+            TOKEN_AMOUNT,
+            amount_specified_is_a,
+        )
+        .unwrap();
+        calculate_liquidity_token_deltas(
+            current_tick_index,
+            sqrt_price,
+            &position,
+            liquidity as i128,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn inside_range_amount_a() {
+        let (delta_a, delta_b) = token_deltas(64, true);
+        assert!(delta_a <= TOKEN_AMOUNT && TOKEN_AMOUNT - delta_a <= 1);
+        assert!(delta_b > 0);
+    }
+
+    #[test]
+    fn inside_range_amount_b() {
+        let (delta_a, delta_b) = token_deltas(64, false);
+        assert!(delta_b <= TOKEN_AMOUNT && TOKEN_AMOUNT - delta_b <= 1);
+        assert!(delta_a > 0);
+    }
+
+    #[test]
+    fn below_range_amount_a() {
+        let (delta_a, delta_b) = token_deltas(-2000, true);
+        assert!(delta_a <= TOKEN_AMOUNT && TOKEN_AMOUNT - delta_a <= 1);
+        assert_eq!(delta_b, 0);
+    }
+
+    #[test]
+    fn above_range_amount_b() {
+        let (delta_a, delta_b) = token_deltas(2000, false);
+        assert!(delta_b <= TOKEN_AMOUNT && TOKEN_AMOUNT - delta_b <= 1);
+        assert_eq!(delta_a, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenNotInPositionRange")]
+    fn below_range_amount_b_not_allowed() {
+        token_deltas(-2000, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenNotInPositionRange")]
+    fn above_range_amount_a_not_allowed() {
+        token_deltas(2000, true);
+    }
+}
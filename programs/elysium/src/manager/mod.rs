@@ -1,5 +1,6 @@
 pub mod liquidity_manager;
 pub mod pool_manager;
 pub mod position_manager;
+pub mod reward_extension_manager;
 pub mod swap_manager;
 pub mod tick_manager;
@@ -1,5 +1,7 @@
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+
 use crate::errors::ErrorCode;
-use crate::math::{add_liquidity_delta, checked_mul_div};
+use crate::math::{add_liquidity_delta, div_round_up, Q64_RESOLUTION};
 use crate::state::*;
 
 // Calculates the next global reward growth variables based on the given timestamp.
@@ -8,43 +10,211 @@ pub fn next_pool_reward_infos(
     pool: &ElysiumPool,
     next_timestamp: u64,
 ) -> Result<[ElysiumPoolRewardInfo; NUM_REWARDS], ErrorCode> {
-    let curr_timestamp = pool.reward_last_updated_timestamp;
+    next_reward_infos(
+        &pool.reward_infos,
+        pool.liquidity,
+        pool.reward_last_updated_timestamp,
+        next_timestamp,
+    )
+}
+
+// Calculates the next global reward growth variables of the extension rewards of a pool based on
+// the given timestamp. Extension rewards accrue against the liquidity of the pool.
+pub fn next_extension_reward_infos(
+    pool: &ElysiumPool,
+    reward_extension: &PoolRewardExtension,
+    next_timestamp: u64,
+) -> Result<[ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS], ErrorCode> {
+    next_reward_infos(
+        &reward_extension.reward_infos,
+        pool.liquidity,
+        reward_extension.reward_last_updated_timestamp,
+        next_timestamp,
+    )
+}
+
+// Calculates the next global reward growth variables based on the given timestamp, after syncing
+// the funded amount of the reward at reward_index with the balance of its vault. Tokens sent to
+// the vault directly then back the emissions that were due since the last update.
+pub fn next_pool_reward_infos_with_vault(
+    pool: &ElysiumPool,
+    reward_index: usize,
+    vault_amount: u64,
+    next_timestamp: u64,
+) -> anchor_lang::Result<[ElysiumPoolRewardInfo; NUM_REWARDS]> {
+    if reward_index >= NUM_REWARDS {
+        return Err(ErrorCode::InvalidRewardIndex.into());
+    }
+
+    let mut reward_infos = pool.reward_infos;
+    reward_infos[reward_index].sync_funded(vault_amount)?;
+    Ok(next_reward_infos(
+        &reward_infos,
+        pool.liquidity,
+        pool.reward_last_updated_timestamp,
+        next_timestamp,
+    )?)
+}
+
+// Calculates the next global reward growth variables of the extension rewards of a pool based on
+// the given timestamp, after syncing the funded amount of the extension reward at reward_index
+// with the balance of its vault.
+pub fn next_extension_reward_infos_with_vault(
+    pool: &ElysiumPool,
+    reward_extension: &PoolRewardExtension,
+    reward_index: usize,
+    vault_amount: u64,
+    next_timestamp: u64,
+) -> anchor_lang::Result<[ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS]> {
+    if reward_index >= NUM_EXTENSION_REWARDS {
+        return Err(ErrorCode::InvalidRewardIndex.into());
+    }
+
+    let mut reward_infos = reward_extension.reward_infos;
+    reward_infos[reward_index].sync_funded(vault_amount)?;
+    Ok(next_reward_infos(
+        &reward_infos,
+        pool.liquidity,
+        reward_extension.reward_last_updated_timestamp,
+        next_timestamp,
+    )?)
+}
+
+// Calculates the next global reward growth variables for the given rewards, that were last
+// updated at curr_timestamp and accrue against the given liquidity.
+pub fn next_reward_infos<const N: usize>(
+    reward_infos: &[ElysiumPoolRewardInfo; N],
+    liquidity: u128,
+    curr_timestamp: u64,
+    next_timestamp: u64,
+) -> Result<[ElysiumPoolRewardInfo; N], ErrorCode> {
     if next_timestamp < curr_timestamp {
         return Err(ErrorCode::InvalidTimestamp.into());
     }
 
     // No-op if no liquidity or no change in timestamp
-    if pool.liquidity == 0 || next_timestamp == curr_timestamp {
-        return Ok(pool.reward_infos);
+    if liquidity == 0 || next_timestamp == curr_timestamp {
+        return Ok(*reward_infos);
     }
 
     // Calculate new global reward growth
-    let mut next_reward_infos = pool.reward_infos;
-    let time_delta = u128::from(next_timestamp - curr_timestamp);
-    for i in 0..NUM_REWARDS {
+    let mut next_reward_infos = *reward_infos;
+    for i in 0..N {
         if !next_reward_infos[i].initialized() {
             continue;
         }
         let reward_info = &mut next_reward_infos[i];
 
-        // Calculate the new reward growth delta.
-        // If the calculation overflows, set the delta value to zero.
+        // Only the portion of elapsed time within the emissions schedule accrues rewards.
+        let time_delta = u128::from(reward_info.emissions_seconds(curr_timestamp, next_timestamp));
+
+        // Calculate the amount of tokens emitted, capped at the funded amount that has not been
+        // emitted yet, so that positions are never credited rewards the vault cannot pay.
+        // Emissions beyond the funded amount are deferred until the reward vault is topped up,
+        // so the time a reward is throttled for is not lost.
+        // If the calculation overflows, set the amount to zero.
         // This will halt reward distributions for this reward.
-        let reward_growth_delta = checked_mul_div(
-            time_delta,
-            reward_info.emissions_per_second_x64,
-            pool.liquidity,
-        )
-        .unwrap_or(0);
+        let max_emissions_x64 = u128::from(reward_info.unemitted_amount()) << Q64_RESOLUTION;
+        let due_emissions_x64 = time_delta
+            .checked_mul(reward_info.emissions_per_second_x64)
+            .and_then(|emissions_x64| {
+                emissions_x64.checked_add(reward_info.unfunded_emissions_x64)
+            });
+        let emissions_x64 = match due_emissions_x64 {
+            Some(due_emissions_x64) => {
+                let emissions_x64 = due_emissions_x64.min(max_emissions_x64);
+                reward_info.unfunded_emissions_x64 = due_emissions_x64 - emissions_x64;
+                emissions_x64
+            }
+            None => 0,
+        };
+
+        // Calculate the new reward growth delta.
+        let reward_growth_delta = emissions_x64 / liquidity;
 
         // Add the reward growth delta to the global reward growth.
         let curr_growth_global = reward_info.growth_global_x64;
         reward_info.growth_global_x64 = curr_growth_global.wrapping_add(reward_growth_delta);
+
+        // The growth is credited to positions in proportion to their liquidity, which adds up to
+        // the pool liquidity exactly.
+        reward_info.uncredited_x64 = reward_info
+            .uncredited_x64
+            .saturating_add(reward_growth_delta.saturating_mul(liquidity));
+
+        // Round the emitted amount up, the sum of rewards credited to positions is rounded down.
+        let emitted = div_round_up(emissions_x64, 1 << Q64_RESOLUTION)? as u64;
+        reward_info.total_emitted = reward_info.total_emitted.saturating_add(emitted);
     }
 
     Ok(next_reward_infos)
 }
 
+// Calculates the next global reward variables after a position with the given liquidity credits
+// the rewards it earned since its last checkpoints to the amounts it is owed.
+pub fn next_credited_reward_infos<const N: usize>(
+    reward_infos: &[ElysiumPoolRewardInfo; N],
+    liquidity: u128,
+    position_reward_infos: &[PositionRewardInfo; N],
+    next_position_reward_infos: &[PositionRewardInfo; N],
+) -> [ElysiumPoolRewardInfo; N] {
+    let mut next_reward_infos = *reward_infos;
+    for i in 0..N {
+        let reward_info = &mut next_reward_infos[i];
+        let growth_delta = next_position_reward_infos[i]
+            .growth_inside_checkpoint
+            .wrapping_sub(position_reward_infos[i].growth_inside_checkpoint);
+        let amount_owed_delta = next_position_reward_infos[i]
+            .amount_owed
+            .wrapping_sub(position_reward_infos[i].amount_owed);
+
+        reward_info.uncredited_x64 = reward_info
+            .uncredited_x64
+            .saturating_sub(growth_delta.saturating_mul(liquidity));
+        reward_info.total_credited = reward_info.total_credited.saturating_add(amount_owed_delta);
+    }
+    next_reward_infos
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RewardRunway {
+    /// Funded reward tokens that have not been emitted yet.
+    pub unemitted_amount: u64,
+    /// Seconds of emissions at the current rate that the unemitted amount can cover.
+    /// `u64::MAX` if the reward is not emitting.
+    pub runway_seconds: u64,
+}
+
+// Calculates the funded amount left for a reward and how long it lasts at the current emissions
+// rate, accounting for rewards accrued since the last update and deposits made to the vault.
+pub fn calculate_reward_runway(
+    pool: &ElysiumPool,
+    reward_index: usize,
+    vault_amount: u64,
+    timestamp: u64,
+) -> anchor_lang::Result<RewardRunway> {
+    if reward_index >= NUM_REWARDS {
+        return Err(ErrorCode::InvalidRewardIndex.into());
+    }
+
+    let reward_info =
+        next_pool_reward_infos_with_vault(pool, reward_index, vault_amount, timestamp)?
+            [reward_index];
+
+    let unemitted_amount = reward_info.unemitted_amount();
+    // Division by zero means the reward is not emitting, so the runway is unlimited.
+    let runway_seconds = (u128::from(unemitted_amount) << Q64_RESOLUTION)
+        .checked_div(reward_info.emissions_per_second_x64)
+        .map_or(u64::MAX, |runway_seconds| {
+            runway_seconds.min(u128::from(u64::MAX)) as u64
+        });
+
+    Ok(RewardRunway {
+        unemitted_amount,
+        runway_seconds,
+    })
+}
+
 // Calculates the next global liquidity for a pool depending on its position relative
 // to the lower and upper tick indexes and the liquidity_delta.
 pub fn next_pool_liquidity(
@@ -65,12 +235,16 @@ mod pool_manager_tests {
 
     use anchor_lang::prelude::Pubkey;
 
-    use crate::manager::pool_manager::next_pool_reward_infos;
+    use crate::manager::pool_manager::{
+        calculate_reward_runway, next_credited_reward_infos, next_pool_reward_infos,
+        next_pool_reward_infos_with_vault, RewardRunway,
+    };
+    use crate::manager::position_manager::next_position_reward_infos;
     use crate::math::Q64_RESOLUTION;
     use crate::state::pool::ElysiumPoolRewardInfo;
     use crate::state::pool::NUM_REWARDS;
     use crate::state::pool_builder::ElysiumPoolBuilder;
-    use crate::state::ElysiumPool;
+    use crate::state::{ElysiumPool, PositionRewardInfo};
 
     // Initializes a pool for testing with all the rewards initialized
     fn init_test_pool(liquidity: u128, reward_last_updated_timestamp: u64) -> ElysiumPool {
@@ -82,18 +256,21 @@ mod pool_manager_tests {
                     mint: Pubkey::new_unique(),
                     emissions_per_second_x64: 10 << Q64_RESOLUTION,
                     growth_global_x64: 100 << Q64_RESOLUTION,
+                    total_funded: 1_000_000,
                     ..Default::default()
                 },
                 ElysiumPoolRewardInfo {
                     mint: Pubkey::new_unique(),
                     emissions_per_second_x64: 0b11 << (Q64_RESOLUTION - 1), // 1.5
                     growth_global_x64: 200 << Q64_RESOLUTION,
+                    total_funded: 1_000_000,
                     ..Default::default()
                 },
                 ElysiumPoolRewardInfo {
                     mint: Pubkey::new_unique(),
                     emissions_per_second_x64: 1 << (Q64_RESOLUTION - 1), // 0.5
                     growth_global_x64: 300 << Q64_RESOLUTION,
+                    total_funded: 1_000_000,
                     ..Default::default()
                 },
             ])
@@ -164,6 +341,7 @@ mod pool_manager_tests {
                 ElysiumPoolRewardInfo {
                     mint: Pubkey::new_unique(),
                     emissions_per_second_x64: 1 << Q64_RESOLUTION,
+                    total_funded: 1_000_000,
                     ..Default::default()
                 },
             )
@@ -214,4 +392,132 @@ mod pool_manager_tests {
             0b1001011011 << (Q64_RESOLUTION - 1) // 301.5
         );
     }
+
+    #[test]
+    fn test_next_pool_reward_infos_scheduled_emissions_clipped_to_window() {
+        let mut pool = init_test_pool(100, 1577854800);
+        pool.reward_infos[0].emissions_start_timestamp = 1577854800 + 100;
+        pool.reward_infos[0].emissions_end_timestamp = 1577854800 + 200;
+
+        let result = next_pool_reward_infos(&pool, 1577854800 + 300).unwrap();
+        // Only 100 of the 300 elapsed seconds are within the schedule
+        assert_eq!(result[0].growth_global_x64, 110 << Q64_RESOLUTION);
+        assert_eq!(
+            result[1].growth_global_x64,
+            0b110011001 << (Q64_RESOLUTION - 1) // 204.5
+        );
+    }
+
+    #[test]
+    fn test_next_pool_reward_infos_scheduled_emissions_ended() {
+        let mut pool = init_test_pool(100, 1577854800);
+        pool.reward_infos[0].emissions_start_timestamp = 1577854800 - 200;
+        pool.reward_infos[0].emissions_end_timestamp = 1577854800 - 100;
+
+        let result = next_pool_reward_infos(&pool, 1577854800 + 300).unwrap();
+        assert_eq!(result[0].growth_global_x64, 100 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_next_pool_reward_infos_throttled_at_funded_amount() {
+        let mut pool = init_test_pool(100, 1577854800);
+        pool.reward_infos[0].total_funded = 1_500;
+        pool.reward_infos[0].total_emitted = 1_000;
+
+        let result = next_pool_reward_infos(&pool, 1577854800 + 300).unwrap();
+        // 3000 tokens would be emitted, but only 500 are funded
+        assert_eq!(result[0].growth_global_x64, 105 << Q64_RESOLUTION);
+        assert_eq!(result[0].total_emitted, 1_500);
+        assert_eq!(result[1].total_emitted, 450);
+
+        assert_eq!(result[0].unfunded_emissions_x64, 2_500 << Q64_RESOLUTION);
+
+        // Nothing accrues once the funded amount is used up
+        pool.reward_infos = result;
+        pool.reward_last_updated_timestamp = 1577854800 + 300;
+        let result = next_pool_reward_infos(&pool, 1577854800 + 600).unwrap();
+        assert_eq!(result[0].growth_global_x64, 105 << Q64_RESOLUTION);
+        assert_eq!(result[0].total_emitted, 1_500);
+        assert_eq!(result[0].unfunded_emissions_x64, 5_500 << Q64_RESOLUTION);
+
+        // Tokens sent to the vault directly back the deferred emissions
+        let result = next_pool_reward_infos_with_vault(&pool, 0, 10_000, 1577854800 + 600).unwrap();
+        assert_eq!(result[0].growth_global_x64, 160 << Q64_RESOLUTION);
+        assert_eq!(result[0].total_emitted, 7_000);
+        assert_eq!(result[0].unfunded_emissions_x64, 0);
+    }
+
+    #[test]
+    fn test_next_pool_reward_infos_emitted_rounds_up() {
+        let mut pool = init_test_pool(100, 1577854800);
+        pool.reward_infos[2].total_emitted = 0;
+
+        // 0.5 tokens per second for 3 seconds
+        let result = next_pool_reward_infos(&pool, 1577854800 + 3).unwrap();
+        assert_eq!(result[2].total_emitted, 2);
+    }
+
+    #[test]
+    fn test_next_credited_reward_infos_credits_all_emitted_growth() {
+        let pool = init_test_pool(300, 1577854800);
+        let mut reward_infos = next_pool_reward_infos(&pool, 1577854800 + 10).unwrap();
+        assert!(reward_infos.iter().all(|r| r.uncredited_x64 > 0));
+
+        // Both positions were in range for the whole period and checkpointed the previous growth
+        let growths_inside = [
+            reward_infos[0].growth_global_x64,
+            reward_infos[1].growth_global_x64,
+            reward_infos[2].growth_global_x64,
+        ];
+        for liquidity in [100, 200] {
+            let position_reward_infos = [
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 100 << Q64_RESOLUTION,
+                    amount_owed: 0,
+                },
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 200 << Q64_RESOLUTION,
+                    amount_owed: 0,
+                },
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 300 << Q64_RESOLUTION,
+                    amount_owed: 0,
+                },
+            ];
+            let next_position_reward_infos =
+                next_position_reward_infos(liquidity, &position_reward_infos, &growths_inside);
+            reward_infos = next_credited_reward_infos(
+                &reward_infos,
+                liquidity,
+                &position_reward_infos,
+                &next_position_reward_infos,
+            );
+        }
+
+        // 100 tokens were emitted, of which each position is credited its rounded down share
+        assert!(reward_infos.iter().all(|r| r.uncredited_x64 == 0));
+        assert_eq!(reward_infos[0].total_credited, 33 + 66);
+    }
+
+    #[test]
+    fn test_calculate_reward_runway() {
+        let mut pool = init_test_pool(100, 1577854800);
+        pool.reward_infos[0].total_emitted = 1_000;
+        pool.reward_infos[0].total_collected = 500;
+
+        // 500 collected + 3000 in the vault, 1000 emitted before and 1000 emitted since the update
+        let runway = calculate_reward_runway(&pool, 0, 3_000, 1577854800 + 100).unwrap();
+        assert_eq!(
+            runway,
+            RewardRunway {
+                unemitted_amount: 1_500,
+                runway_seconds: 150,
+            }
+        );
+
+        pool.reward_infos[0].emissions_per_second_x64 = 0;
+        let runway = calculate_reward_runway(&pool, 0, 3_000, 1577854800).unwrap();
+        assert_eq!(runway.runway_seconds, u64::MAX);
+        assert!(calculate_reward_runway(&pool, NUM_REWARDS, 0, 1577854800).is_err());
+    }
 }
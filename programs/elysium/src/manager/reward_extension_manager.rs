@@ -0,0 +1,275 @@
+use crate::{
+    errors::ErrorCode,
+    manager::{
+        pool_manager::{next_credited_reward_infos, next_extension_reward_infos},
+        position_manager::next_position_reward_infos,
+        tick_manager::{
+            next_reward_growths_inside_from_outside, next_reward_growths_outside_on_modify,
+        },
+    },
+    state::*,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct RewardExtensionModifyLiquidityUpdate {
+    pub reward_infos: [ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS],
+    pub tick_lower_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    pub tick_upper_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    pub position_reward_infos: [PositionRewardInfo; NUM_EXTENSION_REWARDS],
+}
+
+// Calculates the extension reward state after modifying liquidity by the liquidity_delta for the
+// given position. Mirrors the reward calculations of calculate_modify_liquidity, so it must be
+// called with the pool, ticks and position from before the liquidity is modified.
+pub fn calculate_reward_extension_modify_liquidity(
+    pool: &ElysiumPool,
+    reward_extension: &PoolRewardExtension,
+    position: &Position,
+    position_extension: &PositionRewardExtension,
+    tick_lower: &Tick,
+    tick_lower_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    tick_upper: &Tick,
+    tick_upper_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    liquidity_delta: i128,
+    timestamp: u64,
+) -> Result<RewardExtensionModifyLiquidityUpdate, ErrorCode> {
+    let next_reward_infos = next_extension_reward_infos(pool, reward_extension, timestamp)?;
+
+    let next_tick_lower_reward_growths_outside = next_reward_growths_outside_on_modify(
+        tick_lower,
+        tick_lower_reward_growths_outside,
+        position.tick_lower_index,
+        pool.tick_current_index,
+        &next_reward_infos,
+        liquidity_delta,
+    )?;
+
+    let next_tick_upper_reward_growths_outside = next_reward_growths_outside_on_modify(
+        tick_upper,
+        tick_upper_reward_growths_outside,
+        position.tick_upper_index,
+        pool.tick_current_index,
+        &next_reward_infos,
+        liquidity_delta,
+    )?;
+
+    let reward_growths_inside = next_reward_growths_inside_from_outside(
+        pool.tick_current_index,
+        tick_lower.initialized,
+        position.tick_lower_index,
+        tick_lower_reward_growths_outside,
+        tick_upper.initialized,
+        position.tick_upper_index,
+        tick_upper_reward_growths_outside,
+        &next_reward_infos,
+    );
+
+    let position_reward_infos = next_position_reward_infos(
+        position.liquidity,
+        &position_extension.reward_infos,
+        &reward_growths_inside,
+    );
+
+    let next_reward_infos = next_credited_reward_infos(
+        &next_reward_infos,
+        position.liquidity,
+        &position_extension.reward_infos,
+        &position_reward_infos,
+    );
+
+    Ok(RewardExtensionModifyLiquidityUpdate {
+        reward_infos: next_reward_infos,
+        tick_lower_reward_growths_outside: next_tick_lower_reward_growths_outside,
+        tick_upper_reward_growths_outside: next_tick_upper_reward_growths_outside,
+        position_reward_infos,
+    })
+}
+
+// Calculates the extension reward state and the extension reward growths inside the range of a
+// position, which are the checkpoints a position starts earning extension rewards from.
+pub fn calculate_reward_extension_growths_inside(
+    pool: &ElysiumPool,
+    reward_extension: &PoolRewardExtension,
+    position: &Position,
+    tick_lower: &Tick,
+    tick_lower_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    tick_upper: &Tick,
+    tick_upper_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    timestamp: u64,
+) -> Result<
+    (
+        [ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS],
+        [u128; NUM_EXTENSION_REWARDS],
+    ),
+    ErrorCode,
+> {
+    let next_reward_infos = next_extension_reward_infos(pool, reward_extension, timestamp)?;
+
+    let reward_growths_inside = next_reward_growths_inside_from_outside(
+        pool.tick_current_index,
+        tick_lower.initialized,
+        position.tick_lower_index,
+        tick_lower_reward_growths_outside,
+        tick_upper.initialized,
+        position.tick_upper_index,
+        tick_upper_reward_growths_outside,
+        &next_reward_infos,
+    );
+
+    Ok((next_reward_infos, reward_growths_inside))
+}
+
+#[cfg(test)]
+mod reward_extension_manager_tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use super::{
+        calculate_reward_extension_growths_inside, calculate_reward_extension_modify_liquidity,
+    };
+    use crate::{
+        math::Q64_RESOLUTION,
+        state::{
+            pool_builder::ElysiumPoolBuilder, position_builder::PositionBuilder,
+            tick_builder::TickBuilder, ElysiumPoolRewardInfo, PoolRewardExtension,
+            PositionRewardExtension, PositionRewardInfo, Tick, NUM_EXTENSION_REWARDS,
+        },
+    };
+
+    fn reward_extension(growth_global_x64: u128) -> PoolRewardExtension {
+        PoolRewardExtension {
+            reward_last_updated_timestamp: 100,
+            reward_infos: [ElysiumPoolRewardInfo {
+                mint: Pubkey::new_unique(),
+                emissions_per_second_x64: 10 << Q64_RESOLUTION,
+                growth_global_x64,
+                total_funded: 1_000_000,
+                ..Default::default()
+            }; NUM_EXTENSION_REWARDS],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn accrues_rewards_for_position_inside_range() {
+        let pool = ElysiumPoolBuilder::new()
+            .liquidity(100)
+            .tick_current_index(0)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let tick = TickBuilder::default()
+            .initialized(true)
+            .liquidity_gross(100)
+            .build();
+
+        let update = calculate_reward_extension_modify_liquidity(
+            &pool,
+            &reward_extension(0),
+            &position,
+            &PositionRewardExtension::default(),
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            0,
+            110,
+        )
+        .unwrap();
+
+        // 100 tokens emitted over 10 seconds, all of the pool liquidity belongs to the position
+        assert_eq!(
+            update.reward_infos[0].growth_global_x64,
+            1 << Q64_RESOLUTION
+        );
+        assert_eq!(
+            update.position_reward_infos,
+            [PositionRewardInfo {
+                growth_inside_checkpoint: 1 << Q64_RESOLUTION,
+                amount_owed: 100,
+            }; NUM_EXTENSION_REWARDS]
+        );
+        assert_eq!(update.tick_lower_reward_growths_outside, [0, 0]);
+    }
+
+    #[test]
+    fn initializes_tick_growths_by_convention() {
+        let pool = ElysiumPoolBuilder::new().tick_current_index(0).build();
+        let position = PositionBuilder::new(-10, 10).build();
+
+        let update = calculate_reward_extension_modify_liquidity(
+            &pool,
+            &reward_extension(5 << Q64_RESOLUTION),
+            &position,
+            &PositionRewardExtension::default(),
+            &Tick::default(),
+            [0; NUM_EXTENSION_REWARDS],
+            &Tick::default(),
+            [0; NUM_EXTENSION_REWARDS],
+            100,
+            100,
+        )
+        .unwrap();
+
+        // The lower tick is below the current tick, so all prior growth is assumed to be below it
+        assert_eq!(
+            update.tick_lower_reward_growths_outside,
+            [5 << Q64_RESOLUTION; NUM_EXTENSION_REWARDS]
+        );
+        assert_eq!(update.tick_upper_reward_growths_outside, [0, 0]);
+        // Nothing was earned before the position had liquidity
+        assert_eq!(update.position_reward_infos[0].amount_owed, 0);
+        assert_eq!(update.position_reward_infos[0].growth_inside_checkpoint, 0);
+    }
+
+    #[test]
+    fn position_extension_starts_from_current_growth_inside() {
+        let pool = ElysiumPoolBuilder::new()
+            .liquidity(100)
+            .tick_current_index(0)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let tick = TickBuilder::default()
+            .initialized(true)
+            .liquidity_gross(100)
+            .build();
+
+        // The tick array extensions were created after rewards had already been emitted
+        let (reward_infos, reward_growths_inside) = calculate_reward_extension_growths_inside(
+            &pool,
+            &reward_extension(5 << Q64_RESOLUTION),
+            &position,
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            110,
+        )
+        .unwrap();
+        assert_eq!(reward_infos[0].growth_global_x64, 6 << Q64_RESOLUTION);
+        assert_eq!(
+            reward_growths_inside,
+            [6 << Q64_RESOLUTION; NUM_EXTENSION_REWARDS]
+        );
+
+        let mut position_extension = PositionRewardExtension::default();
+        position_extension.initialize(Pubkey::new_unique(), reward_growths_inside);
+        let mut reward_extension = reward_extension(0);
+        reward_extension.update_rewards(reward_infos, 110);
+
+        let update = calculate_reward_extension_modify_liquidity(
+            &pool,
+            &reward_extension,
+            &position,
+            &position_extension,
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            &tick,
+            [0; NUM_EXTENSION_REWARDS],
+            0,
+            120,
+        )
+        .unwrap();
+
+        // Only the rewards emitted since the position extension was created are owed
+        assert_eq!(update.position_reward_infos[0].amount_owed, 100);
+    }
+}
@@ -20,6 +20,8 @@ pub struct PostSwapUpdate {
     pub next_fee_growth_global: u128,
     pub next_reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
     pub next_protocol_fee: u64,
+    pub next_creator_fee: u64,
+    pub crossed_tick_indexes: Vec<i32>,
 }
 
 pub fn swap(
@@ -30,6 +32,7 @@ pub fn swap(
     amount_specified_is_input: bool,
     a_to_b: bool,
     timestamp: u64,
+    slot: u64,
 ) -> Result<PostSwapUpdate> {
     if sqrt_price_limit < MIN_SQRT_PRICE_X64 || sqrt_price_limit > MAX_SQRT_PRICE_X64 {
         return Err(ErrorCode::SqrtPriceOutOfBounds.into());
@@ -45,8 +48,9 @@ pub fn swap(
     }
 
     let tick_spacing = pool.tick_spacing;
-    let fee_rate = pool.fee_rate;
+    let fee_rate = pool.effective_fee_rate(timestamp, slot);
     let protocol_fee_rate = pool.protocol_fee_rate;
+    let creator_fee_rate = pool.creator_fee_rate;
     let next_reward_infos = next_pool_reward_infos(pool, timestamp)?;
 
     let mut amount_remaining: u64 = amount;
@@ -55,7 +59,9 @@ pub fn swap(
     let mut curr_tick_index = pool.tick_current_index;
     let mut curr_liquidity = pool.liquidity;
     let mut curr_protocol_fee: u64 = 0;
+    let mut curr_creator_fee: u64 = 0;
     let mut curr_array_index: usize = 0;
+    let mut crossed_tick_indexes = Vec::new();
     let mut curr_fee_growth_global_input = if a_to_b {
         pool.fee_growth_global_a
     } else {
@@ -108,14 +114,19 @@ pub fn swap(
                 .ok_or(ErrorCode::AmountCalcOverflow)?;
         }
 
-        let (next_protocol_fee, next_fee_growth_global_input) = calculate_fees(
+        let (next_protocol_fee, next_creator_fee, next_fee_growth_global_input) = calculate_fees(
             swap_computation.fee_amount,
+            pool.fee_rate,
+            fee_rate,
             protocol_fee_rate,
+            creator_fee_rate,
             curr_liquidity,
             curr_protocol_fee,
+            curr_creator_fee,
             curr_fee_growth_global_input,
         );
         curr_protocol_fee = next_protocol_fee;
+        curr_creator_fee = next_creator_fee;
         curr_fee_growth_global_input = next_fee_growth_global_input;
 
         if swap_computation.next_price == next_tick_sqrt_price {
@@ -146,6 +157,7 @@ pub fn swap(
                     tick_spacing,
                     &update,
                 )?;
+                crossed_tick_indexes.push(next_tick_index);
             }
 
             let tick_offset = swap_tick_sequence.get_tick_offset(
@@ -203,30 +215,59 @@ pub fn swap(
         next_fee_growth_global: curr_fee_growth_global_input,
         next_reward_infos,
         next_protocol_fee: curr_protocol_fee,
+        next_creator_fee: curr_creator_fee,
+        crossed_tick_indexes,
     })
 }
 
 fn calculate_fees(
     fee_amount: u64,
+    fee_rate: u16,
+    effective_fee_rate: u32,
     protocol_fee_rate: u16,
+    creator_fee_rate: u16,
     curr_liquidity: u128,
     curr_protocol_fee: u64,
+    curr_creator_fee: u64,
     curr_fee_growth_global_input: u128,
-) -> (u64, u128) {
+) -> (u64, u64, u128) {
     let mut next_protocol_fee = curr_protocol_fee;
+    let mut next_creator_fee = curr_creator_fee;
     let mut next_fee_growth_global_input = curr_fee_growth_global_input;
     let mut global_fee = fee_amount;
+    // The protocol and creator fees only apply to the portion of the fee charged at the pool's
+    // fee rate. The excess charged by a launch fee schedule goes entirely to LPs.
+    let base_fee = calculate_base_fee(global_fee, fee_rate, effective_fee_rate);
     if protocol_fee_rate > 0 {
-        let delta = calculate_protocol_fee(global_fee, protocol_fee_rate);
+        let delta = calculate_protocol_fee(base_fee, protocol_fee_rate);
         global_fee -= delta;
         next_protocol_fee = next_protocol_fee.wrapping_add(delta);
     }
+    if creator_fee_rate > 0 {
+        // Creator fee rate is stored as basis points, the same as the protocol fee rate
+        let delta = calculate_protocol_fee(base_fee, creator_fee_rate);
+        global_fee -= delta;
+        next_creator_fee = next_creator_fee.wrapping_add(delta);
+    }
 
     if curr_liquidity > 0 {
         next_fee_growth_global_input = next_fee_growth_global_input
             .wrapping_add(((global_fee as u128) << Q64_RESOLUTION) / curr_liquidity);
     }
-    (next_protocol_fee, next_fee_growth_global_input)
+    (
+        next_protocol_fee,
+        next_creator_fee,
+        next_fee_growth_global_input,
+    )
+}
+
+fn calculate_base_fee(fee_amount: u64, fee_rate: u16, effective_fee_rate: u32) -> u64 {
+    if effective_fee_rate <= fee_rate as u32 {
+        return fee_amount;
+    }
+    ((fee_amount as u128) * (fee_rate as u128) / (effective_fee_rate as u128))
+        .try_into()
+        .unwrap()
 }
 
 fn calculate_protocol_fee(global_fee: u64, protocol_fee_rate: u16) -> u64 {
@@ -2543,3 +2584,38 @@ mod swap_error_tests {
         swap_test_info.run(&mut tick_sequence, 100);
     }
 }
+
+#[cfg(test)]
+mod swap_fee_tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_fees_protocol_fee_on_base_fee_only() {
+        // 10% protocol fee on a pool charging 1% while the launch fee is at 4%.
+        let (protocol_fee, creator_fee, fee_growth) =
+            calculate_fees(4_000, 10_000, 40_000, 1_000, 0, 1, 0, 0, 0);
+        assert_eq!((protocol_fee, creator_fee), (100, 0));
+        assert_eq!(fee_growth, 3_900 << Q64_RESOLUTION);
+
+        // Without a launch fee the protocol fee applies to the whole fee amount.
+        let (protocol_fee, creator_fee, fee_growth) =
+            calculate_fees(4_000, 10_000, 10_000, 1_000, 0, 1, 0, 0, 0);
+        assert_eq!((protocol_fee, creator_fee), (400, 0));
+        assert_eq!(fee_growth, 3_600 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_calculate_fees_creator_fee() {
+        // 10% protocol fee and 5% creator fee, accumulated onto previously owed fees.
+        let (protocol_fee, creator_fee, fee_growth) =
+            calculate_fees(4_000, 10_000, 10_000, 1_000, 500, 1, 1, 2, 0);
+        assert_eq!((protocol_fee, creator_fee), (401, 202));
+        assert_eq!(fee_growth, 3_400 << Q64_RESOLUTION);
+
+        // The creator fee is also limited to the base fee during a launch fee schedule.
+        let (_, creator_fee, fee_growth) =
+            calculate_fees(4_000, 10_000, 40_000, 0, 500, 1, 0, 0, 0);
+        assert_eq!(creator_fee, 50);
+        assert_eq!(fee_growth, 3_950 << Q64_RESOLUTION);
+    }
+}
@@ -15,16 +15,28 @@ pub fn next_tick_cross_update(
     update.fee_growth_outside_a = fee_growth_global_a.wrapping_sub(tick.fee_growth_outside_a);
     update.fee_growth_outside_b = fee_growth_global_b.wrapping_sub(tick.fee_growth_outside_b);
 
-    for i in 0..NUM_REWARDS {
+    update.reward_growths_outside =
+        next_reward_growths_outside_on_cross(tick.reward_growths_outside, reward_infos);
+    Ok(update)
+}
+
+// Calculates the reward growths outside of a tick after the tick is crossed.
+// The growths of uninitialized rewards are left unchanged.
+pub fn next_reward_growths_outside_on_cross<const N: usize>(
+    reward_growths_outside: [u128; N],
+    reward_infos: &[ElysiumPoolRewardInfo; N],
+) -> [u128; N] {
+    let mut next_reward_growths_outside = reward_growths_outside;
+    for i in 0..N {
         if !reward_infos[i].initialized() {
             continue;
         }
 
-        update.reward_growths_outside[i] = reward_infos[i]
+        next_reward_growths_outside[i] = reward_infos[i]
             .growth_global_x64
-            .wrapping_sub(tick.reward_growths_outside[i]);
+            .wrapping_sub(reward_growths_outside[i]);
     }
-    Ok(update)
+    next_reward_growths_outside
 }
 
 pub fn next_tick_modify_liquidity_update(
@@ -49,25 +61,25 @@ pub fn next_tick_modify_liquidity_update(
         return Ok(TickUpdate::default());
     }
 
-    let (fee_growth_outside_a, fee_growth_outside_b, reward_growths_outside) =
-        if tick.liquidity_gross == 0 {
-            // By convention, assume all prior growth happened below the tick
-            if tick_current_index >= tick_index {
-                (
-                    fee_growth_global_a,
-                    fee_growth_global_b,
-                    ElysiumPoolRewardInfo::to_reward_growths(reward_infos),
-                )
-            } else {
-                (0, 0, [0; NUM_REWARDS])
-            }
+    let (fee_growth_outside_a, fee_growth_outside_b) = if tick.liquidity_gross == 0 {
+        // By convention, assume all prior growth happened below the tick
+        if tick_current_index >= tick_index {
+            (fee_growth_global_a, fee_growth_global_b)
         } else {
-            (
-                tick.fee_growth_outside_a,
-                tick.fee_growth_outside_b,
-                tick.reward_growths_outside,
-            )
-        };
+            (0, 0)
+        }
+    } else {
+        (tick.fee_growth_outside_a, tick.fee_growth_outside_b)
+    };
+
+    let reward_growths_outside = next_reward_growths_outside_on_modify(
+        tick,
+        tick.reward_growths_outside,
+        tick_index,
+        tick_current_index,
+        reward_infos,
+        liquidity_delta,
+    )?;
 
     let liquidity_net = if is_upper_tick {
         tick.liquidity_net
@@ -89,6 +101,38 @@ pub fn next_tick_modify_liquidity_update(
     })
 }
 
+// Calculates the reward growths outside of a tick after modifying its liquidity by the
+// liquidity_delta. The initialized state and liquidity of the tick decide whether the growths are
+// reset, initialized or left unchanged.
+pub fn next_reward_growths_outside_on_modify<const N: usize>(
+    tick: &Tick,
+    reward_growths_outside: [u128; N],
+    tick_index: i32,
+    tick_current_index: i32,
+    reward_infos: &[ElysiumPoolRewardInfo; N],
+    liquidity_delta: i128,
+) -> Result<[u128; N], ErrorCode> {
+    if liquidity_delta == 0 {
+        return Ok(reward_growths_outside);
+    }
+
+    // Uninitialized ticks do not track any growth
+    if add_liquidity_delta(tick.liquidity_gross, liquidity_delta)? == 0 {
+        return Ok([0; N]);
+    }
+
+    if tick.liquidity_gross != 0 {
+        return Ok(reward_growths_outside);
+    }
+
+    // By convention, assume all prior growth happened below the tick
+    if tick_current_index >= tick_index {
+        Ok(ElysiumPoolRewardInfo::to_reward_growths(reward_infos))
+    } else {
+        Ok([0; N])
+    }
+}
+
 // Calculates the fee growths inside of tick_lower and tick_upper based on their
 // index relative to tick_current_index.
 pub fn next_fee_growths_inside(
@@ -150,33 +194,57 @@ pub fn next_reward_growths_inside(
     tick_upper_index: i32,
     reward_infos: &[ElysiumPoolRewardInfo; NUM_REWARDS],
 ) -> [u128; NUM_REWARDS] {
-    let mut reward_growths_inside = [0; NUM_REWARDS];
+    next_reward_growths_inside_from_outside(
+        tick_current_index,
+        tick_lower.initialized,
+        tick_lower_index,
+        tick_lower.reward_growths_outside,
+        tick_upper.initialized,
+        tick_upper_index,
+        tick_upper.reward_growths_outside,
+        reward_infos,
+    )
+}
+
+// Calculates the reward growths inside of two ticks from their reward growths outside. This allows
+// rewards tracked outside of the Tick struct, such as extension rewards, to share the same math.
+pub fn next_reward_growths_inside_from_outside<const N: usize>(
+    tick_current_index: i32,
+    tick_lower_initialized: bool,
+    tick_lower_index: i32,
+    tick_lower_reward_growths_outside: [u128; N],
+    tick_upper_initialized: bool,
+    tick_upper_index: i32,
+    tick_upper_reward_growths_outside: [u128; N],
+    reward_infos: &[ElysiumPoolRewardInfo; N],
+) -> [u128; N] {
+    let mut reward_growths_inside = [0; N];
 
-    for i in 0..NUM_REWARDS {
+    for i in 0..N {
         if !reward_infos[i].initialized() {
             continue;
         }
 
         // By convention, assume all prior growth happened below the tick
-        let reward_growths_below = if !tick_lower.initialized {
+        let reward_growths_below = if !tick_lower_initialized {
             reward_infos[i].growth_global_x64
         } else if tick_current_index < tick_lower_index {
             reward_infos[i]
                 .growth_global_x64
-                .wrapping_sub(tick_lower.reward_growths_outside[i])
+                .wrapping_sub(tick_lower_reward_growths_outside[i])
         } else {
-            tick_lower.reward_growths_outside[i]
+            tick_lower_reward_growths_outside[i]
         };
 
         // By convention, assume all prior growth happened below the tick, not above
-        let reward_growths_above = if !tick_upper.initialized {
+        let reward_growths_above = if !tick_upper_initialized {
             0
         } else if tick_current_index < tick_upper_index {
-            tick_upper.reward_growths_outside[i]
+            tick_upper_reward_growths_outside[i]
         } else {
             reward_infos[i]
                 .growth_global_x64
-                .wrapping_sub(tick_upper.reward_growths_outside[i])
+                .wrapping_sub(tick_upper_reward_growths_outside[i])
         };
 
         reward_growths_inside[i] = reward_infos[i]
@@ -13,7 +13,7 @@ pub struct SwapStepComputation {
 
 pub fn compute_swap(
     amount_remaining: u64,
-    fee_rate: u16,
+    fee_rate: u32,
     liquidity: u128,
     sqrt_price_current: u128,
     sqrt_price_target: u128,
@@ -92,7 +92,14 @@ pub fn compute_swap(
             fee_rate as u128,
             FEE_RATE_MUL_VALUE - fee_rate as u128,
         )?
-        .try_into()?;
+        .try_into()
+        .map_err(|_| ErrorCode::AmountCalcOverflow)?;
+    }
+
+    // At launch fee rates the fee can be several times the amount in, so the input required for
+    // an exact output may not fit in a token amount.
+    if amount_in.checked_add(fee_amount).is_none() {
+        return Err(ErrorCode::AmountCalcOverflow);
     }
 
     Ok(SwapStepComputation {
@@ -161,7 +168,7 @@ mod fuzz_tests {
         fn test_compute_swap(
             amount in 1..u64::MAX,
             liquidity in 1..u32::MAX as u128,
-            fee_rate in 1..u16::MAX,
+            fee_rate in 1..MAX_LAUNCH_FEE_RATE,
             price_0 in MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64,
             price_1 in MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64,
             amount_specified_is_input in proptest::bool::ANY,
@@ -171,7 +178,7 @@ mod fuzz_tests {
             // Rather than use logic to correctly input the prices, we just use the distribution to determine direction
             let a_to_b = price_0 >= price_1;
 
-            let swap_computation = compute_swap(
+            let swap_computation = match compute_swap(
                 amount,
                 fee_rate,
                 liquidity,
@@ -179,7 +186,15 @@ mod fuzz_tests {
                 price_1,
                 amount_specified_is_input,
                 a_to_b,
-            ).ok().unwrap();
+            ) {
+                Ok(swap_computation) => swap_computation,
+                Err(error) => {
+                    // Only an exact output can require more input than a token amount holds.
+                    assert!(!amount_specified_is_input);
+                    assert_eq!(error, ErrorCode::AmountCalcOverflow);
+                    return Ok(());
+                }
+            };
 
             let amount_in = swap_computation.amount_in;
             let amount_out = swap_computation.amount_out;
@@ -211,7 +226,7 @@ mod fuzz_tests {
         fn test_compute_swap_inversion(
             amount in 1..u64::MAX,
             liquidity in 1..u32::MAX as u128,
-            fee_rate in 1..u16::MAX,
+            fee_rate in 1..MAX_LAUNCH_FEE_RATE,
             price_0 in MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64,
             price_1 in MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64,
             amount_specified_is_input in proptest::bool::ANY,
@@ -221,7 +236,7 @@ mod fuzz_tests {
             // Rather than use logic to correctly input the prices, we just use the distribution to determine direction
             let a_to_b = price_0 >= price_1;
 
-            let swap_computation = compute_swap(
+            let swap_computation = match compute_swap(
                 amount,
                 fee_rate,
                 liquidity,
@@ -229,7 +244,15 @@ mod fuzz_tests {
                 price_1,
                 amount_specified_is_input,
                 a_to_b,
-            ).ok().unwrap();
+            ) {
+                Ok(swap_computation) => swap_computation,
+                Err(error) => {
+                    // Only an exact output can require more input than a token amount holds.
+                    assert!(!amount_specified_is_input);
+                    assert_eq!(error, ErrorCode::AmountCalcOverflow);
+                    return Ok(());
+                }
+            };
 
             let amount_in = swap_computation.amount_in;
             let amount_out = swap_computation.amount_out;
@@ -243,7 +266,7 @@ mod fuzz_tests {
             };
 
             if inverted_amount != 0 {
-                let inverted = compute_swap(
+                let inverted = match compute_swap(
                     inverted_amount,
                     fee_rate,
                     liquidity,
@@ -251,7 +274,16 @@ mod fuzz_tests {
                     price_1,
                     !amount_specified_is_input,
                     a_to_b,
-                ).ok().unwrap();
+                ) {
+                    Ok(inverted) => inverted,
+                    Err(error) => {
+                        // The output rounds down, so asking for it exactly can swap to the target
+                        // price and require more input than a token amount holds.
+                        assert!(amount_specified_is_input);
+                        assert_eq!(error, ErrorCode::AmountCalcOverflow);
+                        return Ok(());
+                    }
+                };
 
                 // A to B = price decreasing
 
@@ -916,6 +948,21 @@ mod unit_tests {
                 },
             );
         }
+
+        #[test]
+        fn swap_exact_output_overflowing_input_at_launch_fee_rate() {
+            // The fee at a 65.8% launch fee rate pushes the required input past u64::MAX.
+            let swap_computation = compute_swap(
+                8,
+                658635,
+                1962664397,
+                3786658470826605048000807043,
+                62971759276405725446582589486,
+                false,
+                false,
+            );
+            assert_eq!(swap_computation, Err(ErrorCode::AmountCalcOverflow));
+        }
     }
 
     fn test_swap(
@@ -930,7 +977,7 @@ mod unit_tests {
     ) {
         let swap_computation = compute_swap(
             amount_remaining,
-            fee_rate,
+            fee_rate.into(),
             liquidity,
             sqrt_price_current,
             sqrt_price_target_limit,
@@ -11,6 +11,12 @@ use super::{
 // Max fee rate supported is 3%.
 pub const MAX_FEE_RATE: u16 = 30_000;
 
+// Max fee rate supported at the start of a launch fee schedule is 90%.
+pub const MAX_LAUNCH_FEE_RATE: u32 = 900_000;
+
+// Max creator fee rate supported is 25% of the fee, stored as basis points like the protocol fee.
+pub const MAX_CREATOR_FEE_RATE: u16 = 2_500;
+
 // Assuming that FEE_RATE is represented as hundredths of a basis point
 // We want FEE_RATE_MUL_VALUE = 1/FEE_RATE_UNIT, so 1e6
 pub const FEE_RATE_MUL_VALUE: u128 = 1_000_000;
@@ -91,6 +97,61 @@ pub fn get_amount_delta_b(
     checked_mul_shift_right_round_up_if(liquidity, price_upper - price_lower, round_up)
 }
 
+//
+// Get liquidity corresponding to a deposit of token_a over a price range
+//
+
+// Invert 6.16
+// liquidity = Δt_a * (sqrt_price_upper * sqrt_price_lower) / (sqrt_price_upper - sqrt_price_lower)
+//
+// The result is rounded down so that depositing the liquidity never requires more than Δt_a.
+pub fn get_liquidity_from_amount_a(
+    sqrt_price_0: u128,
+    sqrt_price_1: u128,
+    amount: u64,
+) -> Result<u128, ErrorCode> {
+    let (sqrt_price_lower, sqrt_price_upper) = increasing_price_order(sqrt_price_0, sqrt_price_1);
+
+    let sqrt_price_diff = sqrt_price_upper - sqrt_price_lower;
+    if sqrt_price_diff == 0 {
+        return Err(ErrorCode::DivideByZero);
+    }
+
+    // Q64.64 * Q64.64 >> 64 => Q64.64, then * Q64.0 => Q128.64
+    let numerator = mul_u256(sqrt_price_upper, sqrt_price_lower)
+        .shift_word_right()
+        .mul(U256Muldiv::new(0, amount as u128));
+
+    let (quotient, _) = numerator.div(U256Muldiv::new(0, sqrt_price_diff), false);
+
+    quotient
+        .try_into_u128()
+        .map_err(|_| ErrorCode::LiquidityOverflow)
+}
+
+//
+// Get liquidity corresponding to a deposit of token_b over a price range
+//
+
+// Invert 6.14
+// liquidity = Δt_b / (sqrt_price_upper - sqrt_price_lower)
+//
+// The result is rounded down so that depositing the liquidity never requires more than Δt_b.
+pub fn get_liquidity_from_amount_b(
+    sqrt_price_0: u128,
+    sqrt_price_1: u128,
+    amount: u64,
+) -> Result<u128, ErrorCode> {
+    let (sqrt_price_lower, sqrt_price_upper) = increasing_price_order(sqrt_price_0, sqrt_price_1);
+
+    // Q64.0 << 64 => Q64.64, then / Q64.64 => Q64.0
+    div_round_up_if(
+        (amount as u128) << Q64_RESOLUTION,
+        sqrt_price_upper - sqrt_price_lower,
+        false,
+    )
+}
+
 pub fn increasing_price_order(sqrt_price_0: u128, sqrt_price_1: u128) -> (u128, u128) {
     if sqrt_price_0 > sqrt_price_1 {
         (sqrt_price_1, sqrt_price_0)
@@ -457,6 +518,50 @@ mod fuzz_tests {
     }
 }
 
+#[cfg(test)]
+mod test_get_liquidity_from_amount {
+    use super::*;
+    use crate::math::sqrt_price_from_tick_index;
+
+    #[test]
+    fn test_get_liquidity_from_amount_ok() {
+        // A
+        assert_eq!(get_liquidity_from_amount_a(4 << 64, 2 << 64, 1).unwrap(), 4);
+        assert_eq!(get_liquidity_from_amount_a(2 << 64, 4 << 64, 1).unwrap(), 4);
+
+        // B
+        assert_eq!(get_liquidity_from_amount_b(4 << 64, 2 << 64, 8).unwrap(), 4);
+        assert_eq!(get_liquidity_from_amount_b(2 << 64, 4 << 64, 8).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_get_liquidity_from_amount_price_diff_zero() {
+        assert!(get_liquidity_from_amount_a(4 << 64, 4 << 64, 1).is_err());
+        assert!(get_liquidity_from_amount_b(4 << 64, 4 << 64, 1).is_err());
+    }
+
+    #[test]
+    fn test_get_liquidity_from_amount_never_exceeds_amount() {
+        let sqrt_price_lower = sqrt_price_from_tick_index(-1280);
+        let sqrt_price_upper = sqrt_price_from_tick_index(2560);
+        let amount = 1_000_000_007;
+
+        let liquidity_a =
+            get_liquidity_from_amount_a(sqrt_price_lower, sqrt_price_upper, amount).unwrap();
+        let delta_a =
+            get_amount_delta_a(sqrt_price_lower, sqrt_price_upper, liquidity_a, true).unwrap();
+        assert!(delta_a <= amount);
+        assert!(amount - delta_a <= 1);
+
+        let liquidity_b =
+            get_liquidity_from_amount_b(sqrt_price_lower, sqrt_price_upper, amount).unwrap();
+        let delta_b =
+            get_amount_delta_b(sqrt_price_lower, sqrt_price_upper, liquidity_b, true).unwrap();
+        assert!(delta_b <= amount);
+        assert!(amount - delta_b <= 1);
+    }
+}
+
 #[cfg(test)]
 mod test_get_amount_delta {
     // Δt_a = ((liquidity * (sqrt_price_lower - sqrt_price_upper)) / sqrt_price_upper) / sqrt_price_lower
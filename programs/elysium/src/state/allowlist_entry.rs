@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Grants a wallet access to a permissioned ElysiumPool. Entries are created and closed by the
+/// access authority of the pool.
+#[account]
+#[derive(Default)]
+pub struct AllowlistEntry {
+    pub pool: Pubkey,   // 32
+    pub wallet: Pubkey, // 32
+}
+
+impl AllowlistEntry {
+    pub const LEN: usize = 8 + 64;
+
+    pub fn initialize(&mut self, pool: Pubkey, wallet: Pubkey) {
+        self.pool = pool;
+        self.wallet = wallet;
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, math::MAX_PROTOCOL_FEE_RATE};
+use crate::{
+    errors::ErrorCode,
+    math::{MAX_CREATOR_FEE_RATE, MAX_PROTOCOL_FEE_RATE},
+};
 
 #[account]
 pub struct ElysiumPoolsConfig {
@@ -9,9 +12,13 @@ pub struct ElysiumPoolsConfig {
     pub reward_emissions_super_authority: Pubkey,
 
     pub default_protocol_fee_rate: u16,
+
+    // Upper bound on the creator fee rate of pools in this config, stored as basis points
+    pub max_creator_fee_rate: u16,
 }
 
 impl ElysiumPoolsConfig {
+    // max_creator_fee_rate fits in the padding of the original account size
     pub const LEN: usize = 8 + 96 + 4;
 
     pub fn update_fee_authority(&mut self, fee_authority: Pubkey) {
@@ -58,4 +65,13 @@ impl ElysiumPoolsConfig {
 
         Ok(())
     }
+
+    pub fn update_max_creator_fee_rate(&mut self, max_creator_fee_rate: u16) -> Result<()> {
+        if max_creator_fee_rate > MAX_CREATOR_FEE_RATE {
+            return Err(ErrorCode::CreatorFeeRateMaxExceeded.into());
+        }
+        self.max_creator_fee_rate = max_creator_fee_rate;
+
+        Ok(())
+    }
 }
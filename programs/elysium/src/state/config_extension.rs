@@ -0,0 +1,310 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, state::LaunchFeeSchedule};
+
+pub const NUM_HOOK_PROGRAMS: usize = 4;
+
+/// Number of seconds between allowlisting a hook program and pools being able to use it, giving
+/// LPs time to withdraw from pools that could start invoking it.
+pub const HOOK_PROGRAM_TIMELOCK_SECONDS: u64 = 60 * 60 * 24 * 7;
+
+/// Settings of an ElysiumPoolsConfig that do not fit in the config account. Pools of a config
+/// without an extension are initialized with the strictest settings.
+#[account]
+#[derive(Default)]
+pub struct ElysiumPoolsConfigExtension {
+    pub pools_config: Pubkey, // 32
+
+    // Upper bound on how far past its creation the activation of a pool can be set
+    pub max_activation_delay: u64,      // 8, seconds
+    pub max_activation_slot_delay: u64, // 8, slots
+
+    // Upper bound on the decay period of the launch fee schedule of a pool
+    pub max_launch_fee_decay_period: u64, // 8, seconds
+    pub max_launch_fee_decay_slots: u64,  // 8, slots
+
+    // Authority that signs the creation of permissioned pools. No permissioned pools can be
+    // created while it is the default pubkey.
+    pub permissioned_pool_authority: Pubkey, // 32
+
+    // Hook programs pools of the config can be set to use
+    pub hook_programs: [HookProgramEntry; NUM_HOOK_PROGRAMS], // 160
+}
+
+impl ElysiumPoolsConfigExtension {
+    pub const LEN: usize = 8 + 32 + 16 + 16 + 32 + 160;
+
+    pub fn find_address(pools_config: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"config_extension", pools_config.as_ref()], &crate::ID).0
+    }
+
+    pub fn initialize(&mut self, pools_config: Pubkey) {
+        self.pools_config = pools_config;
+    }
+
+    pub fn update_max_activation_delay(
+        &mut self,
+        max_activation_delay: u64,
+        max_activation_slot_delay: u64,
+    ) {
+        self.max_activation_delay = max_activation_delay;
+        self.max_activation_slot_delay = max_activation_slot_delay;
+    }
+
+    pub fn update_max_launch_fee_decay_period(
+        &mut self,
+        max_launch_fee_decay_period: u64,
+        max_launch_fee_decay_slots: u64,
+    ) {
+        self.max_launch_fee_decay_period = max_launch_fee_decay_period;
+        self.max_launch_fee_decay_slots = max_launch_fee_decay_slots;
+    }
+
+    pub fn update_permissioned_pool_authority(&mut self, permissioned_pool_authority: Pubkey) {
+        self.permissioned_pool_authority = permissioned_pool_authority;
+    }
+
+    /// Returns true if the key is the authority that can create permissioned pools.
+    pub fn is_permissioned_pool_authority(&self, key: Pubkey) -> bool {
+        self.permissioned_pool_authority.ne(&Pubkey::default())
+            && self.permissioned_pool_authority.eq(&key)
+    }
+
+    /// Allowlists a hook program, which pools can use once the timelock has elapsed. Adding a
+    /// program that is already allowlisted keeps its original timelock.
+    ///
+    /// # Errors
+    /// - `HookProgramAllowlistFull` - Every hook program slot is in use.
+    pub fn add_hook_program(&mut self, hook_program: Pubkey, timestamp: u64) -> Result<()> {
+        if self.find_hook_program(hook_program).is_some() {
+            return Ok(());
+        }
+        let entry = self
+            .hook_programs
+            .iter_mut()
+            .find(|entry| entry.program.eq(&Pubkey::default()))
+            .ok_or(ErrorCode::HookProgramAllowlistFull)?;
+        entry.program = hook_program;
+        entry.allowed_timestamp = timestamp.saturating_add(HOOK_PROGRAM_TIMELOCK_SECONDS);
+
+        Ok(())
+    }
+
+    /// Removes a hook program from the allowlist. Pools already using it keep invoking it until
+    /// their hook is changed.
+    ///
+    /// # Errors
+    /// - `HookProgramNotAllowlisted` - The hook program is not allowlisted.
+    pub fn remove_hook_program(&mut self, hook_program: Pubkey) -> Result<()> {
+        let index = self
+            .find_hook_program(hook_program)
+            .ok_or(ErrorCode::HookProgramNotAllowlisted)?;
+        self.hook_programs[index] = HookProgramEntry::default();
+
+        Ok(())
+    }
+
+    /// Verifies that a pool can be set to use the hook program. Removing the hook of a pool,
+    /// by setting the default pubkey, is always allowed.
+    ///
+    /// # Errors
+    /// - `HookProgramNotAllowlisted` - The hook program is not allowlisted.
+    /// - `HookProgramTimelocked` - The timelock of the hook program has not elapsed.
+    pub fn verify_hook_program(&self, hook_program: Pubkey, timestamp: u64) -> Result<()> {
+        if hook_program.eq(&Pubkey::default()) {
+            return Ok(());
+        }
+        let index = self
+            .find_hook_program(hook_program)
+            .ok_or(ErrorCode::HookProgramNotAllowlisted)?;
+        if timestamp < self.hook_programs[index].allowed_timestamp {
+            return Err(ErrorCode::HookProgramTimelocked.into());
+        }
+
+        Ok(())
+    }
+
+    fn find_hook_program(&self, hook_program: Pubkey) -> Option<usize> {
+        if hook_program.eq(&Pubkey::default()) {
+            return None;
+        }
+        self.hook_programs
+            .iter()
+            .position(|entry| entry.program.eq(&hook_program))
+    }
+
+    /// Returns the upper bound on the decay period of a launch fee schedule, in the unit the
+    /// schedule decays in.
+    pub fn max_decay_period(&self, launch_fee_schedule: &LaunchFeeSchedule) -> u64 {
+        if launch_fee_schedule.decay_in_slots {
+            self.max_launch_fee_decay_slots
+        } else {
+            self.max_launch_fee_decay_period
+        }
+    }
+
+    /// Verifies that the activation of a pool created at the given timestamp and slot is not
+    /// delayed past the maximum activation delay.
+    ///
+    /// # Errors
+    /// - `ActivationDelayMaxExceeded` - The activation is later than the maximum delay allows.
+    pub fn verify_activation(
+        &self,
+        activation_timestamp: u64,
+        activation_slot: u64,
+        timestamp: u64,
+        slot: u64,
+    ) -> Result<()> {
+        if activation_timestamp > timestamp.saturating_add(self.max_activation_delay)
+            || activation_slot > slot.saturating_add(self.max_activation_slot_delay)
+        {
+            return Err(ErrorCode::ActivationDelayMaxExceeded.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Hook program pools of a config can use from the allowed timestamp.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct HookProgramEntry {
+    pub program: Pubkey,        // 32
+    pub allowed_timestamp: u64, // 8
+}
+
+#[cfg(test)]
+mod config_extension_tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_activation_without_delay() {
+        let config_extension = ElysiumPoolsConfigExtension::default();
+        assert!(config_extension
+            .verify_activation(1_000, 50, 1_000, 50)
+            .is_ok());
+        // Activations in the past are equivalent to activating on creation
+        assert!(config_extension.verify_activation(0, 0, 1_000, 50).is_ok());
+        assert!(config_extension
+            .verify_activation(1_001, 50, 1_000, 50)
+            .is_err());
+        assert!(config_extension
+            .verify_activation(1_000, 51, 1_000, 50)
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_activation_with_max_delay() {
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        config_extension.update_max_activation_delay(3_600, 9_000);
+        assert!(config_extension
+            .verify_activation(4_600, 9_050, 1_000, 50)
+            .is_ok());
+        assert!(config_extension
+            .verify_activation(4_601, 50, 1_000, 50)
+            .is_err());
+        assert!(config_extension
+            .verify_activation(1_000, 9_051, 1_000, 50)
+            .is_err());
+        assert!(config_extension
+            .verify_activation(u64::MAX, u64::MAX, 1_000, 50)
+            .is_err());
+    }
+
+    #[test]
+    fn test_is_permissioned_pool_authority() {
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        assert!(!config_extension.is_permissioned_pool_authority(Pubkey::default()));
+
+        let authority = Pubkey::new_unique();
+        config_extension.update_permissioned_pool_authority(authority);
+        assert!(config_extension.is_permissioned_pool_authority(authority));
+        assert!(!config_extension.is_permissioned_pool_authority(Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_max_decay_period_in_unit_of_schedule() {
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        let schedule = LaunchFeeSchedule {
+            initial_fee_rate: 10_000,
+            decay_period: 100,
+            decay_in_slots: false,
+        };
+        assert_eq!(config_extension.max_decay_period(&schedule), 0);
+
+        config_extension.update_max_launch_fee_decay_period(3_600, 9_000);
+        assert_eq!(config_extension.max_decay_period(&schedule), 3_600);
+        assert_eq!(
+            config_extension.max_decay_period(&LaunchFeeSchedule {
+                decay_in_slots: true,
+                ..schedule
+            }),
+            9_000
+        );
+    }
+
+    #[test]
+    fn test_hook_program_timelock() {
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        let hook_program = Pubkey::new_unique();
+        assert!(config_extension
+            .verify_hook_program(Pubkey::default(), 0)
+            .is_ok());
+        assert_eq!(
+            config_extension.verify_hook_program(hook_program, 0),
+            Err(ErrorCode::HookProgramNotAllowlisted.into())
+        );
+
+        config_extension
+            .add_hook_program(hook_program, 1_000)
+            .unwrap();
+        assert_eq!(
+            config_extension
+                .verify_hook_program(hook_program, 1_000 + HOOK_PROGRAM_TIMELOCK_SECONDS - 1),
+            Err(ErrorCode::HookProgramTimelocked.into())
+        );
+        assert!(config_extension
+            .verify_hook_program(hook_program, 1_000 + HOOK_PROGRAM_TIMELOCK_SECONDS)
+            .is_ok());
+
+        // Adding the program again does not restart the timelock
+        config_extension
+            .add_hook_program(hook_program, 5_000)
+            .unwrap();
+        assert!(config_extension
+            .verify_hook_program(hook_program, 1_000 + HOOK_PROGRAM_TIMELOCK_SECONDS)
+            .is_ok());
+
+        config_extension.remove_hook_program(hook_program).unwrap();
+        assert_eq!(
+            config_extension.verify_hook_program(hook_program, u64::MAX),
+            Err(ErrorCode::HookProgramNotAllowlisted.into())
+        );
+        assert_eq!(
+            config_extension.remove_hook_program(hook_program),
+            Err(ErrorCode::HookProgramNotAllowlisted.into())
+        );
+    }
+
+    #[test]
+    fn test_hook_program_allowlist_full() {
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        let hook_programs: Vec<Pubkey> = (0..NUM_HOOK_PROGRAMS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for hook_program in &hook_programs {
+            config_extension.add_hook_program(*hook_program, 0).unwrap();
+        }
+        assert_eq!(
+            config_extension.add_hook_program(Pubkey::new_unique(), 0),
+            Err(ErrorCode::HookProgramAllowlistFull.into())
+        );
+
+        // Removing a program frees its slot
+        config_extension
+            .remove_hook_program(hook_programs[1])
+            .unwrap();
+        assert!(config_extension
+            .add_hook_program(Pubkey::new_unique(), 0)
+            .is_ok());
+    }
+}
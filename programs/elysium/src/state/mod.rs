@@ -1,13 +1,27 @@
+pub mod allowlist_entry;
 pub mod config;
+pub mod config_extension;
 pub mod fee_tier;
 pub mod pool;
 pub mod position;
 pub mod position_bundle;
+pub mod position_lock;
+pub mod reward_extension;
+pub mod reward_settlement;
 pub mod tick;
+pub mod tick_array_bitmap;
+pub mod tick_array_funder;
 
 pub use self::pool::*;
+pub use allowlist_entry::*;
 pub use config::*;
+pub use config_extension::*;
 pub use fee_tier::*;
 pub use position::*;
 pub use position_bundle::*;
+pub use position_lock::*;
+pub use reward_extension::*;
+pub use reward_settlement::*;
 pub use tick::*;
+pub use tick_array_bitmap::*;
+pub use tick_array_funder::*;
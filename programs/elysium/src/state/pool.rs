@@ -1,8 +1,8 @@
 use crate::{
     errors::ErrorCode,
     math::{
-        tick_index_from_sqrt_price, MAX_FEE_RATE, MAX_PROTOCOL_FEE_RATE, MAX_SQRT_PRICE_X64,
-        MIN_SQRT_PRICE_X64,
+        div_round_up, tick_index_from_sqrt_price, MAX_FEE_RATE, MAX_LAUNCH_FEE_RATE,
+        MAX_PROTOCOL_FEE_RATE, MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64, Q64_RESOLUTION,
     },
 };
 use anchor_lang::prelude::*;
@@ -15,8 +15,8 @@ pub struct ElysiumPool {
     pub pools_config: Pubkey, // 32
     pub pool_bump: [u8; 1],   // 1
 
-    pub tick_spacing: u16,          // 2
-    pub tick_spacing_seed: [u8; 2], // 2
+    pub tick_spacing: u16,            // 2
+    pub fee_tier_index_seed: [u8; 2], // 2
 
     // Stored as hundredths of a basis point
     // u16::MAX corresponds to ~6.5%
@@ -50,21 +50,46 @@ pub struct ElysiumPool {
 
     pub reward_last_updated_timestamp: u64, // 8
 
-    pub reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS], // 384
+    pub reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS], // 648
+
+    pub reward_extension: Pubkey, // 32
+
+    // Swaps are rejected before both the activation timestamp and slot are reached
+    pub activation_timestamp: u64, // 8
+    pub activation_slot: u64,      // 8
+
+    pub launch_fee_schedule: LaunchFeeSchedule, // 13
+
+    // Pool creator and their portion of the fee rate, stored as basis points
+    pub creator: Pubkey,         // 32
+    pub creator_fee_rate: u16,   // 2
+    pub creator_fee_owed_a: u64, // 8
+    pub creator_fee_owed_b: u64, // 8
+
+    pub tick_array_bitmap: Pubkey, // 32
+
+    // Program invoked before and after swaps and liquidity changes, approved by the fee authority
+    pub hook_program: Pubkey, // 32
+
+    // Trading and liquidity are restricted to allowlisted wallets while an access authority is set
+    pub access_authority: Pubkey, // 32
 }
 
 // Number of rewards supported by ElysiumPools
 pub const NUM_REWARDS: usize = 3;
 
 impl ElysiumPool {
-    pub const LEN: usize = 8 + 261 + 384;
+    pub const LEN: usize = 8 + 261 + 648 + 32 + 16 + 13 + 50 + 32 + 32 + 32;
+    // Size of pools initialized before the reward accounting, activation, launch fee, creator
+    // fee, bitmap, hook and access fields, which `migrate_pool` grows to `LEN`.
+    pub const LEN_V0: usize = 8 + 261 + 384;
     pub fn seeds(&self) -> [&[u8]; 6] {
         [
             &b"pool"[..],
             self.pools_config.as_ref(),
             self.token_mint_a.as_ref(),
             self.token_mint_b.as_ref(),
-            self.tick_spacing_seed.as_ref(),
+            self.fee_tier_index_seed.as_ref(),
             self.pool_bump.as_ref(),
         ]
     }
@@ -74,6 +99,7 @@ impl ElysiumPool {
         pools_config: &Account<ElysiumPoolsConfig>,
         bump: u8,
         tick_spacing: u16,
+        fee_tier_index: u16,
         sqrt_price: u128,
         default_fee_rate: u16,
         token_mint_a: Pubkey,
@@ -93,7 +119,7 @@ impl ElysiumPool {
         self.pool_bump = [bump];
 
         self.tick_spacing = tick_spacing;
-        self.tick_spacing_seed = self.tick_spacing.to_le_bytes();
+        self.fee_tier_index_seed = fee_tier_index.to_le_bytes();
 
         self.update_fee_rate(default_fee_rate)?;
         self.update_protocol_fee_rate(pools_config.default_protocol_fee_rate)?;
@@ -120,6 +146,108 @@ impl ElysiumPool {
         Ok(())
     }
 
+    /// Set the timestamp and slot from which the pool can be traded. Zero means no restriction.
+    pub fn update_activation(&mut self, activation_timestamp: u64, activation_slot: u64) {
+        self.activation_timestamp = activation_timestamp;
+        self.activation_slot = activation_slot;
+    }
+
+    /// Returns true if the pool can be traded at the given timestamp and slot.
+    pub fn is_activated(&self, timestamp: u64, slot: u64) -> bool {
+        timestamp >= self.activation_timestamp && slot >= self.activation_slot
+    }
+
+    /// Set the launch fee schedule, which decays from the pool's activation.
+    ///
+    /// # Errors
+    /// - `LaunchFeeRateMaxExceeded` - The initial fee rate exceeds MAX_LAUNCH_FEE_RATE.
+    /// - `InvalidLaunchFeeSchedule` - The initial fee rate is not above the pool's fee rate or
+    ///                                the decay period is zero.
+    /// - `LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds max_decay_period, which is
+    ///                                       in the unit of the schedule.
+    pub fn update_launch_fee_schedule(
+        &mut self,
+        launch_fee_schedule: LaunchFeeSchedule,
+        max_decay_period: u64,
+    ) -> Result<()> {
+        if launch_fee_schedule.initial_fee_rate > MAX_LAUNCH_FEE_RATE {
+            return Err(ErrorCode::LaunchFeeRateMaxExceeded.into());
+        }
+        if launch_fee_schedule.initial_fee_rate <= u32::from(self.fee_rate)
+            || launch_fee_schedule.decay_period == 0
+        {
+            return Err(ErrorCode::InvalidLaunchFeeSchedule.into());
+        }
+        if launch_fee_schedule.decay_period > max_decay_period {
+            return Err(ErrorCode::LaunchFeeDecayPeriodMaxExceeded.into());
+        }
+        self.launch_fee_schedule = launch_fee_schedule;
+
+        Ok(())
+    }
+
+    /// Returns the fee rate charged on swaps at the given timestamp and slot. During the launch
+    /// fee schedule this decays linearly from the initial launch fee rate to the pool's fee rate,
+    /// and may exceed MAX_FEE_RATE.
+    pub fn effective_fee_rate(&self, timestamp: u64, slot: u64) -> u32 {
+        let schedule = &self.launch_fee_schedule;
+        let fee_rate = u32::from(self.fee_rate);
+        if schedule.initial_fee_rate <= fee_rate || schedule.decay_period == 0 {
+            return fee_rate;
+        }
+
+        let elapsed = if schedule.decay_in_slots {
+            slot.saturating_sub(self.activation_slot)
+        } else {
+            timestamp.saturating_sub(self.activation_timestamp)
+        };
+        if elapsed >= schedule.decay_period {
+            return fee_rate;
+        }
+
+        // Bounded by the fee rate difference, so the cast cannot truncate.
+        let excess_fee_rate = u128::from(schedule.initial_fee_rate - fee_rate)
+            * u128::from(schedule.decay_period - elapsed)
+            / u128::from(schedule.decay_period);
+        fee_rate + excess_fee_rate as u32
+    }
+
+    /// Returns true if this pool tracks additional rewards in a PoolRewardExtension account.
+    pub fn has_reward_extension(&self) -> bool {
+        self.reward_extension.ne(&Pubkey::default())
+    }
+
+    pub fn update_reward_extension(&mut self, reward_extension: Pubkey) {
+        self.reward_extension = reward_extension;
+    }
+
+    /// Returns true if this pool records its TickArrays in a TickArrayBitmap account.
+    pub fn has_tick_array_bitmap(&self) -> bool {
+        self.tick_array_bitmap.ne(&Pubkey::default())
+    }
+
+    pub fn update_tick_array_bitmap(&mut self, tick_array_bitmap: Pubkey) {
+        self.tick_array_bitmap = tick_array_bitmap;
+    }
+
+    /// Returns true if this pool invokes a hook program around swaps and liquidity changes.
+    pub fn has_hook_program(&self) -> bool {
+        self.hook_program.ne(&Pubkey::default())
+    }
+
+    pub fn update_hook_program(&mut self, hook_program: Pubkey) {
+        self.hook_program = hook_program;
+    }
+
+    /// Returns true if only allowlisted wallets can trade and provide liquidity in this pool.
+    pub fn is_permissioned(&self) -> bool {
+        self.access_authority.ne(&Pubkey::default())
+    }
+
+    pub fn update_access_authority(&mut self, access_authority: Pubkey) {
+        self.access_authority = access_authority;
+    }
+
     /// Update all reward values for the ElysiumPool.
     ///
     /// # Parameters
@@ -166,6 +294,169 @@ impl ElysiumPool {
         }
         self.update_rewards(reward_infos, timestamp);
         self.reward_infos[index].emissions_per_second_x64 = emissions_per_second_x64;
+        self.reward_infos[index].clear_emissions_schedule();
+
+        Ok(())
+    }
+
+    pub fn update_emissions_schedule(
+        &mut self,
+        index: usize,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
+        timestamp: u64,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        budget: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        if start_timestamp < timestamp || end_timestamp <= start_timestamp {
+            return Err(ErrorCode::InvalidRewardSchedule.into());
+        }
+
+        // Spread the budget evenly across the campaign. Rounded down so that the campaign never
+        // emits more than its budget.
+        let emissions_per_second_x64 =
+            ((budget as u128) << Q64_RESOLUTION) / u128::from(end_timestamp - start_timestamp);
+
+        self.update_rewards(reward_infos, timestamp);
+        let reward_info = &mut self.reward_infos[index];
+        reward_info.emissions_per_second_x64 = emissions_per_second_x64;
+        reward_info.emissions_start_timestamp = start_timestamp;
+        reward_info.emissions_end_timestamp = end_timestamp;
+        reward_info.emissions_budget = budget;
+
+        Ok(())
+    }
+
+    /// Retire a reward whose emissions have ended. Emissions stay turned off, while the mint and
+    /// vault are kept so positions can still settle what they are owed.
+    ///
+    /// # Errors
+    /// - `RewardEmissionsNotEnded` - The reward is not initialized or still emits rewards.
+    pub fn retire_reward(
+        &mut self,
+        index: usize,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
+        timestamp: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        if !reward_infos[index].initialized() || !reward_infos[index].emissions_ended(timestamp) {
+            return Err(ErrorCode::RewardEmissionsNotEnded.into());
+        }
+
+        self.update_rewards(reward_infos, timestamp);
+        let reward_info = &mut self.reward_infos[index];
+        reward_info.emissions_per_second_x64 = 0;
+        reward_info.clear_emissions_schedule();
+
+        Ok(())
+    }
+
+    /// Reinitialize a retired reward with a new mint and vault. Every position must have settled
+    /// the retired reward, so that no position is paid for it in the new mint, unless the
+    /// settlement period has elapsed. Positions that have not settled by then are credited what
+    /// they are still owed for the retired reward in the new mint. The growth accumulator is kept,
+    /// as the reward growths already stored in ticks and position checkpoints are relative to it.
+    ///
+    /// # Errors
+    /// - `RewardEmissionsNotEnded` - Emissions were turned back on after the reward was retired.
+    /// - `RewardNotSettled` - Positions have not credited or collected everything they are owed
+    ///                        and the settlement period has not elapsed.
+    pub fn reinitialize_reward(
+        &mut self,
+        index: usize,
+        mint: Pubkey,
+        vault: Pubkey,
+        timestamp: u64,
+        settlement_period_elapsed: bool,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        if !self.reward_infos[index].emissions_ended(timestamp) {
+            return Err(ErrorCode::RewardEmissionsNotEnded.into());
+        }
+        if !self.reward_infos[index].settled() && !settlement_period_elapsed {
+            return Err(ErrorCode::RewardNotSettled.into());
+        }
+
+        let reward_info = &mut self.reward_infos[index];
+        *reward_info = ElysiumPoolRewardInfo {
+            mint,
+            vault,
+            authority: reward_info.authority,
+            growth_global_x64: reward_info.growth_global_x64,
+            ..Default::default()
+        };
+
+        Ok(())
+    }
+
+    /// Record a deposit of `amount` tokens into the reward vault. The rewards must be updated
+    /// before the deposit, so that the deposit only backs emissions for time that has already
+    /// passed if they were deferred for lack of funds.
+    pub fn fund_reward(
+        &mut self,
+        index: usize,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
+        timestamp: u64,
+        vault_amount: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.update_rewards(reward_infos, timestamp);
+        let funded_vault_amount = vault_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+        self.reward_infos[index].sync_funded(funded_vault_amount)
+    }
+
+    /// Record a withdrawal of `amount` tokens from the reward vault by the reward authority.
+    ///
+    /// # Errors
+    /// - `UnallocatedRewardInsufficient` - The amount exceeds the unallocated reward amount.
+    pub fn withdraw_unallocated_reward(
+        &mut self,
+        index: usize,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
+        timestamp: u64,
+        vault_amount: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.update_rewards(reward_infos, timestamp);
+        let reward_info = &mut self.reward_infos[index];
+        reward_info.sync_funded(vault_amount)?;
+        if amount > reward_info.unallocated_amount(timestamp)? {
+            return Err(ErrorCode::UnallocatedRewardInsufficient.into());
+        }
+        // Withdrawn tokens were never collected, so they no longer count as funded.
+        reward_info.sync_funded(vault_amount - amount)
+    }
+
+    pub fn record_reward_collected(
+        &mut self,
+        index: usize,
+        vault_amount: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        let reward_info = &mut self.reward_infos[index];
+        reward_info.sync_funded(vault_amount)?;
+        reward_info.total_collected = reward_info
+            .total_collected
+            .checked_add(amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
 
         Ok(())
     }
@@ -190,6 +481,12 @@ impl ElysiumPool {
         Ok(())
     }
 
+    /// Returns the index of the fee tier this pool was initialized with. For pools initialized
+    /// with a fee tier keyed by tick spacing, this is the tick spacing.
+    pub fn fee_tier_index(&self) -> u16 {
+        u16::from_le_bytes(self.fee_tier_index_seed)
+    }
+
     pub fn update_after_swap(
         &mut self,
         liquidity: u128,
@@ -198,6 +495,7 @@ impl ElysiumPool {
         fee_growth_global: u128,
         reward_infos: [ElysiumPoolRewardInfo; NUM_REWARDS],
         protocol_fee: u64,
+        creator_fee: u64,
         is_token_fee_in_a: bool,
         reward_last_updated_timestamp: u64,
     ) {
@@ -210,10 +508,12 @@ impl ElysiumPool {
             // Add fees taken via a
             self.fee_growth_global_a = fee_growth_global;
             self.protocol_fee_owed_a += protocol_fee;
+            self.creator_fee_owed_a += creator_fee;
         } else {
             // Add fees taken via b
             self.fee_growth_global_b = fee_growth_global;
             self.protocol_fee_owed_b += protocol_fee;
+            self.creator_fee_owed_b += creator_fee;
         }
     }
 
@@ -239,6 +539,30 @@ impl ElysiumPool {
         self.protocol_fee_owed_a = 0;
         self.protocol_fee_owed_b = 0;
     }
+
+    /// Record the creator of the pool and the portion of the fee rate they receive.
+    ///
+    /// # Errors
+    /// - `CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.
+    pub fn update_creator_fee(
+        &mut self,
+        creator: Pubkey,
+        creator_fee_rate: u16,
+        max_creator_fee_rate: u16,
+    ) -> Result<()> {
+        if creator_fee_rate > max_creator_fee_rate {
+            return Err(ErrorCode::CreatorFeeRateMaxExceeded.into());
+        }
+        self.creator = creator;
+        self.creator_fee_rate = creator_fee_rate;
+
+        Ok(())
+    }
+
+    pub fn reset_creator_fees_owed(&mut self) {
+        self.creator_fee_owed_a = 0;
+        self.creator_fee_owed_b = 0;
+    }
 }
 
 /// Stores the state relevant for tracking liquidity mining rewards at the `ElysiumPool` level.
@@ -258,6 +582,26 @@ pub struct ElysiumPoolRewardInfo {
     /// Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward
     /// emissions were turned on.
     pub growth_global_x64: u128,
+    /// Timestamp from which emissions accrue. Zero if emissions are not scheduled.
+    pub emissions_start_timestamp: u64,
+    /// Timestamp after which emissions stop accruing. Zero if emissions run until changed.
+    pub emissions_end_timestamp: u64,
+    /// Total amount of reward tokens the scheduled campaign distributes.
+    pub emissions_budget: u64,
+    /// Total amount of reward tokens deposited into the reward vault, as of the last sync.
+    pub total_funded: u64,
+    /// Total amount of reward tokens credited to positions.
+    pub total_emitted: u64,
+    /// Total amount of reward tokens collected from the reward vault.
+    pub total_collected: u64,
+    /// Q64.64 amount of emitted reward tokens that positions have not credited to the amounts
+    /// they are owed yet.
+    pub uncredited_x64: u128,
+    /// Total amount of reward tokens credited to the amounts owed of positions.
+    pub total_credited: u64,
+    /// Q64.64 amount of emissions that were due while the funded amount was used up. Emitted as
+    /// soon as the reward vault is topped up, until the emissions are changed.
+    pub unfunded_emissions_x64: u128,
 }
 
 impl ElysiumPoolRewardInfo {
@@ -270,23 +614,123 @@ impl ElysiumPoolRewardInfo {
     }
 
     /// Returns true if this reward is initialized.
-    /// Once initialized, a reward cannot transition back to uninitialized.
+    /// Once initialized, a reward cannot transition back to uninitialized. A retired reward can
+    /// be reinitialized with a new mint and vault instead.
     pub fn initialized(&self) -> bool {
         self.mint.ne(&Pubkey::default())
     }
 
+    /// Returns the number of seconds between `curr_timestamp` and `next_timestamp` that fall
+    /// within the emissions schedule of this reward.
+    pub fn emissions_seconds(&self, curr_timestamp: u64, next_timestamp: u64) -> u64 {
+        let start = curr_timestamp.max(self.emissions_start_timestamp);
+        let end = if self.emissions_end_timestamp == 0 {
+            next_timestamp
+        } else {
+            next_timestamp.min(self.emissions_end_timestamp)
+        };
+        end.saturating_sub(start)
+    }
+
+    /// Returns true if this reward no longer emits at `timestamp`, either because emissions are
+    /// turned off, the scheduled campaign is over or the funded amount has been emitted.
+    pub fn emissions_ended(&self, timestamp: u64) -> bool {
+        self.emissions_per_second_x64 == 0
+            || (self.emissions_end_timestamp != 0 && timestamp >= self.emissions_end_timestamp)
+            || self.unemitted_amount() == 0
+    }
+
+    /// Clears the emissions schedule, along with the emissions that were due under it but could
+    /// not be funded.
+    pub fn clear_emissions_schedule(&mut self) {
+        self.emissions_start_timestamp = 0;
+        self.emissions_end_timestamp = 0;
+        self.emissions_budget = 0;
+        self.unfunded_emissions_x64 = 0;
+    }
+
+    /// Returns true if every emitted reward token has been credited to a position and every
+    /// credited reward token has been collected.
+    pub fn settled(&self) -> bool {
+        self.uncredited_x64 == 0 && self.total_credited <= self.total_collected
+    }
+
+    /// Returns the amount of funded reward tokens that have not been emitted yet.
+    pub fn unemitted_amount(&self) -> u64 {
+        self.total_funded.saturating_sub(self.total_emitted)
+    }
+
+    /// Returns the amount of funded reward tokens that are neither emitted yet nor committed to
+    /// deferred emissions or the remainder of a scheduled campaign at `timestamp`.
+    pub fn unallocated_amount(&self, timestamp: u64) -> Result<u64> {
+        let unfunded_amount = div_round_up(self.unfunded_emissions_x64, 1 << Q64_RESOLUTION)?
+            .min(u128::from(u64::MAX)) as u64;
+        let committed_amount = if self.emissions_end_timestamp == 0 {
+            0
+        } else {
+            let seconds = self.emissions_seconds(timestamp, self.emissions_end_timestamp);
+            match self
+                .emissions_per_second_x64
+                .checked_mul(u128::from(seconds))
+            {
+                Some(emissions_x64) => div_round_up(emissions_x64, 1 << Q64_RESOLUTION)?
+                    .min(u128::from(u64::MAX)) as u64,
+                None => u64::MAX,
+            }
+        };
+
+        Ok(self
+            .unemitted_amount()
+            .saturating_sub(committed_amount)
+            .saturating_sub(unfunded_amount))
+    }
+
+    /// Records deposits made to the reward vault since the last sync. Every token that left the
+    /// vault was collected, so the vault balance plus the collected amount is the funded amount.
+    pub fn sync_funded(&mut self, vault_amount: u64) -> Result<()> {
+        self.total_funded = vault_amount
+            .checked_add(self.total_collected)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+
+        Ok(())
+    }
+
+    /// Starts the accounting of a reward initialized before pools tracked reward accounting.
+    /// The vault balance counts as funded and nothing as emitted, so the reward keeps emitting
+    /// until the vault balance has been emitted. What positions owe is unknown, so the reward
+    /// never counts as settled and can only be reinitialized once the settlement period after its
+    /// retirement has elapsed.
+    pub fn migrate_accounting(&mut self, vault_amount: u64) {
+        self.total_funded = vault_amount;
+        self.total_emitted = 0;
+        self.uncredited_x64 = u128::MAX;
+    }
+
     /// Maps all reward data to only the reward growth accumulators
-    pub fn to_reward_growths(
-        reward_infos: &[ElysiumPoolRewardInfo; NUM_REWARDS],
-    ) -> [u128; NUM_REWARDS] {
-        let mut reward_growths = [0u128; NUM_REWARDS];
-        for i in 0..NUM_REWARDS {
+    pub fn to_reward_growths<const N: usize>(
+        reward_infos: &[ElysiumPoolRewardInfo; N],
+    ) -> [u128; N] {
+        let mut reward_growths = [0u128; N];
+        for i in 0..N {
             reward_growths[i] = reward_infos[i].growth_global_x64;
         }
         reward_growths
     }
 }
 
+/// Fee schedule that starts at a high fee rate on activation and decays linearly to the pool's
+/// fee rate, deterring snipers at launch. The fees charged above the pool's fee rate go to LPs.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct LaunchFeeSchedule {
+    /// Fee rate charged at activation, stored as hundredths of a basis point.
+    pub initial_fee_rate: u32,
+    /// Number of seconds, or slots if `decay_in_slots` is set, until the pool's fee rate applies.
+    pub decay_period: u64,
+    /// True if the decay period is measured in slots from the activation slot, rather than in
+    /// seconds from the activation timestamp.
+    pub decay_in_slots: bool,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy)]
 pub struct ElysiumPoolBumps {
     pub pool_bump: u8,
@@ -305,6 +749,410 @@ fn test_pool_reward_info_initialized() {
     assert_eq!(reward_info.initialized(), true);
 }
 
+#[test]
+fn test_pool_reward_info_emissions_seconds_unscheduled() {
+    let reward_info = ElysiumPoolRewardInfo::default();
+    assert_eq!(reward_info.emissions_seconds(100, 250), 150);
+}
+
+#[test]
+fn test_pool_reward_info_emissions_seconds_scheduled() {
+    let reward_info = ElysiumPoolRewardInfo {
+        emissions_start_timestamp: 200,
+        emissions_end_timestamp: 300,
+        ..Default::default()
+    };
+    // Before, overlapping start, inside, overlapping end, after and spanning the schedule
+    assert_eq!(reward_info.emissions_seconds(100, 150), 0);
+    assert_eq!(reward_info.emissions_seconds(100, 250), 50);
+    assert_eq!(reward_info.emissions_seconds(220, 240), 20);
+    assert_eq!(reward_info.emissions_seconds(250, 400), 50);
+    assert_eq!(reward_info.emissions_seconds(300, 400), 0);
+    assert_eq!(reward_info.emissions_seconds(0, 1000), 100);
+}
+
+#[test]
+fn test_pool_update_emissions_schedule() {
+    let mut pool = ElysiumPool::default();
+    let reward_infos = pool.reward_infos;
+
+    assert!(pool
+        .update_emissions_schedule(0, reward_infos, 100, 50, 200, 1_000)
+        .is_err());
+    assert!(pool
+        .update_emissions_schedule(0, reward_infos, 100, 200, 200, 1_000)
+        .is_err());
+
+    pool.update_emissions_schedule(0, reward_infos, 100, 200, 300, 1_000)
+        .unwrap();
+    assert_eq!(pool.reward_infos[0].emissions_per_second_x64, 10 << 64);
+    assert_eq!(pool.reward_infos[0].emissions_start_timestamp, 200);
+    assert_eq!(pool.reward_infos[0].emissions_end_timestamp, 300);
+    assert_eq!(pool.reward_infos[0].emissions_budget, 1_000);
+
+    // Setting emissions directly clears the schedule
+    pool.update_emissions(0, reward_infos, 150, 1 << 64)
+        .unwrap();
+    assert_eq!(pool.reward_infos[0].emissions_end_timestamp, 0);
+    assert_eq!(pool.reward_infos[0].emissions_budget, 0);
+}
+
+#[test]
+fn test_pool_reward_info_sync_funded() {
+    let mut reward_info = ElysiumPoolRewardInfo {
+        total_emitted: 400,
+        total_collected: 300,
+        ..Default::default()
+    };
+    reward_info.sync_funded(1_000).unwrap();
+    assert_eq!(reward_info.total_funded, 1_300);
+    assert_eq!(reward_info.unemitted_amount(), 900);
+}
+
+#[test]
+fn test_pool_record_reward_collected() {
+    let mut pool = ElysiumPool::default();
+    pool.record_reward_collected(1, 1_000, 250).unwrap();
+    assert_eq!(pool.reward_infos[1].total_funded, 1_000);
+    assert_eq!(pool.reward_infos[1].total_collected, 250);
+
+    // Tokens that left the vault remain counted as funded
+    pool.record_reward_collected(1, 750, 0).unwrap();
+    assert_eq!(pool.reward_infos[1].total_funded, 1_000);
+    assert!(pool.record_reward_collected(NUM_REWARDS, 0, 0).is_err());
+}
+
+#[test]
+fn test_pool_is_activated() {
+    let mut pool = ElysiumPool::default();
+    assert!(pool.is_activated(0, 0));
+
+    pool.update_activation(1_000, 0);
+    assert!(!pool.is_activated(999, u64::MAX));
+    assert!(pool.is_activated(1_000, 0));
+
+    pool.update_activation(1_000, 50);
+    assert!(!pool.is_activated(1_000, 49));
+    assert!(pool.is_activated(1_000, 50));
+}
+
+#[test]
+fn test_pool_fee_tier_index() {
+    let pool = ElysiumPool {
+        tick_spacing: 64,
+        fee_tier_index_seed: 1_064u16.to_le_bytes(),
+        ..Default::default()
+    };
+    assert_eq!(pool.fee_tier_index(), 1_064);
+}
+
+#[test]
+fn test_pool_update_creator_fee() {
+    let mut pool = ElysiumPool::default();
+    let creator = Pubkey::new_unique();
+
+    assert!(pool.update_creator_fee(creator, 1_001, 1_000).is_err());
+    pool.update_creator_fee(creator, 1_000, 1_000).unwrap();
+    assert_eq!(pool.creator, creator);
+    assert_eq!(pool.creator_fee_rate, 1_000);
+
+    pool.update_after_swap(0, 0, 0, 0, pool.reward_infos, 10, 5, true, 0);
+    pool.update_after_swap(0, 0, 0, 0, pool.reward_infos, 10, 7, false, 0);
+    assert_eq!((pool.creator_fee_owed_a, pool.creator_fee_owed_b), (5, 7));
+    assert_eq!(
+        (pool.protocol_fee_owed_a, pool.protocol_fee_owed_b),
+        (10, 10)
+    );
+
+    pool.reset_creator_fees_owed();
+    assert_eq!((pool.creator_fee_owed_a, pool.creator_fee_owed_b), (0, 0));
+}
+
+#[test]
+fn test_pool_effective_fee_rate_without_schedule() {
+    let pool = ElysiumPool {
+        fee_rate: 3_000,
+        ..Default::default()
+    };
+    assert_eq!(pool.effective_fee_rate(0, 0), 3_000);
+}
+
+#[test]
+fn test_pool_effective_fee_rate_decays_in_seconds() {
+    let mut pool = ElysiumPool {
+        fee_rate: 3_000,
+        activation_timestamp: 1_000,
+        ..Default::default()
+    };
+    pool.update_launch_fee_schedule(
+        LaunchFeeSchedule {
+            initial_fee_rate: 503_000,
+            decay_period: 100,
+            decay_in_slots: false,
+        },
+        100,
+    )
+    .unwrap();
+
+    assert_eq!(pool.effective_fee_rate(500, u64::MAX), 503_000);
+    assert_eq!(pool.effective_fee_rate(1_000, 0), 503_000);
+    assert_eq!(pool.effective_fee_rate(1_050, 0), 253_000);
+    assert_eq!(pool.effective_fee_rate(1_099, 0), 8_000);
+    assert_eq!(pool.effective_fee_rate(1_100, 0), 3_000);
+}
+
+#[test]
+fn test_pool_effective_fee_rate_decays_in_slots() {
+    let mut pool = ElysiumPool {
+        fee_rate: 3_000,
+        activation_slot: 10,
+        ..Default::default()
+    };
+    pool.update_launch_fee_schedule(
+        LaunchFeeSchedule {
+            initial_fee_rate: 13_000,
+            decay_period: 4,
+            decay_in_slots: true,
+        },
+        4,
+    )
+    .unwrap();
+
+    assert_eq!(pool.effective_fee_rate(u64::MAX, 10), 13_000);
+    assert_eq!(pool.effective_fee_rate(u64::MAX, 11), 10_500);
+    assert_eq!(pool.effective_fee_rate(0, 14), 3_000);
+}
+
+#[test]
+fn test_pool_update_launch_fee_schedule_invalid() {
+    let mut pool = ElysiumPool {
+        fee_rate: 3_000,
+        ..Default::default()
+    };
+    let schedule = LaunchFeeSchedule {
+        initial_fee_rate: 500_000,
+        decay_period: 100,
+        decay_in_slots: false,
+    };
+
+    assert!(pool
+        .update_launch_fee_schedule(
+            LaunchFeeSchedule {
+                initial_fee_rate: MAX_LAUNCH_FEE_RATE + 1,
+                ..schedule
+            },
+            100
+        )
+        .is_err());
+    assert!(pool
+        .update_launch_fee_schedule(
+            LaunchFeeSchedule {
+                initial_fee_rate: 3_000,
+                ..schedule
+            },
+            100
+        )
+        .is_err());
+    assert!(pool
+        .update_launch_fee_schedule(
+            LaunchFeeSchedule {
+                decay_period: 0,
+                ..schedule
+            },
+            100
+        )
+        .is_err());
+    assert!(pool.update_launch_fee_schedule(schedule, 99).is_err());
+    assert!(pool.update_launch_fee_schedule(schedule, 100).is_ok());
+}
+
+#[test]
+fn test_pool_reward_info_emissions_ended() {
+    let mut reward_info = ElysiumPoolRewardInfo {
+        emissions_per_second_x64: 1 << 64,
+        total_funded: 1_000,
+        ..Default::default()
+    };
+    assert!(!reward_info.emissions_ended(100));
+
+    reward_info.emissions_end_timestamp = 200;
+    assert!(!reward_info.emissions_ended(199));
+    assert!(reward_info.emissions_ended(200));
+
+    reward_info.clear_emissions_schedule();
+    reward_info.total_emitted = 1_000;
+    assert!(reward_info.emissions_ended(100));
+}
+
+#[test]
+fn test_pool_retire_and_reinitialize_reward() {
+    let mut pool = ElysiumPool::default();
+    let authority = Pubkey::new_unique();
+    pool.reward_infos[0] = ElysiumPoolRewardInfo {
+        mint: Pubkey::new_unique(),
+        vault: Pubkey::new_unique(),
+        authority,
+        emissions_per_second_x64: 1 << 64,
+        growth_global_x64: 500,
+        total_funded: 1_000,
+        total_emitted: 400,
+        total_collected: 300,
+        uncredited_x64: 100 << 64,
+        total_credited: 300,
+        ..Default::default()
+    };
+
+    // Still emitting
+    let reward_infos = pool.reward_infos;
+    assert!(pool.retire_reward(0, reward_infos, 100).is_err());
+    // Uninitialized
+    assert!(pool.retire_reward(1, reward_infos, 100).is_err());
+
+    let mut reward_infos = pool.reward_infos;
+    reward_infos[0].total_emitted = 1_000;
+    pool.retire_reward(0, reward_infos, 100).unwrap();
+    assert_eq!(pool.reward_infos[0].emissions_per_second_x64, 0);
+    assert!(pool.reward_infos[0].initialized());
+
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    // Positions have not credited all emitted rewards
+    assert_eq!(
+        pool.reinitialize_reward(0, mint, vault, 100, false)
+            .unwrap_err(),
+        ErrorCode::RewardNotSettled.into()
+    );
+    // Positions have not collected all credited rewards
+    pool.reward_infos[0].uncredited_x64 = 0;
+    pool.reward_infos[0].total_credited = 400;
+    assert_eq!(
+        pool.reinitialize_reward(0, mint, vault, 100, false)
+            .unwrap_err(),
+        ErrorCode::RewardNotSettled.into()
+    );
+
+    pool.reward_infos[0].total_credited = 300;
+    pool.reinitialize_reward(0, mint, vault, 100, false)
+        .unwrap();
+    assert_eq!(
+        pool.reward_infos[0],
+        ElysiumPoolRewardInfo {
+            mint,
+            vault,
+            authority,
+            growth_global_x64: 500,
+            ..Default::default()
+        }
+    );
+    assert!(pool
+        .reinitialize_reward(NUM_REWARDS, mint, vault, 100, false)
+        .is_err());
+
+    // Emissions turned back on
+    pool.reward_infos[0].emissions_per_second_x64 = 1 << 64;
+    pool.reward_infos[0].total_funded = 1_000;
+    assert!(pool.reinitialize_reward(0, mint, vault, 100, true).is_err());
+}
+
+#[test]
+fn test_pool_reinitialize_unsettled_reward_after_settlement_period() {
+    let mut pool = ElysiumPool::default();
+    pool.reward_infos[0] = ElysiumPoolRewardInfo {
+        mint: Pubkey::new_unique(),
+        vault: Pubkey::new_unique(),
+        growth_global_x64: 500,
+        uncredited_x64: 100 << 64,
+        total_credited: 400,
+        total_collected: 300,
+        ..Default::default()
+    };
+
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    assert_eq!(
+        pool.reinitialize_reward(0, mint, vault, 100, false)
+            .unwrap_err(),
+        ErrorCode::RewardNotSettled.into()
+    );
+    pool.reinitialize_reward(0, mint, vault, 100, true).unwrap();
+    assert_eq!(pool.reward_infos[0].mint, mint);
+    assert_eq!(pool.reward_infos[0].vault, vault);
+    assert_eq!(pool.reward_infos[0].growth_global_x64, 500);
+    assert!(pool.reward_infos[0].settled());
+}
+
+#[test]
+fn test_pool_reward_info_unallocated_amount() {
+    let mut reward_info = ElysiumPoolRewardInfo {
+        emissions_per_second_x64: 10 << 64,
+        total_funded: 2_000,
+        total_emitted: 500,
+        ..Default::default()
+    };
+    // Unscheduled emissions commit nothing beyond what has been emitted
+    assert_eq!(reward_info.unallocated_amount(100).unwrap(), 1_500);
+
+    // The remainder of a scheduled campaign is committed
+    reward_info.emissions_start_timestamp = 200;
+    reward_info.emissions_end_timestamp = 300;
+    assert_eq!(reward_info.unallocated_amount(100).unwrap(), 500);
+    assert_eq!(reward_info.unallocated_amount(250).unwrap(), 1_000);
+    assert_eq!(reward_info.unallocated_amount(300).unwrap(), 1_500);
+
+    // Emissions deferred for lack of funds are committed as well
+    reward_info.unfunded_emissions_x64 = 100 << 64;
+    assert_eq!(reward_info.unallocated_amount(300).unwrap(), 1_400);
+    reward_info.clear_emissions_schedule();
+    assert_eq!(reward_info.unallocated_amount(300).unwrap(), 1_500);
+}
+
+#[test]
+fn test_pool_fund_and_withdraw_unallocated_reward() {
+    let mut pool = ElysiumPool::default();
+    let reward_infos = pool.reward_infos;
+
+    pool.fund_reward(0, reward_infos, 100, 0, 1_000).unwrap();
+    assert_eq!(pool.reward_infos[0].total_funded, 1_000);
+    assert_eq!(pool.reward_last_updated_timestamp, 100);
+
+    let mut reward_infos = pool.reward_infos;
+    reward_infos[0].total_emitted = 400;
+    assert!(pool
+        .withdraw_unallocated_reward(0, reward_infos, 200, 1_000, 601)
+        .is_err());
+    pool.withdraw_unallocated_reward(0, reward_infos, 200, 1_000, 600)
+        .unwrap();
+    assert_eq!(pool.reward_infos[0].total_funded, 400);
+    assert_eq!(pool.reward_infos[0].unemitted_amount(), 0);
+
+    assert!(pool
+        .fund_reward(NUM_REWARDS, reward_infos, 200, 0, 1)
+        .is_err());
+}
+
+#[test]
+fn test_pool_reward_info_migrate_accounting() {
+    let mut pool = ElysiumPool::default();
+    pool.reward_infos[0].emissions_per_second_x64 = 1 << Q64_RESOLUTION;
+    pool.reward_infos[0].migrate_accounting(1_000);
+
+    // The vault balance stays emittable
+    assert_eq!(pool.reward_infos[0].unemitted_amount(), 1_000);
+    assert!(!pool.reward_infos[0].emissions_ended(100));
+    pool.reward_infos[0].emissions_per_second_x64 = 0;
+    assert!(!pool.reward_infos[0].settled());
+    assert!(pool
+        .reinitialize_reward(0, Pubkey::new_unique(), Pubkey::new_unique(), 100, false)
+        .is_err());
+
+    // Collecting owed rewards leaves the accounting consistent, and deposits are emitted
+    pool.record_reward_collected(0, 1_000, 400).unwrap();
+    assert_eq!(pool.reward_infos[0].total_funded, 1_000);
+    let reward_infos = pool.reward_infos;
+    pool.fund_reward(0, reward_infos, 100, 600, 500).unwrap();
+    assert_eq!(pool.reward_infos[0].unemitted_amount(), 1_500);
+}
+
 #[cfg(test)]
 pub mod pool_builder {
     use super::{ElysiumPool, ElysiumPoolRewardInfo, NUM_REWARDS};
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Unlock timestamp of a position whose liquidity is locked forever.
+pub const POSITION_LOCK_PERMANENT: u64 = u64::MAX;
+
+/// Lock on the liquidity of a Position. While the lock exists, the position token is held in a
+/// token account owned by the lock, so liquidity cannot be withdrawn and the position cannot be
+/// closed. The beneficiary can still collect fees and rewards, and gets the position token back
+/// once the lock has expired.
+#[account]
+#[derive(Default)]
+pub struct PositionLock {
+    pub position: Pubkey,      // 32
+    pub beneficiary: Pubkey,   // 32
+    pub unlock_timestamp: u64, // 8
+    pub bump: [u8; 1],         // 1
+}
+
+impl PositionLock {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [
+            &b"position_lock"[..],
+            self.position.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+
+    /// Address of the lock of `position`, which owns the position token while the lock exists.
+    pub fn find_address(position: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"position_lock", position.as_ref()], &crate::ID).0
+    }
+
+    /// Lock the liquidity of `position` until `unlock_timestamp`, or forever if it is
+    /// POSITION_LOCK_PERMANENT.
+    ///
+    /// # Errors
+    /// - `InvalidPositionLock` - The unlock timestamp is not in the future.
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        beneficiary: Pubkey,
+        bump: u8,
+        unlock_timestamp: u64,
+        timestamp: u64,
+    ) -> Result<()> {
+        if unlock_timestamp <= timestamp {
+            return Err(ErrorCode::InvalidPositionLock.into());
+        }
+        self.position = position;
+        self.beneficiary = beneficiary;
+        self.unlock_timestamp = unlock_timestamp;
+        self.bump = [bump];
+
+        Ok(())
+    }
+
+    /// Returns true if liquidity cannot be withdrawn from the position at `timestamp`.
+    pub fn is_locked(&self, timestamp: u64) -> bool {
+        self.unlock_timestamp > timestamp
+    }
+
+    /// Extend the lock until `unlock_timestamp`. A lock can never be shortened.
+    ///
+    /// # Errors
+    /// - `InvalidPositionLock` - The unlock timestamp is earlier than the current one.
+    pub fn extend(&mut self, unlock_timestamp: u64) -> Result<()> {
+        if unlock_timestamp < self.unlock_timestamp {
+            return Err(ErrorCode::InvalidPositionLock.into());
+        }
+        self.unlock_timestamp = unlock_timestamp;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod position_lock_tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_until_timestamp() {
+        let mut lock = PositionLock::default();
+        lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 255, 200, 100)
+            .unwrap();
+        assert!(lock.is_locked(199));
+        assert!(!lock.is_locked(200));
+    }
+
+    #[test]
+    fn test_lock_must_be_in_future() {
+        let mut lock = PositionLock::default();
+        assert!(lock
+            .initialize(Pubkey::new_unique(), Pubkey::new_unique(), 255, 100, 100)
+            .is_err());
+    }
+
+    #[test]
+    fn test_lock_permanent() {
+        let mut lock = PositionLock::default();
+        lock.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+            POSITION_LOCK_PERMANENT,
+            100,
+        )
+        .unwrap();
+        assert!(lock.is_locked(u64::MAX - 1));
+        assert!(lock.extend(200).is_err());
+    }
+
+    #[test]
+    fn test_lock_can_only_be_extended() {
+        let mut lock = PositionLock::default();
+        lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 255, 300, 100)
+            .unwrap();
+        assert!(lock.extend(200).is_err());
+        lock.extend(400).unwrap();
+        assert_eq!(lock.unlock_timestamp, 400);
+    }
+}
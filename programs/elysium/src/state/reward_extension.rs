@@ -0,0 +1,301 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::{
+    get_offset, ElysiumPool, ElysiumPoolRewardInfo, PositionRewardInfo, Tick, TICK_ARRAY_SIZE,
+    TICK_ARRAY_SIZE_USIZE,
+};
+
+pub const NUM_EXTENSION_REWARDS: usize = 2;
+
+/// Additional reward slots of a ElysiumPool. Extension rewards accrue against the pool liquidity
+/// and share the growth-inside math with the rewards stored on the pool.
+#[account]
+#[derive(Default)]
+pub struct PoolRewardExtension {
+    pub pool: Pubkey, // 32
+
+    pub reward_last_updated_timestamp: u64, // 8
+
+    pub reward_infos: [ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS], // 432
+}
+
+impl PoolRewardExtension {
+    pub const LEN: usize = 8 + 40 + 432;
+
+    pub fn initialize(
+        &mut self,
+        pool: &Account<ElysiumPool>,
+        authority: Pubkey,
+        timestamp: u64,
+    ) -> Result<()> {
+        self.pool = pool.key();
+        self.reward_last_updated_timestamp = timestamp;
+        self.reward_infos = [ElysiumPoolRewardInfo::new(authority); NUM_EXTENSION_REWARDS];
+
+        Ok(())
+    }
+
+    /// Update all extension reward values.
+    ///
+    /// # Parameters
+    /// - `reward_infos` - An array of all updated extension rewards
+    /// - `reward_last_updated_timestamp` - The timestamp when the rewards were last updated
+    pub fn update_rewards(
+        &mut self,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS],
+        reward_last_updated_timestamp: u64,
+    ) {
+        self.reward_last_updated_timestamp = reward_last_updated_timestamp;
+        self.reward_infos = reward_infos;
+    }
+
+    pub fn initialize_reward(&mut self, index: usize, mint: Pubkey, vault: Pubkey) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+
+        let lowest_index = match self.reward_infos.iter().position(|r| !r.initialized()) {
+            Some(lowest_index) => lowest_index,
+            None => return Err(ErrorCode::InvalidRewardIndex.into()),
+        };
+
+        if lowest_index != index {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+
+        self.reward_infos[index].mint = mint;
+        self.reward_infos[index].vault = vault;
+
+        Ok(())
+    }
+
+    pub fn update_reward_authority(&mut self, index: usize, authority: Pubkey) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.reward_infos[index].authority = authority;
+
+        Ok(())
+    }
+
+    pub fn update_emissions(
+        &mut self,
+        index: usize,
+        reward_infos: [ElysiumPoolRewardInfo; NUM_EXTENSION_REWARDS],
+        timestamp: u64,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.update_rewards(reward_infos, timestamp);
+        self.reward_infos[index].emissions_per_second_x64 = emissions_per_second_x64;
+        self.reward_infos[index].clear_emissions_schedule();
+
+        Ok(())
+    }
+
+    pub fn record_reward_collected(
+        &mut self,
+        index: usize,
+        vault_amount: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        let reward_info = &mut self.reward_infos[index];
+        reward_info.sync_funded(vault_amount)?;
+        reward_info.total_collected = reward_info
+            .total_collected
+            .checked_add(amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+
+        Ok(())
+    }
+}
+
+/// Extension reward growths outside of every tick in a TickArray. The initialized state of a
+/// tick is tracked by the TickArray itself.
+#[account(zero_copy)]
+#[repr(packed)]
+pub struct TickArrayRewardExtension {
+    pub tick_array: Pubkey,
+    pub start_tick_index: i32,
+    pub reward_growths_outside: [[u128; NUM_EXTENSION_REWARDS]; TICK_ARRAY_SIZE_USIZE],
+}
+
+impl Default for TickArrayRewardExtension {
+    #[inline]
+    fn default() -> TickArrayRewardExtension {
+        TickArrayRewardExtension {
+            tick_array: Pubkey::default(),
+            start_tick_index: 0,
+            reward_growths_outside: [[0; NUM_EXTENSION_REWARDS]; TICK_ARRAY_SIZE_USIZE],
+        }
+    }
+}
+
+impl TickArrayRewardExtension {
+    pub const LEN: usize = 8 + 36 + (16 * NUM_EXTENSION_REWARDS * TICK_ARRAY_SIZE_USIZE);
+
+    /// Address of the extension of `tick_array`.
+    pub fn find_address(tick_array: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"tick_array_reward_extension", tick_array.as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Initialize the TickArrayRewardExtension object
+    ///
+    /// # Parameters
+    /// - `tick_array` - the key of the TickArray this extension belongs to
+    /// - `start_tick_index` - the start tick index of the TickArray
+    pub fn initialize(&mut self, tick_array: Pubkey, start_tick_index: i32) {
+        self.tick_array = tick_array;
+        self.start_tick_index = start_tick_index;
+    }
+
+    /// Get the extension reward growths outside of the tick at the given tick-index & tick-spacing
+    ///
+    /// # Errors
+    /// - `TickNotFound`: - The provided tick-index is not an initializable tick index in this array.
+    pub fn get_reward_growths_outside(
+        &self,
+        tick_index: i32,
+        tick_spacing: u16,
+    ) -> Result<[u128; NUM_EXTENSION_REWARDS]> {
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        Ok(self.reward_growths_outside[offset])
+    }
+
+    /// Update the extension reward growths outside of the tick at the given tick-index & tick-spacing
+    ///
+    /// # Errors
+    /// - `TickNotFound`: - The provided tick-index is not an initializable tick index in this array.
+    pub fn update_reward_growths_outside(
+        &mut self,
+        tick_index: i32,
+        tick_spacing: u16,
+        reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    ) -> Result<()> {
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        self.reward_growths_outside[offset] = reward_growths_outside;
+        Ok(())
+    }
+
+    pub fn check_in_array_bounds(&self, tick_index: i32, tick_spacing: u16) -> bool {
+        let start_tick_index = self.start_tick_index;
+        tick_index >= start_tick_index
+            && tick_index < start_tick_index + TICK_ARRAY_SIZE * tick_spacing as i32
+    }
+
+    fn tick_offset(&self, tick_index: i32, tick_spacing: u16) -> Result<usize> {
+        if tick_spacing == 0 {
+            return Err(ErrorCode::InvalidTickSpacing.into());
+        }
+        if !self.check_in_array_bounds(tick_index, tick_spacing)
+            || !Tick::check_is_usable_tick(tick_index, tick_spacing)
+        {
+            return Err(ErrorCode::TickNotFound.into());
+        }
+        Ok(get_offset(tick_index, self.start_tick_index, tick_spacing) as usize)
+    }
+}
+
+/// Rewards owed to a Position for the extension rewards of its pool.
+#[account]
+#[derive(Default)]
+pub struct PositionRewardExtension {
+    pub position: Pubkey, // 32
+
+    pub reward_infos: [PositionRewardInfo; NUM_EXTENSION_REWARDS], // 48
+}
+
+impl PositionRewardExtension {
+    pub const LEN: usize = 8 + 32 + 48;
+
+    /// Address of the extension of `position`.
+    pub fn find_address(position: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"position_reward_extension", position.as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Initialize the PositionRewardExtension object. The position starts earning extension
+    /// rewards from the current growths inside its range.
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        reward_growths_inside: [u128; NUM_EXTENSION_REWARDS],
+    ) {
+        self.position = position;
+        for (reward_info, growth_inside) in self.reward_infos.iter_mut().zip(reward_growths_inside)
+        {
+            reward_info.growth_inside_checkpoint = growth_inside;
+        }
+    }
+
+    pub fn update_reward_infos(
+        &mut self,
+        reward_infos: [PositionRewardInfo; NUM_EXTENSION_REWARDS],
+    ) {
+        self.reward_infos = reward_infos;
+    }
+
+    pub fn update_reward_owed(&mut self, index: usize, amount_owed: u64) {
+        self.reward_infos[index].amount_owed = amount_owed;
+    }
+}
+
+#[cfg(test)]
+mod reward_extension_tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_array_reward_extension_offsets() {
+        let mut extension = TickArrayRewardExtension::default();
+        extension.initialize(Pubkey::new_unique(), -88 * 8);
+
+        extension
+            .update_reward_growths_outside(-8, 8, [1, 2])
+            .unwrap();
+        assert_eq!(extension.get_reward_growths_outside(-8, 8).unwrap(), [1, 2]);
+        let growths = extension.reward_growths_outside;
+        assert_eq!(growths[TICK_ARRAY_SIZE_USIZE - 1], [1, 2]);
+
+        // Out of range or not a multiple of the tick spacing
+        assert!(extension.get_reward_growths_outside(0, 8).is_err());
+        assert!(extension
+            .get_reward_growths_outside(-88 * 8 - 8, 8)
+            .is_err());
+        assert!(extension.get_reward_growths_outside(-7, 8).is_err());
+    }
+
+    #[test]
+    fn test_pool_reward_extension_initialize_reward_in_order() {
+        let mut extension = PoolRewardExtension::default();
+        assert!(extension
+            .initialize_reward(1, Pubkey::new_unique(), Pubkey::new_unique())
+            .is_err());
+        extension
+            .initialize_reward(0, Pubkey::new_unique(), Pubkey::new_unique())
+            .unwrap();
+        extension
+            .initialize_reward(1, Pubkey::new_unique(), Pubkey::new_unique())
+            .unwrap();
+        assert!(extension
+            .initialize_reward(
+                NUM_EXTENSION_REWARDS,
+                Pubkey::new_unique(),
+                Pubkey::new_unique()
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+/// Number of seconds after retiring a reward after which its slot can be reinitialized even if
+/// positions have not settled the rewards they are owed.
+pub const REWARD_SETTLEMENT_PERIOD_SECONDS: u64 = 60 * 60 * 24 * 7;
+
+/// Record of a retired ElysiumPool reward. Positions settle the amount they are owed for the
+/// retired reward against this record until the reward slot is reinitialized.
+#[account]
+#[derive(Default)]
+pub struct RewardSettlement {
+    pub pool: Pubkey,     // 32
+    pub reward_index: u8, // 1
+    pub mint: Pubkey,     // 32
+    pub vault: Pubkey,    // 32
+
+    // Q64.64
+    pub growth_global_x64: u128, // 16
+    pub retired_timestamp: u64,  // 8
+    pub total_settled: u64,      // 8
+}
+
+impl RewardSettlement {
+    pub const LEN: usize = 8 + 129;
+
+    pub fn initialize(
+        &mut self,
+        pool: Pubkey,
+        reward_index: u8,
+        mint: Pubkey,
+        vault: Pubkey,
+        growth_global_x64: u128,
+        retired_timestamp: u64,
+    ) {
+        self.pool = pool;
+        self.reward_index = reward_index;
+        self.mint = mint;
+        self.vault = vault;
+        self.growth_global_x64 = growth_global_x64;
+        self.retired_timestamp = retired_timestamp;
+    }
+
+    /// Returns true once positions have had the full settlement period to settle.
+    pub fn settlement_period_elapsed(&self, timestamp: u64) -> bool {
+        timestamp
+            >= self
+                .retired_timestamp
+                .saturating_add(REWARD_SETTLEMENT_PERIOD_SECONDS)
+    }
+
+    pub fn record_settled(&mut self, amount: u64) {
+        self.total_settled = self.total_settled.saturating_add(amount);
+    }
+}
+
+#[cfg(test)]
+mod reward_settlement_tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_period_elapsed() {
+        let mut settlement = RewardSettlement::default();
+        settlement.initialize(
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            1_000,
+        );
+        assert!(!settlement.settlement_period_elapsed(1_000));
+        assert!(!settlement.settlement_period_elapsed(1_000 + REWARD_SETTLEMENT_PERIOD_SECONDS - 1));
+        assert!(settlement.settlement_period_elapsed(1_000 + REWARD_SETTLEMENT_PERIOD_SECONDS));
+    }
+}
@@ -13,6 +13,9 @@ pub const MIN_TICK_INDEX: i32 = -443636;
 pub const TICK_ARRAY_SIZE: i32 = 88;
 pub const TICK_ARRAY_SIZE_USIZE: usize = 88;
 
+// Bit i of the initialized-tick bitmap of a tick array is set if the tick at offset i is initialized.
+pub const INITIALIZED_TICK_BITMAP_LEN: usize = 16;
+
 #[zero_copy]
 #[repr(packed)]
 #[derive(Default, Debug, PartialEq)]
@@ -159,6 +162,9 @@ impl Default for TickArray {
 
 impl TickArray {
     pub const LEN: usize = 8 + 36 + (Tick::LEN * TICK_ARRAY_SIZE_USIZE);
+    // Tick arrays created or migrated since the initialized-tick bitmap was introduced store it
+    // right after the TickArray, so the layout of the TickArray itself is unchanged.
+    pub const LEN_WITH_BITMAP: usize = Self::LEN + INITIALIZED_TICK_BITMAP_LEN;
 
     /// Search for the next initialized tick in this array.
     ///
@@ -179,20 +185,7 @@ impl TickArray {
         tick_spacing: u16,
         a_to_b: bool,
     ) -> Result<Option<i32>> {
-        if !self.in_search_range(tick_index, tick_spacing, !a_to_b) {
-            return Err(ErrorCode::InvalidTickArraySequence.into());
-        }
-
-        let mut curr_offset = match self.tick_offset(tick_index, tick_spacing) {
-            Ok(value) => value as i32,
-            Err(e) => return Err(e),
-        };
-
-        // For a_to_b searches, the search moves to the left. The next possible init-tick can be the 1st tick in the current offset
-        // For b_to_a searches, the search moves to the right. The next possible init-tick cannot be within the current offset
-        if !a_to_b {
-            curr_offset += 1;
-        }
+        let mut curr_offset = self.search_start_offset(tick_index, tick_spacing, a_to_b)?;
 
         while curr_offset >= 0 && curr_offset < TICK_ARRAY_SIZE {
             let curr_tick = self.ticks[curr_offset as usize];
@@ -212,6 +205,80 @@ impl TickArray {
         Ok(None)
     }
 
+    /// Search for the next initialized tick in this array using its initialized-tick bitmap,
+    /// instead of reading the ticks one by one.
+    ///
+    /// # Parameters
+    /// - `initialized_tick_bitmap` - The bitmap of this array, bit i is set if the tick at offset i is initialized
+    /// - `tick_index` - A i32 integer representing the tick index to start searching for
+    /// - `tick_spacing` - A u8 integer of the tick spacing for this pool
+    /// - `a_to_b` - If the trade is from a_to_b, the search will move to the left and the starting search tick is inclusive.
+    ///              If the trade is from b_to_a, the search will move to the right and the starting search tick is not inclusive.
+    ///
+    /// # Returns
+    /// - `Some(i32)`: The next initialized tick index of this array
+    /// - `None`: An initialized tick index was not found in this array
+    /// - `InvalidTickArraySequence` - error if `tick_index` is not a valid search tick for the array
+    /// - `InvalidTickSpacing` - error if the provided tick spacing is 0
+    pub fn get_next_init_tick_index_in_bitmap(
+        &self,
+        initialized_tick_bitmap: u128,
+        tick_index: i32,
+        tick_spacing: u16,
+        a_to_b: bool,
+    ) -> Result<Option<i32>> {
+        let curr_offset = self.search_start_offset(tick_index, tick_spacing, a_to_b)?;
+        if !(0..TICK_ARRAY_SIZE).contains(&curr_offset) {
+            return Ok(None);
+        }
+
+        // Find the closest set bit at or below the offset for a_to_b searches, and at or above the
+        // offset for b_to_a searches.
+        let next_offset = if a_to_b {
+            let masked = initialized_tick_bitmap & (u128::MAX >> (127 - curr_offset));
+            if masked == 0 {
+                return Ok(None);
+            }
+            127 - masked.leading_zeros() as i32
+        } else {
+            let shifted = initialized_tick_bitmap >> curr_offset;
+            if shifted == 0 {
+                return Ok(None);
+            }
+            curr_offset + shifted.trailing_zeros() as i32
+        };
+
+        Ok(Some(
+            (next_offset * tick_spacing as i32) + self.start_tick_index,
+        ))
+    }
+
+    fn search_start_offset(&self, tick_index: i32, tick_spacing: u16, a_to_b: bool) -> Result<i32> {
+        if !self.in_search_range(tick_index, tick_spacing, !a_to_b) {
+            return Err(ErrorCode::InvalidTickArraySequence.into());
+        }
+
+        let curr_offset = self.tick_offset(tick_index, tick_spacing)? as i32;
+
+        // For a_to_b searches, the search moves to the left. The next possible init-tick can be the 1st tick in the current offset
+        // For b_to_a searches, the search moves to the right. The next possible init-tick cannot be within the current offset
+        if a_to_b {
+            Ok(curr_offset)
+        } else {
+            Ok(curr_offset + 1)
+        }
+    }
+
+    /// Returns the bitmap of the initialized ticks in this array, bit i is set if the tick at
+    /// offset i is initialized.
+    pub fn initialized_tick_bitmap(&self) -> u128 {
+        self.ticks
+            .iter()
+            .enumerate()
+            .filter(|(_, tick)| tick.initialized)
+            .fold(0, |bitmap, (offset, _)| bitmap | (1 << offset))
+    }
+
     /// Initialize the TickArray object
     ///
     /// # Parameters
@@ -230,6 +297,12 @@ impl TickArray {
         Ok(())
     }
 
+    /// Returns true if no tick in this array is initialized. Positions with liquidity keep their
+    /// lower and upper ticks initialized, so no such position references an empty array.
+    pub fn is_empty(&self) -> bool {
+        self.ticks.iter().all(|tick| !tick.initialized)
+    }
+
     /// Get the Tick object at the given tick-index & tick-spacing
     ///
     /// # Parameters
@@ -324,7 +397,7 @@ impl TickArray {
     }
 }
 
-fn get_offset(tick_index: i32, start_tick_index: i32, tick_spacing: u16) -> isize {
+pub fn get_offset(tick_index: i32, start_tick_index: i32, tick_spacing: u16) -> isize {
     // TODO: replace with i32.div_floor once not experimental
     let lhs = tick_index - start_tick_index;
     let rhs = tick_spacing as i32;
@@ -442,6 +515,50 @@ mod fuzz_tests {
             }
         }
 
+        #[test]
+        fn test_get_next_init_tick_index_in_bitmap_matches_scan(
+            initialized in proptest::collection::vec(proptest::bool::ANY, TICK_ARRAY_SIZE_USIZE),
+            offset in -1..TICK_ARRAY_SIZE,
+            a_to_b in proptest::bool::ANY,
+        ) {
+            let tick_spacing = 8;
+            let mut array = TickArray {
+                start_tick_index: -TICK_ARRAY_SIZE * tick_spacing as i32,
+                ..TickArray::default()
+            };
+            for (i, &tick_initialized) in initialized.iter().enumerate() {
+                let mut update = TickUpdate::from(&Tick::default());
+                update.initialized = tick_initialized;
+                let tick_index = array.start_tick_index + i as i32 * tick_spacing as i32;
+                array.update_tick(tick_index, tick_spacing, &update).unwrap();
+            }
+
+            let tick_index = array.start_tick_index + offset * tick_spacing as i32;
+            prop_assume!(array.in_search_range(tick_index, tick_spacing, !a_to_b));
+
+            let mut expected = None;
+            let search_offsets: Vec<i32> = if a_to_b {
+                (0..=offset).rev().collect()
+            } else {
+                (offset + 1..TICK_ARRAY_SIZE).collect()
+            };
+            for search_offset in search_offsets {
+                if initialized[search_offset as usize] {
+                    expected = Some(array.start_tick_index + search_offset * tick_spacing as i32);
+                    break;
+                }
+            }
+
+            let scanned = array.get_next_init_tick_index(tick_index, tick_spacing, a_to_b).unwrap();
+            assert_eq!(scanned, expected);
+
+            let initialized_tick_bitmap = array.initialized_tick_bitmap();
+            let result = array
+                .get_next_init_tick_index_in_bitmap(initialized_tick_bitmap, tick_index, tick_spacing, a_to_b)
+                .unwrap();
+            assert_eq!(result, expected);
+        }
+
         #[test]
         fn test_get_offset(
             tick_index in 2 * MIN_TICK_INDEX..2 * MAX_TICK_INDEX,
@@ -617,4 +734,19 @@ mod array_update_tests {
         let result = array.get_tick(tick_index, tick_spacing).unwrap();
         assert_eq!(*result, expected);
     }
+
+    #[test]
+    fn is_empty_tracks_initialized_ticks() {
+        let mut array = TickArray::default();
+        assert!(array.is_empty());
+
+        let mut update = TickUpdate::from(&Tick::default());
+        update.initialized = true;
+        array.update_tick(8 * 87, 8, &update).unwrap();
+        assert!(!array.is_empty());
+
+        update.initialized = false;
+        array.update_tick(8 * 87, 8, &update).unwrap();
+        assert!(array.is_empty());
+    }
 }
@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::{Tick, MIN_TICK_INDEX, TICK_ARRAY_SIZE};
+
+// Number of u64 words needed for a bit per tick array of a pool with a tick spacing of 1, which
+// has the most tick arrays.
+pub const TICK_ARRAY_BITMAP_WORDS: usize = 158;
+
+/// Bitmap of the TickArrays of a ElysiumPool, indexed by the position of their start tick index
+/// in the pool's range of tick arrays. Lets clients locate tick arrays without probing addresses.
+/// The program only writes the bitmap, as tick arrays initialized before it are missing until
+/// they are synced.
+#[account(zero_copy)]
+pub struct TickArrayBitmap {
+    pub pool: Pubkey,
+    // Bits of the tick arrays that are initialized
+    pub initialized: [u64; TICK_ARRAY_BITMAP_WORDS],
+    // Bits of the tick arrays that have at least one initialized tick
+    pub non_empty: [u64; TICK_ARRAY_BITMAP_WORDS],
+}
+
+impl Default for TickArrayBitmap {
+    #[inline]
+    fn default() -> TickArrayBitmap {
+        TickArrayBitmap {
+            pool: Pubkey::default(),
+            initialized: [0; TICK_ARRAY_BITMAP_WORDS],
+            non_empty: [0; TICK_ARRAY_BITMAP_WORDS],
+        }
+    }
+}
+
+impl TickArrayBitmap {
+    pub const LEN: usize = 8 + 32 + (8 * TICK_ARRAY_BITMAP_WORDS * 2);
+
+    pub fn initialize(&mut self, pool: Pubkey) {
+        self.pool = pool;
+    }
+
+    /// Record the state of the TickArray at the given start tick index.
+    ///
+    /// # Parameters
+    /// - `start_tick_index` - the start tick index of the TickArray
+    /// - `tick_spacing` - the tick spacing of the pool
+    /// - `initialized` - true if the TickArray is initialized
+    /// - `non_empty` - true if the TickArray has at least one initialized tick
+    ///
+    /// # Errors
+    /// - `InvalidStartTick` - The start tick index is not a valid start tick index for the tick spacing.
+    pub fn update(
+        &mut self,
+        start_tick_index: i32,
+        tick_spacing: u16,
+        initialized: bool,
+        non_empty: bool,
+    ) -> Result<()> {
+        let bit = bit_index(start_tick_index, tick_spacing)?;
+        set_bit(&mut self.initialized, bit, initialized);
+        set_bit(&mut self.non_empty, bit, non_empty);
+        Ok(())
+    }
+
+    pub fn is_initialized(&self, start_tick_index: i32, tick_spacing: u16) -> Result<bool> {
+        let bit = bit_index(start_tick_index, tick_spacing)?;
+        Ok(get_bit(&self.initialized, bit))
+    }
+
+    pub fn is_non_empty(&self, start_tick_index: i32, tick_spacing: u16) -> Result<bool> {
+        let bit = bit_index(start_tick_index, tick_spacing)?;
+        Ok(get_bit(&self.non_empty, bit))
+    }
+
+    /// Search for the closest non-empty TickArray in the direction of a swap, including the
+    /// TickArray at the given start tick index. Used by clients to find the tick arrays a swap
+    /// quote needs.
+    ///
+    /// # Parameters
+    /// - `start_tick_index` - the start tick index of the TickArray the search begins at
+    /// - `tick_spacing` - the tick spacing of the pool
+    /// - `a_to_b` - If the search moves to the left (decreasing price)
+    ///
+    /// # Returns
+    /// - `Some(i32)`: The start tick index of the next non-empty TickArray
+    /// - `None`: No non-empty TickArray exists in the search direction
+    pub fn next_non_empty_start_tick_index(
+        &self,
+        start_tick_index: i32,
+        tick_spacing: u16,
+        a_to_b: bool,
+    ) -> Result<Option<i32>> {
+        let bit = bit_index(start_tick_index, tick_spacing)?;
+        let mut word_index = bit / 64;
+        let mut word = if a_to_b {
+            self.non_empty[word_index] & (u64::MAX >> (63 - bit % 64))
+        } else {
+            self.non_empty[word_index] & (u64::MAX << (bit % 64))
+        };
+
+        loop {
+            if word != 0 {
+                let next_bit = if a_to_b {
+                    word_index * 64 + 63 - word.leading_zeros() as usize
+                } else {
+                    word_index * 64 + word.trailing_zeros() as usize
+                };
+                return Ok(Some(start_tick_index_from_bit(next_bit, tick_spacing)));
+            }
+
+            if a_to_b {
+                if word_index == 0 {
+                    return Ok(None);
+                }
+                word_index -= 1;
+            } else {
+                word_index += 1;
+                if word_index == TICK_ARRAY_BITMAP_WORDS {
+                    return Ok(None);
+                }
+            }
+            word = self.non_empty[word_index];
+        }
+    }
+}
+
+fn min_array_index(tick_spacing: u16) -> i32 {
+    MIN_TICK_INDEX.div_euclid(TICK_ARRAY_SIZE * tick_spacing as i32)
+}
+
+fn bit_index(start_tick_index: i32, tick_spacing: u16) -> Result<usize> {
+    if !Tick::check_is_valid_start_tick(start_tick_index, tick_spacing) {
+        return Err(ErrorCode::InvalidStartTick.into());
+    }
+    let array_index = start_tick_index.div_euclid(TICK_ARRAY_SIZE * tick_spacing as i32);
+    Ok((array_index - min_array_index(tick_spacing)) as usize)
+}
+
+fn start_tick_index_from_bit(bit: usize, tick_spacing: u16) -> i32 {
+    (bit as i32 + min_array_index(tick_spacing)) * TICK_ARRAY_SIZE * tick_spacing as i32
+}
+
+fn get_bit(words: &[u64; TICK_ARRAY_BITMAP_WORDS], bit: usize) -> bool {
+    words[bit / 64] & (1 << (bit % 64)) != 0
+}
+
+fn set_bit(words: &mut [u64; TICK_ARRAY_BITMAP_WORDS], bit: usize, value: bool) {
+    if value {
+        words[bit / 64] |= 1 << (bit % 64);
+    } else {
+        words[bit / 64] &= !(1 << (bit % 64));
+    }
+}
+
+#[cfg(test)]
+mod tick_array_bitmap_tests {
+    use super::*;
+    use crate::state::MAX_TICK_INDEX;
+
+    #[test]
+    fn test_bitmap_covers_every_tick_array() {
+        for tick_spacing in [1u16, 8, 64, 128, u16::MAX] {
+            let max_start_tick_index = MAX_TICK_INDEX
+                .div_euclid(TICK_ARRAY_SIZE * tick_spacing as i32)
+                * TICK_ARRAY_SIZE
+                * tick_spacing as i32;
+            let min_start_tick_index =
+                min_array_index(tick_spacing) * TICK_ARRAY_SIZE * tick_spacing as i32;
+
+            assert_eq!(bit_index(min_start_tick_index, tick_spacing).unwrap(), 0);
+            let max_bit = bit_index(max_start_tick_index, tick_spacing).unwrap();
+            assert!(max_bit < TICK_ARRAY_BITMAP_WORDS * 64);
+            assert_eq!(
+                start_tick_index_from_bit(max_bit, tick_spacing),
+                max_start_tick_index
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_and_search() {
+        let tick_spacing = 8;
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        let mut bitmap = TickArrayBitmap::default();
+
+        bitmap
+            .update(-ticks_in_array * 100, tick_spacing, true, true)
+            .unwrap();
+        bitmap.update(0, tick_spacing, true, false).unwrap();
+        bitmap
+            .update(ticks_in_array * 3, tick_spacing, true, true)
+            .unwrap();
+
+        assert!(bitmap.is_initialized(0, tick_spacing).unwrap());
+        assert!(!bitmap.is_non_empty(0, tick_spacing).unwrap());
+        assert!(!bitmap.is_initialized(ticks_in_array, tick_spacing).unwrap());
+        assert!(bitmap.update(1, tick_spacing, true, true).is_err());
+
+        assert_eq!(
+            bitmap
+                .next_non_empty_start_tick_index(0, tick_spacing, false)
+                .unwrap(),
+            Some(ticks_in_array * 3)
+        );
+        assert_eq!(
+            bitmap
+                .next_non_empty_start_tick_index(0, tick_spacing, true)
+                .unwrap(),
+            Some(-ticks_in_array * 100)
+        );
+        assert_eq!(
+            bitmap
+                .next_non_empty_start_tick_index(ticks_in_array * 3, tick_spacing, true)
+                .unwrap(),
+            Some(ticks_in_array * 3)
+        );
+        assert_eq!(
+            bitmap
+                .next_non_empty_start_tick_index(ticks_in_array * 4, tick_spacing, false)
+                .unwrap(),
+            None
+        );
+
+        bitmap
+            .update(-ticks_in_array * 100, tick_spacing, false, false)
+            .unwrap();
+        assert_eq!(
+            bitmap
+                .next_non_empty_start_tick_index(0, tick_spacing, true)
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Record of the account that paid the rent of a TickArray. The rent of the TickArray and of this
+/// record is refunded to the funder when the TickArray is closed.
+#[account]
+#[derive(Default)]
+pub struct TickArrayFunder {
+    pub tick_array: Pubkey, // 32
+    pub funder: Pubkey,     // 32
+}
+
+impl TickArrayFunder {
+    pub const LEN: usize = 8 + 64;
+
+    pub fn initialize(&mut self, tick_array: Pubkey, funder: Pubkey) {
+        self.tick_array = tick_array;
+        self.funder = funder;
+    }
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, state::ElysiumPoolsConfigExtension};
+
+/// Finds the extension of a config among the remaining accounts. If the extension is not passed
+/// or has not been initialized, the default extension is returned, which holds the strictest
+/// settings. Leaving the extension out can therefore never relax a setting.
+pub fn load_config_extension(
+    pools_config: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<ElysiumPoolsConfigExtension> {
+    let address = ElysiumPoolsConfigExtension::find_address(&pools_config);
+    let account_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == address);
+
+    match account_info {
+        Some(account_info) if account_info.owner == &crate::ID => {
+            Ok(Account::<ElysiumPoolsConfigExtension>::try_from(account_info)?.into_inner())
+        }
+        _ => Ok(ElysiumPoolsConfigExtension::default()),
+    }
+}
+
+/// Verifies that the permissioned pool authority of the config signed the creation of a
+/// permissioned pool. The authority can sign anywhere in the remaining accounts.
+///
+/// # Errors
+/// - `InvalidPermissionedPoolAuthority` - The remaining accounts hold no signature of the config
+///                                        extension's permissioned_pool_authority.
+pub fn verify_permissioned_pool_authority(
+    config_extension: &ElysiumPoolsConfigExtension,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let signed = remaining_accounts.iter().any(|account_info| {
+        account_info.is_signer
+            && config_extension.is_permissioned_pool_authority(account_info.key())
+    });
+    if !signed {
+        return Err(ErrorCode::InvalidPermissionedPoolAuthority.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod config_extension_tests {
+    use super::*;
+    use crate::util::test_utils::TestAccount;
+    use anchor_lang::system_program;
+
+    #[test]
+    fn test_missing_extension_uses_defaults() {
+        let pools_config = Pubkey::new_unique();
+        let config_extension = load_config_extension(pools_config, &[]).unwrap();
+        assert_eq!(config_extension.max_activation_delay, 0);
+        assert_eq!(config_extension.max_activation_slot_delay, 0);
+    }
+
+    #[test]
+    fn test_extension_is_found_by_address() {
+        let pools_config = Pubkey::new_unique();
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        config_extension.initialize(pools_config);
+        config_extension.update_max_activation_delay(3_600, 9_000);
+
+        // An extension of another config is ignored
+        let remaining_accounts = [TestAccount::with_account(
+            ElysiumPoolsConfigExtension::find_address(&Pubkey::new_unique()),
+            &config_extension,
+        )
+        .into_account_info()];
+        let loaded = load_config_extension(pools_config, &remaining_accounts).unwrap();
+        assert_eq!(loaded.max_activation_delay, 0);
+
+        let remaining_accounts = [TestAccount::with_account(
+            ElysiumPoolsConfigExtension::find_address(&pools_config),
+            &config_extension,
+        )
+        .into_account_info()];
+        let loaded = load_config_extension(pools_config, &remaining_accounts).unwrap();
+        assert_eq!(loaded.max_activation_delay, 3_600);
+        assert_eq!(loaded.max_activation_slot_delay, 9_000);
+    }
+
+    #[test]
+    fn test_permissioned_pool_authority_must_sign() {
+        let authority = Pubkey::new_unique();
+        let mut config_extension = ElysiumPoolsConfigExtension::default();
+        config_extension.update_permissioned_pool_authority(authority);
+
+        assert_eq!(
+            verify_permissioned_pool_authority(
+                &config_extension,
+                &[
+                    TestAccount::new(authority, system_program::ID, 0, Vec::new())
+                        .into_account_info()
+                ]
+            ),
+            Err(ErrorCode::InvalidPermissionedPoolAuthority.into())
+        );
+        assert_eq!(
+            verify_permissioned_pool_authority(
+                &config_extension,
+                &[TestAccount::signer(Pubkey::new_unique(), 0).into_account_info()]
+            ),
+            Err(ErrorCode::InvalidPermissionedPoolAuthority.into())
+        );
+        assert!(verify_permissioned_pool_authority(
+            &config_extension,
+            &[TestAccount::signer(authority, 0).into_account_info()]
+        )
+        .is_ok());
+
+        // Without an authority no permissioned pools can be created
+        assert!(verify_permissioned_pool_authority(
+            &ElysiumPoolsConfigExtension::default(),
+            &[TestAccount::signer(Pubkey::default(), 0).into_account_info()]
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{AllowlistEntry, ElysiumPool};
+
+use super::to_timestamp_u64;
+
+/// Point in time after which a user instruction is no longer allowed to execute.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Deadline {
+    /// Unix timestamp (inclusive) compared against `Clock.unix_timestamp`.
+    UnixTimestamp(i64),
+    /// Slot (inclusive) compared against `Clock.slot`.
+    Slot(u64),
+}
+
+/// Verify that the optional deadline has not passed.
+///
+/// # Parameters
+/// - `deadline` - The user provided deadline. No check is performed if `None`.
+/// - `clock` - The current cluster clock
+///
+/// # Errors
+/// - `DeadlineExceeded` - The current timestamp or slot is past the deadline.
+pub fn verify_deadline(deadline: Option<Deadline>, clock: &Clock) -> Result<()> {
+    let expired = match deadline {
+        Some(Deadline::UnixTimestamp(timestamp)) => clock.unix_timestamp > timestamp,
+        Some(Deadline::Slot(slot)) => clock.slot > slot,
+        None => false,
+    };
+
+    if expired {
+        return Err(ErrorCode::DeadlineExceeded.into());
+    }
+
+    Ok(())
+}
+
+/// Verify that the pool's sqrt-price sits within the optional user provided bounds.
+///
+/// # Parameters
+/// - `sqrt_price` - The current sqrt-price of the pool
+/// - `min_sqrt_price` - The inclusive lower bound. No check is performed if `None`.
+/// - `max_sqrt_price` - The inclusive upper bound. No check is performed if `None`.
+///
+/// # Errors
+/// - `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is below the min or above the max.
+pub fn verify_sqrt_price_bounds(
+    sqrt_price: u128,
+    min_sqrt_price: Option<u128>,
+    max_sqrt_price: Option<u128>,
+) -> Result<()> {
+    let below_min = matches!(min_sqrt_price, Some(min) if sqrt_price < min);
+    let above_max = matches!(max_sqrt_price, Some(max) if sqrt_price > max);
+
+    if below_min || above_max {
+        return Err(ErrorCode::PoolSqrtPriceOutOfBounds.into());
+    }
+
+    Ok(())
+}
+
+/// Verify that the pool has reached its activation timestamp and slot.
+///
+/// # Errors
+/// - `PoolNotActivated` - The current timestamp or slot is before the pool's activation.
+pub fn verify_pool_activated(pool: &ElysiumPool, clock: &Clock) -> Result<()> {
+    if !pool.is_activated(to_timestamp_u64(clock.unix_timestamp)?, clock.slot) {
+        return Err(ErrorCode::PoolNotActivated.into());
+    }
+
+    Ok(())
+}
+
+/// Verify that a wallet is on the allowlist of a permissioned pool. The AllowlistEntry of the
+/// wallet can be anywhere in the remaining accounts.
+///
+/// # Errors
+/// - `WalletNotAllowlisted` - The pool is permissioned and the remaining accounts hold no
+///                            AllowlistEntry of the wallet for the pool.
+pub fn verify_allowlisted(
+    pool: &Account<ElysiumPool>,
+    wallet: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if !pool.is_permissioned() {
+        return Ok(());
+    }
+
+    let allowlisted = remaining_accounts
+        .iter()
+        .any(|account_info| is_allowlist_entry(account_info, pool.key(), wallet));
+    if !allowlisted {
+        return Err(ErrorCode::WalletNotAllowlisted.into());
+    }
+
+    Ok(())
+}
+
+fn is_allowlist_entry(account_info: &AccountInfo, pool: Pubkey, wallet: Pubkey) -> bool {
+    if account_info.owner != &crate::ID {
+        return false;
+    }
+    match account_info.try_borrow_data() {
+        Ok(data) => matches!(
+            AllowlistEntry::try_deserialize(&mut &data[..]),
+            Ok(entry) if entry.pool == pool && entry.wallet == wallet
+        ),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod guards_tests {
+    use super::*;
+
+    fn clock(unix_timestamp: i64, slot: u64) -> Clock {
+        Clock {
+            unix_timestamp,
+            slot,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_verify_deadline_none() {
+        assert!(verify_deadline(None, &clock(i64::MAX, u64::MAX)).is_ok());
+    }
+
+    #[test]
+    fn test_verify_deadline_timestamp() {
+        let deadline = Some(Deadline::UnixTimestamp(1_000));
+        assert!(verify_deadline(deadline, &clock(999, 5_000)).is_ok());
+        assert!(verify_deadline(deadline, &clock(1_000, 5_000)).is_ok());
+        assert!(verify_deadline(deadline, &clock(1_001, 0)).is_err());
+    }
+
+    #[test]
+    fn test_verify_deadline_slot() {
+        let deadline = Some(Deadline::Slot(1_000));
+        assert!(verify_deadline(deadline, &clock(5_000, 1_000)).is_ok());
+        assert!(verify_deadline(deadline, &clock(0, 1_001)).is_err());
+    }
+
+    #[test]
+    fn test_verify_sqrt_price_bounds() {
+        assert!(verify_sqrt_price_bounds(100, None, None).is_ok());
+        assert!(verify_sqrt_price_bounds(100, Some(100), Some(100)).is_ok());
+        assert!(verify_sqrt_price_bounds(100, Some(101), None).is_err());
+        assert!(verify_sqrt_price_bounds(100, None, Some(99)).is_err());
+    }
+
+    #[test]
+    fn test_verify_pool_activated() {
+        let mut pool = ElysiumPool::default();
+        assert!(verify_pool_activated(&pool, &clock(0, 0)).is_ok());
+
+        pool.update_activation(1_000, 500);
+        assert!(verify_pool_activated(&pool, &clock(999, 500)).is_err());
+        assert!(verify_pool_activated(&pool, &clock(1_000, 499)).is_err());
+        assert!(verify_pool_activated(&pool, &clock(1_000, 500)).is_ok());
+    }
+
+    #[test]
+    fn test_is_allowlist_entry() {
+        let pool = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut data = Vec::new();
+        AllowlistEntry { pool, wallet }
+            .try_serialize(&mut data)
+            .unwrap();
+
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(is_allowlist_entry(&account_info, pool, wallet));
+        assert!(!is_allowlist_entry(
+            &account_info,
+            pool,
+            Pubkey::new_unique()
+        ));
+        assert!(!is_allowlist_entry(
+            &account_info,
+            Pubkey::new_unique(),
+            wallet
+        ));
+
+        let other_owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = account_info.data.borrow().to_vec();
+        let foreign_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &other_owner,
+            false,
+            0,
+        );
+        assert!(!is_allowlist_entry(&foreign_account_info, pool, wallet));
+    }
+}
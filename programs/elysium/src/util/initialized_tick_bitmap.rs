@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use std::convert::TryInto;
+
+use crate::state::TickArray;
+
+/// Returns the initialized-tick bitmap stored after the tick array, or None if the tick array
+/// account has not been migrated to store one.
+pub fn load_initialized_tick_bitmap(tick_array: &AccountLoader<TickArray>) -> Result<Option<u128>> {
+    let account_info = tick_array.to_account_info();
+    let data = account_info.try_borrow_data()?;
+    Ok(data
+        .get(TickArray::LEN..TickArray::LEN_WITH_BITMAP)
+        .map(|bytes| u128::from_le_bytes(bytes.try_into().unwrap())))
+}
+
+/// Rebuilds the initialized-tick bitmap stored after the tick array from its ticks. Tick arrays
+/// that do not store a bitmap are left untouched.
+pub fn sync_initialized_tick_bitmap(tick_array: &AccountLoader<TickArray>) -> Result<()> {
+    let account_info = tick_array.to_account_info();
+    if account_info.data_len() < TickArray::LEN_WITH_BITMAP {
+        return Ok(());
+    }
+
+    let initialized_tick_bitmap = tick_array.load()?.initialized_tick_bitmap();
+    account_info.try_borrow_mut_data()?[TickArray::LEN..TickArray::LEN_WITH_BITMAP]
+        .copy_from_slice(&initialized_tick_bitmap.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::state::{Tick, TickUpdate};
+    use crate::util::test_utils::*;
+
+    fn tick_array_account(data_len: usize) -> AccountInfo<'static> {
+        let tick_array_info =
+            TestAccount::new(Pubkey::new_unique(), crate::ID, 0, vec![0; data_len])
+                .into_account_info();
+        let tick_array =
+            AccountLoader::<TickArray>::try_from_unchecked(&crate::ID, &tick_array_info).unwrap();
+        drop(tick_array.load_init().unwrap());
+        tick_array.exit(&crate::ID).unwrap();
+        tick_array_info
+    }
+
+    fn initialize_tick(tick_array: &AccountLoader<TickArray>, tick_index: i32) {
+        let mut update = TickUpdate::from(&Tick::default());
+        update.initialized = true;
+        tick_array
+            .load_mut()
+            .unwrap()
+            .update_tick(tick_index, 8, &update)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sync_initialized_tick_bitmap() {
+        let tick_array_info = tick_array_account(TickArray::LEN_WITH_BITMAP);
+        let tick_array = AccountLoader::<TickArray>::try_from(&tick_array_info).unwrap();
+        assert_eq!(load_initialized_tick_bitmap(&tick_array).unwrap(), Some(0));
+
+        initialize_tick(&tick_array, 16);
+        initialize_tick(&tick_array, 696);
+        sync_initialized_tick_bitmap(&tick_array).unwrap();
+
+        assert_eq!(
+            load_initialized_tick_bitmap(&tick_array).unwrap(),
+            Some(1 << 2 | 1 << 87)
+        );
+    }
+
+    #[test]
+    fn test_sync_initialized_tick_bitmap_without_bitmap() {
+        let tick_array_info = tick_array_account(TickArray::LEN);
+        let tick_array = AccountLoader::<TickArray>::try_from(&tick_array_info).unwrap();
+
+        initialize_tick(&tick_array, 16);
+        sync_initialized_tick_bitmap(&tick_array).unwrap();
+
+        assert_eq!(tick_array_info.data_len(), TickArray::LEN);
+        assert_eq!(load_initialized_tick_bitmap(&tick_array).unwrap(), None);
+    }
+}
@@ -1,10 +1,24 @@
+pub mod config_extension;
+pub mod guards;
+pub mod initialized_tick_bitmap;
+pub mod pool_hook;
+pub mod position_lock;
+pub mod reward_extension;
 pub mod swap_tick_sequence;
 pub mod swap_utils;
+pub mod tick_array_bitmap;
 pub mod token;
 pub mod util;
 
+pub use config_extension::*;
+pub use guards::*;
+pub use initialized_tick_bitmap::*;
+pub use pool_hook::*;
+pub use position_lock::*;
+pub use reward_extension::*;
 pub use swap_tick_sequence::*;
 pub use swap_utils::*;
+pub use tick_array_bitmap::*;
 pub use token::*;
 pub use util::*;
 
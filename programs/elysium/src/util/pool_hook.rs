@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+use crate::{errors::ErrorCode, state::ElysiumPool};
+
+/// Instruction discriminator of the hook entrypoint, the anchor discriminator of a
+/// `execute_pool_hook(ctx, event: PoolHookEvent)` instruction.
+pub const POOL_HOOK_DISCRIMINATOR: [u8; 8] = [212, 130, 42, 40, 241, 84, 16, 175];
+
+/// The action a pool hook is invoked for. Before-events are sent prior to any state change and
+/// after-events once the pool account has been updated, so the hook can read the new pool state.
+/// Liquidity events are only sent when liquidity is added. Withdrawals never invoke the hook, as
+/// a failing hook would otherwise lock the funds of LPs in the pool. This holds for after-events
+/// too, since an error returned by an invoked program cannot be caught.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolHookEvent {
+    BeforeSwap {
+        amount: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    },
+    AfterSwap {
+        amount_a: u64,
+        amount_b: u64,
+        a_to_b: bool,
+    },
+    BeforeModifyLiquidity {
+        position: Pubkey,
+        liquidity_delta: i128,
+    },
+    AfterModifyLiquidity {
+        position: Pubkey,
+        liquidity_delta: i128,
+        amount_a: u64,
+        amount_b: u64,
+    },
+}
+
+impl PoolHookEvent {
+    /// Instruction data of the hook invocation for this event.
+    pub fn instruction_data(&self) -> Result<Vec<u8>> {
+        let mut data = POOL_HOOK_DISCRIMINATOR.to_vec();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Invokes the pool's hook program for an event. The hook program is found among the remaining
+/// accounts, and every account after it is forwarded to the hook with its original
+/// permissions. The hook receives the accounts in the order:
+/// - pool (readonly)
+/// - authority of the action (readonly, without signer privileges)
+/// - the accounts following the hook program in the remaining accounts
+///
+/// The hook vetoes the action by returning an error, which fails the whole instruction.
+///
+/// # Errors
+/// - `InvalidPoolHookAccounts` - The pool's hook program is not in the remaining accounts.
+pub fn invoke_pool_hook<'info>(
+    pool: &Account<'info, ElysiumPool>,
+    authority: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    event: PoolHookEvent,
+) -> Result<()> {
+    if !pool.has_hook_program() {
+        return Ok(());
+    }
+
+    let hook_program_index = remaining_accounts
+        .iter()
+        .position(|account_info| account_info.key() == pool.hook_program)
+        .ok_or(ErrorCode::InvalidPoolHookAccounts)?;
+    let hook_program = &remaining_accounts[hook_program_index];
+    let hook_accounts = &remaining_accounts[hook_program_index + 1..];
+
+    // Persist the pool so the hook reads the same state as this instruction.
+    pool.exit(&crate::ID)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(pool.key(), false),
+        AccountMeta::new_readonly(authority.key(), false),
+    ];
+    accounts.extend(hook_accounts.iter().map(|account_info| {
+        if account_info.is_writable {
+            AccountMeta::new(account_info.key(), account_info.is_signer)
+        } else {
+            AccountMeta::new_readonly(account_info.key(), account_info.is_signer)
+        }
+    }));
+
+    let mut account_infos = vec![pool.to_account_info(), authority.clone()];
+    account_infos.extend_from_slice(hook_accounts);
+    account_infos.push(hook_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: pool.hook_program,
+            accounts,
+            data: event.instruction_data()?,
+        },
+        &account_infos,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod pool_hook_tests {
+    use super::*;
+    use solana_program::hash::hash;
+
+    use crate::state::pool_builder::ElysiumPoolBuilder;
+    use crate::util::test_utils::{
+        fail_invocations_of, invoked_instructions, reset_test_runtime, TestAccount,
+        FAILED_INVOCATION_ERROR,
+    };
+
+    const EVENT: PoolHookEvent = PoolHookEvent::BeforeSwap {
+        amount: 100,
+        sqrt_price_limit: 0,
+        amount_specified_is_input: true,
+        a_to_b: false,
+    };
+
+    fn pool_account(hook_program: Pubkey) -> Account<'static, ElysiumPool> {
+        let mut pool = ElysiumPoolBuilder::new().build();
+        pool.update_hook_program(hook_program);
+        let account_info: &'static AccountInfo<'static> = Box::leak(Box::new(
+            TestAccount::with_account(Pubkey::new_unique(), &pool).into_account_info(),
+        ));
+        Account::try_from(account_info).unwrap()
+    }
+
+    #[test]
+    fn test_pool_without_hook_is_not_invoked() {
+        reset_test_runtime(Clock::default());
+        let pool = pool_account(Pubkey::default());
+        let authority = TestAccount::signer(Pubkey::new_unique(), 0).into_account_info();
+
+        invoke_pool_hook(&pool, &authority, &[], EVENT).unwrap();
+
+        assert!(invoked_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_missing_hook_program() {
+        reset_test_runtime(Clock::default());
+        let pool = pool_account(Pubkey::new_unique());
+        let authority = TestAccount::signer(Pubkey::new_unique(), 0).into_account_info();
+
+        let result = invoke_pool_hook(&pool, &authority, &[], EVENT);
+
+        assert_eq!(result, Err(ErrorCode::InvalidPoolHookAccounts.into()));
+        assert!(invoked_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_invokes_hook_with_forwarded_accounts() {
+        reset_test_runtime(Clock::default());
+        let hook_program = Pubkey::new_unique();
+        let pool = pool_account(hook_program);
+        let authority = TestAccount::signer(Pubkey::new_unique(), 0).into_account_info();
+        let ignored = TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, Vec::new());
+        let writable = TestAccount::new(Pubkey::new_unique(), hook_program, 0, Vec::new());
+        let readonly =
+            TestAccount::new(Pubkey::new_unique(), hook_program, 0, Vec::new()).readonly();
+        let remaining_accounts = [
+            ignored.into_account_info(),
+            TestAccount::program(hook_program).into_account_info(),
+            writable.into_account_info(),
+            readonly.into_account_info(),
+        ];
+
+        invoke_pool_hook(&pool, &authority, &remaining_accounts, EVENT).unwrap();
+
+        let invoked = invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        assert_eq!(invoked[0].program_id, hook_program);
+        assert_eq!(invoked[0].data, EVENT.instruction_data().unwrap());
+        // The authority is forwarded without its signature, accounts before the hook program
+        // are not forwarded at all
+        assert_eq!(
+            invoked[0].accounts,
+            vec![
+                AccountMeta::new_readonly(pool.key(), false),
+                AccountMeta::new_readonly(authority.key(), false),
+                AccountMeta::new(remaining_accounts[2].key(), false),
+                AccountMeta::new_readonly(remaining_accounts[3].key(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failing_hook_fails_the_action() {
+        reset_test_runtime(Clock::default());
+        let hook_program = Pubkey::new_unique();
+        fail_invocations_of(hook_program);
+        let pool = pool_account(hook_program);
+        let authority = TestAccount::signer(Pubkey::new_unique(), 0).into_account_info();
+        let remaining_accounts = [TestAccount::program(hook_program).into_account_info()];
+
+        let result = invoke_pool_hook(&pool, &authority, &remaining_accounts, EVENT);
+
+        assert_eq!(
+            result,
+            Err(ProgramError::Custom(FAILED_INVOCATION_ERROR).into())
+        );
+    }
+
+    #[test]
+    fn test_discriminator_matches_anchor_instruction() {
+        let preimage = hash(b"global:execute_pool_hook");
+        assert_eq!(POOL_HOOK_DISCRIMINATOR, preimage.to_bytes()[..8]);
+    }
+
+    #[test]
+    fn test_instruction_data_layout() {
+        let data = PoolHookEvent::AfterSwap {
+            amount_a: 1,
+            amount_b: 2,
+            a_to_b: true,
+        }
+        .instruction_data()
+        .unwrap();
+
+        assert_eq!(data[..8], POOL_HOOK_DISCRIMINATOR);
+        // Variant index followed by the borsh encoded fields
+        assert_eq!(data[8], 1);
+        assert_eq!(data[9..17], 1u64.to_le_bytes());
+        assert_eq!(data[17..25], 2u64.to_le_bytes());
+        assert_eq!(data[25], 1);
+        assert_eq!(data.len(), 26);
+    }
+}
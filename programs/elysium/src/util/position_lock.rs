@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{errors::ErrorCode, state::PositionLock};
+
+use super::verify_position_authority;
+
+/// Verifies the authority of a position that is about to lose liquidity or be closed. The token
+/// of a locked position is owned by its PositionLock, so nobody can act as its authority.
+///
+/// # Errors
+/// - `PositionLocked` - The position token is held by the lock of the position.
+pub fn verify_unlocked_position_authority(
+    position_token_account: &TokenAccount,
+    position_authority: &Signer,
+    position: Pubkey,
+) -> Result<()> {
+    // The lock address is only derived if the signer is not the authority of the token.
+    verify_position_authority(position_token_account, position_authority).map_err(|err| {
+        if position_token_account.owner == PositionLock::find_address(&position) {
+            ErrorCode::PositionLocked.into()
+        } else {
+            err
+        }
+    })
+}
+
+/// Verifies the authority of a position that is collecting fees or rewards. If the position token
+/// is held by the lock of the position, the beneficiary of the lock is the authority and the lock
+/// must be passed in the remaining accounts.
+pub fn verify_position_or_lock_authority(
+    position_token_account: &TokenAccount,
+    position_authority: &Signer,
+    position: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let err = match verify_position_authority(position_token_account, position_authority) {
+        Ok(()) => return Ok(()),
+        Err(err) => err,
+    };
+
+    let position_lock = PositionLock::find_address(&position);
+    if position_token_account.owner != position_lock {
+        return Err(err);
+    }
+
+    if lock_beneficiary(position_lock, remaining_accounts) != Some(position_authority.key()) {
+        return Err(ErrorCode::MissingOrInvalidDelegate.into());
+    }
+
+    Ok(())
+}
+
+/// Returns the owner that fees and rewards of a position are paid out to. If the position token
+/// is held by the lock of the position, they are paid out to the beneficiary of the lock, and the
+/// lock must be passed in the remaining accounts.
+///
+/// # Errors
+/// - `MissingOrInvalidDelegate` - The position is locked and its lock is not passed.
+pub fn position_or_lock_owner(
+    position_token_account: &TokenAccount,
+    position: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Pubkey> {
+    let position_lock = PositionLock::find_address(&position);
+    if position_token_account.owner != position_lock {
+        return Ok(position_token_account.owner);
+    }
+
+    lock_beneficiary(position_lock, remaining_accounts)
+        .ok_or_else(|| ErrorCode::MissingOrInvalidDelegate.into())
+}
+
+fn lock_beneficiary(position_lock: Pubkey, remaining_accounts: &[AccountInfo]) -> Option<Pubkey> {
+    let account_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == position_lock)?;
+    if account_info.owner != &crate::ID {
+        return None;
+    }
+    let data = account_info.try_borrow_data().ok()?;
+    PositionLock::try_deserialize(&mut &data[..])
+        .ok()
+        .map(|lock| lock.beneficiary)
+}
+
+#[cfg(test)]
+mod position_lock_tests {
+    use super::*;
+    use crate::util::test_utils::TestAccount;
+    use solana_program::program_pack::Pack;
+
+    fn position_token_account(owner: Pubkey) -> TokenAccount {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount: 1,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        TokenAccount::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    fn lock_account(position: Pubkey, beneficiary: Pubkey) -> AccountInfo<'static> {
+        TestAccount::with_account(
+            PositionLock::find_address(&position),
+            &PositionLock {
+                position,
+                beneficiary,
+                unlock_timestamp: 200,
+                bump: [255],
+            },
+        )
+        .into_account_info()
+    }
+
+    fn signer(key: Pubkey) -> AccountInfo<'static> {
+        TestAccount::signer(key, 0).into_account_info()
+    }
+
+    #[test]
+    fn test_owner_of_unlocked_position() {
+        let position = Pubkey::new_unique();
+        let owner = signer(Pubkey::new_unique());
+        let owner = Signer::try_from(&owner).unwrap();
+        let token_account = position_token_account(owner.key());
+
+        assert!(verify_unlocked_position_authority(&token_account, &owner, position).is_ok());
+        assert!(verify_position_or_lock_authority(&token_account, &owner, position, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_locked_position_cannot_be_withdrawn() {
+        let position = Pubkey::new_unique();
+        let beneficiary = signer(Pubkey::new_unique());
+        let beneficiary = Signer::try_from(&beneficiary).unwrap();
+        let token_account = position_token_account(PositionLock::find_address(&position));
+
+        assert_eq!(
+            verify_unlocked_position_authority(&token_account, &beneficiary, position).unwrap_err(),
+            ErrorCode::PositionLocked.into()
+        );
+    }
+
+    #[test]
+    fn test_beneficiary_collects_from_locked_position() {
+        let position = Pubkey::new_unique();
+        let beneficiary = signer(Pubkey::new_unique());
+        let beneficiary = Signer::try_from(&beneficiary).unwrap();
+        let token_account = position_token_account(PositionLock::find_address(&position));
+        let lock = lock_account(position, beneficiary.key());
+
+        assert!(
+            verify_position_or_lock_authority(&token_account, &beneficiary, position, &[lock])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_locked_position_requires_lock_of_beneficiary() {
+        let position = Pubkey::new_unique();
+        let other = signer(Pubkey::new_unique());
+        let other = Signer::try_from(&other).unwrap();
+        let token_account = position_token_account(PositionLock::find_address(&position));
+        let lock = lock_account(position, Pubkey::new_unique());
+
+        assert_eq!(
+            verify_position_or_lock_authority(&token_account, &other, position, &[]).unwrap_err(),
+            ErrorCode::MissingOrInvalidDelegate.into()
+        );
+        assert_eq!(
+            verify_position_or_lock_authority(&token_account, &other, position, &[lock])
+                .unwrap_err(),
+            ErrorCode::MissingOrInvalidDelegate.into()
+        );
+    }
+
+    #[test]
+    fn test_payouts_of_locked_position_go_to_beneficiary() {
+        let position = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let lock = lock_account(position, beneficiary);
+
+        let token_account = position_token_account(owner);
+        assert_eq!(
+            position_or_lock_owner(&token_account, position, &[]).unwrap(),
+            owner
+        );
+
+        let token_account = position_token_account(PositionLock::find_address(&position));
+        assert_eq!(
+            position_or_lock_owner(&token_account, position, &[lock]).unwrap(),
+            beneficiary
+        );
+        assert_eq!(
+            position_or_lock_owner(&token_account, position, &[]).unwrap_err(),
+            ErrorCode::MissingOrInvalidDelegate.into()
+        );
+    }
+
+    #[test]
+    fn test_lock_of_other_position_is_not_authority() {
+        let position = Pubkey::new_unique();
+        let beneficiary = signer(Pubkey::new_unique());
+        let beneficiary = Signer::try_from(&beneficiary).unwrap();
+        let token_account = position_token_account(Pubkey::new_unique());
+        let lock = lock_account(position, beneficiary.key());
+
+        assert_eq!(
+            verify_position_or_lock_authority(&token_account, &beneficiary, position, &[lock])
+                .unwrap_err(),
+            ErrorCode::MissingOrInvalidDelegate.into()
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    manager::{
+        pool_manager::next_extension_reward_infos,
+        reward_extension_manager::calculate_reward_extension_modify_liquidity,
+        tick_manager::next_reward_growths_outside_on_cross,
+    },
+    state::*,
+};
+
+/// Extension accounts required to modify the liquidity of a position in a pool that has a
+/// PoolRewardExtension.
+pub struct ModifyLiquidityRewardExtension<'info> {
+    pub reward_extension: Account<'info, PoolRewardExtension>,
+    /// `None` if the position has no PositionRewardExtension and does not earn extension rewards.
+    pub position: Option<PositionRewardExtensionAccounts<'info>>,
+}
+
+/// Extension accounts of a position that earns extension rewards.
+pub struct PositionRewardExtensionAccounts<'info> {
+    pub tick_array_lower_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    pub tick_array_upper_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    pub position_extension: Account<'info, PositionRewardExtension>,
+}
+
+/// Finds the extension accounts for a liquidity change among the remaining accounts. The pool's
+/// reward_extension is required, along with the account at the address of the position's
+/// extension. If that account has not been initialized, the position does not earn extension
+/// rewards and no tick array extensions are needed. Otherwise the extensions of both tick arrays
+/// are required, which exist because a position extension can only be created after them.
+///
+/// # Returns
+/// - `None` if the pool has no reward extension
+///
+/// # Errors
+/// - `InvalidRewardExtension` - A required extension account is not in the remaining accounts.
+pub fn load_modify_liquidity_reward_extension<'info>(
+    pool: &ElysiumPool,
+    position: Pubkey,
+    tick_array_lower: Pubkey,
+    tick_array_upper: Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<ModifyLiquidityRewardExtension<'info>>> {
+    if !pool.has_reward_extension() {
+        return Ok(None);
+    }
+
+    let reward_extension_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == pool.reward_extension)
+        .ok_or(ErrorCode::InvalidRewardExtension)?;
+    let reward_extension = Account::<PoolRewardExtension>::try_from(reward_extension_info)?;
+
+    let position_extension_info = find_extension_account(
+        PositionRewardExtension::find_address(&position),
+        remaining_accounts,
+    )?;
+    let position = match position_extension_info {
+        Some(position_extension_info) => Some(PositionRewardExtensionAccounts {
+            tick_array_lower_extension: find_tick_array_extension(
+                tick_array_lower,
+                remaining_accounts,
+            )?
+            .ok_or(ErrorCode::InvalidRewardExtension)?,
+            tick_array_upper_extension: find_tick_array_extension(
+                tick_array_upper,
+                remaining_accounts,
+            )?
+            .ok_or(ErrorCode::InvalidRewardExtension)?,
+            position_extension: Account::try_from(position_extension_info)?,
+        }),
+        None => None,
+    };
+
+    Ok(Some(ModifyLiquidityRewardExtension {
+        reward_extension,
+        position,
+    }))
+}
+
+// Finds the account at the address of an extension among the remaining accounts. Returns None if
+// the account is passed but has not been initialized.
+fn find_extension_account<'a, 'info>(
+    address: Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    let account_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == address)
+        .ok_or(ErrorCode::InvalidRewardExtension)?;
+
+    if account_info.owner != &crate::ID {
+        return Ok(None);
+    }
+    Ok(Some(account_info))
+}
+
+// Finds the extension of a tick array among the remaining accounts, by the tick array it belongs
+// to. Extensions are created at an address derived from their tick array, so there is at most one.
+fn find_tick_array_extension<'info>(
+    tick_array: Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<AccountLoader<'info, TickArrayRewardExtension>>> {
+    for account_info in remaining_accounts {
+        if account_info.owner != &crate::ID {
+            continue;
+        }
+        if let Ok(tick_array_extension) =
+            AccountLoader::<TickArrayRewardExtension>::try_from(account_info)
+        {
+            if tick_array_extension.load()?.tick_array == tick_array {
+                return Ok(Some(tick_array_extension));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Calculates and applies the extension reward changes for modifying the liquidity of a position.
+/// Must be called before the pool, ticks and position are updated for the liquidity change.
+pub fn modify_liquidity_reward_extension(
+    extension: &mut ModifyLiquidityRewardExtension,
+    pool: &ElysiumPool,
+    position: &Position,
+    tick_array_lower: &AccountLoader<TickArray>,
+    tick_array_upper: &AccountLoader<TickArray>,
+    liquidity_delta: i128,
+    timestamp: u64,
+) -> Result<()> {
+    let position_extension = match &mut extension.position {
+        Some(position_extension) => position_extension,
+        None => {
+            // The pool liquidity may change, so the rewards are accrued up to now.
+            let reward_infos =
+                next_extension_reward_infos(pool, &extension.reward_extension, timestamp)?;
+            extension
+                .reward_extension
+                .update_rewards(reward_infos, timestamp);
+            return extension.reward_extension.exit(&crate::ID);
+        }
+    };
+
+    let tick_spacing = pool.tick_spacing;
+    let tick_lower = *tick_array_lower
+        .load()?
+        .get_tick(position.tick_lower_index, tick_spacing)?;
+    let tick_upper = *tick_array_upper
+        .load()?
+        .get_tick(position.tick_upper_index, tick_spacing)?;
+
+    let update = calculate_reward_extension_modify_liquidity(
+        pool,
+        &extension.reward_extension,
+        position,
+        &position_extension.position_extension,
+        &tick_lower,
+        position_extension
+            .tick_array_lower_extension
+            .load()?
+            .get_reward_growths_outside(position.tick_lower_index, tick_spacing)?,
+        &tick_upper,
+        position_extension
+            .tick_array_upper_extension
+            .load()?
+            .get_reward_growths_outside(position.tick_upper_index, tick_spacing)?,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    // The lower and upper tick can live in the same tick array, so each update is applied
+    // with a separate borrow.
+    position_extension
+        .tick_array_lower_extension
+        .load_mut()?
+        .update_reward_growths_outside(
+            position.tick_lower_index,
+            tick_spacing,
+            update.tick_lower_reward_growths_outside,
+        )?;
+    position_extension
+        .tick_array_upper_extension
+        .load_mut()?
+        .update_reward_growths_outside(
+            position.tick_upper_index,
+            tick_spacing,
+            update.tick_upper_reward_growths_outside,
+        )?;
+
+    extension
+        .reward_extension
+        .update_rewards(update.reward_infos, timestamp);
+    position_extension
+        .position_extension
+        .update_reward_infos(update.position_reward_infos);
+
+    // Accounts loaded from remaining accounts are not persisted by anchor.
+    extension.reward_extension.exit(&crate::ID)?;
+    position_extension.position_extension.exit(&crate::ID)?;
+
+    Ok(())
+}
+
+/// Extension accounts required to swap in a pool that has a PoolRewardExtension.
+pub struct SwapRewardExtension<'info> {
+    pub reward_extension: Account<'info, PoolRewardExtension>,
+    pub tick_array_extensions: Vec<AccountLoader<'info, TickArrayRewardExtension>>,
+}
+
+/// Finds the extension accounts for a swap among the remaining accounts. Extension rewards only
+/// need to be accrued by swaps that cross a tick, as the pool liquidity does not change otherwise.
+/// Such swaps require the pool's reward_extension, along with the account at the address of the
+/// extension of every tick array of the swap. Tick arrays whose extension has not been initialized
+/// back no position extensions, so the ticks crossed in them are not tracked. Accounts that belong
+/// to other pools or tick arrays are ignored, which allows a single list of remaining accounts to
+/// serve multiple swaps.
+///
+/// # Returns
+/// - `None` if the pool has no reward extension or the swap did not cross a tick
+///
+/// # Errors
+/// - `InvalidRewardExtension` - A required extension account is not in the remaining accounts.
+pub fn load_swap_reward_extension<'info>(
+    pool: &ElysiumPool,
+    tick_arrays: &[Pubkey],
+    crossed_tick_indexes: &[i32],
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<SwapRewardExtension<'info>>> {
+    if !pool.has_reward_extension() || crossed_tick_indexes.is_empty() {
+        return Ok(None);
+    }
+
+    let reward_extension_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == pool.reward_extension)
+        .ok_or(ErrorCode::InvalidRewardExtension)?;
+    let reward_extension = Account::<PoolRewardExtension>::try_from(reward_extension_info)?;
+
+    let mut tick_array_extensions = Vec::new();
+    for (index, tick_array) in tick_arrays.iter().enumerate() {
+        // The same tick array can be passed more than once.
+        if tick_arrays[..index].contains(tick_array) {
+            continue;
+        }
+        if let Some(account_info) = find_extension_account(
+            TickArrayRewardExtension::find_address(tick_array),
+            remaining_accounts,
+        )? {
+            tick_array_extensions.push(AccountLoader::try_from(account_info)?);
+        }
+    }
+
+    Ok(Some(SwapRewardExtension {
+        reward_extension,
+        tick_array_extensions,
+    }))
+}
+
+/// Accrues the extension rewards and flips the extension reward growths outside of every tick
+/// crossed by a swap that is held by a tick array with an extension. Must be called before the
+/// pool is updated with the swap result.
+pub fn swap_reward_extension(
+    extension: &mut SwapRewardExtension,
+    pool: &ElysiumPool,
+    crossed_tick_indexes: &[i32],
+    timestamp: u64,
+) -> Result<()> {
+    // Rewards are accrued once per swap, so every crossed tick uses the same global growths.
+    let next_reward_infos =
+        next_extension_reward_infos(pool, &extension.reward_extension, timestamp)?;
+
+    for &tick_index in crossed_tick_indexes {
+        let tick_array_extension = extension.tick_array_extensions.iter().find(
+            |tick_array_extension| {
+                matches!(
+                    tick_array_extension.load(),
+                    Ok(tick_array_extension)
+                        if tick_array_extension.check_in_array_bounds(tick_index, pool.tick_spacing)
+                )
+            },
+        );
+        let tick_array_extension = match tick_array_extension {
+            Some(tick_array_extension) => tick_array_extension,
+            None => continue,
+        };
+
+        let mut tick_array_extension = tick_array_extension.load_mut()?;
+        let reward_growths_outside =
+            tick_array_extension.get_reward_growths_outside(tick_index, pool.tick_spacing)?;
+        tick_array_extension.update_reward_growths_outside(
+            tick_index,
+            pool.tick_spacing,
+            next_reward_growths_outside_on_cross(reward_growths_outside, &next_reward_infos),
+        )?;
+    }
+
+    extension
+        .reward_extension
+        .update_rewards(next_reward_infos, timestamp);
+    extension.reward_extension.exit(&crate::ID)
+}
+
+#[cfg(test)]
+mod reward_extension_tests {
+    use super::*;
+    use crate::state::pool_builder::ElysiumPoolBuilder;
+    use crate::util::test_utils::TestAccount;
+    use anchor_lang::system_program;
+
+    fn pool_with_extension(reward_extension: Pubkey) -> ElysiumPool {
+        let mut pool = ElysiumPoolBuilder::new().build();
+        pool.reward_extension = reward_extension;
+        pool
+    }
+
+    fn reward_extension_account(key: Pubkey) -> AccountInfo<'static> {
+        TestAccount::with_account(key, &PoolRewardExtension::default()).into_account_info()
+    }
+
+    fn uninitialized_account(key: Pubkey) -> AccountInfo<'static> {
+        TestAccount::new(key, system_program::ID, 0, Vec::new()).into_account_info()
+    }
+
+    #[test]
+    fn test_position_without_extension_needs_no_tick_array_extensions() {
+        let reward_extension = Pubkey::new_unique();
+        let position = Pubkey::new_unique();
+        let remaining_accounts = [
+            reward_extension_account(reward_extension),
+            uninitialized_account(PositionRewardExtension::find_address(&position)),
+        ];
+
+        let extension = load_modify_liquidity_reward_extension(
+            &pool_with_extension(reward_extension),
+            position,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &remaining_accounts,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(extension.position.is_none());
+    }
+
+    #[test]
+    fn test_position_extension_address_is_required() {
+        let reward_extension = Pubkey::new_unique();
+        let remaining_accounts = [reward_extension_account(reward_extension)];
+
+        let result = load_modify_liquidity_reward_extension(
+            &pool_with_extension(reward_extension),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &remaining_accounts,
+        );
+
+        assert_eq!(
+            result.err().unwrap(),
+            ErrorCode::InvalidRewardExtension.into()
+        );
+    }
+
+    #[test]
+    fn test_swap_without_crossing_needs_no_extension() {
+        let pool = pool_with_extension(Pubkey::new_unique());
+
+        let extension =
+            load_swap_reward_extension(&pool, &[Pubkey::new_unique()], &[], &[]).unwrap();
+
+        assert!(extension.is_none());
+    }
+
+    #[test]
+    fn test_swap_skips_uninitialized_tick_array_extensions() {
+        let reward_extension = Pubkey::new_unique();
+        let tick_array = Pubkey::new_unique();
+        let remaining_accounts = [
+            reward_extension_account(reward_extension),
+            uninitialized_account(TickArrayRewardExtension::find_address(&tick_array)),
+        ];
+
+        let extension = load_swap_reward_extension(
+            &pool_with_extension(reward_extension),
+            &[tick_array, tick_array],
+            &[64],
+            &remaining_accounts,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(extension.tick_array_extensions.is_empty());
+    }
+
+    #[test]
+    fn test_swap_requires_tick_array_extension_address() {
+        let reward_extension = Pubkey::new_unique();
+        let remaining_accounts = [reward_extension_account(reward_extension)];
+
+        let result = load_swap_reward_extension(
+            &pool_with_extension(reward_extension),
+            &[Pubkey::new_unique()],
+            &[64],
+            &remaining_accounts,
+        );
+
+        assert_eq!(
+            result.err().unwrap(),
+            ErrorCode::InvalidRewardExtension.into()
+        );
+    }
+}
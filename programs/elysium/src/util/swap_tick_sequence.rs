@@ -5,6 +5,7 @@ use std::cell::RefMut;
 
 pub struct SwapTickSequence<'info> {
     arrays: Vec<RefMut<'info, TickArray>>,
+    initialized_tick_bitmaps: Vec<Option<u128>>,
 }
 
 impl<'info> SwapTickSequence<'info> {
@@ -13,15 +14,33 @@ impl<'info> SwapTickSequence<'info> {
         ta1: Option<RefMut<'info, TickArray>>,
         ta2: Option<RefMut<'info, TickArray>>,
     ) -> Self {
-        let mut vec = Vec::with_capacity(3);
-        vec.push(ta0);
-        if ta1.is_some() {
-            vec.push(ta1.unwrap());
+        Self::new_with_initialized_tick_bitmaps(ta0, ta1, ta2, [None; 3])
+    }
+
+    /// Creates a sequence that searches for initialized ticks with the initialized-tick bitmaps
+    /// of the tick arrays, where present. The bitmaps are given in the same order as the arrays.
+    pub fn new_with_initialized_tick_bitmaps(
+        ta0: RefMut<'info, TickArray>,
+        ta1: Option<RefMut<'info, TickArray>>,
+        ta2: Option<RefMut<'info, TickArray>>,
+        initialized_tick_bitmaps: [Option<u128>; 3],
+    ) -> Self {
+        let mut arrays = Vec::with_capacity(3);
+        let mut bitmaps = Vec::with_capacity(3);
+        arrays.push(ta0);
+        bitmaps.push(initialized_tick_bitmaps[0]);
+        if let Some(ta1) = ta1 {
+            arrays.push(ta1);
+            bitmaps.push(initialized_tick_bitmaps[1]);
         }
-        if ta2.is_some() {
-            vec.push(ta2.unwrap());
+        if let Some(ta2) = ta2 {
+            arrays.push(ta2);
+            bitmaps.push(initialized_tick_bitmaps[2]);
+        }
+        Self {
+            arrays,
+            initialized_tick_bitmaps: bitmaps,
         }
-        Self { arrays: vec }
     }
 
     /// Get the Tick object at the given tick-index & tick-spacing
@@ -122,8 +141,15 @@ impl<'info> SwapTickSequence<'info> {
                 None => return Err(ErrorCode::TickArraySequenceInvalidIndex.into()),
             };
 
-            let next_index =
-                next_array.get_next_init_tick_index(search_index, tick_spacing, a_to_b)?;
+            let next_index = match self.initialized_tick_bitmaps[array_index] {
+                Some(initialized_tick_bitmap) => next_array.get_next_init_tick_index_in_bitmap(
+                    initialized_tick_bitmap,
+                    search_index,
+                    tick_spacing,
+                    a_to_b,
+                )?,
+                None => next_array.get_next_init_tick_index(search_index, tick_spacing, a_to_b)?,
+            };
 
             match next_index {
                 Some(next_index) => {
@@ -733,4 +759,68 @@ mod swap_tick_sequence_tests {
             curr_array_index = array_index;
         }
     }
+
+    mod initialized_tick_bitmaps {
+        use super::*;
+
+        #[test]
+        /// Searching with the bitmaps of the arrays finds the same ticks as reading the ticks
+        fn search_with_bitmaps_matches_scan() {
+            let ta0 = build_tick_array(704, vec![10, 40]);
+            let ta1 = build_tick_array(0, vec![LAST_TICK_OFFSET]);
+            let ta2 = build_tick_array(-704, vec![10, 80]);
+            let initialized_tick_bitmaps = [
+                Some(ta0.borrow().initialized_tick_bitmap()),
+                None,
+                Some(ta2.borrow().initialized_tick_bitmap()),
+            ];
+            let swap_tick_sequence = SwapTickSequence::new_with_initialized_tick_bitmaps(
+                ta0.borrow_mut(),
+                Some(ta1.borrow_mut()),
+                Some(ta2.borrow_mut()),
+                initialized_tick_bitmaps,
+            );
+
+            let mut search_index = 1407;
+            let mut curr_array_index = 0;
+            let expectation = [
+                (1024, 0),
+                (784, 0),
+                (696, 1),
+                (-64, 2),
+                (-624, 2),
+                (-704, 2),
+            ];
+
+            for (expected_index, expected_array_index) in expectation {
+                let (array_index, index) = swap_tick_sequence
+                    .get_next_initialized_tick_index(search_index, TS_8, true, curr_array_index)
+                    .unwrap();
+                assert_eq!(index, expected_index);
+                assert_eq!(array_index, expected_array_index);
+
+                search_index = index - 1;
+                curr_array_index = array_index;
+            }
+        }
+
+        #[test]
+        /// The bitmaps are given in the order of the arrays, so they stay aligned when an array is
+        /// missing from the sequence.
+        fn search_with_missing_array() {
+            let ta0 = build_tick_array(0, vec![]);
+            let ta2 = build_tick_array(704, vec![30]);
+            let swap_tick_sequence = SwapTickSequence::new_with_initialized_tick_bitmaps(
+                ta0.borrow_mut(),
+                None,
+                Some(ta2.borrow_mut()),
+                [Some(0), Some(u128::MAX), Some(1 << 30)],
+            );
+
+            let (array_index, index) = swap_tick_sequence
+                .get_next_initialized_tick_index(0, TS_8, false, 0)
+                .unwrap();
+            assert_eq!((array_index, index), (1, 944));
+        }
+    }
 }
@@ -24,6 +24,7 @@ pub fn update_and_swap_pool<'info>(
         swap_update.next_fee_growth_global,
         swap_update.next_reward_infos,
         swap_update.next_protocol_fee,
+        swap_update.next_creator_fee,
         is_token_fee_in_a,
         reward_last_updated_timestamp,
     );
@@ -175,18 +175,21 @@ pub fn create_pool_reward_infos(
             mint: Pubkey::new_unique(),
             emissions_per_second_x64,
             growth_global_x64,
+            total_funded: 1_000_000,
             ..Default::default()
         },
         ElysiumPoolRewardInfo {
             mint: Pubkey::new_unique(),
             emissions_per_second_x64,
             growth_global_x64,
+            total_funded: 1_000_000,
             ..Default::default()
         },
         ElysiumPoolRewardInfo {
             mint: Pubkey::new_unique(),
             emissions_per_second_x64,
             growth_global_x64,
+            total_funded: 1_000_000,
             ..Default::default()
         },
     ]
@@ -1,5 +1,7 @@
 pub mod liquidity_test_fixture;
+pub mod runtime_test_fixture;
 pub mod swap_test_fixture;
 
 pub use liquidity_test_fixture::*;
+pub use runtime_test_fixture::*;
 pub use swap_test_fixture::*;
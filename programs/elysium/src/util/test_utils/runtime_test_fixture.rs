@@ -0,0 +1,207 @@
+use std::{cell::RefCell, collections::HashSet, sync::Once};
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use solana_program::{
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    instruction::Instruction,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    program_utils::limited_deserialize,
+    system_instruction::SystemInstruction,
+};
+
+// Error returned by programs registered with `fail_invocations_of`.
+pub const FAILED_INVOCATION_ERROR: u32 = 0xdead;
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+    static INVOKED_INSTRUCTIONS: RefCell<Vec<Instruction>> = const { RefCell::new(Vec::new()) };
+    static FAILING_PROGRAMS: RefCell<HashSet<Pubkey>> = RefCell::new(HashSet::new());
+}
+
+static INSTALL_STUBS: Once = Once::new();
+
+// Syscalls for running instruction handlers in unit tests. Sysvars and invocations are kept per
+// thread, so tests running in parallel do not observe each other. Invocations of the system
+// program are applied to the account infos, other invocations are only recorded.
+struct TestSyscallStubs;
+
+impl SyscallStubs for TestSyscallStubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED_INSTRUCTIONS.with(|invoked| invoked.borrow_mut().push(instruction.clone()));
+        if FAILING_PROGRAMS.with(|failing| failing.borrow().contains(&instruction.program_id)) {
+            return Err(ProgramError::Custom(FAILED_INVOCATION_ERROR));
+        }
+        if instruction.program_id == system_program::ID {
+            process_system_instruction(instruction, account_infos)?;
+        }
+        Ok(())
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = CLOCK.with(|clock| clock.borrow().clone());
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+}
+
+fn process_system_instruction(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+) -> ProgramResult {
+    let account_info = |index: usize| {
+        account_infos
+            .iter()
+            .find(|account_info| account_info.key == &instruction.accounts[index].pubkey)
+            .unwrap()
+    };
+    let transfer = |from: &AccountInfo, to: &AccountInfo, lamports: u64| {
+        **from.lamports.borrow_mut() -= lamports;
+        **to.lamports.borrow_mut() += lamports;
+    };
+
+    match limited_deserialize(&instruction.data, 1024).unwrap() {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let new_account = account_info(1);
+            if new_account.lamports() > 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            transfer(account_info(0), new_account, lamports);
+            new_account.realloc(space as usize, true)?;
+            new_account.assign(&owner);
+        }
+        SystemInstruction::Transfer { lamports } => {
+            transfer(account_info(0), account_info(1), lamports);
+        }
+        SystemInstruction::Allocate { space } => {
+            account_info(0).realloc(space as usize, true)?;
+        }
+        SystemInstruction::Assign { owner } => {
+            account_info(0).assign(&owner);
+        }
+        system_instruction => panic!("unsupported system instruction {:?}", system_instruction),
+    }
+    Ok(())
+}
+
+// Installs the test syscalls and resets the state of the current thread.
+pub fn reset_test_runtime(clock: Clock) {
+    INSTALL_STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+    });
+    CLOCK.with(|curr_clock| *curr_clock.borrow_mut() = clock);
+    INVOKED_INSTRUCTIONS.with(|invoked| invoked.borrow_mut().clear());
+    FAILING_PROGRAMS.with(|failing| failing.borrow_mut().clear());
+}
+
+// Makes every invocation of the program fail with FAILED_INVOCATION_ERROR.
+pub fn fail_invocations_of(program_id: Pubkey) {
+    FAILING_PROGRAMS.with(|failing| failing.borrow_mut().insert(program_id));
+}
+
+pub fn invoked_instructions() -> Vec<Instruction> {
+    INVOKED_INSTRUCTIONS.with(|invoked| invoked.borrow().clone())
+}
+
+// Account key preceded by the original data length, where the runtime serializes it.
+#[repr(C)]
+struct SerializedKey {
+    original_data_len: u32,
+    key: Pubkey,
+}
+
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    pub fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports,
+            data,
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    // A program-owned account holding the serialized anchor account.
+    pub fn with_account<T: AccountSerialize>(key: Pubkey, account: &T) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        let lamports = Rent::default().minimum_balance(data.len());
+        Self::new(key, crate::ID, lamports, data)
+    }
+
+    pub fn signer(key: Pubkey, lamports: u64) -> Self {
+        Self {
+            is_signer: true,
+            ..Self::new(key, system_program::ID, lamports, Vec::new())
+        }
+    }
+
+    pub fn program(program_id: Pubkey) -> Self {
+        Self {
+            is_writable: false,
+            executable: true,
+            ..Self::new(program_id, solana_program::bpf_loader::ID, 1, Vec::new())
+        }
+    }
+
+    pub fn readonly(self) -> Self {
+        Self {
+            is_writable: false,
+            ..self
+        }
+    }
+
+    // Lays the account out in leaked memory the way the runtime serializes it, so that handlers
+    // can reallocate and reassign it.
+    pub fn into_account_info(self) -> AccountInfo<'static> {
+        let serialized_key = Box::leak(Box::new(SerializedKey {
+            original_data_len: self.data.len() as u32,
+            key: self.key,
+        }));
+
+        let data_len = self.data.len();
+        let buffer =
+            Box::leak(vec![0u8; 8 + data_len + MAX_PERMITTED_DATA_INCREASE].into_boxed_slice());
+        buffer[..8].copy_from_slice(&(data_len as u64).to_le_bytes());
+        buffer[8..8 + data_len].copy_from_slice(&self.data);
+
+        AccountInfo::new(
+            &serialized_key.key,
+            self.is_signer,
+            self.is_writable,
+            Box::leak(Box::new(self.lamports)),
+            &mut buffer[8..8 + data_len],
+            Box::leak(Box::new(self.owner)),
+            self.executable,
+            0,
+        )
+    }
+}
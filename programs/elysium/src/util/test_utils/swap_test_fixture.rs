@@ -213,6 +213,7 @@ impl SwapTestFixture {
             self.amount_specified_is_input,
             self.a_to_b,
             next_timestamp,
+            0,
         )
         .unwrap()
     }
@@ -230,6 +231,7 @@ impl SwapTestFixture {
             self.amount_specified_is_input,
             self.a_to_b,
             next_timestamp,
+            0,
         )
     }
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, state::*};
+
+/// Finds the pool's TickArrayBitmap among the remaining accounts.
+///
+/// # Returns
+/// - `None` if the pool has no tick array bitmap
+///
+/// # Errors
+/// - `InvalidTickArrayBitmap` - The pool's tick_array_bitmap is not in the remaining accounts.
+pub fn load_tick_array_bitmap<'info>(
+    pool: &ElysiumPool,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<AccountLoader<'info, TickArrayBitmap>>> {
+    if !pool.has_tick_array_bitmap() {
+        return Ok(None);
+    }
+
+    let tick_array_bitmap_info = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == pool.tick_array_bitmap)
+        .ok_or(ErrorCode::InvalidTickArrayBitmap)?;
+
+    Ok(Some(AccountLoader::<TickArrayBitmap>::try_from(
+        tick_array_bitmap_info,
+    )?))
+}
+
+/// Records the current state of an initialized TickArray in the pool's TickArrayBitmap.
+pub fn record_tick_array_in_bitmap(
+    tick_array_bitmap: &AccountLoader<TickArrayBitmap>,
+    tick_array: &AccountLoader<TickArray>,
+    tick_spacing: u16,
+) -> Result<()> {
+    let tick_array = tick_array.load()?;
+    tick_array_bitmap.load_mut()?.update(
+        tick_array.start_tick_index,
+        tick_spacing,
+        true,
+        !tick_array.is_empty(),
+    )
+}
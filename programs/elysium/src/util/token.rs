@@ -1,4 +1,4 @@
-use crate::state::{ElysiumPool, PositionBundle};
+use crate::state::{ElysiumPool, PositionBundle, PositionLock};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use mpl_token_metadata::instruction::create_metadata_accounts_v3;
@@ -98,6 +98,64 @@ pub fn burn_and_close_user_position_token<'info>(
     Ok(())
 }
 
+pub fn transfer_position_token_to_lock<'info>(
+    position_authority: &Signer<'info>,
+    position_token_account: &Account<'info, TokenAccount>,
+    lock_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: position_token_account.to_account_info(),
+                to: lock_token_account.to_account_info(),
+                authority: position_authority.to_account_info(),
+            },
+        ),
+        1,
+    )
+}
+
+pub fn transfer_position_token_from_lock_and_close<'info>(
+    position_lock: &Account<'info, PositionLock>,
+    lock_token_account: &Account<'info, TokenAccount>,
+    position_token_account: &Account<'info, TokenAccount>,
+    receiver: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: lock_token_account.to_account_info(),
+                to: position_token_account.to_account_info(),
+                authority: position_lock.to_account_info(),
+            },
+            &[&position_lock.seeds()],
+        ),
+        1,
+    )?;
+
+    invoke_signed(
+        &close_account(
+            token_program.key,
+            lock_token_account.to_account_info().key,
+            receiver.key,
+            position_lock.to_account_info().key,
+            &[],
+        )?,
+        &[
+            token_program.to_account_info(),
+            lock_token_account.to_account_info(),
+            receiver.to_account_info(),
+            position_lock.to_account_info(),
+        ],
+        &[&position_lock.seeds()],
+    )?;
+    Ok(())
+}
+
 pub fn mint_position_token_and_remove_authority<'info>(
     pool: &Account<'info, ElysiumPool>,
     position_mint: &Account<'info, Mint>,
@@ -1,6 +1,6 @@
 use anchor_lang::{
     prelude::{AccountInfo, Pubkey, Signer, *},
-    ToAccountInfo,
+    system_program, ToAccountInfo,
 };
 use anchor_spl::token::TokenAccount;
 use solana_program::program_option::COption;
@@ -47,3 +47,93 @@ fn validate_owner(expected_owner: &Pubkey, owner_account_info: &AccountInfo) ->
 pub fn to_timestamp_u64(t: i64) -> Result<u64> {
     u64::try_from(t).or(Err(ErrorCode::InvalidTimestampConversion.into()))
 }
+
+/// Creates a program-owned account at a PDA, following the same steps as anchor's `init`
+/// constraint. Accounts that were prefunded with lamports are topped up, allocated and assigned.
+pub fn create_pda_account<'info>(
+    payer: &Signer<'info>,
+    new_account: &AccountInfo<'info>,
+    space: usize,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = new_account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: new_account.clone(),
+                },
+            )
+            .with_signer(&[signer_seeds]),
+            lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let required_lamports = lamports.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: new_account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: new_account.clone(),
+            },
+        )
+        .with_signer(&[signer_seeds]),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: new_account.clone(),
+            },
+        )
+        .with_signer(&[signer_seeds]),
+        &crate::ID,
+    )
+}
+
+/// Grows a program-owned account to `space` bytes, with the payer topping up its rent. The added
+/// bytes are zeroed.
+pub fn realloc_account<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
@@ -53,11 +53,14 @@ export type ElysiumPool = {
       name: "initializePool";
       docs: [
         "Initializes a ElysiumPool account.",
-        "Fee rate is set to the default values on the config and supplied fee_tier.",
+        "Fee rate and tick spacing are set to the default values on the config and supplied fee_tier.",
+        "The pool is activated on creation, without launch fee schedule, creator fee or access",
+        "authority. Use `initialize_pool_v2` to set them.",
         "",
         "### Parameters",
         "- `bumps` - The bump value when deriving the PDA of the ElysiumPool address.",
-        "- `tick_spacing` - The desired tick spacing for this pool.",
+        "- `fee_tier_index` - The index of the fee tier for this pool. Fee tiers initialized with",
+        "`initialize_fee_tier` are indexed by their tick spacing.",
         "- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
         "",
         "#### Special Errors",
@@ -130,7 +133,7 @@ export type ElysiumPool = {
           };
         },
         {
-          name: "tickSpacing";
+          name: "feeTierIndex";
           type: "u16";
         },
         {
@@ -140,21 +143,56 @@ export type ElysiumPool = {
       ];
     },
     {
-      name: "initializeTickArray";
+      name: "initializePoolV2";
       docs: [
-        "Initializes a tick_array account to represent a tick-range in a ElysiumPool.",
+        "Initializes a ElysiumPool account with launch settings.",
+        "Takes the accounts and parameters of `initialize_pool`, followed by the parameters below.",
         "",
         "### Parameters",
-        "- `start_tick_index` - The starting tick index for this tick-array.",
-        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "- `activation_timestamp` - Optional unix timestamp before which swaps are rejected. At most",
+        "the config extension's max_activation_delay after creation.",
+        "- `activation_slot` - Optional slot before which swaps are rejected. At most the config",
+        "extension's max_activation_slot_delay after creation.",
+        "- `launch_fee_schedule` - Optional fee schedule that decays from an initial fee rate to the",
+        "pool's fee rate after activation. The excess fees go to LPs.",
+        "- `creator_fee_rate` - Optional portion of the fee, in basis points, owed to the funder as",
+        "the pool creator.",
+        "- `access_authority` - Optional authority managing the allowlist of the pool. If provided,",
+        "only allowlisted wallets can trade and provide liquidity, and the",
+        "config extension's permissioned_pool_authority has to sign.",
         "",
         "#### Special Errors",
-        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
-        "TICK_ARRAY_SIZE * tick spacing."
+        "`LaunchFeeRateMaxExceeded` - The initial launch fee rate exceeds MAX_LAUNCH_FEE_RATE.",
+        "`InvalidLaunchFeeSchedule` - The initial launch fee rate is not above the pool's fee rate",
+        "or the decay period is zero.",
+        "`LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds the config extension's max",
+        "launch fee decay period in the unit of the schedule.",
+        "`CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.",
+        "`ActivationDelayMaxExceeded` - The activation is delayed past the max activation delay.",
+        "`InvalidPermissionedPoolAuthority` - An access authority is provided without the signature",
+        "of the config extension's permissioned_pool_authority.",
+        "The special errors of `initialize_pool`.",
+        "",
+        "### Remaining Accounts",
+        "- Optionally the ElysiumPoolsConfigExtension of the config. Without it the activation can",
+        "not be delayed past creation, no launch fee schedule can be set and no permissioned pool",
+        "can be created.",
+        "- If an access authority is provided: the permissioned_pool_authority as a signer.",
+        ""
       ];
       accounts: [
         {
-          name: "pool";
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintA";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintB";
           isMut: false;
           isSigner: false;
         },
@@ -164,59 +202,122 @@ export type ElysiumPool = {
           isSigner: true;
         },
         {
-          name: "tickArray";
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenVaultB";
           isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "feeTier";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "startTickIndex";
-          type: "i32";
+          name: "bumps";
+          type: {
+            defined: "ElysiumPoolBumps";
+          };
+        },
+        {
+          name: "feeTierIndex";
+          type: "u16";
+        },
+        {
+          name: "initialSqrtPrice";
+          type: "u128";
+        },
+        {
+          name: "activationTimestamp";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "activationSlot";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "launchFeeSchedule";
+          type: {
+            option: {
+              defined: "LaunchFeeSchedule";
+            };
+          };
+        },
+        {
+          name: "creatorFeeRate";
+          type: {
+            option: "u16";
+          };
+        },
+        {
+          name: "accessAuthority";
+          type: {
+            option: "publicKey";
+          };
         }
       ];
     },
     {
-      name: "initializeFeeTier";
+      name: "initializeTickArray";
       docs: [
-        "Initializes a fee_tier account usable by ElysiumPools in a ElysiumPoolConfig space.",
-        "",
-        "### Authority",
-        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "Initializes a tick_array account to represent a tick-range in a ElysiumPool.",
         "",
         "### Parameters",
-        "- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
-        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-        "fee tier during initialization.",
+        "- `start_tick_index` - The starting tick index for this tick-array.",
+        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
         "",
         "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE."
+        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing."
       ];
       accounts: [
         {
-          name: "config";
+          name: "pool";
           isMut: false;
           isSigner: false;
         },
-        {
-          name: "feeTier";
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: "funder";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "feeAuthority";
-          isMut: false;
-          isSigner: true;
+          name: "tickArray";
+          isMut: true;
+          isSigner: false;
         },
         {
           name: "systemProgram";
@@ -226,37 +327,36 @@ export type ElysiumPool = {
       ];
       args: [
         {
-          name: "tickSpacing";
-          type: "u16";
-        },
-        {
-          name: "defaultFeeRate";
-          type: "u16";
+          name: "startTickIndex";
+          type: "i32";
         }
       ];
     },
     {
-      name: "initializeReward";
+      name: "initializeTickArrayV2";
       docs: [
-        "Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.",
-        "",
-        "### Authority",
-        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-        "reward-index in this ElysiumPool",
+        "Initializes a tick_array account to represent a tick-range in a ElysiumPool, and records",
+        "the funder in a tick_array_funder account so that the funder can close the tick array",
+        "once it is empty. Takes the accounts of `initialize_tick_array` with the tick_array_funder",
+        "account after the tick_array.",
         "",
         "### Parameters",
-        "- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+        "- `start_tick_index` - The starting tick index for this tick-array.",
+        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
         "",
         "#### Special Errors",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized."
+        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing."
       ];
       accounts: [
         {
-          name: "rewardAuthority";
+          name: "pool";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
           name: "funder";
@@ -264,465 +364,466 @@ export type ElysiumPool = {
           isSigner: true;
         },
         {
-          name: "pool";
+          name: "tickArray";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardMint";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "rewardVault";
+          name: "tickArrayFunder";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
           isSigner: false;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "rent";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "rewardIndex";
-          type: "u8";
+          name: "startTickIndex";
+          type: "i32";
         }
       ];
     },
     {
-      name: "setRewardEmissions";
+      name: "initializeTickArrays";
       docs: [
-        "Set the reward emissions for a reward in a ElysiumPool.",
-        "",
-        "### Authority",
-        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-        "reward-index in this ElysiumPool",
+        "Initializes a tick_array account for every provided start tick index, skipping tick",
+        "arrays that already exist. Allows a range of tick arrays to be prepared in one instruction",
+        "without checking which of them exist.",
         "",
         "### Parameters",
-        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
-        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+        "- `start_tick_indexes` - The starting tick index of every tick-array.",
+        "Each has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- For every start tick index, in order: the tick array followed by its tick_array_funder",
+        "record.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap, after the tick array accounts.",
         "",
         "#### Special Errors",
-        "- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
-        "more than a day of desired emissions.",
-        "- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized."
+        "- `InvalidStartTick` - if a provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayAccounts` - The remaining accounts are not the tick arrays and funder",
+        "records of the provided start tick indexes.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing."
       ];
       accounts: [
         {
           name: "pool";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "rewardAuthority";
-          isMut: false;
+          name: "funder";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "rewardVault";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "rewardIndex";
-          type: "u8";
-        },
-        {
-          name: "emissionsPerSecondX64";
-          type: "u128";
+          name: "startTickIndexes";
+          type: {
+            vec: "i32";
+          };
         }
       ];
     },
     {
-      name: "openPosition";
+      name: "closeTickArray";
       docs: [
-        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
-        "in the users wallet. The position will start off with 0 liquidity.",
+        "Closes an empty tick_array account, refunding its rent and the rent of its funder record",
+        "to the funder that initialized it. Only tick arrays initialized by `initialize_tick_array_v2`",
+        "or `initialize_tick_arrays` have a funder record and can be closed. A closed tick array can",
+        "be initialized again by anyone.",
         "",
-        "### Parameters",
-        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
-        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "### Authority",
+        '- "funder" - The funder recorded when the tick_array was initialized.',
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
         "",
         "#### Special Errors",
-        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-        "the tick-spacing in this pool."
+        "- `TickArrayNotEmpty` - The tick array has initialized ticks, so positions with liquidity",
+        "may reference it.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing."
       ];
       accounts: [
         {
-          name: "funder";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "owner";
+          name: "pool";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "position";
+          name: "tickArray";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionMint";
+          name: "tickArrayFunder";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "positionTokenAccount";
+          name: "funder";
           isMut: true;
-          isSigner: false;
-        },
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migratePool";
+      docs: [
+        "Migrates a ElysiumPool account initialized with the original layout to the current layout.",
+        "The account is grown, with the funder paying the additional rent, and the fields added",
+        "since are zeroed: the pool is active, without launch fee, creator fee, hook or access",
+        "authority. Initialized rewards keep emitting until the balances of their vaults at migration",
+        "have been emitted, and can only be reinitialized once the settlement period after their",
+        "retirement has elapsed. Migrating a pool that already has the current layout does nothing.",
+        "",
+        "### Authority",
+        '- "funder" - Any account that pays for the additional rent.',
+        "",
+        "### Remaining Accounts",
+        "- The reward vaults of the initialized rewards of the pool.",
+        "",
+        "#### Special Errors",
+        "- `UnsupportedPoolLayout` - The pool account has neither the original nor the current layout.",
+        "- `InvalidRewardAccounts` - The vault of an initialized reward is missing."
+      ];
+      accounts: [
         {
           name: "pool";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "funder";
+          isMut: true;
+          isSigner: true;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateTickArray";
+      docs: [
+        "Migrates a tick_array account initialized before tick arrays stored a bitmap of their",
+        "initialized ticks. The account is grown to hold the bitmap, with the funder paying the",
+        "additional rent, and the bitmap is rebuilt from the ticks. Swaps search the ticks of",
+        "migrated tick arrays with the bitmap, and the ticks of other tick arrays one by one.",
+        "",
+        "### Authority",
+        '- "funder" - Any account that pays for the additional rent.'
+      ];
+      accounts: [
         {
-          name: "rent";
-          isMut: false;
+          name: "tickArray";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "bumps";
-          type: {
-            defined: "OpenPositionBumps";
-          };
-        },
-        {
-          name: "tickLowerIndex";
-          type: "i32";
+          name: "funder";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "tickUpperIndex";
-          type: "i32";
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "openPositionWithMetadata";
+      name: "initializeTickArrayBitmap";
       docs: [
-        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
-        "in the users wallet. Additional Metaplex metadata is appended to identify the token.",
-        "The position will start off with 0 liquidity.",
+        "Initialize the TickArrayBitmap of a ElysiumPool, which records the initialized and",
+        "non-empty TickArrays of the pool. Once initialized, clients must pass the bitmap to every",
+        "instruction that initializes or closes tick arrays or modifies liquidity, which fail with",
+        "`InvalidTickArrayBitmap` without it.",
         "",
-        "### Parameters",
-        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
-        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "The bitmap is an index for clients and is not read by swaps. Tick arrays initialized",
+        "before the bitmap are only recorded once passed to `sync_tick_array_bitmap`, so swaps keep",
+        "taking consecutive tick arrays rather than skipping arrays the bitmap reports as empty.",
         "",
-        "#### Special Errors",
-        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-        "the tick-spacing in this pool."
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig'
       ];
       accounts: [
         {
-          name: "funder";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "owner";
+          name: "poolsConfig";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "position";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionMint";
-          isMut: true;
+          name: "feeAuthority";
+          isMut: false;
           isSigner: true;
         },
         {
-          name: "positionMetadataAccount";
+          name: "funder";
           isMut: true;
-          isSigner: false;
-          docs: [
-            "https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873"
-          ];
+          isSigner: true;
         },
         {
-          name: "positionTokenAccount";
+          name: "tickArrayBitmap";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "pool";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "syncTickArrayBitmap";
+      docs: [
+        "Record the current state of tick arrays in the TickArrayBitmap of their ElysiumPool, such",
+        "as tick arrays initialized before the bitmap.",
+        "",
+        "### Remaining Accounts",
+        "- The tick arrays to record.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickArrayAccounts` - A tick array does not belong to the pool."
+      ];
+      accounts: [
         {
-          name: "tokenProgram";
+          name: "pool";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "tickArrayBitmap";
+          isMut: true;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeFeeTier";
+      docs: [
+        "Initializes a fee_tier account usable by ElysiumPools in a ElysiumPoolConfig space.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
+        "",
+        "#### Special Errors",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE."
+      ];
+      accounts: [
         {
-          name: "rent";
+          name: "config";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
+          name: "feeTier";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "metadataProgram";
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "feeAuthority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "metadataUpdateAuth";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "bumps";
-          type: {
-            defined: "OpenPositionWithMetadataBumps";
-          };
-        },
-        {
-          name: "tickLowerIndex";
-          type: "i32";
+          name: "tickSpacing";
+          type: "u16";
         },
         {
-          name: "tickUpperIndex";
-          type: "i32";
+          name: "defaultFeeRate";
+          type: "u16";
         }
       ];
     },
     {
-      name: "increaseLiquidity";
+      name: "initializeFeeTierWithIndex";
       docs: [
-        "Add liquidity to a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "Initializes a fee_tier account keyed by a fee tier index rather than its tick spacing,",
+        "allowing multiple fee tiers with the same tick spacing in a ElysiumPoolConfig space.",
+        "The index must not be in use by another fee tier, including fee tiers keyed by tick spacing.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
         "",
         "### Parameters",
-        "- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
-        "- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
-        "- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+        "- `fee_tier_index` - The index that pools using this fee tier are keyed by.",
+        "- `tick_spacing` - The tick-spacing of pools initialized with this fee-tier.",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
         "",
         "#### Special Errors",
-        "- `LiquidityZero` - Provided liquidity amount is zero.",
-        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-        "- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount."
+        "- `InvalidTickSpacing` - If the provided tick_spacing is 0.",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE."
       ];
       accounts: [
         {
-          name: "pool";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "positionAuthority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "position";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionTokenAccount";
+          name: "config";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenOwnerAccountA";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "tokenOwnerAccountB";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "tokenVaultA";
+          name: "feeTier";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenVaultB";
+          name: "funder";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tickArrayLower";
-          isMut: true;
-          isSigner: false;
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "tickArrayUpper";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "liquidityAmount";
-          type: "u128";
+          name: "feeTierIndex";
+          type: "u16";
         },
         {
-          name: "tokenMaxA";
-          type: "u64";
+          name: "tickSpacing";
+          type: "u16";
         },
         {
-          name: "tokenMaxB";
-          type: "u64";
+          name: "defaultFeeRate";
+          type: "u16";
         }
       ];
     },
     {
-      name: "decreaseLiquidity";
+      name: "initializeReward";
       docs: [
-        "Withdraw liquidity from a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
         "",
         "### Parameters",
-        "- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
-        "- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
-        "- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+        "- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
         "",
         "#### Special Errors",
-        "- `LiquidityZero` - Provided liquidity amount is zero.",
-        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-        "- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount."
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized."
       ];
       accounts: [
         {
-          name: "pool";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "positionAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "position";
+          name: "funder";
           isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionTokenAccount";
-          isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenOwnerAccountA";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenOwnerAccountB";
-          isMut: true;
+          name: "rewardMint";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenVaultA";
+          name: "rewardVault";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenVaultB";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayLower";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayUpper";
-          isMut: true;
+          name: "rent";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "liquidityAmount";
-          type: "u128";
-        },
-        {
-          name: "tokenMinA";
-          type: "u64";
-        },
-        {
-          name: "tokenMinB";
-          type: "u64";
+          name: "rewardIndex";
+          type: "u8";
         }
       ];
     },
     {
-      name: "updateFeesAndRewards";
+      name: "setRewardEmissions";
       docs: [
-        "Update the accrued fees and rewards for a position.",
+        "Set the reward emissions for a reward in a ElysiumPool.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
         "",
         "#### Special Errors",
-        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
-        "- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values."
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot",
+        "cover a day of desired emissions.",
+        "- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized."
       ];
       accounts: [
         {
@@ -731,87 +832,100 @@ export type ElysiumPool = {
           isSigner: false;
         },
         {
-          name: "position";
-          isMut: true;
-          isSigner: false;
+          name: "rewardAuthority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "tickArrayLower";
+          name: "rewardVault";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
         },
         {
-          name: "tickArrayUpper";
-          isMut: false;
-          isSigner: false;
+          name: "emissionsPerSecondX64";
+          type: "u128";
         }
       ];
-      args: [];
     },
     {
-      name: "collectFees";
+      name: "setRewardEmissionsSchedule";
       docs: [
-        "Collect fees accrued for this position.",
+        "Schedule a reward emissions campaign for a reward in a ElysiumPool. The budget is emitted",
+        "at a constant rate between the start and end timestamps, and no rewards accrue outside of",
+        "that window. Calling `set_reward_emissions` afterwards clears the schedule.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position."
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+        "- `start_timestamp` - The timestamp from which rewards start accruing.",
+        "- `end_timestamp` - The timestamp after which rewards stop accruing.",
+        "- `budget` - The total amount of rewards emitted over the campaign.",
+        "",
+        "#### Special Errors",
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet are",
+        "less than the budget of the campaign.",
+        "- `InvalidRewardSchedule` - The start timestamp is in the past or the end timestamp is not",
+        "after the start timestamp.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
       ];
       accounts: [
         {
           name: "pool";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "positionAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "position";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionTokenAccount";
+          name: "rewardVault";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "tokenOwnerAccountA";
-          isMut: true;
-          isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenVaultA";
-          isMut: true;
-          isSigner: false;
+          name: "rewardIndex";
+          type: "u8";
         },
         {
-          name: "tokenOwnerAccountB";
-          isMut: true;
-          isSigner: false;
+          name: "startTimestamp";
+          type: "u64";
         },
         {
-          name: "tokenVaultB";
-          isMut: true;
-          isSigner: false;
+          name: "endTimestamp";
+          type: "u64";
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "budget";
+          type: "u64";
         }
       ];
-      args: [];
     },
     {
-      name: "collectReward";
+      name: "getRewardRunway";
       docs: [
-        "Collect rewards accrued for this position.",
+        "Get the funded rewards that have not been emitted yet for a reward in a ElysiumPool, and",
+        "how many seconds of emissions at the current rate they cover. Once the funded amount has",
+        "been emitted, emissions are deferred until the reward vault is topped up. The result is",
+        "written to the return data.",
         "",
-        "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position."
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to query.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
       ];
       accounts: [
         {
@@ -820,22 +934,50 @@ export type ElysiumPool = {
           isSigner: false;
         },
         {
-          name: "positionAuthority";
+          name: "rewardVault";
           isMut: false;
-          isSigner: true;
-        },
+          isSigner: false;
+        }
+      ];
+      args: [
         {
-          name: "position";
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+      returns: {
+        defined: "RewardRunway";
+      };
+    },
+    {
+      name: "fundReward";
+      docs: [
+        "Deposit tokens into the reward vault of a reward in a ElysiumPool. Anyone can fund a",
+        "reward. Rewards are updated before the deposit, so it only backs future emissions.",
+        "",
+        "### Authority",
+        '- "funder" - The authority to withdraw tokens from the funder token account.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to fund.",
+        "- `amount` - The amount of reward tokens to deposit.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
+      ];
+      accounts: [
+        {
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionTokenAccount";
+          name: "funder";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "rewardOwnerAccount";
+          name: "funderTokenAccount";
           isMut: true;
           isSigner: false;
         },
@@ -854,226 +996,280 @@ export type ElysiumPool = {
         {
           name: "rewardIndex";
           type: "u8";
+        },
+        {
+          name: "amount";
+          type: "u64";
         }
       ];
     },
     {
-      name: "collectProtocolFees";
+      name: "withdrawUnallocatedReward";
       docs: [
-        "Collect the protocol fees accrued in this ElysiumPool",
+        "Withdraw reward tokens that have not been emitted to positions and are not committed to",
+        "the remainder of a scheduled campaign.",
         "",
         "### Authority",
-        "- `collect_protocol_fees_authority` - assigned authority in the ElysiumPoolConfig that can collect protocol fees"
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.",
+        "- `amount` - The amount of reward tokens to withdraw.",
+        "",
+        "#### Special Errors",
+        "- `UnallocatedRewardInsufficient` - The amount exceeds the unallocated reward amount.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
       ];
       accounts: [
-        {
-          name: "poolsConfig";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "collectProtocolFeesAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "tokenVaultA";
+          name: "rewardVault";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenVaultB";
+          name: "tokenDestination";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenDestinationA";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenDestinationB";
-          isMut: true;
-          isSigner: false;
+          name: "rewardIndex";
+          type: "u8";
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "amount";
+          type: "u64";
         }
       ];
-      args: [];
     },
     {
-      name: "swap";
+      name: "retireReward";
       docs: [
-        "Perform a swap in this ElysiumPool",
+        "Retire a reward in a ElysiumPool whose emissions have ended. Emissions are turned off and",
+        "a RewardSettlement records the retired reward, so positions can settle what they are owed",
+        "before the reward slot is reinitialized.",
         "",
         "### Authority",
-        '- "token_authority" - The authority to withdraw tokens from the input token account.',
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
         "",
         "### Parameters",
-        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-        "- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
-        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-        "- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to retire.",
         "",
         "#### Special Errors",
-        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0."
+        "- `RewardEmissionsNotEnded` - The reward is not initialized or still emits rewards.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
       ];
       accounts: [
         {
-          name: "tokenProgram";
-          isMut: false;
+          name: "pool";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "pool";
+          name: "funder";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenOwnerAccountA";
-          isMut: true;
+          name: "rewardVault";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenVaultA";
+          name: "rewardSettlement";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenOwnerAccountB";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenVaultB";
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "settleReward";
+      docs: [
+        "Update the accrued rewards of a position and transfer everything it is owed for a retired",
+        "reward to the owner of the position token, or to the beneficiary of its PositionLock if the",
+        "position is locked. Anyone can settle a position.",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardSettlement` - The reward slot has been reinitialized since the settlement,",
+        "or emissions were turned back on.",
+        "- `MissingOrInvalidDelegate` - The position is locked and its PositionLock is not passed.",
+        "- `InvalidRewardAccounts` - The reward owner account is not owned by the position owner, or",
+        "the lock beneficiary if the position is locked.",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position."
+      ];
+      accounts: [
+        {
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tickArray0";
+          name: "rewardSettlement";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tickArray1";
+          name: "position";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tickArray2";
-          isMut: true;
+          name: "positionTokenAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "oracle";
+          name: "tickArrayLower";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "amount";
-          type: "u64";
+          name: "tickArrayUpper";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "otherAmountThreshold";
-          type: "u64";
+          name: "rewardOwnerAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "sqrtPriceLimit";
-          type: "u128";
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "amountSpecifiedIsInput";
-          type: "bool";
-        },
-        {
-          name: "aToB";
-          type: "bool";
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "closePosition";
+      name: "reinitializeReward";
       docs: [
-        "Close a position in a ElysiumPool. Burns the position token in the owner's wallet.",
+        "Reinitialize a retired reward in a ElysiumPool with a new mint and vault, once every",
+        "position has been settled or the settlement period of the retired reward has elapsed.",
+        "Positions that have not settled the retired reward by then are credited what they are",
+        "still owed for it in the new mint.",
         "",
         "### Authority",
-        '- "position_authority" - The authority that owns the position token.',
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to reinitialize.",
         "",
         "#### Special Errors",
-        "- `ClosePositionNotEmpty` - The provided position account is not empty."
+        "- `InvalidRewardSettlement` - The settlement does not belong to the reward in the slot.",
+        "- `RewardEmissionsNotEnded` - Emissions were turned back on after the reward was retired.",
+        "- `RewardNotSettled` - Positions have not credited or collected everything they are owed",
+        "and the settlement period has not elapsed yet.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS."
       ];
       accounts: [
         {
-          name: "positionAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "receiver";
+          name: "funder";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "position";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionMint";
-          isMut: true;
+          name: "rewardSettlement";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "positionTokenAccount";
-          isMut: true;
+          name: "rewardMint";
+          isMut: false;
           isSigner: false;
         },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
         }
       ];
-      args: [];
     },
     {
-      name: "setDefaultFeeRate";
+      name: "initializeRewardExtension";
       docs: [
-        "Set the default_fee_rate for a FeeTier",
-        "Only the current fee authority has permission to invoke this instruction.",
+        "Initialize the PoolRewardExtension of a ElysiumPool, adding NUM_EXTENSION_REWARDS reward",
+        "slots on top of the rewards stored on the pool. Once initialized, every liquidity change",
+        "and every swap that crosses a tick must pass the reward extension as a remaining account.",
+        "Tick array and position extensions are optional and can be created at any time.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
-        "",
-        "### Parameters",
-        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-        "fee tier during initialization.",
-        "",
-        "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE."
+        '- "reward_emissions_super_authority" - Set authority in the ElysiumPoolConfig. Becomes the',
+        "reward authority of every extension reward slot."
       ];
       accounts: [
         {
@@ -1082,213 +1278,269 @@ export type ElysiumPool = {
           isSigner: false;
         },
         {
-          name: "feeTier";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "feeAuthority";
+          name: "rewardEmissionsSuperAuthority";
           isMut: false;
           isSigner: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: "defaultFeeRate";
-          type: "u16";
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "rewardExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "setDefaultProtocolFeeRate";
+      name: "initializeTickArrayRewardExtension";
       docs: [
-        "Sets the default protocol fee rate for a ElysiumPoolConfig",
-        "Protocol fee rate is represented as a basis point.",
-        "Only the current fee authority has permission to invoke this instruction.",
-        "",
-        "### Authority",
-        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
-        "",
-        "### Parameters",
-        "- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a ElysiumPool using this config.",
-        "",
-        "#### Special Errors",
-        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE."
+        "Initialize the TickArrayRewardExtension of a TickArray. Required before a position with",
+        "a tick in the tick array can earn extension rewards. Ticks crossed before the extension",
+        "exists are not tracked, which no position extension depends on."
       ];
       accounts: [
         {
-          name: "poolsConfig";
-          isMut: true;
+          name: "pool";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "feeAuthority";
+          name: "tickArray";
           isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
           isSigner: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: "defaultProtocolFeeRate";
-          type: "u16";
+          name: "tickArrayExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "setFeeRate";
+      name: "initializePositionRewardExtension";
       docs: [
-        "Sets the fee rate for a ElysiumPool.",
-        "Fee rate is represented as hundredths of a basis point.",
-        "Only the current fee authority has permission to invoke this instruction.",
-        "",
-        "### Authority",
-        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
-        "",
-        "### Parameters",
-        "- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
-        "",
-        "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE."
+        "Initialize the PositionRewardExtension of a Position, which tracks the extension rewards",
+        "owed to the position. The position earns extension rewards from this point on, so the",
+        "extensions of its lower and upper tick arrays must already exist."
       ];
       accounts: [
         {
-          name: "poolsConfig";
+          name: "pool";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "pool";
+          name: "rewardExtension";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "feeAuthority";
+          name: "position";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLowerExtension";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpperExtension";
           isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
           isSigner: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: "feeRate";
-          type: "u16";
+          name: "positionExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "setProtocolFeeRate";
+      name: "initializeExtensionReward";
       docs: [
-        "Sets the protocol fee rate for a ElysiumPool.",
-        "Protocol fee rate is represented as a basis point.",
-        "Only the current fee authority has permission to invoke this instruction.",
+        "Initialize an extension reward of a ElysiumPool.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
         "",
         "### Parameters",
-        "- `protocol_fee_rate` - The rate that the pool will use to calculate protocol fees going onwards.",
+        "- `reward_index` - The extension reward index that we'd like to initialize.",
+        "(0 <= index < NUM_EXTENSION_REWARDS)",
         "",
         "#### Special Errors",
-        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE."
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this extension, or exceeds NUM_EXTENSION_REWARDS."
       ];
       accounts: [
         {
-          name: "poolsConfig";
+          name: "rewardAuthority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
         },
         {
           name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardExtension";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "feeAuthority";
+          name: "rewardMint";
           isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
           isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "protocolFeeRate";
-          type: "u16";
+          name: "rewardIndex";
+          type: "u8";
         }
       ];
     },
     {
-      name: "setFeeAuthority";
+      name: "setExtensionRewardAuthority";
       docs: [
-        "Sets the fee authority for a ElysiumPoolConfig.",
-        "The fee authority can set the fee & protocol fee rate for individual pools or",
-        "set the default fee rate for newly minted pools.",
-        "Only the current fee authority has permission to invoke this instruction.",
+        "Set the authority of an extension reward of a ElysiumPool.",
+        "Only the current authority of the extension reward has permission to invoke this instruction.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig'
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS."
       ];
       accounts: [
         {
-          name: "poolsConfig";
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardExtension";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "feeAuthority";
+          name: "rewardAuthority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "newFeeAuthority";
+          name: "newRewardAuthority";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
     },
     {
-      name: "setCollectProtocolFeesAuthority";
+      name: "setExtensionRewardEmissions";
       docs: [
-        "Sets the fee authority to collect protocol fees for a ElysiumPoolConfig.",
-        "Only the current collect protocol fee authority has permission to invoke this instruction.",
+        "Set the reward emissions for an extension reward of a ElysiumPool.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority that can collect protocol fees in the ElysiumPoolConfig'
-      ];
-      accounts: [
-        {
-          name: "poolsConfig";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "collectProtocolFeesAuthority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "newCollectProtocolFeesAuthority";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "setRewardAuthority";
-      docs: [
-        "Set the pool reward authority at the provided `reward_index`.",
-        "Only the current reward authority for this reward index has permission to invoke this instruction.",
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
         "",
-        "### Authority",
-        '- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+        "### Parameters",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
         "",
         "#### Special Errors",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized."
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot",
+        "cover a day of desired emissions.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS."
       ];
       accounts: [
         {
           name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardExtension";
           isMut: true;
           isSigner: false;
         },
@@ -1298,7 +1550,7 @@ export type ElysiumPool = {
           isSigner: true;
         },
         {
-          name: "newRewardAuthority";
+          name: "rewardVault";
           isMut: false;
           isSigner: false;
         }
@@ -1307,342 +1559,260 @@ export type ElysiumPool = {
         {
           name: "rewardIndex";
           type: "u8";
+        },
+        {
+          name: "emissionsPerSecondX64";
+          type: "u128";
         }
       ];
     },
     {
-      name: "setRewardAuthorityBySuperAuthority";
+      name: "openPosition";
       docs: [
-        "Set the pool reward authority at the provided `reward_index`.",
-        "Only the current reward super authority has permission to invoke this instruction.",
+        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
+        "in the users wallet. The position will start off with 0 liquidity.",
         "",
-        "### Authority",
-        '- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+        "### Parameters",
+        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
+        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position owner.",
         "",
         "#### Special Errors",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized."
+        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+        "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted."
       ];
       accounts: [
         {
-          name: "poolsConfig";
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "owner";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "pool";
+          name: "position";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardEmissionsSuperAuthority";
-          isMut: false;
+          name: "positionMint";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "newRewardAuthority";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "rewardIndex";
-          type: "u8";
-        }
-      ];
-    },
-    {
-      name: "setRewardEmissionsSuperAuthority";
-      docs: [
-        "Set the pool reward super authority for a ElysiumPoolConfig",
-        "Only the current reward super authority has permission to invoke this instruction.",
-        "This instruction will not change the authority on any `ElysiumPoolRewardInfo` pool rewards.",
-        "",
-        "### Authority",
-        '- "reward_emissions_super_authority" - Set authority that can control reward authorities for all pools in this config space.'
-      ];
-      accounts: [
-        {
-          name: "poolsConfig";
+          name: "positionTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardEmissionsSuperAuthority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "newRewardEmissionsSuperAuthority";
+          name: "pool";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "twoHopSwap";
-      docs: [
-        "Perform a two-hop swap in this ElysiumPool",
-        "",
-        "### Authority",
-        '- "token_authority" - The authority to withdraw tokens from the input token account.',
-        "",
-        "### Parameters",
-        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-        "- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
-        "- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
-        "- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
-        "- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
-        "",
-        "#### Special Errors",
-        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
-        "- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
-        "- `DuplicateTwoHopPool` - Error if pool one & two are the same pool."
-      ];
-      accounts: [
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenAuthority";
+          name: "systemProgram";
           isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "poolOne";
-          isMut: true;
           isSigner: false;
         },
         {
-          name: "poolTwo";
-          isMut: true;
+          name: "rent";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenOwnerAccountOneA";
-          isMut: true;
+          name: "associatedTokenProgram";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenVaultOneA";
-          isMut: true;
-          isSigner: false;
+          name: "bumps";
+          type: {
+            defined: "OpenPositionBumps";
+          };
         },
         {
-          name: "tokenOwnerAccountOneB";
-          isMut: true;
-          isSigner: false;
+          name: "tickLowerIndex";
+          type: "i32";
         },
         {
-          name: "tokenVaultOneB";
+          name: "tickUpperIndex";
+          type: "i32";
+        }
+      ];
+    },
+    {
+      name: "openPositionWithMetadata";
+      docs: [
+        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
+        "in the users wallet. Additional Metaplex metadata is appended to identify the token.",
+        "The position will start off with 0 liquidity.",
+        "",
+        "### Parameters",
+        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
+        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position owner.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+        "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "funder";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenOwnerAccountTwoA";
-          isMut: true;
+          name: "owner";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenVaultTwoA";
+          name: "position";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenOwnerAccountTwoB";
+          name: "positionMint";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenVaultTwoB";
+          name: "positionMetadataAccount";
           isMut: true;
           isSigner: false;
+          docs: [
+            "https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873"
+          ];
         },
         {
-          name: "tickArrayOne0";
+          name: "positionTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tickArrayOne1";
-          isMut: true;
+          name: "pool";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayOne2";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayTwo0";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayTwo1";
-          isMut: true;
+          name: "rent";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tickArrayTwo2";
-          isMut: true;
+          name: "associatedTokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "oracleOne";
+          name: "metadataProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "oracleTwo";
+          name: "metadataUpdateAuth";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "amount";
-          type: "u64";
-        },
-        {
-          name: "otherAmountThreshold";
-          type: "u64";
+          name: "bumps";
+          type: {
+            defined: "OpenPositionWithMetadataBumps";
+          };
         },
         {
-          name: "amountSpecifiedIsInput";
-          type: "bool";
+          name: "tickLowerIndex";
+          type: "i32";
         },
         {
-          name: "aToBOne";
-          type: "bool";
-        },
-        {
-          name: "aToBTwo";
-          type: "bool";
-        },
-        {
-          name: "sqrtPriceLimitOne";
-          type: "u128";
-        },
-        {
-          name: "sqrtPriceLimitTwo";
-          type: "u128";
+          name: "tickUpperIndex";
+          type: "i32";
         }
       ];
     },
     {
-      name: "initializePositionBundle";
+      name: "lockPosition";
       docs: [
-        "Initializes a PositionBundle account that bundles several positions.",
-        "A unique token will be minted to represent the position bundle in the users wallet."
+        "Lock the liquidity of a position until a timestamp, or forever. The position token is moved",
+        'into a token account owned by the PositionLock at `[b"position_lock", position]`, so the',
+        "liquidity cannot be withdrawn and the position cannot be closed. The position authority",
+        "becomes the beneficiary of the lock, and can still collect fees and rewards by passing the",
+        "PositionLock as a remaining account. To lock a position from the block it is opened in,",
+        "call this instruction in the same transaction as `open_position_with_metadata`.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "",
+        "### Parameters",
+        "- `unlock_timestamp` - The timestamp until which the position's liquidity is locked.",
+        "POSITION_LOCK_PERMANENT locks the liquidity forever.",
+        "",
+        "#### Special Errors",
+        "- `InvalidPositionLock` - The unlock timestamp is not in the future."
       ];
       accounts: [
-        {
-          name: "positionBundle";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionBundleMint";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "positionBundleTokenAccount";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionBundleOwner";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "funder";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "systemProgram";
+          name: "positionAuthority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "rent";
+          name: "position";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
+          name: "positionMint";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "initializePositionBundleWithMetadata";
-      docs: [
-        "Initializes a PositionBundle account that bundles several positions.",
-        "A unique token will be minted to represent the position bundle in the users wallet.",
-        "Additional Metaplex metadata is appended to identify the token."
-      ];
-      accounts: [
-        {
-          name: "positionBundle";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "positionBundleMint";
-          isMut: true;
-          isSigner: true;
         },
         {
-          name: "positionBundleMetadata";
+          name: "positionTokenAccount";
           isMut: true;
           isSigner: false;
-          docs: [
-            "https://github.com/metaplex-foundation/metaplex-program-library/blob/773a574c4b34e5b9f248a81306ec24db064e255f/token-metadata/program/src/utils/metadata.rs#L100"
-          ];
         },
         {
-          name: "positionBundleTokenAccount";
+          name: "positionLock";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundleOwner";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "funder";
+          name: "lockTokenAccount";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "metadataUpdateAuth";
-          isMut: false;
           isSigner: false;
         },
         {
@@ -1664,49 +1834,84 @@ export type ElysiumPool = {
           name: "associatedTokenProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "metadataProgram";
+          name: "unlockTimestamp";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "extendPositionLock";
+      docs: [
+        "Extend the lock on the liquidity of a position. A lock can never be shortened.",
+        "",
+        "### Authority",
+        "- `beneficiary` - The beneficiary of the lock.",
+        "",
+        "### Parameters",
+        "- `unlock_timestamp` - The timestamp until which the position's liquidity is locked.",
+        "POSITION_LOCK_PERMANENT locks the liquidity forever.",
+        "",
+        "#### Special Errors",
+        "- `InvalidPositionLock` - The unlock timestamp is earlier than the current one."
+      ];
+      accounts: [
+        {
+          name: "beneficiary";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "positionLock";
+          isMut: true;
           isSigner: false;
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "unlockTimestamp";
+          type: "u64";
+        }
+      ];
     },
     {
-      name: "deletePositionBundle";
+      name: "unlockPosition";
       docs: [
-        "Delete a PositionBundle account. Burns the position bundle token in the owner's wallet.",
+        "Return the position token of an expired lock to the beneficiary and close the lock. The",
+        "rent of the lock and of its token account is refunded to the beneficiary.",
         "",
         "### Authority",
-        "- `position_bundle_owner` - The owner that owns the position bundle token.",
+        "- `beneficiary` - The beneficiary of the lock.",
         "",
-        "### Special Errors",
-        "- `PositionBundleNotDeletable` - The provided position bundle has open positions."
+        "#### Special Errors",
+        "- `PositionLocked` - The lock has not expired."
       ];
       accounts: [
         {
-          name: "positionBundle";
+          name: "beneficiary";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "positionBundleMint";
-          isMut: true;
+          name: "position";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "positionBundleTokenAccount";
+          name: "positionLock";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundleOwner";
-          isMut: false;
-          isSigner: true;
+          name: "lockTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "receiver";
+          name: "positionTokenAccount";
           isMut: true;
           isSigner: false;
         },
@@ -1719,1087 +1924,6708 @@ export type ElysiumPool = {
       args: [];
     },
     {
-      name: "openBundledPosition";
+      name: "increaseLiquidity";
       docs: [
-        "Open a bundled position in a ElysiumPool. No new tokens are issued",
-        "because the owner of the position bundle becomes the owner of the position.",
-        "The position will start off with 0 liquidity.",
+        "Add liquidity to a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
         "",
         "### Authority",
-        "- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
         "",
         "### Parameters",
-        "- `bundle_index` - The bundle index that we'd like to open.",
-        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
-        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
+        "- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+        "- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the position_authority.",
         "",
         "#### Special Errors",
-        "- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
-        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-        "the tick-spacing in this pool."
+        "- `LiquidityZero` - Provided liquidity amount is zero.",
+        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+        "- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted."
       ];
       accounts: [
         {
-          name: "bundledPosition";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundle";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "positionBundleTokenAccount";
+          name: "positionAuthority";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundleAuthority";
+          name: "positionTokenAccount";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "pool";
-          isMut: false;
+          name: "tokenOwnerAccountA";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "funder";
+          name: "tokenOwnerAccountB";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "tokenVaultA";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rent";
-          isMut: false;
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: true;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "bundleIndex";
-          type: "u16";
+          name: "liquidityAmount";
+          type: "u128";
         },
         {
-          name: "tickLowerIndex";
-          type: "i32";
+          name: "tokenMaxA";
+          type: "u64";
         },
         {
-          name: "tickUpperIndex";
-          type: "i32";
+          name: "tokenMaxB";
+          type: "u64";
         }
       ];
     },
     {
-      name: "closeBundledPosition";
+      name: "increaseLiquidityV2";
       docs: [
-        "Close a bundled position in a ElysiumPool.",
-        "",
-        "### Authority",
-        "- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+        "Add liquidity to a position in the ElysiumPool, rejecting it once the deadline has passed or",
+        "while the pool's sqrt-price is outside of the provided bounds.",
+        "Takes the accounts, parameters and remaining accounts of `increase_liquidity`, followed by the",
+        "parameters below.",
         "",
         "### Parameters",
-        "- `bundle_index` - The bundle index that we'd like to close.",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
         "",
         "#### Special Errors",
-        "- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
-        "- `ClosePositionNotEmpty` - The provided position account is not empty."
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- The special errors of `increase_liquidity`."
       ];
       accounts: [
         {
-          name: "bundledPosition";
+          name: "pool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundle";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "positionBundleTokenAccount";
+          name: "positionAuthority";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "positionBundleAuthority";
+          name: "positionTokenAccount";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "receiver";
+          name: "tokenOwnerAccountA";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "bundleIndex";
-          type: "u16";
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: true;
+          isSigner: false;
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "poolsConfig";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "feeAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "collectProtocolFeesAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "rewardEmissionsSuperAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "defaultProtocolFeeRate";
-            type: "u16";
-          }
-        ];
-      };
-    },
-    {
-      name: "feeTier";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "poolsConfig";
-            type: "publicKey";
-          },
-          {
-            name: "tickSpacing";
-            type: "u16";
-          },
-          {
-            name: "defaultFeeRate";
-            type: "u16";
-          }
-        ];
-      };
-    },
-    {
-      name: "positionBundle";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "positionBundleMint";
-            type: "publicKey";
-          },
-          {
-            name: "positionBitmap";
-            type: {
-              array: ["u8", 32];
+      args: [
+        {
+          name: "liquidityAmount";
+          type: "u128";
+        },
+        {
+          name: "tokenMaxA";
+          type: "u64";
+        },
+        {
+          name: "tokenMaxB";
+          type: "u64";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: {
+              defined: "Deadline";
             };
-          }
-        ];
-      };
+          };
+        },
+        {
+          name: "minSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        },
+        {
+          name: "maxSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        }
+      ];
     },
     {
-      name: "position";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "pool";
-            type: "publicKey";
-          },
-          {
-            name: "positionMint";
-            type: "publicKey";
-          },
-          {
-            name: "liquidity";
-            type: "u128";
-          },
-          {
-            name: "tickLowerIndex";
-            type: "i32";
-          },
-          {
-            name: "tickUpperIndex";
-            type: "i32";
-          },
-          {
-            name: "feeGrowthCheckpointA";
-            type: "u128";
-          },
-          {
-            name: "feeOwedA";
-            type: "u64";
-          },
-          {
-            name: "feeGrowthCheckpointB";
-            type: "u128";
-          },
-          {
-            name: "feeOwedB";
-            type: "u64";
-          },
-          {
-            name: "rewardInfos";
-            type: {
-              array: [
-                {
-                  defined: "PositionRewardInfo";
-                },
-                3
-              ];
+      name: "increaseLiquidityByTokenAmount";
+      docs: [
+        "Add liquidity to a position in the ElysiumPool, sized by an exact amount of one token.",
+        "The liquidity is derived on-chain from the pool's current sqrt-price.",
+        "This call also updates the position's accrued fees and rewards.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "",
+        "### Parameters",
+        "- `token_amount` - The exact amount of tokenA (or tokenB) the user wants to deposit.",
+        "- `other_token_max` - The maximum amount of the other token the user is willing to deposit.",
+        "- `amount_specified_is_a` - True if `token_amount` is tokenA. False if it is tokenB.",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the position_authority.",
+        "",
+        "#### Special Errors",
+        "- `LiquidityZero` - Provided token amount is zero or too small to mint any liquidity.",
+        "- `TokenNotInPositionRange` - The position's range does not take the specified token at the current price.",
+        "- `TokenMaxExceeded` - The required amount of the other token exceeds the user defined amount.",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "tokenAmount";
+          type: "u64";
+        },
+        {
+          name: "otherTokenMax";
+          type: "u64";
+        },
+        {
+          name: "amountSpecifiedIsA";
+          type: "bool";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: {
+              defined: "Deadline";
             };
-          }
-        ];
-      };
+          };
+        },
+        {
+          name: "minSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        },
+        {
+          name: "maxSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        }
+      ];
     },
     {
-      name: "tickArray";
-      type: {
+      name: "decreaseLiquidity";
+      docs: [
+        "Withdraw liquidity from a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "Does not have to be allowlisted, so that liquidity can always be",
+        "withdrawn from a permissioned pool. The pool's hook program is",
+        "not invoked either, before or after the withdrawal, as a failing",
+        "hook would fail the withdrawal.",
+        "",
+        "### Parameters",
+        "- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
+        "- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
+        "- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "",
+        "#### Special Errors",
+        "- `LiquidityZero` - Provided liquidity amount is zero.",
+        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+        "- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+        "- `PositionLocked` - The position token is held by the lock of the position.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "liquidityAmount";
+          type: "u128";
+        },
+        {
+          name: "tokenMinA";
+          type: "u64";
+        },
+        {
+          name: "tokenMinB";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "decreaseLiquidityV2";
+      docs: [
+        "Withdraw liquidity from a position in the ElysiumPool, rejecting it once the deadline has",
+        "passed or while the pool's sqrt-price is outside of the provided bounds.",
+        "Takes the accounts, parameters and remaining accounts of `decrease_liquidity`, followed by the",
+        "parameters below.",
+        "",
+        "### Parameters",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
+        "",
+        "#### Special Errors",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- The special errors of `decrease_liquidity`."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "liquidityAmount";
+          type: "u128";
+        },
+        {
+          name: "tokenMinA";
+          type: "u64";
+        },
+        {
+          name: "tokenMinB";
+          type: "u64";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: {
+              defined: "Deadline";
+            };
+          };
+        },
+        {
+          name: "minSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        },
+        {
+          name: "maxSqrtPrice";
+          type: {
+            option: "u128";
+          };
+        }
+      ];
+    },
+    {
+      name: "updateFeesAndRewards";
+      docs: [
+        "Update the accrued fees and rewards for a position.",
+        "",
+        "#### Special Errors",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+        "- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectFees";
+      docs: [
+        "Collect fees accrued for this position.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectReward";
+      docs: [
+        "Collect rewards accrued for this position.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardOwnerAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "collectExtensionReward";
+      docs: [
+        "Update the accrued extension rewards for a position and collect an extension reward.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
+        "",
+        "### Parameters",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
+        "",
+        "#### Special Errors",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLowerExtension";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpperExtension";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardOwnerAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "harvest";
+      docs: [
+        "Update the accrued fees and rewards for a position, then collect all fees and every",
+        "initialized reward in one call.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
+        "",
+        "### Remaining Accounts",
+        "- For every initialized reward, in reward index order: the reward owner account followed by",
+        "the reward vault.",
+        "- If the position is locked: the PositionLock of the position, after the reward accounts.",
+        "",
+        "#### Special Errors",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+        "- `InvalidRewardAccounts` - A reward owner account or vault is missing or does not match the reward."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayLower";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayUpper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectProtocolFees";
+      docs: [
+        "Collect the protocol fees accrued in this ElysiumPool",
+        "",
+        "### Authority",
+        "- `collect_protocol_fees_authority` - assigned authority in the ElysiumPoolConfig that can collect protocol fees"
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectProtocolFeesAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenDestinationA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenDestinationB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectCreatorFees";
+      docs: [
+        "Collect the creator fees accrued in this ElysiumPool",
+        "",
+        "### Authority",
+        "- `creator` - the creator recorded when the ElysiumPool was initialized"
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creator";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenDestinationA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenDestinationB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "swap";
+      docs: [
+        "Perform a swap in this ElysiumPool",
+        "",
+        "### Authority",
+        '- "token_authority" - The authority to withdraw tokens from the input token account.',
+        "",
+        "### Parameters",
+        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+        "- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+        "- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension",
+        "and the account at the address of the extension of every tick array of the swap, whether",
+        "initialized or not.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the token_authority.",
+        "",
+        "#### Special Errors",
+        "- `PoolNotActivated` - The pool has not reached its activation timestamp or slot.",
+        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the token_authority is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "oracle";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "otherAmountThreshold";
+          type: "u64";
+        },
+        {
+          name: "sqrtPriceLimit";
+          type: "u128";
+        },
+        {
+          name: "amountSpecifiedIsInput";
+          type: "bool";
+        },
+        {
+          name: "aToB";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "swapV2";
+      docs: [
+        "Perform a swap in this ElysiumPool, rejecting it once the deadline has passed.",
+        "Takes the accounts, parameters and remaining accounts of `swap`, followed by the",
+        "parameters below.",
+        "",
+        "### Parameters",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "",
+        "#### Special Errors",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- The special errors of `swap`."
+      ];
+      accounts: [
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArray2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "oracle";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "otherAmountThreshold";
+          type: "u64";
+        },
+        {
+          name: "sqrtPriceLimit";
+          type: "u128";
+        },
+        {
+          name: "amountSpecifiedIsInput";
+          type: "bool";
+        },
+        {
+          name: "aToB";
+          type: "bool";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: {
+              defined: "Deadline";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "closePosition";
+      docs: [
+        "Close a position in a ElysiumPool. Burns the position token in the owner's wallet.",
+        "",
+        "### Authority",
+        '- "position_authority" - The authority that owns the position token.',
+        "",
+        "#### Special Errors",
+        "- `ClosePositionNotEmpty` - The provided position account is not empty.",
+        "- `PositionLocked` - The position token is held by the lock of the position."
+      ];
+      accounts: [
+        {
+          name: "positionAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setDefaultFeeRate";
+      docs: [
+        "Set the default_fee_rate for a FeeTier",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
+        "",
+        "#### Special Errors",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeTier";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "defaultFeeRate";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "setDefaultProtocolFeeRate";
+      docs: [
+        "Sets the default protocol fee rate for a ElysiumPoolConfig",
+        "Protocol fee rate is represented as a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a ElysiumPool using this config.",
+        "",
+        "#### Special Errors",
+        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "defaultProtocolFeeRate";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "setMaxCreatorFeeRate";
+      docs: [
+        "Sets the max creator fee rate for a ElysiumPoolConfig",
+        "Creator fee rate is represented as a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_creator_fee_rate` - Upper bound on the creator fee rate of pools initialized using this config.",
+        "",
+        "#### Special Errors",
+        "- `CreatorFeeRateMaxExceeded` - If the provided max_creator_fee_rate exceeds MAX_CREATOR_FEE_RATE."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxCreatorFeeRate";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "initializeConfigExtension";
+      docs: [
+        "Initializes the ElysiumPoolsConfigExtension of a ElysiumPoolsConfig, which holds the",
+        "config settings that do not fit in the config account. Every setting starts at its",
+        "strictest value.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig'
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setMaxActivationDelay";
+      docs: [
+        "Sets how far past its creation the activation of a pool in the config can be set.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_activation_delay` - Upper bound, in seconds, on the delay of activation_timestamp.",
+        "- `max_activation_slot_delay` - Upper bound, in slots, on the delay of activation_slot."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxActivationDelay";
+          type: "u64";
+        },
+        {
+          name: "maxActivationSlotDelay";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setMaxLaunchFeeDecayPeriod";
+      docs: [
+        "Sets the longest decay period of the launch fee schedule of pools in the config.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_launch_fee_decay_period` - Upper bound on the decay period of schedules decaying",
+        "in seconds.",
+        "- `max_launch_fee_decay_slots` - Upper bound on the decay period of schedules decaying",
+        "in slots."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxLaunchFeeDecayPeriod";
+          type: "u64";
+        },
+        {
+          name: "maxLaunchFeeDecaySlots";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setPermissionedPoolAuthority";
+      docs: [
+        "Sets the authority that signs the creation of permissioned pools in the config. Setting",
+        "the default pubkey disables the creation of permissioned pools.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig'
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newPermissionedPoolAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "addHookProgram";
+      docs: [
+        "Adds a program to the hook program allowlist of the config. Pools can be set to use the",
+        "program once HOOK_PROGRAM_TIMELOCK_SECONDS have passed.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `hook_program` - The hook program to allowlist.",
+        "",
+        "#### Special Errors",
+        "- `HookProgramAllowlistFull` - The allowlist holds NUM_HOOK_PROGRAMS programs already."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "hookProgram";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "removeHookProgram";
+      docs: [
+        "Removes a program from the hook program allowlist of the config. Pools already using it",
+        "keep invoking it until their hook program is changed.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `hook_program` - The hook program to remove.",
+        "",
+        "#### Special Errors",
+        "- `HookProgramNotAllowlisted` - The hook program is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "hookProgram";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "setFeeRate";
+      docs: [
+        "Sets the fee rate for a ElysiumPool.",
+        "Fee rate is represented as hundredths of a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
+        "",
+        "#### Special Errors",
+        "- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "feeRate";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "setPoolHook";
+      docs: [
+        "Sets the hook program of a ElysiumPool. The hook program is invoked before and after",
+        "swaps and increases of liquidity, and can veto the action by returning an error.",
+        "Withdrawals never invoke the hook program, not even after the withdrawal: an error",
+        "returned by an invoked program fails the whole transaction, so a notification of",
+        "`decrease_liquidity` could not be made non-failing and would let the hook lock the",
+        "liquidity of LPs. Hook programs that track liquidity have to read the positions instead.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `hook_program` - The program the pool invokes. Has to be on the hook program allowlist",
+        "of the config extension. The default pubkey removes the hook.",
+        "",
+        "#### Special Errors",
+        "- `HookProgramNotAllowlisted` - The hook program is not allowlisted.",
+        "- `HookProgramTimelocked` - The timelock of the allowlisted hook program has not elapsed."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "configExtension";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "hookProgram";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "initializeAllowlistEntry";
+      docs: [
+        "Adds a wallet to the allowlist of a permissioned ElysiumPool.",
+        "Allowlisted wallets can trade, open positions and add liquidity in the pool. Withdrawing",
+        "liquidity, collecting and closing positions never require an allowlist entry.",
+        "",
+        "### Authority",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.'
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "accessAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "wallet";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "allowlistEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAllowlistEntry";
+      docs: [
+        "Removes a wallet from the allowlist of a permissioned ElysiumPool and refunds the rent of",
+        "the allowlist entry to the receiver.",
+        "",
+        "### Authority",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.'
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "accessAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "allowlistEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setAccessAuthority";
+      docs: [
+        "Sets the access authority of a permissioned ElysiumPool. Setting the default pubkey opens",
+        "the pool to every wallet permanently.",
+        "",
+        "### Authority",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.'
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "accessAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newAccessAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setProtocolFeeRate";
+      docs: [
+        "Sets the protocol fee rate for a ElysiumPool.",
+        "Protocol fee rate is represented as a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `protocol_fee_rate` - The rate that the pool will use to calculate protocol fees going onwards.",
+        "",
+        "#### Special Errors",
+        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "protocolFeeRate";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "setFeeAuthority";
+      docs: [
+        "Sets the fee authority for a ElysiumPoolConfig.",
+        "The fee authority can set the fee & protocol fee rate for individual pools or",
+        "set the default fee rate for newly minted pools.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig'
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newFeeAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setCollectProtocolFeesAuthority";
+      docs: [
+        "Sets the fee authority to collect protocol fees for a ElysiumPoolConfig.",
+        "Only the current collect protocol fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can collect protocol fees in the ElysiumPoolConfig'
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectProtocolFeesAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newCollectProtocolFeesAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setRewardAuthority";
+      docs: [
+        "Set the pool reward authority at the provided `reward_index`.",
+        "Only the current reward authority for this reward index has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized."
+      ];
+      accounts: [
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newRewardAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setRewardAuthorityBySuperAuthority";
+      docs: [
+        "Set the pool reward authority at the provided `reward_index`.",
+        "Only the current reward super authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized."
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "pool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardEmissionsSuperAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newRewardAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rewardIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setRewardEmissionsSuperAuthority";
+      docs: [
+        "Set the pool reward super authority for a ElysiumPoolConfig",
+        "Only the current reward super authority has permission to invoke this instruction.",
+        "This instruction will not change the authority on any `ElysiumPoolRewardInfo` pool rewards.",
+        "",
+        "### Authority",
+        '- "reward_emissions_super_authority" - Set authority that can control reward authorities for all pools in this config space.'
+      ];
+      accounts: [
+        {
+          name: "poolsConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardEmissionsSuperAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newRewardEmissionsSuperAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "twoHopSwap";
+      docs: [
+        "Perform a two-hop swap in this ElysiumPool",
+        "",
+        "### Authority",
+        '- "token_authority" - The authority to withdraw tokens from the input token account.',
+        "",
+        "### Parameters",
+        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+        "- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
+        "- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
+        "- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
+        "- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension",
+        "and the account at the address of the extension of every tick array of the swap, whether",
+        "initialized or not.",
+        "- For each permissioned pool: the allowlist entry of the token_authority.",
+        "",
+        "#### Special Errors",
+        "- `PoolNotActivated` - The pool has not reached its activation timestamp or slot.",
+        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+        "- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
+        "- `DuplicateTwoHopPool` - Error if pool one & two are the same pool.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `PoolHookNotSupported` - Either pool has a hook program.",
+        "- `WalletNotAllowlisted` - A pool is permissioned and the token_authority is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "poolOne";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "poolTwo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountOneA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultOneA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountOneB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultOneB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountTwoA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultTwoA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountTwoB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultTwoB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "oracleOne";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "oracleTwo";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "otherAmountThreshold";
+          type: "u64";
+        },
+        {
+          name: "amountSpecifiedIsInput";
+          type: "bool";
+        },
+        {
+          name: "aToBOne";
+          type: "bool";
+        },
+        {
+          name: "aToBTwo";
+          type: "bool";
+        },
+        {
+          name: "sqrtPriceLimitOne";
+          type: "u128";
+        },
+        {
+          name: "sqrtPriceLimitTwo";
+          type: "u128";
+        }
+      ];
+    },
+    {
+      name: "twoHopSwapV2";
+      docs: [
+        "Perform a two-hop swap, rejecting it once the deadline has passed.",
+        "Takes the accounts, parameters and remaining accounts of `two_hop_swap`, followed by the",
+        "parameters below.",
+        "",
+        "### Parameters",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "",
+        "#### Special Errors",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- The special errors of `two_hop_swap`."
+      ];
+      accounts: [
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "poolOne";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "poolTwo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountOneA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultOneA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountOneB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultOneB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountTwoA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultTwoA";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenOwnerAccountTwoB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenVaultTwoB";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayOne2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo0";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo1";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tickArrayTwo2";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "oracleOne";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "oracleTwo";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "otherAmountThreshold";
+          type: "u64";
+        },
+        {
+          name: "amountSpecifiedIsInput";
+          type: "bool";
+        },
+        {
+          name: "aToBOne";
+          type: "bool";
+        },
+        {
+          name: "aToBTwo";
+          type: "bool";
+        },
+        {
+          name: "sqrtPriceLimitOne";
+          type: "u128";
+        },
+        {
+          name: "sqrtPriceLimitTwo";
+          type: "u128";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: {
+              defined: "Deadline";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initializePositionBundle";
+      docs: [
+        "Initializes a PositionBundle account that bundles several positions.",
+        "A unique token will be minted to represent the position bundle in the users wallet."
+      ];
+      accounts: [
+        {
+          name: "positionBundle";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "positionBundleTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleOwner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializePositionBundleWithMetadata";
+      docs: [
+        "Initializes a PositionBundle account that bundles several positions.",
+        "A unique token will be minted to represent the position bundle in the users wallet.",
+        "Additional Metaplex metadata is appended to identify the token."
+      ];
+      accounts: [
+        {
+          name: "positionBundle";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "positionBundleMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "https://github.com/metaplex-foundation/metaplex-program-library/blob/773a574c4b34e5b9f248a81306ec24db064e255f/token-metadata/program/src/utils/metadata.rs#L100"
+          ];
+        },
+        {
+          name: "positionBundleTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleOwner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "metadataUpdateAuth";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadataProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "deletePositionBundle";
+      docs: [
+        "Delete a PositionBundle account. Burns the position bundle token in the owner's wallet.",
+        "",
+        "### Authority",
+        "- `position_bundle_owner` - The owner that owns the position bundle token.",
+        "",
+        "### Special Errors",
+        "- `PositionBundleNotDeletable` - The provided position bundle has open positions."
+      ];
+      accounts: [
+        {
+          name: "positionBundle";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "openBundledPosition";
+      docs: [
+        "Open a bundled position in a ElysiumPool. No new tokens are issued",
+        "because the owner of the position bundle becomes the owner of the position.",
+        "The position will start off with 0 liquidity.",
+        "",
+        "### Authority",
+        "- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+        "",
+        "### Parameters",
+        "- `bundle_index` - The bundle index that we'd like to open.",
+        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
+        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position_bundle_authority.",
+        "",
+        "#### Special Errors",
+        "- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
+        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+        "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_bundle_authority is not allowlisted."
+      ];
+      accounts: [
+        {
+          name: "bundledPosition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundle";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "pool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "bundleIndex";
+          type: "u16";
+        },
+        {
+          name: "tickLowerIndex";
+          type: "i32";
+        },
+        {
+          name: "tickUpperIndex";
+          type: "i32";
+        }
+      ];
+    },
+    {
+      name: "closeBundledPosition";
+      docs: [
+        "Close a bundled position in a ElysiumPool.",
+        "",
+        "### Authority",
+        "- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+        "",
+        "### Parameters",
+        "- `bundle_index` - The bundle index that we'd like to close.",
+        "",
+        "#### Special Errors",
+        "- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
+        "- `ClosePositionNotEmpty` - The provided position account is not empty."
+      ];
+      accounts: [
+        {
+          name: "bundledPosition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundle";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionBundleAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "bundleIndex";
+          type: "u16";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "poolsConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feeAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "collectProtocolFeesAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "rewardEmissionsSuperAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "defaultProtocolFeeRate";
+            type: "u16";
+          },
+          {
+            name: "maxCreatorFeeRate";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "feeTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "poolsConfig";
+            type: "publicKey";
+          },
+          {
+            name: "tickSpacing";
+            type: "u16";
+          },
+          {
+            name: "defaultFeeRate";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "positionBundle";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "positionBundleMint";
+            type: "publicKey";
+          },
+          {
+            name: "positionBitmap";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "position";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "positionMint";
+            type: "publicKey";
+          },
+          {
+            name: "liquidity";
+            type: "u128";
+          },
+          {
+            name: "tickLowerIndex";
+            type: "i32";
+          },
+          {
+            name: "tickUpperIndex";
+            type: "i32";
+          },
+          {
+            name: "feeGrowthCheckpointA";
+            type: "u128";
+          },
+          {
+            name: "feeOwedA";
+            type: "u64";
+          },
+          {
+            name: "feeGrowthCheckpointB";
+            type: "u128";
+          },
+          {
+            name: "feeOwedB";
+            type: "u64";
+          },
+          {
+            name: "rewardInfos";
+            type: {
+              array: [
+                {
+                  defined: "PositionRewardInfo";
+                },
+                3
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "tickArray";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startTickIndex";
+            type: "i32";
+          },
+          {
+            name: "ticks";
+            type: {
+              array: [
+                {
+                  defined: "Tick";
+                },
+                88
+              ];
+            };
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "pool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "poolsConfig";
+            type: "publicKey";
+          },
+          {
+            name: "poolBump";
+            type: {
+              array: ["u8", 1];
+            };
+          },
+          {
+            name: "tickSpacing";
+            type: "u16";
+          },
+          {
+            name: "feeTierIndexSeed";
+            type: {
+              array: ["u8", 2];
+            };
+          },
+          {
+            name: "feeRate";
+            type: "u16";
+          },
+          {
+            name: "protocolFeeRate";
+            type: "u16";
+          },
+          {
+            name: "liquidity";
+            type: "u128";
+          },
+          {
+            name: "sqrtPrice";
+            type: "u128";
+          },
+          {
+            name: "tickCurrentIndex";
+            type: "i32";
+          },
+          {
+            name: "protocolFeeOwedA";
+            type: "u64";
+          },
+          {
+            name: "protocolFeeOwedB";
+            type: "u64";
+          },
+          {
+            name: "tokenMintA";
+            type: "publicKey";
+          },
+          {
+            name: "tokenVaultA";
+            type: "publicKey";
+          },
+          {
+            name: "feeGrowthGlobalA";
+            type: "u128";
+          },
+          {
+            name: "tokenMintB";
+            type: "publicKey";
+          },
+          {
+            name: "tokenVaultB";
+            type: "publicKey";
+          },
+          {
+            name: "feeGrowthGlobalB";
+            type: "u128";
+          },
+          {
+            name: "rewardLastUpdatedTimestamp";
+            type: "u64";
+          },
+          {
+            name: "rewardInfos";
+            type: {
+              array: [
+                {
+                  defined: "ElysiumPoolRewardInfo";
+                },
+                3
+              ];
+            };
+          },
+          {
+            name: "rewardExtension";
+            type: "publicKey";
+          },
+          {
+            name: "activationTimestamp";
+            type: "u64";
+          },
+          {
+            name: "activationSlot";
+            type: "u64";
+          },
+          {
+            name: "launchFeeSchedule";
+            type: {
+              defined: "LaunchFeeSchedule";
+            };
+          },
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "creatorFeeRate";
+            type: "u16";
+          },
+          {
+            name: "creatorFeeOwedA";
+            type: "u64";
+          },
+          {
+            name: "creatorFeeOwedB";
+            type: "u64";
+          },
+          {
+            name: "tickArrayBitmap";
+            type: "publicKey";
+          },
+          {
+            name: "hookProgram";
+            type: "publicKey";
+          },
+          {
+            name: "accessAuthority";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "allowlistEntry";
+      docs: [
+        "Grants a wallet access to a permissioned ElysiumPool. Entries are created and closed by the",
+        "access authority of the pool."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "poolsConfigExtension";
+      docs: [
+        "Settings of an ElysiumPoolsConfig that do not fit in the config account. Pools of a config",
+        "without an extension are initialized with the strictest settings."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "poolsConfig";
+            type: "publicKey";
+          },
+          {
+            name: "maxActivationDelay";
+            type: "u64";
+          },
+          {
+            name: "maxActivationSlotDelay";
+            type: "u64";
+          },
+          {
+            name: "maxLaunchFeeDecayPeriod";
+            type: "u64";
+          },
+          {
+            name: "maxLaunchFeeDecaySlots";
+            type: "u64";
+          },
+          {
+            name: "permissionedPoolAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "hookPrograms";
+            type: {
+              array: [
+                {
+                  defined: "HookProgramEntry";
+                },
+                4
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "positionLock";
+      docs: [
+        "Lock on the liquidity of a Position. While the lock exists, the position token is held in a",
+        "token account owned by the lock, so liquidity cannot be withdrawn and the position cannot be",
+        "closed. The beneficiary can still collect fees and rewards, and gets the position token back",
+        "once the lock has expired."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "position";
+            type: "publicKey";
+          },
+          {
+            name: "beneficiary";
+            type: "publicKey";
+          },
+          {
+            name: "unlockTimestamp";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: {
+              array: ["u8", 1];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "poolRewardExtension";
+      docs: [
+        "Additional reward slots of a ElysiumPool. Extension rewards accrue against the pool liquidity",
+        "and share the growth-inside math with the rewards stored on the pool."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "rewardLastUpdatedTimestamp";
+            type: "u64";
+          },
+          {
+            name: "rewardInfos";
+            type: {
+              array: [
+                {
+                  defined: "ElysiumPoolRewardInfo";
+                },
+                2
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "tickArrayRewardExtension";
+      docs: [
+        "Extension reward growths outside of every tick in a TickArray. The initialized state of a",
+        "tick is tracked by the TickArray itself."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tickArray";
+            type: "publicKey";
+          },
+          {
+            name: "startTickIndex";
+            type: "i32";
+          },
+          {
+            name: "rewardGrowthsOutside";
+            type: {
+              array: [
+                {
+                  array: ["u128", 2];
+                },
+                88
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "positionRewardExtension";
+      docs: ["Rewards owed to a Position for the extension rewards of its pool."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "position";
+            type: "publicKey";
+          },
+          {
+            name: "rewardInfos";
+            type: {
+              array: [
+                {
+                  defined: "PositionRewardInfo";
+                },
+                2
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "rewardSettlement";
+      docs: [
+        "Record of a retired ElysiumPool reward. Positions settle the amount they are owed for the",
+        "retired reward against this record until the reward slot is reinitialized."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "rewardIndex";
+            type: "u8";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "vault";
+            type: "publicKey";
+          },
+          {
+            name: "growthGlobalX64";
+            type: "u128";
+          },
+          {
+            name: "retiredTimestamp";
+            type: "u64";
+          },
+          {
+            name: "totalSettled";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "tickArrayBitmap";
+      docs: [
+        "Bitmap of the TickArrays of a ElysiumPool, indexed by the position of their start tick index",
+        "in the pool's range of tick arrays. Lets clients locate tick arrays without probing addresses.",
+        "The program only writes the bitmap, as tick arrays initialized before it are missing until",
+        "they are synced."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "initialized";
+            type: {
+              array: ["u64", 158];
+            };
+          },
+          {
+            name: "nonEmpty";
+            type: {
+              array: ["u64", 158];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "tickArrayFunder";
+      docs: [
+        "Record of the account that paid the rent of a TickArray. The rent of the TickArray and of this",
+        "record is refunded to the funder when the TickArray is closed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tickArray";
+            type: "publicKey";
+          },
+          {
+            name: "funder";
+            type: "publicKey";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "OpenPositionBumps";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "positionBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "OpenPositionWithMetadataBumps";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "positionBump";
+            type: "u8";
+          },
+          {
+            name: "metadataBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "PositionRewardInfo";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "growthInsideCheckpoint";
+            type: "u128";
+          },
+          {
+            name: "amountOwed";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Tick";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "initialized";
+            type: "bool";
+          },
+          {
+            name: "liquidityNet";
+            type: "i128";
+          },
+          {
+            name: "liquidityGross";
+            type: "u128";
+          },
+          {
+            name: "feeGrowthOutsideA";
+            type: "u128";
+          },
+          {
+            name: "feeGrowthOutsideB";
+            type: "u128";
+          },
+          {
+            name: "rewardGrowthsOutside";
+            type: {
+              array: ["u128", 3];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ElysiumPoolRewardInfo";
+      docs: [
+        "Stores the state relevant for tracking liquidity mining rewards at the `ElysiumPool` level.",
+        "These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
+        "and `ElysiumPool.reward_last_updated_timestamp` to determine how many rewards are earned by open",
+        "positions."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Reward token mint."];
+            type: "publicKey";
+          },
+          {
+            name: "vault";
+            docs: ["Reward vault token account."];
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            docs: [
+              "Authority account that has permission to initialize the reward and set emissions."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "emissionsPerSecondX64";
+            docs: [
+              "Q64.64 number that indicates how many tokens per second are earned per unit of liquidity."
+            ];
+            type: "u128";
+          },
+          {
+            name: "growthGlobalX64";
+            docs: [
+              "Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
+              "emissions were turned on."
+            ];
+            type: "u128";
+          },
+          {
+            name: "emissionsStartTimestamp";
+            docs: ["Timestamp from which emissions accrue. Zero if emissions are not scheduled."];
+            type: "u64";
+          },
+          {
+            name: "emissionsEndTimestamp";
+            docs: [
+              "Timestamp after which emissions stop accruing. Zero if emissions run until changed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "emissionsBudget";
+            docs: ["Total amount of reward tokens the scheduled campaign distributes."];
+            type: "u64";
+          },
+          {
+            name: "totalFunded";
+            docs: [
+              "Total amount of reward tokens deposited into the reward vault, as of the last sync."
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalEmitted";
+            docs: ["Total amount of reward tokens credited to positions."];
+            type: "u64";
+          },
+          {
+            name: "totalCollected";
+            docs: ["Total amount of reward tokens collected from the reward vault."];
+            type: "u64";
+          },
+          {
+            name: "uncreditedX64";
+            docs: [
+              "Q64.64 amount of emitted reward tokens that positions have not credited to the amounts",
+              "they are owed yet."
+            ];
+            type: "u128";
+          },
+          {
+            name: "totalCredited";
+            docs: ["Total amount of reward tokens credited to the amounts owed of positions."];
+            type: "u64";
+          },
+          {
+            name: "unfundedEmissionsX64";
+            docs: [
+              "Q64.64 amount of emissions that were due while the funded amount was used up. Emitted as",
+              "soon as the reward vault is topped up, until the emissions are changed."
+            ];
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "ElysiumPoolBumps";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "poolBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "CurrIndex";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Below";
+          },
+          {
+            name: "Inside";
+          },
+          {
+            name: "Above";
+          }
+        ];
+      };
+    },
+    {
+      name: "TickLabel";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Upper";
+          },
+          {
+            name: "Lower";
+          }
+        ];
+      };
+    },
+    {
+      name: "Direction";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Left";
+          },
+          {
+            name: "Right";
+          }
+        ];
+      };
+    },
+    {
+      name: "RewardRunway";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "unemittedAmount";
+            docs: ["Funded reward tokens that have not been emitted yet."];
+            type: "u64";
+          },
+          {
+            name: "runwaySeconds";
+            docs: [
+              "Seconds of emissions at the current rate that the unemitted amount can cover.",
+              "`u64::MAX` if the reward is not emitting."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "HookProgramEntry";
+      docs: ["Hook program pools of a config can use from the allowed timestamp."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "program";
+            type: "publicKey";
+          },
+          {
+            name: "allowedTimestamp";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LaunchFeeSchedule";
+      docs: [
+        "Fee schedule that starts at a high fee rate on activation and decays linearly to the pool's",
+        "fee rate, deterring snipers at launch. The fees charged above the pool's fee rate go to LPs."
+      ];
+      type: {
         kind: "struct";
         fields: [
           {
-            name: "startTickIndex";
-            type: "i32";
+            name: "initialFeeRate";
+            docs: ["Fee rate charged at activation, stored as hundredths of a basis point."];
+            type: "u32";
+          },
+          {
+            name: "decayPeriod";
+            docs: [
+              "Number of seconds, or slots if `decay_in_slots` is set, until the pool's fee rate applies."
+            ];
+            type: "u64";
+          },
+          {
+            name: "decayInSlots";
+            docs: [
+              "True if the decay period is measured in slots from the activation slot, rather than in",
+              "seconds from the activation timestamp."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "Deadline";
+      docs: ["Point in time after which a user instruction is no longer allowed to execute."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "UnixTimestamp";
+            fields: ["i64"];
           },
           {
-            name: "ticks";
-            type: {
-              array: [
-                {
-                  defined: "Tick";
-                },
-                88
-              ];
-            };
+            name: "Slot";
+            fields: ["u64"];
+          }
+        ];
+      };
+    },
+    {
+      name: "PoolHookEvent";
+      docs: [
+        "The action a pool hook is invoked for. Before-events are sent prior to any state change and",
+        "after-events once the pool account has been updated, so the hook can read the new pool state.",
+        "Liquidity events are only sent when liquidity is added. Withdrawals never invoke the hook, as",
+        "a failing hook would otherwise lock the funds of LPs in the pool. This holds for after-events",
+        "too, since an error returned by an invoked program cannot be caught."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "BeforeSwap";
+            fields: [
+              {
+                name: "amount";
+                type: "u64";
+              },
+              {
+                name: "sqrt_price_limit";
+                type: "u128";
+              },
+              {
+                name: "amount_specified_is_input";
+                type: "bool";
+              },
+              {
+                name: "a_to_b";
+                type: "bool";
+              }
+            ];
           },
           {
-            name: "pool";
-            type: "publicKey";
+            name: "AfterSwap";
+            fields: [
+              {
+                name: "amount_a";
+                type: "u64";
+              },
+              {
+                name: "amount_b";
+                type: "u64";
+              },
+              {
+                name: "a_to_b";
+                type: "bool";
+              }
+            ];
+          },
+          {
+            name: "BeforeModifyLiquidity";
+            fields: [
+              {
+                name: "position";
+                type: "publicKey";
+              },
+              {
+                name: "liquidity_delta";
+                type: "i128";
+              }
+            ];
+          },
+          {
+            name: "AfterModifyLiquidity";
+            fields: [
+              {
+                name: "position";
+                type: "publicKey";
+              },
+              {
+                name: "liquidity_delta";
+                type: "i128";
+              },
+              {
+                name: "amount_a";
+                type: "u64";
+              },
+              {
+                name: "amount_b";
+                type: "u64";
+              }
+            ];
           }
         ];
       };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidEnum";
+      msg: "Enum value could not be converted";
+    },
+    {
+      code: 6001;
+      name: "InvalidStartTick";
+      msg: "Invalid start tick index provided.";
+    },
+    {
+      code: 6002;
+      name: "TickArrayExistInPool";
+      msg: "Tick-array already exists in this pool";
+    },
+    {
+      code: 6003;
+      name: "TickArrayIndexOutofBounds";
+      msg: "Attempt to search for a tick-array failed";
+    },
+    {
+      code: 6004;
+      name: "InvalidTickSpacing";
+      msg: "Tick-spacing is not supported";
+    },
+    {
+      code: 6005;
+      name: "ClosePositionNotEmpty";
+      msg: "Position is not empty It cannot be closed";
+    },
+    {
+      code: 6006;
+      name: "DivideByZero";
+      msg: "Unable to divide by zero";
+    },
+    {
+      code: 6007;
+      name: "NumberCastError";
+      msg: "Unable to cast number into BigInt";
+    },
+    {
+      code: 6008;
+      name: "NumberDownCastError";
+      msg: "Unable to down cast number";
+    },
+    {
+      code: 6009;
+      name: "TickNotFound";
+      msg: "Tick not found within tick array";
+    },
+    {
+      code: 6010;
+      name: "InvalidTickIndex";
+      msg: "Provided tick index is either out of bounds or uninitializable";
+    },
+    {
+      code: 6011;
+      name: "SqrtPriceOutOfBounds";
+      msg: "Provided sqrt price out of bounds";
+    },
+    {
+      code: 6012;
+      name: "LiquidityZero";
+      msg: "Liquidity amount must be greater than zero";
+    },
+    {
+      code: 6013;
+      name: "LiquidityTooHigh";
+      msg: "Liquidity amount must be less than i64::MAX";
+    },
+    {
+      code: 6014;
+      name: "LiquidityOverflow";
+      msg: "Liquidity overflow";
+    },
+    {
+      code: 6015;
+      name: "LiquidityUnderflow";
+      msg: "Liquidity underflow";
+    },
+    {
+      code: 6016;
+      name: "LiquidityNetError";
+      msg: "Tick liquidity net underflowed or overflowed";
+    },
+    {
+      code: 6017;
+      name: "TokenMaxExceeded";
+      msg: "Exceeded token max";
+    },
+    {
+      code: 6018;
+      name: "TokenMinSubceeded";
+      msg: "Did not meet token min";
+    },
+    {
+      code: 6019;
+      name: "MissingOrInvalidDelegate";
+      msg: "Position token account has a missing or invalid delegate";
+    },
+    {
+      code: 6020;
+      name: "InvalidPositionTokenAmount";
+      msg: "Position token amount must be 1";
+    },
+    {
+      code: 6021;
+      name: "InvalidTimestampConversion";
+      msg: "Timestamp should be convertible from i64 to u64";
+    },
+    {
+      code: 6022;
+      name: "InvalidTimestamp";
+      msg: "Timestamp should be greater than the last updated timestamp";
+    },
+    {
+      code: 6023;
+      name: "InvalidTickArraySequence";
+      msg: "Invalid tick array sequence provided for instruction.";
+    },
+    {
+      code: 6024;
+      name: "InvalidTokenMintOrder";
+      msg: "Token Mint in wrong order";
+    },
+    {
+      code: 6025;
+      name: "RewardNotInitialized";
+      msg: "Reward not initialized";
+    },
+    {
+      code: 6026;
+      name: "InvalidRewardIndex";
+      msg: "Invalid reward index";
+    },
+    {
+      code: 6027;
+      name: "RewardVaultAmountInsufficient";
+      msg: "Reward vault requires amount to support emissions for at least one day";
+    },
+    {
+      code: 6028;
+      name: "FeeRateMaxExceeded";
+      msg: "Exceeded max fee rate";
+    },
+    {
+      code: 6029;
+      name: "ProtocolFeeRateMaxExceeded";
+      msg: "Exceeded max protocol fee rate";
+    },
+    {
+      code: 6030;
+      name: "MultiplicationShiftRightOverflow";
+      msg: "Multiplication with shift right overflow";
+    },
+    {
+      code: 6031;
+      name: "MulDivOverflow";
+      msg: "Muldiv overflow";
+    },
+    {
+      code: 6032;
+      name: "MulDivInvalidInput";
+      msg: "Invalid div_u256 input";
+    },
+    {
+      code: 6033;
+      name: "MultiplicationOverflow";
+      msg: "Multiplication overflow";
+    },
+    {
+      code: 6034;
+      name: "InvalidSqrtPriceLimitDirection";
+      msg: "Provided SqrtPriceLimit not in the same direction as the swap.";
+    },
+    {
+      code: 6035;
+      name: "ZeroTradableAmount";
+      msg: "There are no tradable amount to swap.";
+    },
+    {
+      code: 6036;
+      name: "AmountOutBelowMinimum";
+      msg: "Amount out below minimum threshold";
+    },
+    {
+      code: 6037;
+      name: "AmountInAboveMaximum";
+      msg: "Amount in above maximum threshold";
+    },
+    {
+      code: 6038;
+      name: "TickArraySequenceInvalidIndex";
+      msg: "Invalid index for tick array sequence";
+    },
+    {
+      code: 6039;
+      name: "AmountCalcOverflow";
+      msg: "Amount calculated overflows";
+    },
+    {
+      code: 6040;
+      name: "AmountRemainingOverflow";
+      msg: "Amount remaining overflows";
+    },
+    {
+      code: 6041;
+      name: "InvalidIntermediaryMint";
+      msg: "Invalid intermediary mint";
+    },
+    {
+      code: 6042;
+      name: "DuplicateTwoHopPool";
+      msg: "Duplicate two hop pool";
+    },
+    {
+      code: 6043;
+      name: "InvalidBundleIndex";
+      msg: "Bundle index is out of bounds";
     },
     {
-      name: "pool";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "poolsConfig";
-            type: "publicKey";
-          },
-          {
-            name: "poolBump";
-            type: {
-              array: ["u8", 1];
-            };
-          },
-          {
-            name: "tickSpacing";
-            type: "u16";
-          },
-          {
-            name: "tickSpacingSeed";
-            type: {
-              array: ["u8", 2];
-            };
-          },
-          {
-            name: "feeRate";
-            type: "u16";
-          },
-          {
-            name: "protocolFeeRate";
-            type: "u16";
-          },
-          {
-            name: "liquidity";
-            type: "u128";
-          },
-          {
-            name: "sqrtPrice";
-            type: "u128";
-          },
-          {
-            name: "tickCurrentIndex";
-            type: "i32";
-          },
-          {
-            name: "protocolFeeOwedA";
-            type: "u64";
-          },
-          {
-            name: "protocolFeeOwedB";
-            type: "u64";
-          },
-          {
-            name: "tokenMintA";
-            type: "publicKey";
-          },
-          {
-            name: "tokenVaultA";
-            type: "publicKey";
-          },
-          {
-            name: "feeGrowthGlobalA";
-            type: "u128";
-          },
-          {
-            name: "tokenMintB";
-            type: "publicKey";
-          },
-          {
-            name: "tokenVaultB";
-            type: "publicKey";
-          },
-          {
-            name: "feeGrowthGlobalB";
-            type: "u128";
-          },
-          {
-            name: "rewardLastUpdatedTimestamp";
-            type: "u64";
-          },
-          {
-            name: "rewardInfos";
-            type: {
-              array: [
-                {
-                  defined: "ElysiumPoolRewardInfo";
-                },
-                3
-              ];
-            };
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+      code: 6044;
+      name: "BundledPositionAlreadyOpened";
+      msg: "Position has already been opened";
+    },
     {
-      name: "OpenPositionBumps";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "positionBump";
-            type: "u8";
-          }
-        ];
-      };
+      code: 6045;
+      name: "BundledPositionAlreadyClosed";
+      msg: "Position has already been closed";
     },
     {
-      name: "OpenPositionWithMetadataBumps";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "positionBump";
-            type: "u8";
-          },
-          {
-            name: "metadataBump";
-            type: "u8";
-          }
-        ];
-      };
+      code: 6046;
+      name: "PositionBundleNotDeletable";
+      msg: "Unable to delete PositionBundle with open positions";
     },
     {
-      name: "PositionRewardInfo";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "growthInsideCheckpoint";
-            type: "u128";
-          },
-          {
-            name: "amountOwed";
-            type: "u64";
-          }
-        ];
-      };
+      code: 6047;
+      name: "InvalidRewardAccounts";
+      msg: "Invalid or missing reward accounts";
     },
     {
-      name: "Tick";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "initialized";
-            type: "bool";
-          },
-          {
-            name: "liquidityNet";
-            type: "i128";
-          },
-          {
-            name: "liquidityGross";
-            type: "u128";
-          },
-          {
-            name: "feeGrowthOutsideA";
-            type: "u128";
-          },
-          {
-            name: "feeGrowthOutsideB";
-            type: "u128";
-          },
-          {
-            name: "rewardGrowthsOutside";
-            type: {
-              array: ["u128", 3];
-            };
-          }
-        ];
-      };
+      code: 6048;
+      name: "TokenNotInPositionRange";
+      msg: "Position range does not accept the specified token";
     },
     {
-      name: "ElysiumPoolRewardInfo";
-      docs: [
-        "Stores the state relevant for tracking liquidity mining rewards at the `ElysiumPool` level.",
-        "These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
-        "and `ElysiumPool.reward_last_updated_timestamp` to determine how many rewards are earned by open",
-        "positions."
-      ];
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mint";
-            docs: ["Reward token mint."];
-            type: "publicKey";
-          },
-          {
-            name: "vault";
-            docs: ["Reward vault token account."];
-            type: "publicKey";
-          },
-          {
-            name: "authority";
-            docs: [
-              "Authority account that has permission to initialize the reward and set emissions."
-            ];
-            type: "publicKey";
-          },
-          {
-            name: "emissionsPerSecondX64";
-            docs: [
-              "Q64.64 number that indicates how many tokens per second are earned per unit of liquidity."
-            ];
-            type: "u128";
-          },
-          {
-            name: "growthGlobalX64";
-            docs: [
-              "Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
-              "emissions were turned on."
-            ];
-            type: "u128";
-          }
-        ];
-      };
+      code: 6049;
+      name: "DeadlineExceeded";
+      msg: "Transaction deadline exceeded";
     },
     {
-      name: "ElysiumPoolBumps";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "poolBump";
-            type: "u8";
-          }
-        ];
-      };
+      code: 6050;
+      name: "PoolSqrtPriceOutOfBounds";
+      msg: "Pool sqrt price is outside of the provided bounds";
     },
     {
-      name: "CurrIndex";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Below";
-          },
-          {
-            name: "Inside";
-          },
-          {
-            name: "Above";
-          }
-        ];
-      };
+      code: 6051;
+      name: "InvalidRewardSchedule";
+      msg: "Reward emissions schedule is invalid";
+    },
+    {
+      code: 6052;
+      name: "InvalidRewardExtension";
+      msg: "Reward extension accounts are missing or invalid";
+    },
+    {
+      code: 6053;
+      name: "RewardEmissionsNotEnded";
+      msg: "Reward emissions have not ended";
+    },
+    {
+      code: 6054;
+      name: "InvalidRewardSettlement";
+      msg: "Reward settlement does not match the reward";
+    },
+    {
+      code: 6055;
+      name: "RewardSettlementPeriodNotElapsed";
+      msg: "Reward settlement period has not elapsed";
+    },
+    {
+      code: 6056;
+      name: "UnallocatedRewardInsufficient";
+      msg: "Amount exceeds the unallocated reward amount";
     },
     {
-      name: "TickLabel";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Upper";
-          },
-          {
-            name: "Lower";
-          }
-        ];
-      };
+      code: 6057;
+      name: "PositionLocked";
+      msg: "Position liquidity is locked";
     },
     {
-      name: "Direction";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Left";
-          },
-          {
-            name: "Right";
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
+      code: 6058;
+      name: "InvalidPositionLock";
+      msg: "Position unlock timestamp is invalid";
+    },
     {
-      code: 6000;
-      name: "InvalidEnum";
-      msg: "Enum value could not be converted";
+      code: 6059;
+      name: "PoolNotActivated";
+      msg: "Pool is not activated for trading yet";
     },
     {
-      code: 6001;
-      name: "InvalidStartTick";
-      msg: "Invalid start tick index provided.";
+      code: 6060;
+      name: "LaunchFeeRateMaxExceeded";
+      msg: "Launch fee rate exceeds MAX_LAUNCH_FEE_RATE";
     },
     {
-      code: 6002;
-      name: "TickArrayExistInPool";
-      msg: "Tick-array already exists in this pool";
+      code: 6061;
+      name: "InvalidLaunchFeeSchedule";
+      msg: "Launch fee schedule is invalid";
     },
     {
-      code: 6003;
-      name: "TickArrayIndexOutofBounds";
-      msg: "Attempt to search for a tick-array failed";
+      code: 6062;
+      name: "CreatorFeeRateMaxExceeded";
+      msg: "Exceeded max creator fee rate";
     },
     {
-      code: 6004;
-      name: "InvalidTickSpacing";
-      msg: "Tick-spacing is not supported";
+      code: 6063;
+      name: "TickArrayNotEmpty";
+      msg: "Tick array has initialized ticks";
     },
     {
-      code: 6005;
-      name: "ClosePositionNotEmpty";
-      msg: "Position is not empty It cannot be closed";
+      code: 6064;
+      name: "InvalidTickArrayAccounts";
+      msg: "Tick array accounts do not match the start tick indexes";
     },
     {
-      code: 6006;
-      name: "DivideByZero";
-      msg: "Unable to divide by zero";
+      code: 6065;
+      name: "InvalidTickArrayBitmap";
+      msg: "Tick array bitmap is missing or does not belong to the pool";
     },
     {
-      code: 6007;
-      name: "NumberCastError";
-      msg: "Unable to cast number into BigInt";
+      code: 6066;
+      name: "InvalidPoolHookAccounts";
+      msg: "Pool hook program is missing from the remaining accounts";
     },
     {
-      code: 6008;
-      name: "NumberDownCastError";
-      msg: "Unable to down cast number";
+      code: 6067;
+      name: "PoolHookNotSupported";
+      msg: "Pools with a hook program are not supported by this instruction";
     },
     {
-      code: 6009;
-      name: "TickNotFound";
-      msg: "Tick not found within tick array";
+      code: 6068;
+      name: "WalletNotAllowlisted";
+      msg: "Wallet is not on the allowlist of the pool";
     },
     {
-      code: 6010;
-      name: "InvalidTickIndex";
-      msg: "Provided tick index is either out of bounds or uninitializable";
+      code: 6069;
+      name: "RewardNotSettled";
+      msg: "Positions have not settled all rewards of the retired reward";
     },
     {
-      code: 6011;
-      name: "SqrtPriceOutOfBounds";
-      msg: "Provided sqrt price out of bounds";
+      code: 6070;
+      name: "ActivationDelayMaxExceeded";
+      msg: "Pool activation is delayed past the max activation delay of the config";
     },
     {
-      code: 6012;
-      name: "LiquidityZero";
-      msg: "Liquidity amount must be greater than zero";
+      code: 6071;
+      name: "LaunchFeeDecayPeriodMaxExceeded";
+      msg: "Launch fee decay period exceeds the max decay period of the config";
+    },
+    {
+      code: 6072;
+      name: "InvalidPermissionedPoolAuthority";
+      msg: "Permissioned pools require the signature of the permissioned pool authority";
+    },
+    {
+      code: 6073;
+      name: "HookProgramNotAllowlisted";
+      msg: "Hook program is not on the hook program allowlist of the config";
+    },
+    {
+      code: 6074;
+      name: "HookProgramTimelocked";
+      msg: "Timelock of the hook program has not elapsed";
+    },
+    {
+      code: 6075;
+      name: "HookProgramAllowlistFull";
+      msg: "Hook program allowlist of the config is full";
+    },
+    {
+      code: 6076;
+      name: "UnsupportedPoolLayout";
+      msg: "Layout of the pool account is not supported";
+    }
+  ];
+};
+
+export const IDL: ElysiumPool = {
+  version: "0.2.0",
+  name: "pool",
+  instructions: [
+    {
+      name: "initializeConfig",
+      docs: [
+        "Initializes a ElysiumPoolsConfig account that hosts info & authorities",
+        "required to govern a set of ElysiumPools.",
+        "",
+        "### Parameters",
+        "- `fee_authority` - Authority authorized to initialize fee-tiers and set customs fees.",
+        "- `collect_protocol_fees_authority` - Authority authorized to collect protocol fees.",
+        "- `reward_emissions_super_authority` - Authority authorized to set reward authorities in pools.",
+      ],
+      accounts: [
+        {
+          name: "config",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "feeAuthority",
+          type: "publicKey",
+        },
+        {
+          name: "collectProtocolFeesAuthority",
+          type: "publicKey",
+        },
+        {
+          name: "rewardEmissionsSuperAuthority",
+          type: "publicKey",
+        },
+        {
+          name: "defaultProtocolFeeRate",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "initializePool",
+      docs: [
+        "Initializes a ElysiumPool account.",
+        "Fee rate and tick spacing are set to the default values on the config and supplied fee_tier.",
+        "The pool is activated on creation, without launch fee schedule, creator fee or access",
+        "authority. Use `initialize_pool_v2` to set them.",
+        "",
+        "### Parameters",
+        "- `bumps` - The bump value when deriving the PDA of the ElysiumPool address.",
+        "- `fee_tier_index` - The index of the fee tier for this pool. Fee tiers initialized with",
+        "`initialize_fee_tier` are indexed by their tick spacing.",
+        "- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+        "",
+        "#### Special Errors",
+        "`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+        "`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+        "",
+      ],
+      accounts: [
+        {
+          name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintA",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintB",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "feeTier",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bumps",
+          type: {
+            defined: "ElysiumPoolBumps",
+          },
+        },
+        {
+          name: "feeTierIndex",
+          type: "u16",
+        },
+        {
+          name: "initialSqrtPrice",
+          type: "u128",
+        },
+      ],
+    },
+    {
+      name: "initializePoolV2",
+      docs: [
+        "Initializes a ElysiumPool account with launch settings.",
+        "Takes the accounts and parameters of `initialize_pool`, followed by the parameters below.",
+        "",
+        "### Parameters",
+        "- `activation_timestamp` - Optional unix timestamp before which swaps are rejected. At most",
+        "the config extension's max_activation_delay after creation.",
+        "- `activation_slot` - Optional slot before which swaps are rejected. At most the config",
+        "extension's max_activation_slot_delay after creation.",
+        "- `launch_fee_schedule` - Optional fee schedule that decays from an initial fee rate to the",
+        "pool's fee rate after activation. The excess fees go to LPs.",
+        "- `creator_fee_rate` - Optional portion of the fee, in basis points, owed to the funder as",
+        "the pool creator.",
+        "- `access_authority` - Optional authority managing the allowlist of the pool. If provided,",
+        "only allowlisted wallets can trade and provide liquidity, and the",
+        "config extension's permissioned_pool_authority has to sign.",
+        "",
+        "#### Special Errors",
+        "`LaunchFeeRateMaxExceeded` - The initial launch fee rate exceeds MAX_LAUNCH_FEE_RATE.",
+        "`InvalidLaunchFeeSchedule` - The initial launch fee rate is not above the pool's fee rate",
+        "or the decay period is zero.",
+        "`LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds the config extension's max",
+        "launch fee decay period in the unit of the schedule.",
+        "`CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.",
+        "`ActivationDelayMaxExceeded` - The activation is delayed past the max activation delay.",
+        "`InvalidPermissionedPoolAuthority` - An access authority is provided without the signature",
+        "of the config extension's permissioned_pool_authority.",
+        "The special errors of `initialize_pool`.",
+        "",
+        "### Remaining Accounts",
+        "- Optionally the ElysiumPoolsConfigExtension of the config. Without it the activation can",
+        "not be delayed past creation, no launch fee schedule can be set and no permissioned pool",
+        "can be created.",
+        "- If an access authority is provided: the permissioned_pool_authority as a signer.",
+        "",
+      ],
+      accounts: [
+        {
+          name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintA",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintB",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "feeTier",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bumps",
+          type: {
+            defined: "ElysiumPoolBumps",
+          },
+        },
+        {
+          name: "feeTierIndex",
+          type: "u16",
+        },
+        {
+          name: "initialSqrtPrice",
+          type: "u128",
+        },
+        {
+          name: "activationTimestamp",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "activationSlot",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "launchFeeSchedule",
+          type: {
+            option: {
+              defined: "LaunchFeeSchedule",
+            },
+          },
+        },
+        {
+          name: "creatorFeeRate",
+          type: {
+            option: "u16",
+          },
+        },
+        {
+          name: "accessAuthority",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
     },
     {
-      code: 6013;
-      name: "LiquidityTooHigh";
-      msg: "Liquidity amount must be less than i64::MAX";
+      name: "initializeTickArray",
+      docs: [
+        "Initializes a tick_array account to represent a tick-range in a ElysiumPool.",
+        "",
+        "### Parameters",
+        "- `start_tick_index` - The starting tick index for this tick-array.",
+        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "",
+        "#### Special Errors",
+        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tickArray",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "startTickIndex",
+          type: "i32",
+        },
+      ],
     },
     {
-      code: 6014;
-      name: "LiquidityOverflow";
-      msg: "Liquidity overflow";
+      name: "initializeTickArrayV2",
+      docs: [
+        "Initializes a tick_array account to represent a tick-range in a ElysiumPool, and records",
+        "the funder in a tick_array_funder account so that the funder can close the tick array",
+        "once it is empty. Takes the accounts of `initialize_tick_array` with the tick_array_funder",
+        "account after the tick_array.",
+        "",
+        "### Parameters",
+        "- `start_tick_index` - The starting tick index for this tick-array.",
+        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "",
+        "#### Special Errors",
+        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tickArray",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayFunder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "startTickIndex",
+          type: "i32",
+        },
+      ],
     },
     {
-      code: 6015;
-      name: "LiquidityUnderflow";
-      msg: "Liquidity underflow";
+      name: "initializeTickArrays",
+      docs: [
+        "Initializes a tick_array account for every provided start tick index, skipping tick",
+        "arrays that already exist. Allows a range of tick arrays to be prepared in one instruction",
+        "without checking which of them exist.",
+        "",
+        "### Parameters",
+        "- `start_tick_indexes` - The starting tick index of every tick-array.",
+        "Each has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "",
+        "### Remaining Accounts",
+        "- For every start tick index, in order: the tick array followed by its tick_array_funder",
+        "record.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap, after the tick array accounts.",
+        "",
+        "#### Special Errors",
+        "- `InvalidStartTick` - if a provided start tick is out of bounds or is not a multiple of",
+        "TICK_ARRAY_SIZE * tick spacing.",
+        "- `InvalidTickArrayAccounts` - The remaining accounts are not the tick arrays and funder",
+        "records of the provided start tick indexes.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "startTickIndexes",
+          type: {
+            vec: "i32",
+          },
+        },
+      ],
     },
     {
-      code: 6016;
-      name: "LiquidityNetError";
-      msg: "Tick liquidity net underflowed or overflowed";
+      name: "closeTickArray",
+      docs: [
+        "Closes an empty tick_array account, refunding its rent and the rent of its funder record",
+        "to the funder that initialized it. Only tick arrays initialized by `initialize_tick_array_v2`",
+        "or `initialize_tick_arrays` have a funder record and can be closed. A closed tick array can",
+        "be initialized again by anyone.",
+        "",
+        "### Authority",
+        '- "funder" - The funder recorded when the tick_array was initialized.',
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "",
+        "#### Special Errors",
+        "- `TickArrayNotEmpty` - The tick array has initialized ticks, so positions with liquidity",
+        "may reference it.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArray",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayFunder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6017;
-      name: "TokenMaxExceeded";
-      msg: "Exceeded token max";
+      name: "migratePool",
+      docs: [
+        "Migrates a ElysiumPool account initialized with the original layout to the current layout.",
+        "The account is grown, with the funder paying the additional rent, and the fields added",
+        "since are zeroed: the pool is active, without launch fee, creator fee, hook or access",
+        "authority. Initialized rewards keep emitting until the balances of their vaults at migration",
+        "have been emitted, and can only be reinitialized once the settlement period after their",
+        "retirement has elapsed. Migrating a pool that already has the current layout does nothing.",
+        "",
+        "### Authority",
+        '- "funder" - Any account that pays for the additional rent.',
+        "",
+        "### Remaining Accounts",
+        "- The reward vaults of the initialized rewards of the pool.",
+        "",
+        "#### Special Errors",
+        "- `UnsupportedPoolLayout` - The pool account has neither the original nor the current layout.",
+        "- `InvalidRewardAccounts` - The vault of an initialized reward is missing.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6018;
-      name: "TokenMinSubceeded";
-      msg: "Did not meet token min";
+      name: "migrateTickArray",
+      docs: [
+        "Migrates a tick_array account initialized before tick arrays stored a bitmap of their",
+        "initialized ticks. The account is grown to hold the bitmap, with the funder paying the",
+        "additional rent, and the bitmap is rebuilt from the ticks. Swaps search the ticks of",
+        "migrated tick arrays with the bitmap, and the ticks of other tick arrays one by one.",
+        "",
+        "### Authority",
+        '- "funder" - Any account that pays for the additional rent.',
+      ],
+      accounts: [
+        {
+          name: "tickArray",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6019;
-      name: "MissingOrInvalidDelegate";
-      msg: "Position token account has a missing or invalid delegate";
+      name: "initializeTickArrayBitmap",
+      docs: [
+        "Initialize the TickArrayBitmap of a ElysiumPool, which records the initialized and",
+        "non-empty TickArrays of the pool. Once initialized, clients must pass the bitmap to every",
+        "instruction that initializes or closes tick arrays or modifies liquidity, which fail with",
+        "`InvalidTickArrayBitmap` without it.",
+        "",
+        "The bitmap is an index for clients and is not read by swaps. Tick arrays initialized",
+        "before the bitmap are only recorded once passed to `sync_tick_array_bitmap`, so swaps keep",
+        "taking consecutive tick arrays rather than skipping arrays the bitmap reports as empty.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+      ],
+      accounts: [
+        {
+          name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tickArrayBitmap",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6020;
-      name: "InvalidPositionTokenAmount";
-      msg: "Position token amount must be 1";
+      name: "syncTickArrayBitmap",
+      docs: [
+        "Record the current state of tick arrays in the TickArrayBitmap of their ElysiumPool, such",
+        "as tick arrays initialized before the bitmap.",
+        "",
+        "### Remaining Accounts",
+        "- The tick arrays to record.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickArrayAccounts` - A tick array does not belong to the pool.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayBitmap",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6021;
-      name: "InvalidTimestampConversion";
-      msg: "Timestamp should be convertible from i64 to u64";
+      name: "initializeFeeTier",
+      docs: [
+        "Initializes a fee_tier account usable by ElysiumPools in a ElysiumPoolConfig space.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
+        "",
+        "#### Special Errors",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+      ],
+      accounts: [
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeTier",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tickSpacing",
+          type: "u16",
+        },
+        {
+          name: "defaultFeeRate",
+          type: "u16",
+        },
+      ],
     },
     {
-      code: 6022;
-      name: "InvalidTimestamp";
-      msg: "Timestamp should be greater than the last updated timestamp";
+      name: "initializeFeeTierWithIndex",
+      docs: [
+        "Initializes a fee_tier account keyed by a fee tier index rather than its tick spacing,",
+        "allowing multiple fee tiers with the same tick spacing in a ElysiumPoolConfig space.",
+        "The index must not be in use by another fee tier, including fee tiers keyed by tick spacing.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `fee_tier_index` - The index that pools using this fee tier are keyed by.",
+        "- `tick_spacing` - The tick-spacing of pools initialized with this fee-tier.",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickSpacing` - If the provided tick_spacing is 0.",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+      ],
+      accounts: [
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeTier",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "feeTierIndex",
+          type: "u16",
+        },
+        {
+          name: "tickSpacing",
+          type: "u16",
+        },
+        {
+          name: "defaultFeeRate",
+          type: "u16",
+        },
+      ],
     },
     {
-      code: 6023;
-      name: "InvalidTickArraySequence";
-      msg: "Invalid tick array sequence provided for instruction.";
+      name: "initializeReward",
+      docs: [
+        "Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized.",
+      ],
+      accounts: [
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6024;
-      name: "InvalidTokenMintOrder";
-      msg: "Token Mint in wrong order";
+      name: "setRewardEmissions",
+      docs: [
+        "Set the reward emissions for a reward in a ElysiumPool.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+        "",
+        "#### Special Errors",
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot",
+        "cover a day of desired emissions.",
+        "- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this pool, or exceeds NUM_REWARDS, or",
+        "all reward slots for this pool has been initialized.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+        {
+          name: "emissionsPerSecondX64",
+          type: "u128",
+        },
+      ],
     },
     {
-      code: 6025;
-      name: "RewardNotInitialized";
-      msg: "Reward not initialized";
+      name: "setRewardEmissionsSchedule",
+      docs: [
+        "Schedule a reward emissions campaign for a reward in a ElysiumPool. The budget is emitted",
+        "at a constant rate between the start and end timestamps, and no rewards accrue outside of",
+        "that window. Calling `set_reward_emissions` afterwards clears the schedule.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+        "- `start_timestamp` - The timestamp from which rewards start accruing.",
+        "- `end_timestamp` - The timestamp after which rewards stop accruing.",
+        "- `budget` - The total amount of rewards emitted over the campaign.",
+        "",
+        "#### Special Errors",
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet are",
+        "less than the budget of the campaign.",
+        "- `InvalidRewardSchedule` - The start timestamp is in the past or the end timestamp is not",
+        "after the start timestamp.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+        {
+          name: "startTimestamp",
+          type: "u64",
+        },
+        {
+          name: "endTimestamp",
+          type: "u64",
+        },
+        {
+          name: "budget",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6026;
-      name: "InvalidRewardIndex";
-      msg: "Invalid reward index";
+      name: "getRewardRunway",
+      docs: [
+        "Get the funded rewards that have not been emitted yet for a reward in a ElysiumPool, and",
+        "how many seconds of emissions at the current rate they cover. Once the funded amount has",
+        "been emitted, emissions are deferred until the reward vault is topped up. The result is",
+        "written to the return data.",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to query.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
+      returns: {
+        defined: "RewardRunway",
+      },
     },
     {
-      code: 6027;
-      name: "RewardVaultAmountInsufficient";
-      msg: "Reward vault requires amount to support emissions for at least one day";
+      name: "fundReward",
+      docs: [
+        "Deposit tokens into the reward vault of a reward in a ElysiumPool. Anyone can fund a",
+        "reward. Rewards are updated before the deposit, so it only backs future emissions.",
+        "",
+        "### Authority",
+        '- "funder" - The authority to withdraw tokens from the funder token account.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to fund.",
+        "- `amount` - The amount of reward tokens to deposit.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6028;
-      name: "FeeRateMaxExceeded";
-      msg: "Exceeded max fee rate";
+      name: "withdrawUnallocatedReward",
+      docs: [
+        "Withdraw reward tokens that have not been emitted to positions and are not committed to",
+        "the remainder of a scheduled campaign.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.",
+        "- `amount` - The amount of reward tokens to withdraw.",
+        "",
+        "#### Special Errors",
+        "- `UnallocatedRewardInsufficient` - The amount exceeds the unallocated reward amount.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenDestination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6029;
-      name: "ProtocolFeeRateMaxExceeded";
-      msg: "Exceeded max protocol fee rate";
+      name: "retireReward",
+      docs: [
+        "Retire a reward in a ElysiumPool whose emissions have ended. Emissions are turned off and",
+        "a RewardSettlement records the retired reward, so positions can settle what they are owed",
+        "before the reward slot is reinitialized.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to retire.",
+        "",
+        "#### Special Errors",
+        "- `RewardEmissionsNotEnded` - The reward is not initialized or still emits rewards.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardSettlement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6030;
-      name: "MultiplicationShiftRightOverflow";
-      msg: "Multiplication with shift right overflow";
+      name: "settleReward",
+      docs: [
+        "Update the accrued rewards of a position and transfer everything it is owed for a retired",
+        "reward to the owner of the position token, or to the beneficiary of its PositionLock if the",
+        "position is locked. Anyone can settle a position.",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardSettlement` - The reward slot has been reinitialized since the settlement,",
+        "or emissions were turned back on.",
+        "- `MissingOrInvalidDelegate` - The position is locked and its PositionLock is not passed.",
+        "- `InvalidRewardAccounts` - The reward owner account is not owned by the position owner, or",
+        "the lock beneficiary if the position is locked.",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardSettlement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardOwnerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6031;
-      name: "MulDivOverflow";
-      msg: "Muldiv overflow";
+      name: "reinitializeReward",
+      docs: [
+        "Reinitialize a retired reward in a ElysiumPool with a new mint and vault, once every",
+        "position has been settled or the settlement period of the retired reward has elapsed.",
+        "Positions that have not settled the retired reward by then are credited what they are",
+        "still owed for it in the new mint.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+        "reward-index in this ElysiumPool",
+        "",
+        "### Parameters",
+        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to reinitialize.",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardSettlement` - The settlement does not belong to the reward in the slot.",
+        "- `RewardEmissionsNotEnded` - Emissions were turned back on after the reward was retired.",
+        "- `RewardNotSettled` - Positions have not credited or collected everything they are owed",
+        "and the settlement period has not elapsed yet.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardSettlement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6032;
-      name: "MulDivInvalidInput";
-      msg: "Invalid div_u256 input";
+      name: "initializeRewardExtension",
+      docs: [
+        "Initialize the PoolRewardExtension of a ElysiumPool, adding NUM_EXTENSION_REWARDS reward",
+        "slots on top of the rewards stored on the pool. Once initialized, every liquidity change",
+        "and every swap that crosses a tick must pass the reward extension as a remaining account.",
+        "Tick array and position extensions are optional and can be created at any time.",
+        "",
+        "### Authority",
+        '- "reward_emissions_super_authority" - Set authority in the ElysiumPoolConfig. Becomes the',
+        "reward authority of every extension reward slot.",
+      ],
+      accounts: [
+        {
+          name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardEmissionsSuperAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "rewardExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6033;
-      name: "MultiplicationOverflow";
-      msg: "Multiplication overflow";
+      name: "initializeTickArrayRewardExtension",
+      docs: [
+        "Initialize the TickArrayRewardExtension of a TickArray. Required before a position with",
+        "a tick in the tick array can earn extension rewards. Ticks crossed before the extension",
+        "exists are not tracked, which no position extension depends on.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArray",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tickArrayExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6034;
-      name: "InvalidSqrtPriceLimitDirection";
-      msg: "Provided SqrtPriceLimit not in the same direction as the swap.";
+      name: "initializePositionRewardExtension",
+      docs: [
+        "Initialize the PositionRewardExtension of a Position, which tracks the extension rewards",
+        "owed to the position. The position earns extension rewards from this point on, so the",
+        "extensions of its lower and upper tick arrays must already exist.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLowerExtension",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpperExtension",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "positionExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6035;
-      name: "ZeroTradableAmount";
-      msg: "There are no tradable amount to swap.";
+      name: "initializeExtensionReward",
+      docs: [
+        "Initialize an extension reward of a ElysiumPool.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The extension reward index that we'd like to initialize.",
+        "(0 <= index < NUM_EXTENSION_REWARDS)",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+        "index in this extension, or exceeds NUM_EXTENSION_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6036;
-      name: "AmountOutBelowMinimum";
-      msg: "Amount out below minimum threshold";
+      name: "setExtensionRewardAuthority",
+      docs: [
+        "Set the authority of an extension reward of a ElysiumPool.",
+        "Only the current authority of the extension reward has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "",
+        "#### Special Errors",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "newRewardAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6037;
-      name: "AmountInAboveMaximum";
-      msg: "Amount in above maximum threshold";
+      name: "setExtensionRewardEmissions",
+      docs: [
+        "Set the reward emissions for an extension reward of a ElysiumPool.",
+        "",
+        "### Authority",
+        '- "reward_authority" - assigned authority for the specified extension reward-index.',
+        "",
+        "### Parameters",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+        "",
+        "#### Special Errors",
+        "- `RewardVaultAmountInsufficient` - The funded rewards that have not been emitted yet cannot",
+        "cover a day of desired emissions.",
+        "- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardExtension",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rewardIndex",
+          type: "u8",
+        },
+        {
+          name: "emissionsPerSecondX64",
+          type: "u128",
+        },
+      ],
     },
     {
-      code: 6038;
-      name: "TickArraySequenceInvalidIndex";
-      msg: "Invalid index for tick array sequence";
+      name: "openPosition",
+      docs: [
+        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
+        "in the users wallet. The position will start off with 0 liquidity.",
+        "",
+        "### Parameters",
+        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
+        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position owner.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+        "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted.",
+      ],
+      accounts: [
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bumps",
+          type: {
+            defined: "OpenPositionBumps",
+          },
+        },
+        {
+          name: "tickLowerIndex",
+          type: "i32",
+        },
+        {
+          name: "tickUpperIndex",
+          type: "i32",
+        },
+      ],
     },
     {
-      code: 6039;
-      name: "AmountCalcOverflow";
-      msg: "Amount calculated overflows";
+      name: "openPositionWithMetadata",
+      docs: [
+        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
+        "in the users wallet. Additional Metaplex metadata is appended to identify the token.",
+        "The position will start off with 0 liquidity.",
+        "",
+        "### Parameters",
+        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
+        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position owner.",
+        "",
+        "#### Special Errors",
+        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+        "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the owner is not allowlisted.",
+      ],
+      accounts: [
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "positionMetadataAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873",
+          ],
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadataUpdateAuth",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bumps",
+          type: {
+            defined: "OpenPositionWithMetadataBumps",
+          },
+        },
+        {
+          name: "tickLowerIndex",
+          type: "i32",
+        },
+        {
+          name: "tickUpperIndex",
+          type: "i32",
+        },
+      ],
     },
     {
-      code: 6040;
-      name: "AmountRemainingOverflow";
-      msg: "Amount remaining overflows";
+      name: "lockPosition",
+      docs: [
+        "Lock the liquidity of a position until a timestamp, or forever. The position token is moved",
+        'into a token account owned by the PositionLock at `[b"position_lock", position]`, so the',
+        "liquidity cannot be withdrawn and the position cannot be closed. The position authority",
+        "becomes the beneficiary of the lock, and can still collect fees and rewards by passing the",
+        "PositionLock as a remaining account. To lock a position from the block it is opened in,",
+        "call this instruction in the same transaction as `open_position_with_metadata`.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "",
+        "### Parameters",
+        "- `unlock_timestamp` - The timestamp until which the position's liquidity is locked.",
+        "POSITION_LOCK_PERMANENT locks the liquidity forever.",
+        "",
+        "#### Special Errors",
+        "- `InvalidPositionLock` - The unlock timestamp is not in the future.",
+      ],
+      accounts: [
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "unlockTimestamp",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6041;
-      name: "InvalidIntermediaryMint";
-      msg: "Invalid intermediary mint";
+      name: "extendPositionLock",
+      docs: [
+        "Extend the lock on the liquidity of a position. A lock can never be shortened.",
+        "",
+        "### Authority",
+        "- `beneficiary` - The beneficiary of the lock.",
+        "",
+        "### Parameters",
+        "- `unlock_timestamp` - The timestamp until which the position's liquidity is locked.",
+        "POSITION_LOCK_PERMANENT locks the liquidity forever.",
+        "",
+        "#### Special Errors",
+        "- `InvalidPositionLock` - The unlock timestamp is earlier than the current one.",
+      ],
+      accounts: [
+        {
+          name: "beneficiary",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "positionLock",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "unlockTimestamp",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6042;
-      name: "DuplicateTwoHopPool";
-      msg: "Duplicate two hop pool";
+      name: "unlockPosition",
+      docs: [
+        "Return the position token of an expired lock to the beneficiary and close the lock. The",
+        "rent of the lock and of its token account is refunded to the beneficiary.",
+        "",
+        "### Authority",
+        "- `beneficiary` - The beneficiary of the lock.",
+        "",
+        "#### Special Errors",
+        "- `PositionLocked` - The lock has not expired.",
+      ],
+      accounts: [
+        {
+          name: "beneficiary",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6043;
-      name: "InvalidBundleIndex";
-      msg: "Bundle index is out of bounds";
+      name: "increaseLiquidity",
+      docs: [
+        "Add liquidity to a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "",
+        "### Parameters",
+        "- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
+        "- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+        "- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the position_authority.",
+        "",
+        "#### Special Errors",
+        "- `LiquidityZero` - Provided liquidity amount is zero.",
+        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+        "- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityAmount",
+          type: "u128",
+        },
+        {
+          name: "tokenMaxA",
+          type: "u64",
+        },
+        {
+          name: "tokenMaxB",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6044;
-      name: "BundledPositionAlreadyOpened";
-      msg: "Position has already been opened";
+      name: "increaseLiquidityV2",
+      docs: [
+        "Add liquidity to a position in the ElysiumPool, rejecting it once the deadline has passed or",
+        "while the pool's sqrt-price is outside of the provided bounds.",
+        "Takes the accounts, parameters and remaining accounts of `increase_liquidity`, followed by the",
+        "parameters below.",
+        "",
+        "### Parameters",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
+        "",
+        "#### Special Errors",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- The special errors of `increase_liquidity`.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityAmount",
+          type: "u128",
+        },
+        {
+          name: "tokenMaxA",
+          type: "u64",
+        },
+        {
+          name: "tokenMaxB",
+          type: "u64",
+        },
+        {
+          name: "deadline",
+          type: {
+            option: {
+              defined: "Deadline",
+            },
+          },
+        },
+        {
+          name: "minSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+        {
+          name: "maxSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+      ],
     },
     {
-      code: 6045;
-      name: "BundledPositionAlreadyClosed";
-      msg: "Position has already been closed";
+      name: "increaseLiquidityByTokenAmount",
+      docs: [
+        "Add liquidity to a position in the ElysiumPool, sized by an exact amount of one token.",
+        "The liquidity is derived on-chain from the pool's current sqrt-price.",
+        "This call also updates the position's accrued fees and rewards.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "",
+        "### Parameters",
+        "- `token_amount` - The exact amount of tokenA (or tokenB) the user wants to deposit.",
+        "- `other_token_max` - The maximum amount of the other token the user is willing to deposit.",
+        "- `amount_specified_is_a` - True if `token_amount` is tokenA. False if it is tokenB.",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the position_authority.",
+        "",
+        "#### Special Errors",
+        "- `LiquidityZero` - Provided token amount is zero or too small to mint any liquidity.",
+        "- `TokenNotInPositionRange` - The position's range does not take the specified token at the current price.",
+        "- `TokenMaxExceeded` - The required amount of the other token exceeds the user defined amount.",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_authority is not allowlisted.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tokenAmount",
+          type: "u64",
+        },
+        {
+          name: "otherTokenMax",
+          type: "u64",
+        },
+        {
+          name: "amountSpecifiedIsA",
+          type: "bool",
+        },
+        {
+          name: "deadline",
+          type: {
+            option: {
+              defined: "Deadline",
+            },
+          },
+        },
+        {
+          name: "minSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+        {
+          name: "maxSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+      ],
     },
     {
-      code: 6046;
-      name: "PositionBundleNotDeletable";
-      msg: "Unable to delete PositionBundle with open positions";
-    }
-  ];
-};
-
-export const IDL: ElysiumPool = {
-  version: "0.2.0",
-  name: "pool",
-  instructions: [
-    {
-      name: "initializeConfig",
+      name: "decreaseLiquidity",
       docs: [
-        "Initializes a ElysiumPoolsConfig account that hosts info & authorities",
-        "required to govern a set of ElysiumPools.",
+        "Withdraw liquidity from a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "Does not have to be allowlisted, so that liquidity can always be",
+        "withdrawn from a permissioned pool. The pool's hook program is",
+        "not invoked either, before or after the withdrawal, as a failing",
+        "hook would fail the withdrawal.",
         "",
         "### Parameters",
-        "- `fee_authority` - Authority authorized to initialize fee-tiers and set customs fees.",
-        "- `collect_protocol_fees_authority` - Authority authorized to collect protocol fees.",
-        "- `reward_emissions_super_authority` - Authority authorized to set reward authorities in pools.",
+        "- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
+        "- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
+        "- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension: the reward extension and the account at the address",
+        "of the position's extension, whether initialized or not. If the position's extension is",
+        "initialized, also the extensions of the lower and upper tick arrays.",
+        "- If the pool has a TickArrayBitmap: the tick array bitmap.",
+        "",
+        "#### Special Errors",
+        "- `LiquidityZero` - Provided liquidity amount is zero.",
+        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+        "- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+        "- `PositionLocked` - The position token is held by the lock of the position.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidTickArrayBitmap` - The pool's tick array bitmap is missing.",
       ],
       accounts: [
         {
-          name: "config",
+          name: "pool",
           isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "funder",
+          name: "position",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "positionTokenAccount",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "feeAuthority",
-          type: "publicKey",
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "collectProtocolFeesAuthority",
-          type: "publicKey",
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "rewardEmissionsSuperAuthority",
-          type: "publicKey",
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "defaultProtocolFeeRate",
-          type: "u16",
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityAmount",
+          type: "u128",
+        },
+        {
+          name: "tokenMinA",
+          type: "u64",
+        },
+        {
+          name: "tokenMinB",
+          type: "u64",
         },
       ],
     },
     {
-      name: "initializePool",
+      name: "decreaseLiquidityV2",
       docs: [
-        "Initializes a ElysiumPool account.",
-        "Fee rate is set to the default values on the config and supplied fee_tier.",
+        "Withdraw liquidity from a position in the ElysiumPool, rejecting it once the deadline has",
+        "passed or while the pool's sqrt-price is outside of the provided bounds.",
+        "Takes the accounts, parameters and remaining accounts of `decrease_liquidity`, followed by the",
+        "parameters below.",
         "",
         "### Parameters",
-        "- `bumps` - The bump value when deriving the PDA of the ElysiumPool address.",
-        "- `tick_spacing` - The desired tick spacing for this pool.",
-        "- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "- `min_sqrt_price` - Optional minimum pool sqrt-price at execution.",
+        "- `max_sqrt_price` - Optional maximum pool sqrt-price at execution.",
         "",
         "#### Special Errors",
-        "`InvalidTokenMintOrder` - The order of mints have to be ordered by",
-        "`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
-        "",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- `PoolSqrtPriceOutOfBounds` - The pool's sqrt-price is outside of the provided bounds.",
+        "- The special errors of `decrease_liquidity`.",
       ],
       accounts: [
         {
-          name: "poolsConfig",
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenMintA",
+          name: "positionAuthority",
           isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenMintB",
+          name: "positionTokenAccount",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "funder",
+          name: "tokenOwnerAccountA",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "pool",
+          name: "tokenOwnerAccountB",
           isMut: true,
           isSigner: false,
         },
         {
           name: "tokenVaultA",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
           name: "tokenVaultB",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "feeTier",
+          name: "tickArrayLower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayUpper",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityAmount",
+          type: "u128",
+        },
+        {
+          name: "tokenMinA",
+          type: "u64",
+        },
+        {
+          name: "tokenMinB",
+          type: "u64",
+        },
+        {
+          name: "deadline",
+          type: {
+            option: {
+              defined: "Deadline",
+            },
+          },
+        },
+        {
+          name: "minSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+        {
+          name: "maxSqrtPrice",
+          type: {
+            option: "u128",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateFeesAndRewards",
+      docs: [
+        "Update the accrued fees and rewards for a position.",
+        "",
+        "#### Special Errors",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+        "- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.",
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayLower",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "tickArrayUpper",
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "collectFees",
+      docs: [
+        "Collect fees accrued for this position.",
+        "",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
+      ],
+      accounts: [
         {
-          name: "systemProgram",
+          name: "pool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "positionAuthority",
           isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "bumps",
-          type: {
-            defined: "ElysiumPoolBumps",
-          },
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "tickSpacing",
-          type: "u16",
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "initialSqrtPrice",
-          type: "u128",
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "initializeTickArray",
+      name: "collectReward",
       docs: [
-        "Initializes a tick_array account to represent a tick-range in a ElysiumPool.",
+        "Collect rewards accrued for this position.",
         "",
-        "### Parameters",
-        "- `start_tick_index` - The starting tick index for this tick-array.",
-        "Has to be a multiple of TickArray size & the tick spacing of this pool.",
+        "### Authority",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
         "",
-        "#### Special Errors",
-        "- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
-        "TICK_ARRAY_SIZE * tick spacing.",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
       ],
       accounts: [
         {
-          name: "pool",
+          name: "pool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "funder",
+          name: "rewardOwnerAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "tickArray",
+          name: "rewardVault",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "startTickIndex",
-          type: "i32",
+          name: "rewardIndex",
+          type: "u8",
         },
       ],
     },
     {
-      name: "initializeFeeTier",
+      name: "collectExtensionReward",
       docs: [
-        "Initializes a fee_tier account usable by ElysiumPools in a ElysiumPoolConfig space.",
+        "Update the accrued extension rewards for a position and collect an extension reward.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
         "",
         "### Parameters",
-        "- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
-        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-        "fee tier during initialization.",
+        "- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS).",
+        "",
+        "### Remaining Accounts",
+        "- If the position is locked: the PositionLock of the position.",
         "",
         "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
       ],
       accounts: [
         {
-          name: "config",
+          name: "pool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "feeTier",
+          name: "rewardExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "funder",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "feeAuthority",
+          name: "positionAuthority",
           isMut: false,
           isSigner: true,
         },
         {
-          name: "systemProgram",
+          name: "position",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "tickSpacing",
-          type: "u16",
+          name: "positionTokenAccount",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "defaultFeeRate",
-          type: "u16",
+          name: "positionExtension",
+          isMut: true,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "initializeReward",
-      docs: [
-        "Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.",
-        "",
-        "### Authority",
-        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-        "reward-index in this ElysiumPool",
-        "",
-        "### Parameters",
-        "- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
-        "",
-        "#### Special Errors",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized.",
-      ],
-      accounts: [
         {
-          name: "rewardAuthority",
+          name: "tickArrayLower",
           isMut: false,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "funder",
-          isMut: true,
-          isSigner: true,
+          name: "tickArrayUpper",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "pool",
-          isMut: true,
+          name: "tickArrayLowerExtension",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "rewardMint",
+          name: "tickArrayUpperExtension",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rewardVault",
+          name: "rewardOwnerAccount",
           isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "rewardVault",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
@@ -2812,25 +8638,23 @@ export const IDL: ElysiumPool = {
       ],
     },
     {
-      name: "setRewardEmissions",
+      name: "harvest",
       docs: [
-        "Set the reward emissions for a reward in a ElysiumPool.",
+        "Update the accrued fees and rewards for a position, then collect all fees and every",
+        "initialized reward in one call.",
         "",
         "### Authority",
-        '- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-        "reward-index in this ElysiumPool",
+        "- `position_authority` - authority that owns the token corresponding to this desired position,",
+        "or the beneficiary of the position's lock.",
         "",
-        "### Parameters",
-        "- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
-        "- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+        "### Remaining Accounts",
+        "- For every initialized reward, in reward index order: the reward owner account followed by",
+        "the reward vault.",
+        "- If the position is locked: the PositionLock of the position, after the reward accounts.",
         "",
         "#### Special Errors",
-        "- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
-        "more than a day of desired emissions.",
-        "- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
-        "- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-        "index in this pool, or exceeds NUM_REWARDS, or",
-        "all reward slots for this pool has been initialized.",
+        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+        "- `InvalidRewardAccounts` - A reward owner account or vault is missing or does not match the reward.",
       ],
       accounts: [
         {
@@ -2839,70 +8663,100 @@ export const IDL: ElysiumPool = {
           isSigner: false,
         },
         {
-          name: "rewardAuthority",
+          name: "positionAuthority",
           isMut: false,
           isSigner: true,
         },
         {
-          name: "rewardVault",
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionTokenAccount",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "rewardIndex",
-          type: "u8",
+          name: "tickArrayLower",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "emissionsPerSecondX64",
-          type: "u128",
+          name: "tickArrayUpper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "openPosition",
+      name: "collectProtocolFees",
       docs: [
-        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
-        "in the users wallet. The position will start off with 0 liquidity.",
-        "",
-        "### Parameters",
-        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
-        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "Collect the protocol fees accrued in this ElysiumPool",
         "",
-        "#### Special Errors",
-        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-        "the tick-spacing in this pool.",
+        "### Authority",
+        "- `collect_protocol_fees_authority` - assigned authority in the ElysiumPoolConfig that can collect protocol fees",
       ],
       accounts: [
         {
-          name: "funder",
+          name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "pool",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "owner",
+          name: "collectProtocolFeesAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "position",
+          name: "tokenVaultA",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "positionMint",
+          name: "tokenVaultB",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "positionTokenAccount",
+          name: "tokenDestinationA",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "pool",
-          isMut: false,
+          name: "tokenDestinationB",
+          isMut: true,
           isSigner: false,
         },
         {
@@ -2910,187 +8764,210 @@ export const IDL: ElysiumPool = {
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "collectCreatorFees",
+      docs: [
+        "Collect the creator fees accrued in this ElysiumPool",
+        "",
+        "### Authority",
+        "- `creator` - the creator recorded when the ElysiumPool was initialized",
+      ],
+      accounts: [
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "pool",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "creator",
           isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenVaultA",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
+          name: "tokenVaultB",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "bumps",
-          type: {
-            defined: "OpenPositionBumps",
-          },
+          name: "tokenDestinationA",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "tickLowerIndex",
-          type: "i32",
+          name: "tokenDestinationB",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "tickUpperIndex",
-          type: "i32",
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "openPositionWithMetadata",
+      name: "swap",
       docs: [
-        "Open a position in a ElysiumPool. A unique token will be minted to represent the position",
-        "in the users wallet. Additional Metaplex metadata is appended to identify the token.",
-        "The position will start off with 0 liquidity.",
+        "Perform a swap in this ElysiumPool",
+        "",
+        "### Authority",
+        '- "token_authority" - The authority to withdraw tokens from the input token account.',
         "",
         "### Parameters",
-        "- `tick_lower_index` - The tick specifying the lower end of the position range.",
-        "- `tick_upper_index` - The tick specifying the upper end of the position range.",
+        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+        "- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+        "- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+        "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension",
+        "and the account at the address of the extension of every tick array of the swap, whether",
+        "initialized or not.",
+        "- If the pool has a hook program: the hook program followed by the accounts it requires,",
+        "after all other remaining accounts.",
+        "- If the pool is permissioned: the allowlist entry of the token_authority.",
         "",
         "#### Special Errors",
-        "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-        "the tick-spacing in this pool.",
+        "- `PoolNotActivated` - The pool has not reached its activation timestamp or slot.",
+        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `InvalidPoolHookAccounts` - The pool's hook program is missing.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the token_authority is not allowlisted.",
       ],
       accounts: [
         {
-          name: "funder",
-          isMut: true,
-          isSigner: true,
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "owner",
+          name: "tokenAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "position",
+          name: "pool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "positionMint",
+          name: "tokenOwnerAccountA",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "positionMetadataAccount",
+          name: "tokenVaultA",
           isMut: true,
           isSigner: false,
-          docs: [
-            "https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873",
-          ],
         },
         {
-          name: "positionTokenAccount",
+          name: "tokenOwnerAccountB",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "pool",
-          isMut: false,
+          name: "tokenVaultB",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "tickArray0",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "tickArray1",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
-          isMut: false,
+          name: "tickArray2",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "associatedTokenProgram",
+          name: "oracle",
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "metadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
         },
         {
-          name: "metadataUpdateAuth",
-          isMut: false,
-          isSigner: false,
+          name: "otherAmountThreshold",
+          type: "u64",
         },
-      ],
-      args: [
         {
-          name: "bumps",
-          type: {
-            defined: "OpenPositionWithMetadataBumps",
-          },
+          name: "sqrtPriceLimit",
+          type: "u128",
         },
         {
-          name: "tickLowerIndex",
-          type: "i32",
+          name: "amountSpecifiedIsInput",
+          type: "bool",
         },
         {
-          name: "tickUpperIndex",
-          type: "i32",
+          name: "aToB",
+          type: "bool",
         },
       ],
     },
     {
-      name: "increaseLiquidity",
+      name: "swapV2",
       docs: [
-        "Add liquidity to a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
-        "",
-        "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        "Perform a swap in this ElysiumPool, rejecting it once the deadline has passed.",
+        "Takes the accounts, parameters and remaining accounts of `swap`, followed by the",
+        "parameters below.",
         "",
         "### Parameters",
-        "- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
-        "- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
-        "- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
         "",
         "#### Special Errors",
-        "- `LiquidityZero` - Provided liquidity amount is zero.",
-        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-        "- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- The special errors of `swap`.",
       ],
       accounts: [
-        {
-          name: "pool",
-          isMut: true,
-          isSigner: false,
-        },
         {
           name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "positionAuthority",
+          name: "tokenAuthority",
           isMut: false,
           isSigner: true,
         },
         {
-          name: "position",
+          name: "pool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "positionTokenAccount",
-          isMut: false,
+          name: "tokenOwnerAccountA",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenOwnerAccountA",
+          name: "tokenVaultA",
           isMut: true,
           isSigner: false,
         },
@@ -3100,215 +8977,252 @@ export const IDL: ElysiumPool = {
           isSigner: false,
         },
         {
-          name: "tokenVaultA",
+          name: "tokenVaultB",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenVaultB",
+          name: "tickArray0",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tickArrayLower",
+          name: "tickArray1",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tickArrayUpper",
+          name: "tickArray2",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "oracle",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: "liquidityAmount",
-          type: "u128",
+          name: "amount",
+          type: "u64",
         },
         {
-          name: "tokenMaxA",
+          name: "otherAmountThreshold",
           type: "u64",
         },
         {
-          name: "tokenMaxB",
-          type: "u64",
+          name: "sqrtPriceLimit",
+          type: "u128",
+        },
+        {
+          name: "amountSpecifiedIsInput",
+          type: "bool",
+        },
+        {
+          name: "aToB",
+          type: "bool",
+        },
+        {
+          name: "deadline",
+          type: {
+            option: {
+              defined: "Deadline",
+            },
+          },
         },
       ],
     },
     {
-      name: "decreaseLiquidity",
+      name: "closePosition",
       docs: [
-        "Withdraw liquidity from a position in the ElysiumPool. This call also updates the position's accrued fees and rewards.",
+        "Close a position in a ElysiumPool. Burns the position token in the owner's wallet.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
-        "",
-        "### Parameters",
-        "- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
-        "- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
-        "- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+        '- "position_authority" - The authority that owns the position token.',
         "",
         "#### Special Errors",
-        "- `LiquidityZero` - Provided liquidity amount is zero.",
-        "- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-        "- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+        "- `ClosePositionNotEmpty` - The provided position account is not empty.",
+        "- `PositionLocked` - The position token is held by the lock of the position.",
       ],
       accounts: [
-        {
-          name: "pool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "positionAuthority",
           isMut: false,
           isSigner: true,
         },
         {
-          name: "position",
+          name: "receiver",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "positionTokenAccount",
-          isMut: false,
+          name: "position",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenOwnerAccountA",
+          name: "positionMint",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenOwnerAccountB",
+          name: "positionTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenVaultA",
-          isMut: true,
+          name: "tokenProgram",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "setDefaultFeeRate",
+      docs: [
+        "Set the default_fee_rate for a FeeTier",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+        "fee tier during initialization.",
+        "",
+        "#### Special Errors",
+        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+      ],
+      accounts: [
         {
-          name: "tokenVaultB",
-          isMut: true,
+          name: "poolsConfig",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "tickArrayLower",
+          name: "feeTier",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tickArrayUpper",
-          isMut: true,
-          isSigner: false,
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
         },
       ],
       args: [
         {
-          name: "liquidityAmount",
-          type: "u128",
+          name: "defaultFeeRate",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "setDefaultProtocolFeeRate",
+      docs: [
+        "Sets the default protocol fee rate for a ElysiumPoolConfig",
+        "Protocol fee rate is represented as a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a ElysiumPool using this config.",
+        "",
+        "#### Special Errors",
+        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
+      ],
+      accounts: [
+        {
+          name: "poolsConfig",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "tokenMinA",
-          type: "u64",
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "tokenMinB",
-          type: "u64",
+          name: "defaultProtocolFeeRate",
+          type: "u16",
         },
       ],
     },
     {
-      name: "updateFeesAndRewards",
+      name: "setMaxCreatorFeeRate",
       docs: [
-        "Update the accrued fees and rewards for a position.",
+        "Sets the max creator fee rate for a ElysiumPoolConfig",
+        "Creator fee rate is represented as a basis point.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_creator_fee_rate` - Upper bound on the creator fee rate of pools initialized using this config.",
         "",
         "#### Special Errors",
-        "- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
-        "- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.",
+        "- `CreatorFeeRateMaxExceeded` - If the provided max_creator_fee_rate exceeds MAX_CREATOR_FEE_RATE.",
       ],
       accounts: [
         {
-          name: "pool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "position",
+          name: "poolsConfig",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tickArrayLower",
+          name: "feeAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "tickArrayUpper",
-          isMut: false,
-          isSigner: false,
+          name: "maxCreatorFeeRate",
+          type: "u16",
         },
       ],
-      args: [],
     },
     {
-      name: "collectFees",
+      name: "initializeConfigExtension",
       docs: [
-        "Collect fees accrued for this position.",
+        "Initializes the ElysiumPoolsConfigExtension of a ElysiumPoolsConfig, which holds the",
+        "config settings that do not fit in the config account. Every setting starts at its",
+        "strictest value.",
+        "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
       ],
       accounts: [
         {
-          name: "pool",
+          name: "poolsConfig",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "positionAuthority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "position",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "positionTokenAccount",
+          name: "feeAuthority",
           isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenOwnerAccountA",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenVaultA",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenOwnerAccountB",
-          isMut: true,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "tokenVaultB",
+          name: "funder",
           isMut: true,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "tokenProgram",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -3316,64 +9230,60 @@ export const IDL: ElysiumPool = {
       args: [],
     },
     {
-      name: "collectReward",
+      name: "setMaxActivationDelay",
       docs: [
-        "Collect rewards accrued for this position.",
+        "Sets how far past its creation the activation of a pool in the config can be set.",
+        "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        "- `position_authority` - authority that owns the token corresponding to this desired position.",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_activation_delay` - Upper bound, in seconds, on the delay of activation_timestamp.",
+        "- `max_activation_slot_delay` - Upper bound, in slots, on the delay of activation_slot.",
       ],
       accounts: [
         {
-          name: "pool",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "positionAuthority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "position",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "positionTokenAccount",
+          name: "poolsConfig",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rewardOwnerAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "rewardVault",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "feeAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
       ],
       args: [
         {
-          name: "rewardIndex",
-          type: "u8",
+          name: "maxActivationDelay",
+          type: "u64",
+        },
+        {
+          name: "maxActivationSlotDelay",
+          type: "u64",
         },
       ],
     },
     {
-      name: "collectProtocolFees",
+      name: "setMaxLaunchFeeDecayPeriod",
       docs: [
-        "Collect the protocol fees accrued in this ElysiumPool",
+        "Sets the longest decay period of the launch fee schedule of pools in the config.",
+        "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        "- `collect_protocol_fees_authority` - assigned authority in the ElysiumPoolConfig that can collect protocol fees",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `max_launch_fee_decay_period` - Upper bound on the decay period of schedules decaying",
+        "in seconds.",
+        "- `max_launch_fee_decay_slots` - Upper bound on the decay period of schedules decaying",
+        "in slots.",
       ],
       accounts: [
         {
@@ -3382,37 +9292,55 @@ export const IDL: ElysiumPool = {
           isSigner: false,
         },
         {
-          name: "pool",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "collectProtocolFeesAuthority",
+          name: "feeAuthority",
           isMut: false,
           isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "tokenVaultA",
-          isMut: true,
-          isSigner: false,
+          name: "maxLaunchFeeDecayPeriod",
+          type: "u64",
         },
         {
-          name: "tokenVaultB",
-          isMut: true,
-          isSigner: false,
+          name: "maxLaunchFeeDecaySlots",
+          type: "u64",
         },
+      ],
+    },
+    {
+      name: "setPermissionedPoolAuthority",
+      docs: [
+        "Sets the authority that signs the creation of permissioned pools in the config. Setting",
+        "the default pubkey disables the creation of permissioned pools.",
+        "Only the current fee authority has permission to invoke this instruction.",
+        "",
+        "### Authority",
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+      ],
+      accounts: [
         {
-          name: "tokenDestinationA",
-          isMut: true,
+          name: "poolsConfig",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenDestinationB",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "newPermissionedPoolAuthority",
           isMut: false,
           isSigner: false,
         },
@@ -3420,170 +9348,100 @@ export const IDL: ElysiumPool = {
       args: [],
     },
     {
-      name: "swap",
+      name: "addHookProgram",
       docs: [
-        "Perform a swap in this ElysiumPool",
+        "Adds a program to the hook program allowlist of the config. Pools can be set to use the",
+        "program once HOOK_PROGRAM_TIMELOCK_SECONDS have passed.",
+        "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        '- "token_authority" - The authority to withdraw tokens from the input token account.',
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
         "",
         "### Parameters",
-        "- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-        "- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-        "- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
-        "- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-        "- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+        "- `hook_program` - The hook program to allowlist.",
         "",
         "#### Special Errors",
-        "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-        "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-        "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-        "- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-        "- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-        "- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-        "- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-        "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+        "- `HookProgramAllowlistFull` - The allowlist holds NUM_HOOK_PROGRAMS programs already.",
       ],
       accounts: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenAuthority",
+          name: "poolsConfig",
           isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "pool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenOwnerAccountA",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenVaultA",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenOwnerAccountB",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenVaultB",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tickArray0",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tickArray1",
-          isMut: true,
           isSigner: false,
         },
         {
-          name: "tickArray2",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "oracle",
+          name: "feeAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
       ],
       args: [
         {
-          name: "amount",
-          type: "u64",
-        },
-        {
-          name: "otherAmountThreshold",
-          type: "u64",
-        },
-        {
-          name: "sqrtPriceLimit",
-          type: "u128",
-        },
-        {
-          name: "amountSpecifiedIsInput",
-          type: "bool",
-        },
-        {
-          name: "aToB",
-          type: "bool",
+          name: "hookProgram",
+          type: "publicKey",
         },
       ],
     },
     {
-      name: "closePosition",
+      name: "removeHookProgram",
       docs: [
-        "Close a position in a ElysiumPool. Burns the position token in the owner's wallet.",
+        "Removes a program from the hook program allowlist of the config. Pools already using it",
+        "keep invoking it until their hook program is changed.",
+        "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        '- "position_authority" - The authority that owns the position token.',
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
+        "",
+        "### Parameters",
+        "- `hook_program` - The hook program to remove.",
         "",
         "#### Special Errors",
-        "- `ClosePositionNotEmpty` - The provided position account is not empty.",
+        "- `HookProgramNotAllowlisted` - The hook program is not allowlisted.",
       ],
       accounts: [
         {
-          name: "positionAuthority",
+          name: "poolsConfig",
           isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "receiver",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "position",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "positionMint",
-          isMut: true,
           isSigner: false,
         },
         {
-          name: "positionTokenAccount",
+          name: "configExtension",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "feeAuthority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "hookProgram",
+          type: "publicKey",
         },
       ],
-      args: [],
     },
     {
-      name: "setDefaultFeeRate",
+      name: "setFeeRate",
       docs: [
-        "Set the default_fee_rate for a FeeTier",
+        "Sets the fee rate for a ElysiumPool.",
+        "Fee rate is represented as hundredths of a basis point.",
         "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority in the ElysiumPoolConfig',
+        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
         "",
         "### Parameters",
-        "- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-        "fee tier during initialization.",
+        "- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
         "",
         "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+        "- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.",
       ],
       accounts: [
         {
@@ -3592,7 +9450,7 @@ export const IDL: ElysiumPool = {
           isSigner: false,
         },
         {
-          name: "feeTier",
+          name: "pool",
           isMut: true,
           isSigner: false,
         },
@@ -3604,30 +9462,46 @@ export const IDL: ElysiumPool = {
       ],
       args: [
         {
-          name: "defaultFeeRate",
+          name: "feeRate",
           type: "u16",
         },
       ],
     },
     {
-      name: "setDefaultProtocolFeeRate",
+      name: "setPoolHook",
       docs: [
-        "Sets the default protocol fee rate for a ElysiumPoolConfig",
-        "Protocol fee rate is represented as a basis point.",
+        "Sets the hook program of a ElysiumPool. The hook program is invoked before and after",
+        "swaps and increases of liquidity, and can veto the action by returning an error.",
+        "Withdrawals never invoke the hook program, not even after the withdrawal: an error",
+        "returned by an invoked program fails the whole transaction, so a notification of",
+        "`decrease_liquidity` could not be made non-failing and would let the hook lock the",
+        "liquidity of LPs. Hook programs that track liquidity have to read the positions instead.",
         "Only the current fee authority has permission to invoke this instruction.",
         "",
         "### Authority",
         '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
         "",
         "### Parameters",
-        "- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a ElysiumPool using this config.",
+        "- `hook_program` - The program the pool invokes. Has to be on the hook program allowlist",
+        "of the config extension. The default pubkey removes the hook.",
         "",
         "#### Special Errors",
-        "- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
+        "- `HookProgramNotAllowlisted` - The hook program is not allowlisted.",
+        "- `HookProgramTimelocked` - The timelock of the allowlisted hook program has not elapsed.",
       ],
       accounts: [
         {
           name: "poolsConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "configExtension",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "pool",
           isMut: true,
           isSigner: false,
         },
@@ -3639,50 +9513,115 @@ export const IDL: ElysiumPool = {
       ],
       args: [
         {
-          name: "defaultProtocolFeeRate",
-          type: "u16",
+          name: "hookProgram",
+          type: "publicKey",
         },
       ],
     },
     {
-      name: "setFeeRate",
+      name: "initializeAllowlistEntry",
       docs: [
-        "Sets the fee rate for a ElysiumPool.",
-        "Fee rate is represented as hundredths of a basis point.",
-        "Only the current fee authority has permission to invoke this instruction.",
+        "Adds a wallet to the allowlist of a permissioned ElysiumPool.",
+        "Allowlisted wallets can trade, open positions and add liquidity in the pool. Withdrawing",
+        "liquidity, collecting and closing positions never require an allowlist entry.",
         "",
         "### Authority",
-        '- "fee_authority" - Set authority that can modify pool fees in the ElysiumPoolConfig',
-        "",
-        "### Parameters",
-        "- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.',
+      ],
+      accounts: [
+        {
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "accessAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "wallet",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "allowlistEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeAllowlistEntry",
+      docs: [
+        "Removes a wallet from the allowlist of a permissioned ElysiumPool and refunds the rent of",
+        "the allowlist entry to the receiver.",
         "",
-        "#### Special Errors",
-        "- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.",
+        "### Authority",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.',
       ],
       accounts: [
         {
-          name: "poolsConfig",
+          name: "pool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "accessAuthority",
           isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "allowlistEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "setAccessAuthority",
+      docs: [
+        "Sets the access authority of a permissioned ElysiumPool. Setting the default pubkey opens",
+        "the pool to every wallet permanently.",
+        "",
+        "### Authority",
+        '- "access_authority" - Set authority that manages the allowlist of the ElysiumPool.',
+      ],
+      accounts: [
         {
           name: "pool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "feeAuthority",
+          name: "accessAuthority",
           isMut: false,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "feeRate",
-          type: "u16",
+          name: "newAccessAuthority",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
       name: "setProtocolFeeRate",
@@ -3909,7 +9848,14 @@ export const IDL: ElysiumPool = {
         "- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
         "- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
         "",
+        "### Remaining Accounts",
+        "- If the pool has a PoolRewardExtension and the swap crosses a tick: the reward extension",
+        "and the account at the address of the extension of every tick array of the swap, whether",
+        "initialized or not.",
+        "- For each permissioned pool: the allowlist entry of the token_authority.",
+        "",
         "#### Special Errors",
+        "- `PoolNotActivated` - The pool has not reached its activation timestamp or slot.",
         "- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
         "- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
         "- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
@@ -3920,6 +9866,156 @@ export const IDL: ElysiumPool = {
         "- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
         "- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
         "- `DuplicateTwoHopPool` - Error if pool one & two are the same pool.",
+        "- `InvalidRewardExtension` - The reward extension accounts are missing or do not match.",
+        "- `PoolHookNotSupported` - Either pool has a hook program.",
+        "- `WalletNotAllowlisted` - A pool is permissioned and the token_authority is not allowlisted.",
+      ],
+      accounts: [
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "poolOne",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "poolTwo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountOneA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultOneA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountOneB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultOneB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountTwoA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultTwoA",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenOwnerAccountTwoB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVaultTwoB",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayOne0",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayOne1",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayOne2",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayTwo0",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayTwo1",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tickArrayTwo2",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "oracleOne",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "oracleTwo",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "otherAmountThreshold",
+          type: "u64",
+        },
+        {
+          name: "amountSpecifiedIsInput",
+          type: "bool",
+        },
+        {
+          name: "aToBOne",
+          type: "bool",
+        },
+        {
+          name: "aToBTwo",
+          type: "bool",
+        },
+        {
+          name: "sqrtPriceLimitOne",
+          type: "u128",
+        },
+        {
+          name: "sqrtPriceLimitTwo",
+          type: "u128",
+        },
+      ],
+    },
+    {
+      name: "twoHopSwapV2",
+      docs: [
+        "Perform a two-hop swap, rejecting it once the deadline has passed.",
+        "Takes the accounts, parameters and remaining accounts of `two_hop_swap`, followed by the",
+        "parameters below.",
+        "",
+        "### Parameters",
+        "- `deadline` - Optional unix timestamp or slot after which the instruction is rejected.",
+        "",
+        "#### Special Errors",
+        "- `DeadlineExceeded` - The provided deadline has passed.",
+        "- The special errors of `two_hop_swap`.",
       ],
       accounts: [
         {
@@ -4052,6 +10148,14 @@ export const IDL: ElysiumPool = {
           name: "sqrtPriceLimitTwo",
           type: "u128",
         },
+        {
+          name: "deadline",
+          type: {
+            option: {
+              defined: "Deadline",
+            },
+          },
+        },
       ],
     },
     {
@@ -4243,10 +10347,14 @@ export const IDL: ElysiumPool = {
         "- `tick_lower_index` - The tick specifying the lower end of the position range.",
         "- `tick_upper_index` - The tick specifying the upper end of the position range.",
         "",
+        "### Remaining Accounts",
+        "- If the pool is permissioned: the allowlist entry of the position_bundle_authority.",
+        "",
         "#### Special Errors",
         "- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
         "- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
         "the tick-spacing in this pool.",
+        "- `WalletNotAllowlisted` - The pool is permissioned and the position_bundle_authority is not allowlisted.",
       ],
       accounts: [
         {
@@ -4377,6 +10485,10 @@ export const IDL: ElysiumPool = {
             name: "defaultProtocolFeeRate",
             type: "u16",
           },
+          {
+            name: "maxCreatorFeeRate",
+            type: "u16",
+          },
         ],
       },
     },
@@ -4520,78 +10632,399 @@ export const IDL: ElysiumPool = {
             type: "u16",
           },
           {
-            name: "tickSpacingSeed",
+            name: "feeTierIndexSeed",
             type: {
               array: ["u8", 2],
             },
           },
           {
-            name: "feeRate",
-            type: "u16",
+            name: "feeRate",
+            type: "u16",
+          },
+          {
+            name: "protocolFeeRate",
+            type: "u16",
+          },
+          {
+            name: "liquidity",
+            type: "u128",
+          },
+          {
+            name: "sqrtPrice",
+            type: "u128",
+          },
+          {
+            name: "tickCurrentIndex",
+            type: "i32",
+          },
+          {
+            name: "protocolFeeOwedA",
+            type: "u64",
+          },
+          {
+            name: "protocolFeeOwedB",
+            type: "u64",
+          },
+          {
+            name: "tokenMintA",
+            type: "publicKey",
+          },
+          {
+            name: "tokenVaultA",
+            type: "publicKey",
+          },
+          {
+            name: "feeGrowthGlobalA",
+            type: "u128",
+          },
+          {
+            name: "tokenMintB",
+            type: "publicKey",
+          },
+          {
+            name: "tokenVaultB",
+            type: "publicKey",
+          },
+          {
+            name: "feeGrowthGlobalB",
+            type: "u128",
+          },
+          {
+            name: "rewardLastUpdatedTimestamp",
+            type: "u64",
+          },
+          {
+            name: "rewardInfos",
+            type: {
+              array: [
+                {
+                  defined: "ElysiumPoolRewardInfo",
+                },
+                3,
+              ],
+            },
+          },
+          {
+            name: "rewardExtension",
+            type: "publicKey",
+          },
+          {
+            name: "activationTimestamp",
+            type: "u64",
+          },
+          {
+            name: "activationSlot",
+            type: "u64",
+          },
+          {
+            name: "launchFeeSchedule",
+            type: {
+              defined: "LaunchFeeSchedule",
+            },
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "creatorFeeRate",
+            type: "u16",
+          },
+          {
+            name: "creatorFeeOwedA",
+            type: "u64",
+          },
+          {
+            name: "creatorFeeOwedB",
+            type: "u64",
+          },
+          {
+            name: "tickArrayBitmap",
+            type: "publicKey",
+          },
+          {
+            name: "hookProgram",
+            type: "publicKey",
+          },
+          {
+            name: "accessAuthority",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "allowlistEntry",
+      docs: [
+        "Grants a wallet access to a permissioned ElysiumPool. Entries are created and closed by the",
+        "access authority of the pool.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "poolsConfigExtension",
+      docs: [
+        "Settings of an ElysiumPoolsConfig that do not fit in the config account. Pools of a config",
+        "without an extension are initialized with the strictest settings.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "poolsConfig",
+            type: "publicKey",
+          },
+          {
+            name: "maxActivationDelay",
+            type: "u64",
+          },
+          {
+            name: "maxActivationSlotDelay",
+            type: "u64",
+          },
+          {
+            name: "maxLaunchFeeDecayPeriod",
+            type: "u64",
+          },
+          {
+            name: "maxLaunchFeeDecaySlots",
+            type: "u64",
+          },
+          {
+            name: "permissionedPoolAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "hookPrograms",
+            type: {
+              array: [
+                {
+                  defined: "HookProgramEntry",
+                },
+                4,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "positionLock",
+      docs: [
+        "Lock on the liquidity of a Position. While the lock exists, the position token is held in a",
+        "token account owned by the lock, so liquidity cannot be withdrawn and the position cannot be",
+        "closed. The beneficiary can still collect fees and rewards, and gets the position token back",
+        "once the lock has expired.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "position",
+            type: "publicKey",
+          },
+          {
+            name: "beneficiary",
+            type: "publicKey",
+          },
+          {
+            name: "unlockTimestamp",
+            type: "u64",
+          },
+          {
+            name: "bump",
+            type: {
+              array: ["u8", 1],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "poolRewardExtension",
+      docs: [
+        "Additional reward slots of a ElysiumPool. Extension rewards accrue against the pool liquidity",
+        "and share the growth-inside math with the rewards stored on the pool.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "rewardLastUpdatedTimestamp",
+            type: "u64",
+          },
+          {
+            name: "rewardInfos",
+            type: {
+              array: [
+                {
+                  defined: "ElysiumPoolRewardInfo",
+                },
+                2,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "tickArrayRewardExtension",
+      docs: [
+        "Extension reward growths outside of every tick in a TickArray. The initialized state of a",
+        "tick is tracked by the TickArray itself.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "tickArray",
+            type: "publicKey",
+          },
+          {
+            name: "startTickIndex",
+            type: "i32",
+          },
+          {
+            name: "rewardGrowthsOutside",
+            type: {
+              array: [
+                {
+                  array: ["u128", 2],
+                },
+                88,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "positionRewardExtension",
+      docs: ["Rewards owed to a Position for the extension rewards of its pool."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "position",
+            type: "publicKey",
+          },
+          {
+            name: "rewardInfos",
+            type: {
+              array: [
+                {
+                  defined: "PositionRewardInfo",
+                },
+                2,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "rewardSettlement",
+      docs: [
+        "Record of a retired ElysiumPool reward. Positions settle the amount they are owed for the",
+        "retired reward against this record until the reward slot is reinitialized.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pool",
+            type: "publicKey",
           },
           {
-            name: "protocolFeeRate",
-            type: "u16",
+            name: "rewardIndex",
+            type: "u8",
           },
           {
-            name: "liquidity",
-            type: "u128",
+            name: "mint",
+            type: "publicKey",
           },
           {
-            name: "sqrtPrice",
-            type: "u128",
+            name: "vault",
+            type: "publicKey",
           },
           {
-            name: "tickCurrentIndex",
-            type: "i32",
+            name: "growthGlobalX64",
+            type: "u128",
           },
           {
-            name: "protocolFeeOwedA",
+            name: "retiredTimestamp",
             type: "u64",
           },
           {
-            name: "protocolFeeOwedB",
+            name: "totalSettled",
             type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "tickArrayBitmap",
+      docs: [
+        "Bitmap of the TickArrays of a ElysiumPool, indexed by the position of their start tick index",
+        "in the pool's range of tick arrays. Lets clients locate tick arrays without probing addresses.",
+        "The program only writes the bitmap, as tick arrays initialized before it are missing until",
+        "they are synced.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "tokenMintA",
+            name: "pool",
             type: "publicKey",
           },
           {
-            name: "tokenVaultA",
-            type: "publicKey",
+            name: "initialized",
+            type: {
+              array: ["u64", 158],
+            },
           },
           {
-            name: "feeGrowthGlobalA",
-            type: "u128",
+            name: "nonEmpty",
+            type: {
+              array: ["u64", 158],
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "tickArrayFunder",
+      docs: [
+        "Record of the account that paid the rent of a TickArray. The rent of the TickArray and of this",
+        "record is refunded to the funder when the TickArray is closed.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "tokenMintB",
+            name: "tickArray",
             type: "publicKey",
           },
           {
-            name: "tokenVaultB",
+            name: "funder",
             type: "publicKey",
           },
-          {
-            name: "feeGrowthGlobalB",
-            type: "u128",
-          },
-          {
-            name: "rewardLastUpdatedTimestamp",
-            type: "u64",
-          },
-          {
-            name: "rewardInfos",
-            type: {
-              array: [
-                {
-                  defined: "ElysiumPoolRewardInfo",
-                },
-                3,
-              ],
-            },
-          },
         ],
       },
     },
@@ -4718,6 +11151,61 @@ export const IDL: ElysiumPool = {
             ],
             type: "u128",
           },
+          {
+            name: "emissionsStartTimestamp",
+            docs: ["Timestamp from which emissions accrue. Zero if emissions are not scheduled."],
+            type: "u64",
+          },
+          {
+            name: "emissionsEndTimestamp",
+            docs: [
+              "Timestamp after which emissions stop accruing. Zero if emissions run until changed.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "emissionsBudget",
+            docs: ["Total amount of reward tokens the scheduled campaign distributes."],
+            type: "u64",
+          },
+          {
+            name: "totalFunded",
+            docs: [
+              "Total amount of reward tokens deposited into the reward vault, as of the last sync.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "totalEmitted",
+            docs: ["Total amount of reward tokens credited to positions."],
+            type: "u64",
+          },
+          {
+            name: "totalCollected",
+            docs: ["Total amount of reward tokens collected from the reward vault."],
+            type: "u64",
+          },
+          {
+            name: "uncreditedX64",
+            docs: [
+              "Q64.64 amount of emitted reward tokens that positions have not credited to the amounts",
+              "they are owed yet.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "totalCredited",
+            docs: ["Total amount of reward tokens credited to the amounts owed of positions."],
+            type: "u64",
+          },
+          {
+            name: "unfundedEmissionsX64",
+            docs: [
+              "Q64.64 amount of emissions that were due while the funded amount was used up. Emitted as",
+              "soon as the reward vault is topped up, until the emissions are changed.",
+            ],
+            type: "u128",
+          },
         ],
       },
     },
@@ -4778,6 +11266,180 @@ export const IDL: ElysiumPool = {
         ],
       },
     },
+    {
+      name: "RewardRunway",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "unemittedAmount",
+            docs: ["Funded reward tokens that have not been emitted yet."],
+            type: "u64",
+          },
+          {
+            name: "runwaySeconds",
+            docs: [
+              "Seconds of emissions at the current rate that the unemitted amount can cover.",
+              "`u64::MAX` if the reward is not emitting.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "HookProgramEntry",
+      docs: ["Hook program pools of a config can use from the allowed timestamp."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "program",
+            type: "publicKey",
+          },
+          {
+            name: "allowedTimestamp",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LaunchFeeSchedule",
+      docs: [
+        "Fee schedule that starts at a high fee rate on activation and decays linearly to the pool's",
+        "fee rate, deterring snipers at launch. The fees charged above the pool's fee rate go to LPs.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "initialFeeRate",
+            docs: ["Fee rate charged at activation, stored as hundredths of a basis point."],
+            type: "u32",
+          },
+          {
+            name: "decayPeriod",
+            docs: [
+              "Number of seconds, or slots if `decay_in_slots` is set, until the pool's fee rate applies.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "decayInSlots",
+            docs: [
+              "True if the decay period is measured in slots from the activation slot, rather than in",
+              "seconds from the activation timestamp.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "Deadline",
+      docs: ["Point in time after which a user instruction is no longer allowed to execute."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "UnixTimestamp",
+            fields: ["i64"],
+          },
+          {
+            name: "Slot",
+            fields: ["u64"],
+          },
+        ],
+      },
+    },
+    {
+      name: "PoolHookEvent",
+      docs: [
+        "The action a pool hook is invoked for. Before-events are sent prior to any state change and",
+        "after-events once the pool account has been updated, so the hook can read the new pool state.",
+        "Liquidity events are only sent when liquidity is added. Withdrawals never invoke the hook, as",
+        "a failing hook would otherwise lock the funds of LPs in the pool. This holds for after-events",
+        "too, since an error returned by an invoked program cannot be caught.",
+      ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "BeforeSwap",
+            fields: [
+              {
+                name: "amount",
+                type: "u64",
+              },
+              {
+                name: "sqrt_price_limit",
+                type: "u128",
+              },
+              {
+                name: "amount_specified_is_input",
+                type: "bool",
+              },
+              {
+                name: "a_to_b",
+                type: "bool",
+              },
+            ],
+          },
+          {
+            name: "AfterSwap",
+            fields: [
+              {
+                name: "amount_a",
+                type: "u64",
+              },
+              {
+                name: "amount_b",
+                type: "u64",
+              },
+              {
+                name: "a_to_b",
+                type: "bool",
+              },
+            ],
+          },
+          {
+            name: "BeforeModifyLiquidity",
+            fields: [
+              {
+                name: "position",
+                type: "publicKey",
+              },
+              {
+                name: "liquidity_delta",
+                type: "i128",
+              },
+            ],
+          },
+          {
+            name: "AfterModifyLiquidity",
+            fields: [
+              {
+                name: "position",
+                type: "publicKey",
+              },
+              {
+                name: "liquidity_delta",
+                type: "i128",
+              },
+              {
+                name: "amount_a",
+                type: "u64",
+              },
+              {
+                name: "amount_b",
+                type: "u64",
+              },
+            ],
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
@@ -5015,5 +11677,155 @@ export const IDL: ElysiumPool = {
       name: "PositionBundleNotDeletable",
       msg: "Unable to delete PositionBundle with open positions",
     },
+    {
+      code: 6047,
+      name: "InvalidRewardAccounts",
+      msg: "Invalid or missing reward accounts",
+    },
+    {
+      code: 6048,
+      name: "TokenNotInPositionRange",
+      msg: "Position range does not accept the specified token",
+    },
+    {
+      code: 6049,
+      name: "DeadlineExceeded",
+      msg: "Transaction deadline exceeded",
+    },
+    {
+      code: 6050,
+      name: "PoolSqrtPriceOutOfBounds",
+      msg: "Pool sqrt price is outside of the provided bounds",
+    },
+    {
+      code: 6051,
+      name: "InvalidRewardSchedule",
+      msg: "Reward emissions schedule is invalid",
+    },
+    {
+      code: 6052,
+      name: "InvalidRewardExtension",
+      msg: "Reward extension accounts are missing or invalid",
+    },
+    {
+      code: 6053,
+      name: "RewardEmissionsNotEnded",
+      msg: "Reward emissions have not ended",
+    },
+    {
+      code: 6054,
+      name: "InvalidRewardSettlement",
+      msg: "Reward settlement does not match the reward",
+    },
+    {
+      code: 6055,
+      name: "RewardSettlementPeriodNotElapsed",
+      msg: "Reward settlement period has not elapsed",
+    },
+    {
+      code: 6056,
+      name: "UnallocatedRewardInsufficient",
+      msg: "Amount exceeds the unallocated reward amount",
+    },
+    {
+      code: 6057,
+      name: "PositionLocked",
+      msg: "Position liquidity is locked",
+    },
+    {
+      code: 6058,
+      name: "InvalidPositionLock",
+      msg: "Position unlock timestamp is invalid",
+    },
+    {
+      code: 6059,
+      name: "PoolNotActivated",
+      msg: "Pool is not activated for trading yet",
+    },
+    {
+      code: 6060,
+      name: "LaunchFeeRateMaxExceeded",
+      msg: "Launch fee rate exceeds MAX_LAUNCH_FEE_RATE",
+    },
+    {
+      code: 6061,
+      name: "InvalidLaunchFeeSchedule",
+      msg: "Launch fee schedule is invalid",
+    },
+    {
+      code: 6062,
+      name: "CreatorFeeRateMaxExceeded",
+      msg: "Exceeded max creator fee rate",
+    },
+    {
+      code: 6063,
+      name: "TickArrayNotEmpty",
+      msg: "Tick array has initialized ticks",
+    },
+    {
+      code: 6064,
+      name: "InvalidTickArrayAccounts",
+      msg: "Tick array accounts do not match the start tick indexes",
+    },
+    {
+      code: 6065,
+      name: "InvalidTickArrayBitmap",
+      msg: "Tick array bitmap is missing or does not belong to the pool",
+    },
+    {
+      code: 6066,
+      name: "InvalidPoolHookAccounts",
+      msg: "Pool hook program is missing from the remaining accounts",
+    },
+    {
+      code: 6067,
+      name: "PoolHookNotSupported",
+      msg: "Pools with a hook program are not supported by this instruction",
+    },
+    {
+      code: 6068,
+      name: "WalletNotAllowlisted",
+      msg: "Wallet is not on the allowlist of the pool",
+    },
+    {
+      code: 6069,
+      name: "RewardNotSettled",
+      msg: "Positions have not settled all rewards of the retired reward",
+    },
+    {
+      code: 6070,
+      name: "ActivationDelayMaxExceeded",
+      msg: "Pool activation is delayed past the max activation delay of the config",
+    },
+    {
+      code: 6071,
+      name: "LaunchFeeDecayPeriodMaxExceeded",
+      msg: "Launch fee decay period exceeds the max decay period of the config",
+    },
+    {
+      code: 6072,
+      name: "InvalidPermissionedPoolAuthority",
+      msg: "Permissioned pools require the signature of the permissioned pool authority",
+    },
+    {
+      code: 6073,
+      name: "HookProgramNotAllowlisted",
+      msg: "Hook program is not on the hook program allowlist of the config",
+    },
+    {
+      code: 6074,
+      name: "HookProgramTimelocked",
+      msg: "Timelock of the hook program has not elapsed",
+    },
+    {
+      code: 6075,
+      name: "HookProgramAllowlistFull",
+      msg: "Hook program allowlist of the config is full",
+    },
+    {
+      code: 6076,
+      name: "UnsupportedPoolLayout",
+      msg: "Layout of the pool account is not supported",
+    },
   ],
 };
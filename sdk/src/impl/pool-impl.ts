@@ -36,7 +36,7 @@ import {
   ElysiumPoolRewardInfo,
 } from "../types/public";
 import { getTickArrayDataForPosition } from "../utils/builder/position-builder-util";
-import { PDAUtil, TickArrayUtil, TickUtil } from "../utils/public";
+import { PDAUtil, PoolUtil, TickArrayUtil, TickUtil } from "../utils/public";
 import {
   TokenMintTypes,
   getTokenMintsFromElysiumPools,
@@ -153,6 +153,11 @@ export class ElysiumPoolImpl implements ElysiumPool {
       this.ctx.provider.wallet,
       this.ctx.txBuilderOpts
     );
+    const tickArrayBitmap = PoolUtil.getTickArrayBitmap(
+      this.ctx.program.programId,
+      this.address,
+      this.data
+    );
     initTickArrayStartPdas.forEach((initTickArrayInfo) => {
       txBuilder.addInstruction(
         initTickArrayIx(this.ctx.program, {
@@ -160,6 +165,7 @@ export class ElysiumPoolImpl implements ElysiumPool {
           tickArrayPda: initTickArrayInfo.pda,
           pool: this.address,
           funder: !!funder ? AddressUtil.toPubKey(funder) : this.ctx.provider.wallet.publicKey,
+          tickArrayBitmap,
         })
       );
     });
@@ -367,6 +373,7 @@ export class ElysiumPoolImpl implements ElysiumPool {
       tokenVaultB: pool.tokenVaultB,
       tickArrayLower: tickArrayLowerPda.publicKey,
       tickArrayUpper: tickArrayUpperPda.publicKey,
+      tickArrayBitmap: PoolUtil.getTickArrayBitmap(this.ctx.program.programId, this.address, pool),
     });
     txBuilder.addInstruction(liquidityIx);
 
@@ -544,6 +551,11 @@ export class ElysiumPoolImpl implements ElysiumPool {
         tokenVaultB: pool.tokenVaultB,
         tickArrayLower,
         tickArrayUpper,
+        tickArrayBitmap: PoolUtil.getTickArrayBitmap(
+          this.ctx.program.programId,
+          positionData.pool,
+          pool
+        ),
       });
 
       txBuilder.addInstruction(liquidityIx);
@@ -173,6 +173,11 @@ export class PositionImpl implements Position {
         this.data.pool,
         TickUtil.getStartTickIndex(this.data.tickUpperIndex, pool.tickSpacing)
       ).publicKey,
+      tickArrayBitmap: PoolUtil.getTickArrayBitmap(
+        this.ctx.program.programId,
+        this.data.pool,
+        pool
+      ),
       positionAuthority: positionWalletKey,
     });
     txBuilder.addInstruction(increaseIx);
@@ -260,6 +265,11 @@ export class PositionImpl implements Position {
         this.data.pool,
         TickUtil.getStartTickIndex(this.data.tickUpperIndex, pool.tickSpacing)
       ).publicKey,
+      tickArrayBitmap: PoolUtil.getTickArrayBitmap(
+        this.ctx.program.programId,
+        this.data.pool,
+        pool
+      ),
       positionAuthority: positionWalletKey,
     });
     txBuilder.addInstruction(decreaseIx);
@@ -3,6 +3,7 @@ import { Instruction } from "@orca-so/common-sdk";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import { ElysiumPool } from "../artifacts/pool";
+import { tickArrayBitmapAccounts } from "../utils/instructions-util";
 
 /**
  * Parameters to remove liquidity from a position.
@@ -21,6 +22,7 @@ import { ElysiumPool } from "../artifacts/pool";
  * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
  * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
  * @param positionAuthority - authority that owns the token corresponding to this desired position.
+ * @param tickArrayBitmap - PublicKey for the TickArrayBitmap of the pool. Required once the pool has one.
  */
 export type DecreaseLiquidityParams = {
   pool: PublicKey;
@@ -33,6 +35,7 @@ export type DecreaseLiquidityParams = {
   tickArrayLower: PublicKey;
   tickArrayUpper: PublicKey;
   positionAuthority: PublicKey;
+  tickArrayBitmap?: PublicKey;
 } & DecreaseLiquidityInput;
 
 /**
@@ -75,6 +78,7 @@ export function decreaseLiquidityIx(
     tokenVaultB,
     tickArrayLower,
     tickArrayUpper,
+    tickArrayBitmap,
   } = params;
 
   const ix = program.instruction.decreaseLiquidity(liquidityAmount, tokenMinA, tokenMinB, {
@@ -91,6 +95,7 @@ export function decreaseLiquidityIx(
       tickArrayLower,
       tickArrayUpper,
     },
+    remainingAccounts: tickArrayBitmapAccounts(tickArrayBitmap),
   });
 
   return {
@@ -3,6 +3,7 @@ import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
 import { ElysiumPool } from "../artifacts/pool";
+import { tickArrayBitmapAccounts } from "../utils/instructions-util";
 
 import { Instruction } from "@orca-so/common-sdk";
 
@@ -23,6 +24,7 @@ import { Instruction } from "@orca-so/common-sdk";
  * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
  * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
  * @param positionAuthority - authority that owns the token corresponding to this desired position.
+ * @param tickArrayBitmap - PublicKey for the TickArrayBitmap of the pool. Required once the pool has one.
  */
 export type IncreaseLiquidityParams = {
   pool: PublicKey;
@@ -35,6 +37,7 @@ export type IncreaseLiquidityParams = {
   tickArrayLower: PublicKey;
   tickArrayUpper: PublicKey;
   positionAuthority: PublicKey;
+  tickArrayBitmap?: PublicKey;
 } & IncreaseLiquidityInput;
 
 /**
@@ -85,6 +88,7 @@ export function increaseLiquidityIx(
     tokenVaultB,
     tickArrayLower,
     tickArrayUpper,
+    tickArrayBitmap,
   } = params;
 
   const ix = program.instruction.increaseLiquidity(liquidityAmount, tokenMaxA, tokenMaxB, {
@@ -101,6 +105,7 @@ export function increaseLiquidityIx(
       tickArrayLower,
       tickArrayUpper,
     },
+    remainingAccounts: tickArrayBitmapAccounts(tickArrayBitmap),
   });
 
   return {
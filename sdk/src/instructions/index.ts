@@ -13,6 +13,7 @@ export * from "./initialize-pool-ix";
 export * from "./initialize-position-bundle-ix";
 export * from "./initialize-reward-ix";
 export * from "./initialize-tick-array-ix";
+export * from "./migrate-pool-ix";
 export * from "./open-bundled-position-ix";
 export * from "./open-position-ix";
 export * from "./set-collect-protocol-fees-authority-ix";
@@ -1,9 +1,15 @@
 import { BN, Program } from "@coral-xyz/anchor";
 import { Instruction, PDA } from "@orca-so/common-sdk";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import {
+  AccountMeta,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
 import { ElysiumPool } from "../artifacts/pool";
-import { ElysiumPoolBumpsData } from "../types/public/anchor-types";
+import { ElysiumPoolBumpsData, LaunchFeeScheduleData } from "../types/public/anchor-types";
 
 /**
  * Parameters to initialize a ElysiumPool account.
@@ -88,3 +94,115 @@ export function initializePoolIx(
     signers: [tokenVaultAKeypair, tokenVaultBKeypair],
   };
 }
+
+/**
+ * Parameters to initialize a ElysiumPool account with launch settings.
+ *
+ * @category Instruction Types
+ * @param activationTimestamp - Optional unix timestamp before which swaps are rejected.
+ * @param activationSlot - Optional slot before which swaps are rejected.
+ * @param launchFeeSchedule - Optional fee schedule that decays to the pool's fee rate after
+ *                            activation. The excess fees go to LPs.
+ * @param creatorFeeRate - Optional portion of the fee, in basis points, owed to the funder as the
+ *                         pool creator.
+ * @param accessAuthority - Optional authority managing the allowlist of a permissioned pool.
+ * @param configExtension - The ElysiumPoolsConfigExtension of the config. Required to delay the
+ *                          activation, set a launch fee schedule or create a permissioned pool.
+ * @param permissionedPoolAuthority - The permissioned pool authority of the config extension.
+ *                                    Required to sign if an access authority is provided.
+ */
+export type InitPoolV2Params = InitPoolParams & {
+  activationTimestamp?: BN;
+  activationSlot?: BN;
+  launchFeeSchedule?: LaunchFeeScheduleData;
+  creatorFeeRate?: number;
+  accessAuthority?: PublicKey;
+  configExtension?: PublicKey;
+  permissionedPoolAuthority?: PublicKey;
+};
+
+/**
+ * Initializes a ElysiumPool account with an optional activation, launch fee schedule, creator fee
+ * and access authority.
+ *
+ * Special Errors
+ * `ActivationDelayMaxExceeded` - The activation is delayed past the max activation delay.
+ * `LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds the max launch fee decay period.
+ * `CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.
+ * `InvalidPermissionedPoolAuthority` - An access authority is provided without the signature of
+ *                                      the permissioned pool authority.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPoolV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePoolV2Ix(
+  program: Program<ElysiumPool>,
+  params: InitPoolV2Params
+): Instruction {
+  const {
+    initSqrtPrice,
+    tokenMintA,
+    tokenMintB,
+    poolsConfig,
+    poolPda,
+    feeTierKey,
+    tokenVaultAKeypair,
+    tokenVaultBKeypair,
+    tickSpacing,
+    funder,
+    activationTimestamp,
+    activationSlot,
+    launchFeeSchedule,
+    creatorFeeRate,
+    accessAuthority,
+    configExtension,
+    permissionedPoolAuthority,
+  } = params;
+
+  const poolBumps: ElysiumPoolBumpsData = {
+    poolBump: poolPda.bump,
+  };
+
+  const remainingAccounts: AccountMeta[] = [];
+  if (configExtension) {
+    remainingAccounts.push({ pubkey: configExtension, isSigner: false, isWritable: false });
+  }
+  if (permissionedPoolAuthority) {
+    remainingAccounts.push({ pubkey: permissionedPoolAuthority, isSigner: true, isWritable: false });
+  }
+
+  const ix = program.instruction.initializePoolV2(
+    poolBumps,
+    tickSpacing,
+    initSqrtPrice,
+    activationTimestamp ?? null,
+    activationSlot ?? null,
+    launchFeeSchedule ?? null,
+    creatorFeeRate ?? null,
+    accessAuthority ?? null,
+    {
+      accounts: {
+        poolsConfig,
+        tokenMintA,
+        tokenMintB,
+        funder,
+        pool: poolPda.publicKey,
+        tokenVaultA: tokenVaultAKeypair.publicKey,
+        tokenVaultB: tokenVaultBKeypair.publicKey,
+        feeTier: feeTierKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      },
+      remainingAccounts,
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [tokenVaultAKeypair, tokenVaultBKeypair],
+  };
+}
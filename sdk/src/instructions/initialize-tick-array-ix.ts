@@ -3,6 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { Instruction, PDA } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
 import { ElysiumPool } from "../artifacts/pool";
+import { tickArrayBitmapAccounts } from "../utils/instructions-util";
 
 /**
  * Parameters to initialize a TickArray account.
@@ -12,12 +13,14 @@ import { ElysiumPool } from "../artifacts/pool";
  * @param tickArrayPda - PDA for the tick array account that will be initialized
  * @param startTick - The starting tick index for this tick-array. Has to be a multiple of TickArray size & the tick spacing of this pool.
  * @param funder - The account that would fund the creation of this account
+ * @param tickArrayBitmap - PublicKey for the TickArrayBitmap of the pool. Required once the pool has one.
  */
 export type InitTickArrayParams = {
   pool: PublicKey;
   tickArrayPda: PDA;
   startTick: number;
   funder: PublicKey;
+  tickArrayBitmap?: PublicKey;
 };
 
 /**
@@ -35,7 +38,7 @@ export function initTickArrayIx(
   program: Program<ElysiumPool>,
   params: InitTickArrayParams
 ): Instruction {
-  const { pool, funder, tickArrayPda } = params;
+  const { pool, funder, tickArrayPda, tickArrayBitmap } = params;
 
   const ix = program.instruction.initializeTickArray(params.startTick, {
     accounts: {
@@ -44,6 +47,7 @@ export function initTickArrayIx(
       tickArray: tickArrayPda.publicKey,
       systemProgram: anchor.web3.SystemProgram.programId,
     },
+    remainingAccounts: tickArrayBitmapAccounts(tickArrayBitmap),
   });
 
   return {
@@ -0,0 +1,59 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { ElysiumPool } from "../artifacts/pool";
+
+/**
+ * Parameters to migrate a ElysiumPool account to the current layout.
+ *
+ * @category Instruction Types
+ * @param pool - PublicKey for the pool to migrate.
+ * @param funder - The account that pays for the additional rent of the grown pool account.
+ * @param rewardVaults - PublicKeys for the vaults of the initialized rewards of the pool.
+ */
+export type MigratePoolParams = {
+  pool: PublicKey;
+  funder: PublicKey;
+  rewardVaults: PublicKey[];
+};
+
+/**
+ * Migrates a ElysiumPool account initialized with the original layout to the current layout.
+ * Pools with the original layout cannot be used by any other instruction until they are migrated.
+ * Migrating a pool that already has the current layout does nothing.
+ *
+ * #### Special Errors
+ * - `UnsupportedPoolLayout` - The pool account has neither the original nor the current layout.
+ * - `InvalidRewardAccounts` - The vault of an initialized reward is missing.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - MigratePoolParams object
+ * @returns - Instruction to perform the action.
+ */
+export function migratePoolIx(
+  program: Program<ElysiumPool>,
+  params: MigratePoolParams
+): Instruction {
+  const { pool, funder, rewardVaults } = params;
+
+  const ix = program.instruction.migratePool({
+    accounts: {
+      pool,
+      funder,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    },
+    remainingAccounts: rewardVaults.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: false,
+    })),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
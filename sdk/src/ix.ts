@@ -50,6 +50,41 @@ export class ElysiumPoolIx {
     return ix.initializePoolIx(program, params);
   }
 
+  /**
+   * Initializes a ElysiumPool account with an optional activation, launch fee schedule, creator
+   * fee and access authority.
+   *
+   * #### Special Errors
+   * `ActivationDelayMaxExceeded` - The activation is delayed past the max activation delay.
+   * `LaunchFeeDecayPeriodMaxExceeded` - The decay period exceeds the max launch fee decay period.
+   * `CreatorFeeRateMaxExceeded` - The creator fee rate exceeds the config's max_creator_fee_rate.
+   * `InvalidPermissionedPoolAuthority` - An access authority is provided without the signature of
+   *                                      the permissioned pool authority.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPoolV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePoolV2Ix(program: Program<ElysiumPool>, params: ix.InitPoolV2Params) {
+    return ix.initializePoolV2Ix(program, params);
+  }
+
+  /**
+   * Migrates a ElysiumPool account initialized with the original layout to the current layout.
+   * Pools with the original layout cannot be used by any other instruction until they are migrated.
+   *
+   * #### Special Errors
+   * - `UnsupportedPoolLayout` - The pool account has neither the original nor the current layout.
+   * - `InvalidRewardAccounts` - The vault of an initialized reward is missing.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - MigratePoolParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static migratePoolIx(program: Program<ElysiumPool>, params: ix.MigratePoolParams) {
+    return ix.migratePoolIx(program, params);
+  }
+
   /**
    * Initialize reward for a ElysiumPool. A pool can only support up to a set number of rewards.
    * The initial emissionsPerSecond is set to 0.
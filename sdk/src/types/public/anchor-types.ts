@@ -47,7 +47,7 @@ export function getAccountSize(accountName: AccountName) {
  * Reserved bytes for each account used for calculating the account size.
  */
 const RESERVED_BYTES: ReservedBytes = {
-  [AccountName.ElysiumPoolsConfig]: 2,
+  [AccountName.ElysiumPoolsConfig]: 0,
   [AccountName.Position]: 0,
   [AccountName.TickArray]: 0,
   [AccountName.ElysiumPool]: 0,
@@ -75,6 +75,7 @@ export type ElysiumPoolsConfigData = {
   rewardEmissionsSuperAuthority: PublicKey;
   defaultFeeRate: number;
   defaultProtocolFeeRate: number;
+  maxCreatorFeeRate: number;
 };
 
 /**
@@ -86,6 +87,15 @@ export type ElysiumPoolRewardInfoData = {
   authority: PublicKey;
   emissionsPerSecondX64: BN;
   growthGlobalX64: BN;
+  emissionsStartTimestamp: BN;
+  emissionsEndTimestamp: BN;
+  emissionsBudget: BN;
+  totalFunded: BN;
+  totalEmitted: BN;
+  totalCollected: BN;
+  uncreditedX64: BN;
+  totalCredited: BN;
+  unfundedEmissionsX64: BN;
 };
 
 /**
@@ -95,6 +105,15 @@ export type ElysiumPoolBumpsData = {
   poolBump: number;
 };
 
+/**
+ * @category Solana Accounts
+ */
+export type LaunchFeeScheduleData = {
+  initialFeeRate: number;
+  decayPeriod: BN;
+  decayInSlots: boolean;
+};
+
 /**
  * @category Solana Accounts
  */
@@ -117,6 +136,17 @@ export type ElysiumPoolData = {
   rewardLastUpdatedTimestamp: BN;
   rewardInfos: ElysiumPoolRewardInfoData[];
   tickSpacing: number;
+  rewardExtension: PublicKey;
+  activationTimestamp: BN;
+  activationSlot: BN;
+  launchFeeSchedule: LaunchFeeScheduleData;
+  creator: PublicKey;
+  creatorFeeRate: number;
+  creatorFeeOwedA: BN;
+  creatorFeeOwedB: BN;
+  tickArrayBitmap: PublicKey;
+  hookProgram: PublicKey;
+  accessAuthority: PublicKey;
 };
 
 /**
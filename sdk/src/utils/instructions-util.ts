@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { SystemProgram } from "@solana/web3.js";
+import { AccountMeta, PublicKey, SystemProgram } from "@solana/web3.js";
 import { OpenPositionParams } from "../instructions";
 
 export function openPositionAccounts(params: OpenPositionParams) {
@@ -25,3 +25,11 @@ export function openPositionAccounts(params: OpenPositionParams) {
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   };
 }
+
+/**
+ * Remaining accounts that keep the TickArrayBitmap of a pool up to date. Once a pool has a
+ * bitmap, instructions that initialize tick arrays or modify liquidity fail without it.
+ */
+export function tickArrayBitmapAccounts(tickArrayBitmap?: PublicKey): AccountMeta[] {
+  return tickArrayBitmap ? [{ pubkey: tickArrayBitmap, isSigner: false, isWritable: true }] : [];
+}
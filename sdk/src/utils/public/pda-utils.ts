@@ -11,6 +11,7 @@ const PDA_METADATA_SEED = "metadata";
 const PDA_TICK_ARRAY_SEED = "tick_array";
 const PDA_FEE_TIER_SEED = "fee_tier";
 const PDA_ORACLE_SEED = "oracle";
+const PDA_TICK_ARRAY_BITMAP_SEED = "tick_array_bitmap";
 const PDA_POSITION_BUNDLE_SEED = "position_bundle";
 const PDA_BUNDLED_POSITION_SEED = "bundled_position";
 
@@ -167,6 +168,19 @@ export class PDAUtil {
     );
   }
 
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param poolAddress
+   * @returns
+   */
+  public static getTickArrayBitmap(programId: PublicKey, poolAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_TICK_ARRAY_BITMAP_SEED), poolAddress.toBuffer()],
+      programId
+    );
+  }
+
   /**
    * @category Program Derived Addresses
    * @param programId
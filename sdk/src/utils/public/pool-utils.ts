@@ -5,6 +5,7 @@ import BN from "bn.js";
 import Decimal from "decimal.js";
 import { ElysiumPoolData, ElysiumPoolRewardInfoData } from "../../types/public";
 import { TOKEN_MINTS } from "../constants";
+import { PDAUtil } from "./pda-utils";
 import { PriceMath } from "./price-math";
 import { TokenType } from "./types";
 
@@ -20,6 +21,24 @@ export class PoolUtil {
     );
   }
 
+  /**
+   * Return the address of the TickArrayBitmap of a ElysiumPool, which instructions that initialize
+   * tick arrays or modify liquidity require once the pool has one.
+   *
+   * @param programId The ElysiumPools program id
+   * @param poolAddress The address of the ElysiumPool
+   * @param pool The ElysiumPool data
+   * @returns The TickArrayBitmap address. undefined if the pool does not have a TickArrayBitmap.
+   */
+  public static getTickArrayBitmap(
+    programId: PublicKey,
+    poolAddress: PublicKey,
+    pool: ElysiumPoolData
+  ): PublicKey | undefined {
+    const tickArrayBitmap = PDAUtil.getTickArrayBitmap(programId, poolAddress).publicKey;
+    return pool.tickArrayBitmap.equals(tickArrayBitmap) ? tickArrayBitmap : undefined;
+  }
+
   /**
    * Return the corresponding token type (TokenA/B) for this mint key for a ElysiumPool.
    *